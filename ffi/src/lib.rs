@@ -0,0 +1,9 @@
+//! C-compatible library - both dynamic and static - exporting the
+//! functions of the `ffi` module of [chinese_rand], so that native apps
+//! can link it without affecting the Rust users of the crate:
+//!
+//! ```text
+//! cargo build --release -p chinese-rand-ffi
+//! ```
+
+pub use chinese_rand::ffi::*;
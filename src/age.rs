@@ -0,0 +1,95 @@
+use crate::{ChineseFormatGenerator, RawGenerator};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Count, Variant};
+
+/// Age in years - such as `两岁` or `三十五岁`.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// assert_eq!(Age(2).to_chinese(Variant::Simplified), "两岁");
+/// assert_eq!(Age(35).to_chinese(Variant::Traditional), "三十五歲");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Age(pub u8);
+
+impl ChineseFormat for Age {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [Count(self.0 as u128), ("岁", "歲")]).collect()
+    }
+}
+
+/// Shapes the range and the distribution of a generated [Age].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AgeProfile {
+    /// From 1 to 12, uniformly distributed.
+    Child,
+
+    /// From 13 to 17, uniformly distributed.
+    Teen,
+
+    /// From 18 to 64, peaking around 41.
+    Adult,
+
+    /// From 65 to 100, becoming rarer as age increases.
+    Elderly,
+
+    /// Any of the above - adults being as likely as
+    /// children, teens and elderly people together.
+    Any,
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a believable random [Age], according to the given [AgeProfile].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// fastrand::seed(90);
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    ///
+    /// let age = generator.age(AgeProfile::Child);
+    /// assert_eq!(age.to_chinese(Variant::Simplified), "九岁");
+    ///
+    /// let age = generator.age(AgeProfile::Adult);
+    /// assert_eq!(age.to_chinese(Variant::Simplified), "四十三岁");
+    ///
+    /// let age = generator.age(AgeProfile::Elderly);
+    /// assert_eq!(age.to_chinese(Variant::Simplified), "七十五岁");
+    ///
+    /// let age = generator.age(AgeProfile::Any);
+    /// assert_eq!(age.to_chinese(Variant::Simplified), "十六岁");
+    /// ```
+    pub fn age(&self, profile: AgeProfile) -> Age {
+        match profile {
+            AgeProfile::Child => Age(self.raw_generator.u8(1..=12)),
+
+            AgeProfile::Teen => Age(self.raw_generator.u8(13..=17)),
+
+            AgeProfile::Adult => {
+                Age(18 + self.raw_generator.u8(0..=23) + self.raw_generator.u8(0..=23))
+            }
+
+            AgeProfile::Elderly => Age(65
+                + self
+                    .raw_generator
+                    .u8(0..=35)
+                    .min(self.raw_generator.u8(0..=35))),
+
+            AgeProfile::Any => {
+                let profile = match self.raw_generator.u8(0..=5) {
+                    0 => AgeProfile::Child,
+                    1 => AgeProfile::Teen,
+                    2 => AgeProfile::Elderly,
+                    _ => AgeProfile::Adult,
+                };
+
+                self.age(profile)
+            }
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{
+    check_range, ChineseFormatGenerator, ChineseRandError, ChineseRandResult, RawGenerator,
+};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Count, CountBase, Variant};
+use std::ops::RangeInclusive;
+
+const WAN: CountBase = 10_000;
+const YI: CountBase = 100_000_000;
+
+/// How an [Approximation] hedges its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ApproxStyle {
+    /// Rounded value preceded by `大约` - as in `大约五百`.
+    Dayue,
+
+    /// Rounded value followed by `左右` - as in `五十左右`.
+    Zuoyou,
+
+    /// `几` replacing the unknown digits - as in `十几` or `几百`.
+    Ji,
+
+    /// Two adjacent digits - as in `两三` or `七八百`.
+    Adjacent,
+}
+
+/// Hedged quantity - the exact value being rendered
+/// according to its [ApproxStyle].
+///
+/// Measure words can be appended via [chinese_vec]:
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{chinese_vec, ChineseFormat, ChineseVec, Variant};
+///
+/// let approximation = Approximation { value: 537, style: ApproxStyle::Dayue };
+/// assert_eq!(approximation.to_chinese(Variant::Simplified), "大约五百");
+///
+/// let approximation = Approximation { value: 48, style: ApproxStyle::Zuoyou };
+/// assert_eq!(approximation.to_chinese(Variant::Simplified), "五十左右");
+///
+/// let approximation = Approximation { value: 14, style: ApproxStyle::Ji };
+/// let with_unit: ChineseVec = chinese_vec!(Variant::Simplified, [approximation, "个"]);
+/// assert_eq!(with_unit.to_chinese(Variant::Simplified), "十几个");
+///
+/// let approximation = Approximation { value: 350, style: ApproxStyle::Ji };
+/// assert_eq!(approximation.to_chinese(Variant::Simplified), "几百");
+///
+/// let approximation = Approximation { value: 37, style: ApproxStyle::Ji };
+/// assert_eq!(approximation.to_chinese(Variant::Traditional), "三十幾");
+///
+/// let approximation = Approximation { value: 150_000, style: ApproxStyle::Ji };
+/// assert_eq!(approximation.to_chinese(Variant::Simplified), "十几万");
+///
+/// let approximation = Approximation { value: 2, style: ApproxStyle::Adjacent };
+/// assert_eq!(approximation.to_chinese(Variant::Simplified), "两三");
+///
+/// let approximation = Approximation { value: 25, style: ApproxStyle::Adjacent };
+/// assert_eq!(approximation.to_chinese(Variant::Simplified), "二三十");
+///
+/// let approximation = Approximation { value: 930, style: ApproxStyle::Adjacent };
+/// assert_eq!(approximation.to_chinese(Variant::Simplified), "八九百");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Approximation {
+    /// The exact value being approximated - expected to be positive.
+    pub value: CountBase,
+
+    /// How the value is hedged.
+    pub style: ApproxStyle,
+}
+
+impl Approximation {
+    /// The value rounded to its most significant digit - as shown by
+    /// [ApproxStyle::Dayue] and [ApproxStyle::Zuoyou].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// assert_eq!(Approximation { value: 7, style: ApproxStyle::Dayue }.rounded(), 7);
+    /// assert_eq!(Approximation { value: 537, style: ApproxStyle::Dayue }.rounded(), 500);
+    /// assert_eq!(Approximation { value: 48, style: ApproxStyle::Zuoyou }.rounded(), 50);
+    /// ```
+    pub fn rounded(&self) -> CountBase {
+        let magnitude = leading_magnitude(self.value);
+        let floor = self.value / magnitude * magnitude;
+
+        if self.value % magnitude >= magnitude / 2 && magnitude > 1 {
+            floor.saturating_add(magnitude)
+        } else {
+            floor
+        }
+    }
+}
+
+impl ChineseFormat for Approximation {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self.style {
+            ApproxStyle::Dayue => {
+                chinese_vec!(variant, [("大约", "大約"), Count(self.rounded())]).collect()
+            }
+
+            ApproxStyle::Zuoyou => chinese_vec!(variant, [Count(self.rounded()), "左右"]).collect(),
+
+            ApproxStyle::Ji => Chinese {
+                logograms: render_ji(self.value, variant),
+                omissible: false,
+            },
+
+            ApproxStyle::Adjacent => Chinese {
+                logograms: render_adjacent(self.value, variant),
+                omissible: false,
+            },
+        }
+    }
+}
+
+fn leading_magnitude(value: CountBase) -> CountBase {
+    let mut magnitude = 1;
+
+    while value / magnitude >= 10 {
+        magnitude *= 10;
+    }
+
+    magnitude
+}
+
+fn render_large(
+    value: CountBase,
+    variant: Variant,
+    render: fn(CountBase, Variant) -> String,
+) -> Option<String> {
+    if value >= YI {
+        Some(render(value / YI, variant) + &("亿", "億").to_chinese(variant).logograms)
+    } else if value >= WAN {
+        Some(render(value / WAN, variant) + &("万", "萬").to_chinese(variant).logograms)
+    } else {
+        None
+    }
+}
+
+fn render_ji(value: CountBase, variant: Variant) -> String {
+    if let Some(large) = render_large(value, variant, render_ji) {
+        return large;
+    }
+
+    let ji = ("几", "幾").to_chinese(variant).logograms;
+
+    match value {
+        0..=9 => ji,
+        10..=19 => format!("十{}", ji),
+        20..=99 if value.is_multiple_of(10) => format!("{}十", ji),
+        20..=99 => Count(value / 10 * 10).to_chinese(variant).logograms + &ji,
+        100..=999 => format!("{}百", ji),
+        _ => format!("{}千", ji),
+    }
+}
+
+fn render_adjacent(value: CountBase, variant: Variant) -> String {
+    if let Some(large) = render_large(value, variant, render_adjacent) {
+        return large;
+    }
+
+    let magnitude = leading_magnitude(value.max(1));
+    let leading = (value.max(1) / magnitude).min(8);
+
+    match magnitude {
+        1 => format!(
+            "{}{}",
+            Count(leading).to_chinese(variant).logograms,
+            Count(leading + 1).to_chinese(variant).logograms
+        ),
+
+        10 => format!(
+            "{}{}十",
+            (leading as i128).to_chinese(variant).logograms,
+            (leading as i128 + 1).to_chinese(variant).logograms
+        ),
+
+        _ => format!(
+            "{}{}{}",
+            Count(leading).to_chinese(variant).logograms,
+            Count(leading + 1).to_chinese(variant).logograms,
+            if magnitude == 100 { "百" } else { "千" }
+        ),
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [Approximation] of a positive value in the given range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if the range is empty,
+    /// and with [ChineseRandError::InvalidRange] if it only contains 0.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// let approximation = generator.approximate(1..=1000, ApproxStyle::Dayue)?;
+    /// assert_eq!(approximation.to_chinese(Variant::Simplified), "大约六百");
+    ///
+    /// let approximation = generator.approximate(1..=1000, ApproxStyle::Zuoyou)?;
+    /// assert_eq!(approximation.to_chinese(Variant::Simplified), "七百左右");
+    ///
+    /// let approximation = generator.approximate(10..=99, ApproxStyle::Ji)?;
+    /// assert_eq!(approximation.to_chinese(Variant::Simplified), "四十几");
+    ///
+    /// let approximation = generator.approximate(1..=9, ApproxStyle::Adjacent)?;
+    /// assert_eq!(approximation.to_chinese(Variant::Simplified), "五六");
+    ///
+    /// assert!(generator.approximate(0..=0, ApproxStyle::Ji).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn approximate(
+        &self,
+        range: RangeInclusive<CountBase>,
+        style: ApproxStyle,
+    ) -> ChineseRandResult<Approximation> {
+        check_range(&range)?;
+
+        if *range.end() == 0 {
+            return Err(ChineseRandError::InvalidRange(
+                "Cannot approximate 0".to_string(),
+            ));
+        }
+
+        let value = self
+            .raw_generator
+            .u128((*range.start()).max(1)..=*range.end());
+
+        Ok(Approximation { value, style })
+    }
+}
@@ -0,0 +1,416 @@
+use crate::{check_range, numeric::gcd, ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Fraction, Variant};
+use std::ops::RangeInclusive;
+
+/// The four basic operators of school math.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ArithmeticOperator {
+    /// `加`.
+    Addition,
+
+    /// `减`.
+    Subtraction,
+
+    /// `乘`.
+    Multiplication,
+
+    /// `除以`.
+    Division,
+}
+
+impl ArithmeticOperator {
+    /// All the operators, in declaration order.
+    pub const ALL: [ArithmeticOperator; 4] = [
+        ArithmeticOperator::Addition,
+        ArithmeticOperator::Subtraction,
+        ArithmeticOperator::Multiplication,
+        ArithmeticOperator::Division,
+    ];
+}
+
+impl ChineseFormat for ArithmeticOperator {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            ArithmeticOperator::Addition => "加".to_chinese(variant),
+            ArithmeticOperator::Subtraction => ("减", "減").to_chinese(variant),
+            ArithmeticOperator::Multiplication => "乘".to_chinese(variant),
+            ArithmeticOperator::Division => "除以".to_chinese(variant),
+        }
+    }
+}
+
+/// Binary expression between two non-negative operands - such as `三加五`.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let expression = ArithmeticExpression {
+///     left: 12,
+///     operator: ArithmeticOperator::Subtraction,
+///     right: 5,
+/// };
+/// assert_eq!(expression.to_chinese(Variant::Simplified), "十二减五");
+/// assert_eq!(expression.to_chinese(Variant::Traditional), "十二減五");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArithmeticExpression {
+    /// The left operand.
+    pub left: u32,
+
+    /// The operator.
+    pub operator: ArithmeticOperator,
+
+    /// The right operand.
+    pub right: u32,
+}
+
+impl ArithmeticExpression {
+    /// Computes the exact [ArithmeticAnswer] - or [None] when dividing by 0.
+    ///
+    /// Non-integer quotients are returned as a reduced [Fraction].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::Fraction;
+    ///
+    /// let expression = ArithmeticExpression {
+    ///     left: 3,
+    ///     operator: ArithmeticOperator::Subtraction,
+    ///     right: 5,
+    /// };
+    /// assert_eq!(expression.answer(), Some(ArithmeticAnswer::Integer(-2)));
+    ///
+    /// let expression = ArithmeticExpression {
+    ///     left: 4,
+    ///     operator: ArithmeticOperator::Division,
+    ///     right: 6,
+    /// };
+    /// assert_eq!(
+    ///     expression.answer(),
+    ///     Some(ArithmeticAnswer::Fraction(Fraction::try_new(3, 2).unwrap()))
+    /// );
+    ///
+    /// let expression = ArithmeticExpression {
+    ///     left: 4,
+    ///     operator: ArithmeticOperator::Division,
+    ///     right: 0,
+    /// };
+    /// assert_eq!(expression.answer(), None);
+    ///
+    /// let expression = ArithmeticExpression {
+    ///     left: u32::MAX,
+    ///     operator: ArithmeticOperator::Multiplication,
+    ///     right: u32::MAX,
+    /// };
+    /// assert_eq!(
+    ///     expression.answer(),
+    ///     Some(ArithmeticAnswer::Integer(u32::MAX as i128 * u32::MAX as i128))
+    /// );
+    /// ```
+    pub fn answer(&self) -> Option<ArithmeticAnswer> {
+        let left = self.left as i128;
+        let right = self.right as i128;
+
+        Some(match self.operator {
+            ArithmeticOperator::Addition => ArithmeticAnswer::Integer(left + right),
+
+            ArithmeticOperator::Subtraction => ArithmeticAnswer::Integer(left - right),
+
+            ArithmeticOperator::Multiplication => ArithmeticAnswer::Integer(left * right),
+
+            ArithmeticOperator::Division => {
+                if right == 0 {
+                    return None;
+                }
+
+                if left % right == 0 {
+                    ArithmeticAnswer::Integer(left / right)
+                } else {
+                    let divisor = gcd(self.left as u128, self.right as u128) as u32;
+
+                    ArithmeticAnswer::Fraction(
+                        Fraction::try_new(
+                            (self.right / divisor) as u128,
+                            (self.left / divisor) as i128,
+                        )
+                        .expect("Denominator non-zero by construction"),
+                    )
+                }
+            }
+        })
+    }
+}
+
+impl ChineseFormat for ArithmeticExpression {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.left, self.operator, self.right]).collect()
+    }
+}
+
+/// The result of an [ArithmeticExpression].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ArithmeticAnswer {
+    /// Integer result - possibly negative.
+    Integer(i128),
+
+    /// Reduced fraction, for non-exact divisions.
+    Fraction(Fraction),
+}
+
+impl ChineseFormat for ArithmeticAnswer {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            ArithmeticAnswer::Integer(value) => value.to_chinese(variant),
+            ArithmeticAnswer::Fraction(fraction) => fraction.to_chinese(variant),
+        }
+    }
+}
+
+/// An [ArithmeticExpression] together with its [ArithmeticAnswer].
+///
+/// As a whole, it is rendered as a complete equation:
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let expression = ArithmeticExpression {
+///     left: 3,
+///     operator: ArithmeticOperator::Addition,
+///     right: 5,
+/// };
+///
+/// let problem = ArithmeticProblem {
+///     expression,
+///     answer: expression.answer().unwrap(),
+/// };
+/// assert_eq!(problem.to_chinese(Variant::Simplified), "三加五等于八");
+/// assert_eq!(problem.to_chinese(Variant::Traditional), "三加五等於八");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArithmeticProblem {
+    /// The question.
+    pub expression: ArithmeticExpression,
+
+    /// The exact result of the expression.
+    pub answer: ArithmeticAnswer,
+}
+
+impl ChineseFormat for ArithmeticProblem {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.expression, ("等于", "等於"), self.answer]).collect()
+    }
+}
+
+/// Parameters for the random creation of [ArithmeticProblem].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ArithmeticParams {
+    /// The operators to choose from - an empty list meaning all of them.
+    pub operators: Vec<ArithmeticOperator>,
+
+    /// The range of both operands; divisors are never 0.
+    pub operand_range: RangeInclusive<u32>,
+
+    /// Whether subtractions can have a negative result.
+    pub allow_negative: bool,
+
+    /// Whether divisions must always have an integer result.
+    pub exact_division: bool,
+}
+
+/// By default, [ArithmeticParams] describes all the operators,
+/// with operands between 0 and 10, no negative results
+/// and exact divisions only.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// assert_eq!(ArithmeticParams::default(), ArithmeticParams {
+///     operators: ArithmeticOperator::ALL.to_vec(),
+///     operand_range: 0..=10,
+///     allow_negative: false,
+///     exact_division: true
+/// });
+///
+/// let customized = ArithmeticParams::default()
+///     .with_operators(vec![ArithmeticOperator::Division])
+///     .with_operand_range(1..=100)
+///     .with_negative(true)
+///     .with_exact_division(false);
+///
+/// assert_eq!(customized, ArithmeticParams {
+///     operators: vec![ArithmeticOperator::Division],
+///     operand_range: 1..=100,
+///     allow_negative: true,
+///     exact_division: false
+/// });
+/// ```
+impl Default for ArithmeticParams {
+    fn default() -> Self {
+        Self {
+            operators: ArithmeticOperator::ALL.to_vec(),
+            operand_range: 0..=10,
+            allow_negative: false,
+            exact_division: true,
+        }
+    }
+}
+
+impl ArithmeticParams {
+    /// Sets the operators to choose from.
+    pub fn with_operators(mut self, operators: Vec<ArithmeticOperator>) -> Self {
+        self.operators = operators;
+        self
+    }
+
+    /// Sets the range of the operands.
+    pub fn with_operand_range(mut self, operand_range: RangeInclusive<u32>) -> Self {
+        self.operand_range = operand_range;
+        self
+    }
+
+    /// Sets whether subtractions can have a negative result.
+    pub fn with_negative(mut self, allow_negative: bool) -> Self {
+        self.allow_negative = allow_negative;
+        self
+    }
+
+    /// Sets whether divisions must always have an integer result.
+    pub fn with_exact_division(mut self, exact_division: bool) -> Self {
+        self.exact_division = exact_division;
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [ArithmeticProblem], whose answer is always defined.
+    ///
+    /// For divisions, the divisor is drawn from the operand range without 0,
+    /// falling back to 1 when the range only contains 0; with `exact_division`,
+    /// the quotient is drawn first - among the ones allowing both the dividend
+    /// and the divisor within the range - and then the divisor, among
+    /// the ones keeping the dividend within the range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// let problem = generator.arithmetic(
+    ///     ArithmeticParams::default().with_operators(vec![ArithmeticOperator::Addition])
+    /// )?;
+    /// assert_eq!(problem.expression.to_chinese(Variant::Simplified), "三加八");
+    /// assert_eq!(problem.answer.to_chinese(Variant::Simplified), "十一");
+    ///
+    /// let problem = generator.arithmetic(
+    ///     ArithmeticParams::default().with_operators(vec![ArithmeticOperator::Subtraction])
+    /// )?;
+    /// assert_eq!(problem.to_chinese(Variant::Simplified), "五减二等于三");
+    ///
+    /// let problem = generator.arithmetic(
+    ///     ArithmeticParams::default().with_operators(vec![ArithmeticOperator::Division])
+    /// )?;
+    /// assert_eq!(problem.to_chinese(Variant::Simplified), "零除以二等于零");
+    ///
+    /// let problem = generator.arithmetic(
+    ///     ArithmeticParams::default()
+    ///         .with_operators(vec![ArithmeticOperator::Division])
+    ///         .with_operand_range(1..=9)
+    ///         .with_exact_division(false)
+    /// )?;
+    /// assert_eq!(problem.to_chinese(Variant::Simplified), "三除以五等于五分之三");
+    ///
+    /// let mut quotients = std::collections::BTreeSet::new();
+    ///
+    /// for _ in 0..100 {
+    ///     let problem = generator.arithmetic(
+    ///         ArithmeticParams::default()
+    ///             .with_operators(vec![ArithmeticOperator::Division])
+    ///             .with_operand_range(100_000..=1_000_000)
+    ///     )?;
+    ///     assert!((100_000..=1_000_000).contains(&problem.expression.left));
+    ///     assert!((100_000..=1_000_000).contains(&problem.expression.right));
+    ///
+    ///     quotients.insert(problem.expression.left / problem.expression.right);
+    /// }
+    /// assert_eq!(quotients, (1..=10).collect());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn arithmetic(&self, params: ArithmeticParams) -> ChineseRandResult<ArithmeticProblem> {
+        check_range(&params.operand_range)?;
+
+        let operators: &[ArithmeticOperator] = if params.operators.is_empty() {
+            &ArithmeticOperator::ALL
+        } else {
+            &params.operators
+        };
+
+        let operator = operators[self.raw_generator.u64(0..=(operators.len() as u64 - 1)) as usize];
+
+        let (start, end) = params.operand_range.into_inner();
+
+        let (left, right) = match operator {
+            ArithmeticOperator::Division => {
+                let lowest_divisor = start.max(1);
+
+                if params.exact_division {
+                    let quotient = self
+                        .raw_generator
+                        .u32(start.div_ceil(end.max(1))..=end / lowest_divisor);
+
+                    let divisor = if quotient == 0 {
+                        self.raw_generator.u32(lowest_divisor..=end.max(1))
+                    } else {
+                        self.raw_generator
+                            .u32(lowest_divisor.max(start.div_ceil(quotient))..=end / quotient)
+                    };
+
+                    (quotient * divisor, divisor)
+                } else {
+                    let divisor = self.raw_generator.u32(lowest_divisor..=end.max(1));
+
+                    (self.raw_generator.u32(start..=end), divisor)
+                }
+            }
+
+            _ => {
+                let left = self.raw_generator.u32(start..=end);
+                let right = self.raw_generator.u32(start..=end);
+
+                if operator == ArithmeticOperator::Subtraction
+                    && !params.allow_negative
+                    && left < right
+                {
+                    (right, left)
+                } else {
+                    (left, right)
+                }
+            }
+        };
+
+        let expression = ArithmeticExpression {
+            left,
+            operator,
+            right,
+        };
+
+        Ok(ArithmeticProblem {
+            expression,
+            answer: expression
+                .answer()
+                .expect("Divisor non-zero by construction"),
+        })
+    }
+}
@@ -0,0 +1,273 @@
+//! Generation drawing its entropy from asynchronous sources - like
+//! hardware RNG services or remote seeds - in server environments
+//! generating exercise content on demand.
+//!
+//! The core concept is the [AsyncChineseFormatGenerator] struct, backed by
+//! an [AsyncRawGenerator]; it runs any generator method on the entropy
+//! fetched in advance - awaiting more entropy and running the method again,
+//! from the same starting point, whenever the fetched entropy is not enough.
+//!
+//! No executor is required: the futures can be awaited within any runtime.
+//!
+//! **Required feature**: `async`.
+
+use crate::{
+    ChineseFormatGenerator, ChineseRandResult, GeneratorConfig, Level, MutRawGenerator,
+    RawGeneratorMut, RegionProfile, DEFAULT_MAX_RETRIES,
+};
+use chinese_format::{Count, CountBase};
+use std::{cell::Cell, future::Future, ops::RangeInclusive};
+
+const INITIAL_FETCH: usize = 16;
+
+/// Asynchronous source of random bits.
+///
+/// **Required feature**: `async`.
+pub trait AsyncRawGenerator {
+    /// Fills the buffer with uniformly distributed random bits.
+    ///
+    /// Failures of the source - like network errors - should be reported
+    /// as [ChineseRandError::Io](crate::ChineseRandError::Io).
+    fn fill(&mut self, buffer: &mut [u64]) -> impl Future<Output = ChineseRandResult<()>>;
+}
+
+/// [RawGeneratorMut] replaying the entropy fetched by an [AsyncChineseFormatGenerator].
+///
+/// When the entropy is over, it yields placeholder values - and the generation
+/// is transparently run again with more entropy.
+///
+/// **Required feature**: `async`.
+pub struct EntropyReplay<'a> {
+    words: &'a [u64],
+    exhausted: &'a Cell<bool>,
+    consumed: &'a Cell<usize>,
+}
+
+impl RawGeneratorMut for EntropyReplay<'_> {
+    fn next_u64(&mut self) -> u64 {
+        match self.words.get(self.consumed.get()) {
+            Some(word) => {
+                self.consumed.set(self.consumed.get() + 1);
+                *word
+            }
+
+            None => {
+                self.exhausted.set(true);
+                // Never rejected by the sampling of MutRawGenerator
+                u64::MAX
+            }
+        }
+    }
+}
+
+/// The [ChineseFormatGenerator] on which the methods are run
+/// by an [AsyncChineseFormatGenerator].
+///
+/// **Required feature**: `async`.
+pub type ReplayedGenerator<'a> = ChineseFormatGenerator<MutRawGenerator<EntropyReplay<'a>>>;
+
+/// Asynchronous counterpart of [ChineseFormatGenerator], backed
+/// by an [AsyncRawGenerator].
+///
+/// ```
+/// use chinese_rand::{*, asynchronous::*};
+/// use chinese_format::{ChineseFormat, Variant};
+/// use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+///
+/// struct RemoteSeeds(u64);
+///
+/// impl AsyncRawGenerator for RemoteSeeds {
+///     async fn fill(&mut self, buffer: &mut [u64]) -> ChineseRandResult<()> {
+///         for word in buffer {
+///             self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+///             *word = self.0;
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// # fn block_on<T>(future: impl Future<Output = T>) -> T {
+/// #     let mut future = pin!(future);
+/// #     let mut context = Context::from_waker(Waker::noop());
+/// #     loop {
+/// #         if let Poll::Ready(value) = future.as_mut().poll(&mut context) {
+/// #             return value;
+/// #         }
+/// #     }
+/// # }
+/// #
+/// # fn main() -> GenericResult<()> {
+/// block_on(async {
+///     let mut generator = AsyncChineseFormatGenerator::new(RemoteSeeds(90))
+///         .with_level(Level::Beginner);
+///
+///     let integer = generator.integer(i128::MIN..=i128::MAX).await?;
+///     assert_eq!(integer, 61);
+///
+///     let count = generator.count(0..=100_000).await?;
+///     assert_eq!(count.to_chinese(Variant::Simplified), "六十");
+///
+///     let fraction = generator.generate(|generator| generator.fraction(2..=9, 1..=9)).await?;
+///     assert_eq!(fraction.to_chinese(Variant::Simplified), "五分之九");
+///
+///     Ok(())
+/// })
+/// # }
+/// ```
+///
+/// **Required feature**: `async`.
+pub struct AsyncChineseFormatGenerator<A: AsyncRawGenerator> {
+    source: A,
+    entropy: Vec<u64>,
+    level: Option<Level>,
+    region: Option<RegionProfile>,
+    max_retries: usize,
+    config: GeneratorConfig,
+}
+
+impl<A: AsyncRawGenerator> AsyncChineseFormatGenerator<A> {
+    /// Creates a generator drawing entropy from the given source.
+    pub fn new(source: A) -> Self {
+        Self {
+            source,
+            entropy: Vec::new(),
+            level: None,
+            region: None,
+            max_retries: DEFAULT_MAX_RETRIES,
+            config: GeneratorConfig::default(),
+        }
+    }
+
+    /// Tunes the generation for the given [Level] - just like
+    /// [ChineseFormatGenerator::with_level].
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Tunes the generation for the given [RegionProfile] - just like
+    /// [ChineseFormatGenerator::with_region].
+    pub fn with_region(mut self, region: RegionProfile) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Applies the given [GeneratorConfig] - just like
+    /// [ChineseFormatGenerator::with_config].
+    pub fn with_config(mut self, config: GeneratorConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the retry budget - just like [ChineseFormatGenerator::with_max_retries].
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Runs the given function on a [ReplayedGenerator], awaiting
+    /// entropy from the source whenever needed.
+    ///
+    /// The function can be run more than once, always from the same
+    /// starting point: only the result of the last run is returned -
+    /// so the sequence of values is the one that a synchronous generator
+    /// would produce from the same entropy.
+    ///
+    /// ```
+    /// use chinese_rand::{*, asynchronous::*};
+    /// use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+    ///
+    /// struct Lcg(u64);
+    ///
+    /// impl RawGeneratorMut for Lcg {
+    ///     fn next_u64(&mut self) -> u64 {
+    ///         self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// impl AsyncRawGenerator for Lcg {
+    ///     async fn fill(&mut self, buffer: &mut [u64]) -> ChineseRandResult<()> {
+    ///         buffer.fill_with(|| self.next_u64());
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// # fn block_on<T>(future: impl Future<Output = T>) -> T {
+    /// #     let mut future = pin!(future);
+    /// #     let mut context = Context::from_waker(Waker::noop());
+    /// #     loop {
+    /// #         if let Poll::Ready(value) = future.as_mut().poll(&mut context) {
+    /// #             return value;
+    /// #         }
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> GenericResult<()> {
+    /// let synchronous = ChineseFormatGenerator::from_raw_generator(MutRawGenerator::new(Lcg(7)));
+    /// let expected: Vec<(TrickyKind, i128)> = (0..50)
+    ///     .map(|_| synchronous.tricky_numbers().any())
+    ///     .collect::<ChineseRandResult<_>>()?;
+    ///
+    /// let mut asynchronous = AsyncChineseFormatGenerator::new(Lcg(7));
+    /// let mut actual = Vec::new();
+    ///
+    /// for _ in 0..50 {
+    ///     actual.push(block_on(asynchronous.generate(|generator| generator.tricky_numbers().any()))?);
+    /// }
+    ///
+    /// assert_eq!(actual, expected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn generate<T>(
+        &mut self,
+        generate: impl Fn(&ReplayedGenerator) -> ChineseRandResult<T>,
+    ) -> ChineseRandResult<T> {
+        let mut fetch = INITIAL_FETCH;
+
+        loop {
+            let exhausted = Cell::new(false);
+            let consumed = Cell::new(0);
+
+            let result = {
+                let replay = EntropyReplay {
+                    words: &self.entropy,
+                    exhausted: &exhausted,
+                    consumed: &consumed,
+                };
+
+                let mut generator =
+                    ChineseFormatGenerator::from_raw_generator(MutRawGenerator::new(replay))
+                        .with_max_retries(self.max_retries)
+                        .with_config(self.config.clone());
+                generator.level = self.level;
+                generator.region = self.region;
+
+                generate(&generator)
+            };
+
+            if !exhausted.get() {
+                self.entropy.drain(..consumed.get());
+                return result;
+            }
+
+            let start = self.entropy.len();
+            self.entropy.resize(start + fetch, 0);
+            self.source.fill(&mut self.entropy[start..]).await?;
+            fetch *= 2;
+        }
+    }
+
+    /// Asynchronous version of [ChineseFormatGenerator::integer].
+    pub async fn integer(&mut self, range: RangeInclusive<i128>) -> ChineseRandResult<i128> {
+        self.generate(|generator| generator.integer(range.clone()))
+            .await
+    }
+
+    /// Asynchronous version of [ChineseFormatGenerator::count].
+    pub async fn count(&mut self, range: RangeInclusive<CountBase>) -> ChineseRandResult<Count> {
+        self.generate(|generator| generator.count(range.clone()))
+            .await
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use chinese_format::{Chinese, ChineseFormat, Fraction, Variant};
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::{DateParams, LinearTimeParams};
+#[cfg(feature = "currency")]
+use crate::RenminbiParams;
+#[cfg(feature = "currency")]
+use chinese_format::currency::RenminbiCurrency;
+#[cfg(feature = "gregorian")]
+use chinese_format::gregorian::{Date, LinearTime};
+
+/// A generated value together with both its renderings -
+/// guaranteed to come from the very same random draw.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// let rendering = BilingualRendering::new(("个", "個"));
+///
+/// assert_eq!(rendering.simplified, "个");
+/// assert_eq!(rendering.traditional, "個");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BilingualRendering<T> {
+    /// The generated value.
+    pub value: T,
+
+    /// The rendering in [Variant::Simplified].
+    pub simplified: String,
+
+    /// The rendering in [Variant::Traditional].
+    pub traditional: String,
+}
+
+impl<T: ChineseFormat> BilingualRendering<T> {
+    /// Renders the given value in both variants.
+    pub fn new(value: T) -> Self {
+        let simplified = value.to_chinese(Variant::Simplified).to_string();
+        let traditional = value.to_chinese(Variant::Traditional).to_string();
+
+        Self {
+            value,
+            simplified,
+            traditional,
+        }
+    }
+}
+
+/// Fixed text available in both variants - like a word, a name or a label.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let city = BilingualLabel::new("广州", "廣州");
+///
+/// assert_eq!(city.to_chinese(Variant::Simplified), "广州");
+/// assert_eq!(city.to_chinese(Variant::Traditional), "廣州");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BilingualLabel {
+    /// The text in [Variant::Simplified].
+    pub simplified: String,
+
+    /// The text in [Variant::Traditional].
+    pub traditional: String,
+}
+
+impl BilingualLabel {
+    /// Creates a label from its two variants.
+    pub fn new(simplified: &str, traditional: &str) -> Self {
+        Self {
+            simplified: simplified.to_string(),
+            traditional: traditional.to_string(),
+        }
+    }
+}
+
+impl ChineseFormat for BilingualLabel {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        (self.simplified.as_str(), self.traditional.as_str()).to_chinese(variant)
+    }
+}
+
+/// Wraps a [ChineseFormatGenerator] so that every generated value
+/// is returned as a [BilingualRendering].
+///
+/// It must be created via [ChineseFormatGenerator::rendered_both].
+pub struct BilingualGenerator<'a, R: RawGenerator = Box<dyn RawGenerator>> {
+    generator: &'a ChineseFormatGenerator<R>,
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Creates a [BilingualGenerator] - for parallel-corpus generation.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// let rendering = generator.rendered_both().count(0..=100_000)?;
+    /// assert_eq!(rendering.simplified, "五万八千九百三十三");
+    /// assert_eq!(rendering.traditional, "五萬八千九百三十三");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rendered_both(&self) -> BilingualGenerator<'_, R> {
+        BilingualGenerator { generator: self }
+    }
+}
+
+impl<R: RawGenerator> BilingualGenerator<'_, R> {
+    /// Renders in both variants the value returned by any generator method.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// fastrand::seed(90);
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// let rendering = generator
+    ///     .rendered_both()
+    ///     .render(|generator| generator.ordinal(1..=10, OrdinalSuffix::Ge).unwrap());
+    /// assert_eq!(rendering.simplified, "第六个");
+    /// assert_eq!(rendering.traditional, "第六個");
+    /// ```
+    pub fn render<T: ChineseFormat>(
+        &self,
+        generate: impl FnOnce(&ChineseFormatGenerator<R>) -> T,
+    ) -> BilingualRendering<T> {
+        BilingualRendering::new(generate(self.generator))
+    }
+
+    /// Like [render](Self::render), but for fallible generator methods.
+    pub fn try_render<T: ChineseFormat>(
+        &self,
+        generate: impl FnOnce(&ChineseFormatGenerator<R>) -> ChineseRandResult<T>,
+    ) -> ChineseRandResult<BilingualRendering<T>> {
+        generate(self.generator).map(BilingualRendering::new)
+    }
+
+    /// See [ChineseFormatGenerator::integer].
+    pub fn integer(
+        &self,
+        range: RangeInclusive<i128>,
+    ) -> ChineseRandResult<BilingualRendering<i128>> {
+        self.try_render(|generator| generator.integer(range))
+    }
+
+    /// See [ChineseFormatGenerator::count].
+    pub fn count(
+        &self,
+        range: RangeInclusive<u128>,
+    ) -> ChineseRandResult<BilingualRendering<chinese_format::Count>> {
+        self.try_render(|generator| generator.count(range))
+    }
+
+    /// See [ChineseFormatGenerator::fraction].
+    pub fn fraction(
+        &self,
+        denominator_range: RangeInclusive<u128>,
+        numerator_range: RangeInclusive<i128>,
+    ) -> ChineseRandResult<BilingualRendering<Fraction>> {
+        self.try_render(|generator| generator.fraction(denominator_range, numerator_range))
+    }
+
+    /// See [ChineseFormatGenerator::renminbi].
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    pub fn renminbi(
+        &self,
+        params: RenminbiParams,
+    ) -> ChineseRandResult<BilingualRendering<RenminbiCurrency>> {
+        self.try_render(|generator| generator.renminbi(params))
+    }
+
+    /// See [GregorianGenerator::date](crate::gregorian::GregorianGenerator::date).
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// let rendering = generator.rendered_both().date(DateParams::default())?;
+    /// assert_eq!(rendering.simplified, "二零三六年五月二十三日");
+    /// assert_eq!(rendering.traditional, "二零三六年五月二十三日");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    pub fn date(&self, params: DateParams) -> ChineseRandResult<BilingualRendering<Date>> {
+        self.try_render(|generator| generator.gregorian().date(params))
+    }
+
+    /// See [GregorianGenerator::linear_time](crate::gregorian::GregorianGenerator::linear_time).
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    pub fn linear_time(
+        &self,
+        params: LinearTimeParams,
+    ) -> ChineseRandResult<BilingualRendering<LinearTime>> {
+        self.try_render(|generator| generator.gregorian().linear_time(params))
+    }
+}
@@ -0,0 +1,143 @@
+//! Command-line generator of random Chinese values - one per line.
+//!
+//! **Required feature**: `cli`.
+use chinese_format::Variant;
+use chinese_rand::*;
+use std::{env, fs, process::ExitCode};
+
+const USAGE: &str = "Usage: chinese-rand [--type <kind>] [--count <n>] [--seed <n>]
+                    [--variant simplified|traditional]
+                    [--level beginner|intermediate|advanced]
+                    [--plan <path.json>]
+
+Kinds: integer, count, count_rounded, fraction, mixed_number, ordinal,
+       range_expression, age, temperature, approximate, arithmetic,
+       digit_sequence, decimal, measurement, renminbi,
+       date, linear_time, delta_time, duration
+
+A JSON plan, if passed, overrides --type, --count and --variant.";
+
+struct Arguments {
+    kind: String,
+    count: usize,
+    seed: Option<u64>,
+    variant: Variant,
+    level: Option<Level>,
+    plan_path: Option<String>,
+}
+
+fn parse_arguments(mut args: impl Iterator<Item = String>) -> Result<Arguments, String> {
+    let mut arguments = Arguments {
+        kind: "integer".to_string(),
+        count: 10,
+        seed: None,
+        variant: Variant::Simplified,
+        level: None,
+        plan_path: None,
+    };
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", flag))
+        };
+
+        match flag.as_str() {
+            "--type" => arguments.kind = value()?,
+
+            "--count" => {
+                arguments.count = value()?.parse().map_err(|_| "Invalid count".to_string())?
+            }
+
+            "--seed" => {
+                arguments.seed = Some(value()?.parse().map_err(|_| "Invalid seed".to_string())?)
+            }
+
+            "--variant" => {
+                arguments.variant = match value()?.to_lowercase().as_str() {
+                    "simplified" => Variant::Simplified,
+                    "traditional" => Variant::Traditional,
+                    other => return Err(format!("Unknown variant: {}", other)),
+                }
+            }
+
+            "--level" => {
+                arguments.level = Some(match value()?.to_lowercase().as_str() {
+                    "beginner" => Level::Beginner,
+                    "intermediate" => Level::Intermediate,
+                    "advanced" => Level::Advanced,
+                    other => return Err(format!("Unknown level: {}", other)),
+                })
+            }
+
+            "--plan" => arguments.plan_path = Some(value()?),
+
+            other => return Err(format!("Unknown argument: {}", other)),
+        }
+    }
+
+    Ok(arguments)
+}
+
+fn load_plan(arguments: &Arguments) -> Result<GenerationPlan, String> {
+    match &arguments.plan_path {
+        Some(path) => {
+            let source = fs::read_to_string(path).map_err(|err| err.to_string())?;
+
+            serde_json::from_str(&source).map_err(|err| err.to_string())
+        }
+
+        None => {
+            let item = PlanItem::from_kind(&arguments.kind)
+                .ok_or_else(|| format!("Unknown type: {}", arguments.kind))?;
+
+            Ok(GenerationPlan {
+                variant: arguments.variant,
+                entries: vec![PlanEntry {
+                    item,
+                    count: arguments.count,
+                }],
+            })
+        }
+    }
+}
+
+fn run() -> Result<(), String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+
+    let arguments = parse_arguments(args.into_iter())?;
+
+    let plan = load_plan(&arguments)?;
+
+    if let Some(seed) = arguments.seed {
+        fastrand::seed(seed);
+    }
+
+    let mut generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+
+    if let Some(level) = arguments.level {
+        generator = generator.with_level(level);
+    }
+
+    for line in generator.run_plan(&plan).map_err(|err| err.to_string())? {
+        println!("{}", line);
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    ChineseFormatGenerator, ChineseRandResult, GeneratorConfig, GeneratorLayer, Level,
+    RawGenerator, RegionProfile, DEFAULT_MAX_RETRIES,
+};
+
+/// Fluently composes a [ChineseFormatGenerator] - backed by a boxed [RawGenerator].
+///
+/// It must be created via [ChineseFormatGenerator::builder].
+pub struct ChineseFormatGeneratorBuilder {
+    raw_generator: Option<Box<dyn RawGenerator>>,
+    seed: Option<u64>,
+    config: GeneratorConfig,
+    level: Option<Level>,
+    region: Option<RegionProfile>,
+    max_retries: usize,
+    layers: Vec<Box<dyn GeneratorLayer>>,
+}
+
+impl ChineseFormatGenerator {
+    /// Creates a [ChineseFormatGeneratorBuilder] - an alternative to [new](Self::new)
+    /// followed by the `with_*` methods.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::Variant;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::builder()
+    ///     .with_seed(90)
+    ///     .with_level(Level::Beginner)
+    ///     .with_region(RegionProfile::Taiwan)
+    ///     .with_config(GeneratorConfig::default().with_variant(Variant::Simplified))
+    ///     .with_max_retries(10)
+    ///     .with_layer(ClampLayer::new(0, 5)?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(generator.level(), Some(Level::Beginner));
+    /// assert_eq!(generator.region(), Some(RegionProfile::Taiwan));
+    /// assert_eq!(generator.output_variant(), Variant::Simplified);
+    /// assert!(generator.integer(0..=1000)? <= 5);
+    ///
+    /// let same_sequence = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    ///     .with_level(Level::Beginner)
+    ///     .with_layer(ClampLayer::new(0, 5)?);
+    /// let replayed = ChineseFormatGenerator::builder()
+    ///     .with_seed(90)
+    ///     .with_level(Level::Beginner)
+    ///     .with_layer(ClampLayer::new(0, 5)?)
+    ///     .build()?;
+    /// assert_eq!(replayed.integer(0..=1000)?, same_sequence.integer(0..=1000)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> ChineseFormatGeneratorBuilder {
+        ChineseFormatGeneratorBuilder {
+            raw_generator: None,
+            seed: None,
+            config: GeneratorConfig::default(),
+            level: None,
+            region: None,
+            max_retries: DEFAULT_MAX_RETRIES,
+            layers: Vec::new(),
+        }
+    }
+}
+
+impl ChineseFormatGeneratorBuilder {
+    /// Sets the [RawGenerator] backend - by default, a [FastRandGenerator](crate::FastRandGenerator),
+    /// if the `fastrand` feature is enabled.
+    pub fn with_raw_generator(mut self, raw_generator: impl RawGenerator + 'static) -> Self {
+        self.raw_generator = Some(Box::new(raw_generator));
+        self
+    }
+
+    /// Sets the seed of the [RawGenerator] - which must support
+    /// [seeding](RawGenerator::seed).
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the [GeneratorConfig] - see [ChineseFormatGenerator::with_config].
+    pub fn with_config(mut self, config: GeneratorConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the [Level] - see [ChineseFormatGenerator::with_level].
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Sets the [RegionProfile] - see [ChineseFormatGenerator::with_region].
+    pub fn with_region(mut self, region: RegionProfile) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Sets the retry budget - see [ChineseFormatGenerator::with_max_retries].
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Adds a [GeneratorLayer] - applied after the seed, in the order
+    /// of addition; see [ChineseFormatGenerator::with_layer].
+    pub fn with_layer(mut self, layer: impl GeneratorLayer + 'static) -> Self {
+        self.layers.push(Box::new(layer));
+        self
+    }
+
+    /// Creates the [ChineseFormatGenerator].
+    ///
+    /// Fails with [ChineseRandError::InvalidParams](crate::ChineseRandError::InvalidParams)
+    /// if no [RawGenerator] is set and the `fastrand` feature is disabled -
+    /// or with the error returned when seeding the [RawGenerator].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// let unseedable = ChineseFormatGenerator::builder()
+    ///     .with_raw_generator(ByteSliceGenerator::new(&[1, 2, 3]))
+    ///     .with_seed(90)
+    ///     .build();
+    /// assert_eq!(
+    ///     unseedable.err(),
+    ///     Some(ChineseRandError::Unsupported("Seeding".to_string()))
+    /// );
+    /// ```
+    pub fn build(self) -> ChineseRandResult<ChineseFormatGenerator> {
+        let raw_generator: Box<dyn RawGenerator> = match self.raw_generator {
+            Some(raw_generator) => {
+                if let Some(seed) = self.seed {
+                    raw_generator.seed(seed)?;
+                }
+
+                raw_generator
+            }
+
+            #[cfg(feature = "fastrand")]
+            None => Box::new(match self.seed {
+                Some(seed) => crate::FastRandGenerator::with_seed(seed),
+                None => crate::FastRandGenerator::new(),
+            }),
+
+            #[cfg(not(feature = "fastrand"))]
+            None => {
+                return Err(crate::ChineseRandError::InvalidParams(
+                    "No raw generator".to_string(),
+                ))
+            }
+        };
+
+        let mut generator = ChineseFormatGenerator::from_raw_generator(raw_generator)
+            .with_config(self.config)
+            .with_max_retries(self.max_retries);
+
+        generator.level = self.level;
+        generator.region = self.region;
+
+        for layer in self.layers {
+            generator.raw_generator = layer.layer(generator.raw_generator);
+        }
+
+        Ok(generator)
+    }
+}
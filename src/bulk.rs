@@ -0,0 +1,139 @@
+use crate::{
+    check_range, statistics::magnitude_bucket, ChineseFormatGenerator, ChineseRandResult, Level,
+    RawGenerator,
+};
+use chinese_format::{ChineseFormat, Count, CountBase, Variant};
+use std::ops::RangeInclusive;
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Fills the buffer with random integers in the given range - just like
+    /// calling [integer](Self::integer) for each element, but checking
+    /// and narrowing the range only once, without allocating.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty - leaving the buffer untouched.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let mut buffer = [0; 4];
+    /// generator.fill_integers(&mut buffer, 0..=99)?;
+    /// assert_eq!(buffer, [58, 65, 34, 45]);
+    ///
+    /// assert!(generator.fill_integers(&mut buffer, 9..=0).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fill_integers(
+        &self,
+        buffer: &mut [i128],
+        range: RangeInclusive<i128>,
+    ) -> ChineseRandResult<()> {
+        check_range(&range)?;
+
+        let range = self.leveled(range, Level::integer_range)?;
+
+        for slot in buffer {
+            *slot = self.raw_generator.i128(range.clone());
+            self.record("integer", || magnitude_bucket(*slot));
+        }
+
+        Ok(())
+    }
+
+    /// Fills the buffer with random [Count] values in the given range - just like
+    /// calling [count](Self::count) for each element, but checking
+    /// and narrowing the range only once, without allocating.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty - leaving the buffer untouched.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::Count;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let mut buffer = [Count(0); 3];
+    /// generator.fill_counts(&mut buffer, 1..=9)?;
+    /// assert_eq!(buffer, [Count(6), Count(6), Count(4)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fill_counts(
+        &self,
+        buffer: &mut [Count],
+        range: RangeInclusive<CountBase>,
+    ) -> ChineseRandResult<()> {
+        check_range(&range)?;
+
+        let range = self.leveled(range, Level::count_range)?;
+
+        for slot in buffer {
+            *slot = Count(self.raw_generator.u128(range.clone()));
+            self.record("count", || {
+                magnitude_bucket(slot.0.min(i128::MAX as u128) as i128)
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Fills the buffer by calling the given function for each element -
+    /// stopping at the first error.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Fraction, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let mut buffer = [Fraction::try_new(1, 0)?; 2];
+    /// generator.fill_buffer(&mut buffer, |generator| generator.fraction(2..=9, 1..=9))?;
+    ///
+    /// assert_eq!(buffer[0].to_chinese(Variant::Simplified), "六分之六");
+    /// assert_eq!(buffer[1].to_chinese(Variant::Simplified), "四分之五");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fill_buffer<T>(
+        &self,
+        buffer: &mut [T],
+        mut generate: impl FnMut(&Self) -> ChineseRandResult<T>,
+    ) -> ChineseRandResult<()> {
+        for slot in buffer {
+            *slot = generate(self)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders the values into the given string - one per line - after clearing it,
+/// so that the same string can be reused across batches.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::Variant;
+///
+/// let mut output = String::with_capacity(64);
+///
+/// render_lines(&[3, 20], Variant::Simplified, &mut output);
+/// assert_eq!(output, "三\n二十\n");
+///
+/// render_lines(&[7], Variant::Simplified, &mut output);
+/// assert_eq!(output, "七\n");
+/// ```
+pub fn render_lines<T: ChineseFormat>(values: &[T], variant: Variant, output: &mut String) {
+    output.clear();
+
+    for value in values {
+        output.push_str(&value.to_chinese(variant).logograms);
+        output.push('\n');
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{ChineseFormatGenerator, RawGenerator};
+use chinese_format::{Chinese, ChineseFormat, Variant};
+
+#[cfg(feature = "currency")]
+use chinese_format::currency::CurrencyStyle;
+#[cfg(feature = "gregorian")]
+use chinese_format::gregorian::WeekFormat;
+#[cfg(feature = "gregorian")]
+use std::ops::RangeInclusive;
+
+/// Generator-level defaults, applied whenever the parameters of a generator
+/// method leave a choice open - so that applications do not have to repeat
+/// the same parameters in every call.
+///
+/// It can be set via [ChineseFormatGenerator::with_config].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct GeneratorConfig {
+    /// The year range used when the parameters do not set one.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    pub year_range: RangeInclusive<u16>,
+
+    /// The currency style used when the [RenminbiParams](crate::RenminbiParams)
+    /// do not set one - the [Level](crate::Level) and the
+    /// [RegionProfile](crate::RegionProfile), if any, still prevailing.
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::remote::option_currency_style")
+    )]
+    pub currency_style: Option<CurrencyStyle>,
+
+    /// The week format used when the parameters do not set one -
+    /// taking precedence over the one preferred by the
+    /// [RegionProfile](crate::RegionProfile).
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::remote::option_week_format"))]
+    pub week_format: Option<WeekFormat>,
+
+    /// The variant used by [ChineseFormatGenerator::to_chinese] - taking precedence
+    /// over the one of the [RegionProfile](crate::RegionProfile).
+    #[cfg_attr(feature = "serde", serde(with = "crate::remote::option_variant"))]
+    pub variant: Option<Variant>,
+}
+
+/// By default, [GeneratorConfig] just leaves every choice to the parameters
+/// and to the [RegionProfile](crate::RegionProfile) - with years
+/// in [DEFAULT_YEAR_RANGE](crate::gregorian::DEFAULT_YEAR_RANGE).
+///
+/// ```
+/// # #[cfg(all(feature = "currency", feature = "gregorian"))]
+/// # {
+/// use chinese_rand::{*, gregorian::*};
+/// use chinese_format::{Variant, currency::CurrencyStyle, gregorian::WeekFormat};
+///
+/// assert_eq!(GeneratorConfig::default(), GeneratorConfig {
+///     year_range: DEFAULT_YEAR_RANGE,
+///     currency_style: None,
+///     week_format: None,
+///     variant: None
+/// });
+///
+/// let customized = GeneratorConfig::default()
+///     .with_year_range(2000..=2030)
+///     .with_currency_style(CurrencyStyle::Financial)
+///     .with_week_format(WeekFormat::XingQi)
+///     .with_variant(Variant::Traditional);
+///
+/// assert_eq!(customized, GeneratorConfig {
+///     year_range: 2000..=2030,
+///     currency_style: Some(CurrencyStyle::Financial),
+///     week_format: Some(WeekFormat::XingQi),
+///     variant: Some(Variant::Traditional)
+/// });
+/// # }
+/// ```
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "gregorian")]
+            year_range: crate::gregorian::DEFAULT_YEAR_RANGE,
+
+            #[cfg(feature = "currency")]
+            currency_style: None,
+
+            #[cfg(feature = "gregorian")]
+            week_format: None,
+
+            variant: None,
+        }
+    }
+}
+
+impl GeneratorConfig {
+    /// Sets the year range used when the parameters do not set one.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    pub fn with_year_range(mut self, year_range: RangeInclusive<u16>) -> Self {
+        self.year_range = year_range;
+        self
+    }
+
+    /// Sets the style replacing the one requested by
+    /// [RenminbiParams](crate::RenminbiParams).
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    pub fn with_currency_style(mut self, currency_style: CurrencyStyle) -> Self {
+        self.currency_style = Some(currency_style);
+        self
+    }
+
+    /// Sets the week format used when the parameters do not set one.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    pub fn with_week_format(mut self, week_format: WeekFormat) -> Self {
+        self.week_format = Some(week_format);
+        self
+    }
+
+    /// Sets the variant used by [ChineseFormatGenerator::to_chinese].
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Applies the given [GeneratorConfig] to all the generator methods.
+    ///
+    /// ```
+    /// # #[cfg(feature = "gregorian")]
+    /// # {
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{Variant, gregorian::{DatePattern, WeekFormat}};
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    ///     .with_config(
+    ///         GeneratorConfig::default()
+    ///             .with_year_range(2000..=2009)
+    ///             .with_week_format(WeekFormat::LiBai)
+    ///             .with_variant(Variant::Traditional)
+    ///     );
+    /// assert_eq!(generator.config().year_range, 2000..=2009);
+    ///
+    /// let date = generator.gregorian().date(
+    ///     DateParams::default().with_pattern(DatePattern::YearMonthDayWeekDay)
+    /// ).unwrap();
+    /// assert_eq!(generator.to_chinese(&date), "二零零六年五月二十三日禮拜一");
+    /// # }
+    /// ```
+    pub fn with_config(mut self, config: GeneratorConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// The [GeneratorConfig] currently set.
+    pub fn config(&self) -> &GeneratorConfig {
+        &self.config
+    }
+
+    /// The variant used by [to_chinese](Self::to_chinese): the one in the
+    /// [GeneratorConfig] or, if unset, the one of the
+    /// [RegionProfile](crate::RegionProfile) - [Variant::Simplified] otherwise.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::Variant;
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// assert_eq!(generator.output_variant(), Variant::Simplified);
+    ///
+    /// let generator = generator.with_region(RegionProfile::Taiwan);
+    /// assert_eq!(generator.output_variant(), Variant::Traditional);
+    ///
+    /// let generator = generator.with_config(GeneratorConfig::default().with_variant(Variant::Simplified));
+    /// assert_eq!(generator.output_variant(), Variant::Simplified);
+    /// ```
+    pub fn output_variant(&self) -> Variant {
+        self.config
+            .variant
+            .or_else(|| self.region.map(|region| region.variant()))
+            .unwrap_or(Variant::Simplified)
+    }
+
+    /// Renders the given value in the [output variant](Self::output_variant).
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::Variant;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    ///     .with_config(GeneratorConfig::default().with_variant(Variant::Traditional));
+    ///
+    /// let count = generator.count(0..=100_000)?;
+    /// assert_eq!(generator.to_chinese(&count), "五萬八千九百三十三");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_chinese(&self, value: &dyn ChineseFormat) -> Chinese {
+        value.to_chinese(self.output_variant())
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use chinese_format::{ChineseFormat, Count, CountBase, Variant};
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+/// Digits commonly avoided in Chinese contexts - like 4, sounding like 死.
+pub const UNLUCKY_DIGITS: [u8; 1] = [4];
+
+/// Logograms rendering [UNLUCKY_DIGITS] - including the financial form.
+pub const UNLUCKY_LOGOGRAMS: &str = "四肆";
+
+/// Chinese numeric unit, for magnitude-based [Constraint]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Scale {
+    /// 十 - 10.
+    Shi,
+
+    /// 百 - 100.
+    Bai,
+
+    /// 千 - 1000.
+    Qian,
+
+    /// 万 - 10⁴.
+    Wan,
+
+    /// 亿 - 10⁸.
+    Yi,
+}
+
+impl Scale {
+    /// The numeric value of the unit.
+    pub fn value(&self) -> u128 {
+        match self {
+            Scale::Shi => 10,
+            Scale::Bai => 100,
+            Scale::Qian => 1_000,
+            Scale::Wan => 10_000,
+            Scale::Yi => 100_000_000,
+        }
+    }
+}
+
+/// Integer value whose decimal digits can be inspected by a [Constraint].
+pub trait DecimalValue {
+    /// The absolute value.
+    fn magnitude(&self) -> u128;
+}
+
+impl DecimalValue for i128 {
+    fn magnitude(&self) -> u128 {
+        self.unsigned_abs()
+    }
+}
+
+impl DecimalValue for u128 {
+    fn magnitude(&self) -> u128 {
+        *self
+    }
+}
+
+impl DecimalValue for u64 {
+    fn magnitude(&self) -> u128 {
+        *self as u128
+    }
+}
+
+impl DecimalValue for Count {
+    fn magnitude(&self) -> u128 {
+        self.0
+    }
+}
+
+/// Predicate that generated values must satisfy - see
+/// [ChineseFormatGenerator::constrained].
+///
+/// Constraints can be created from closures or via the built-in
+/// numeric constructors, and then combined.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// let no_four: Constraint<i128> = Constraint::contains_digit(4).not();
+/// assert!(no_four.accepts(&123));
+/// assert!(!no_four.accepts(&-140));
+///
+/// let round: Constraint<i128> = Constraint::ends_with_zero();
+/// assert!(round.accepts(&120));
+///
+/// let large: Constraint<i128> = Constraint::magnitude_at_least(Scale::Wan);
+/// assert!(large.accepts(&-10_000));
+/// assert!(!large.accepts(&9_999));
+///
+/// let combined = no_four.and(round).or(Constraint::new(|value: &i128| *value == 4));
+/// assert!(combined.accepts(&120));
+/// assert!(combined.accepts(&4));
+/// assert!(!combined.accepts(&140));
+/// assert!(!combined.accepts(&123));
+/// ```
+pub struct Constraint<T> {
+    predicate: Rc<dyn Fn(&T) -> bool>,
+}
+
+impl<T> Clone for Constraint<T> {
+    fn clone(&self) -> Self {
+        Self {
+            predicate: Rc::clone(&self.predicate),
+        }
+    }
+}
+
+impl<T: 'static> Constraint<T> {
+    /// Creates a constraint from a predicate.
+    pub fn new(predicate: impl Fn(&T) -> bool + 'static) -> Self {
+        Self {
+            predicate: Rc::new(predicate),
+        }
+    }
+
+    /// Whether the value satisfies the constraint.
+    pub fn accepts(&self, value: &T) -> bool {
+        (self.predicate)(value)
+    }
+
+    /// The constraint satisfied when this one is not.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Self::new(move |value| !self.accepts(value))
+    }
+
+    /// The constraint satisfied when both this one and `other` are.
+    pub fn and(self, other: Self) -> Self {
+        Self::new(move |value| self.accepts(value) && other.accepts(value))
+    }
+
+    /// The constraint satisfied when this one or `other` is.
+    pub fn or(self, other: Self) -> Self {
+        Self::new(move |value| self.accepts(value) || other.accepts(value))
+    }
+}
+
+impl<T: DecimalValue + 'static> Constraint<T> {
+    /// The decimal representation of the absolute value includes the digit.
+    pub fn contains_digit(digit: u8) -> Self {
+        let digit = char::from(b'0' + digit % 10);
+
+        Self::new(move |value: &T| value.magnitude().to_string().contains(digit))
+    }
+
+    /// The decimal representation of the absolute value includes none
+    /// of the digits.
+    pub fn without_digits(digits: &[u8]) -> Self {
+        digits
+            .iter()
+            .map(|&digit| Self::contains_digit(digit).not())
+            .fold(Self::new(|_| true), Self::and)
+    }
+
+    /// The decimal representation of the absolute value includes none
+    /// of the [UNLUCKY_DIGITS].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let lucky: Constraint<u128> = Constraint::without_unlucky_digits();
+    /// assert!(lucky.accepts(&808));
+    /// assert!(!lucky.accepts(&1_400));
+    ///
+    /// for _ in 0..100 {
+    ///     let room = generator.integer_where(100..=999, &Constraint::without_unlucky_digits())?;
+    ///     assert!(!room.to_string().contains('4'));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn without_unlucky_digits() -> Self {
+        Self::without_digits(&UNLUCKY_DIGITS)
+    }
+
+    /// The absolute value is a multiple of 10 - including 0.
+    pub fn ends_with_zero() -> Self {
+        Self::new(|value: &T| value.magnitude().is_multiple_of(10))
+    }
+
+    /// The absolute value is at least the value of the [Scale].
+    pub fn magnitude_at_least(scale: Scale) -> Self {
+        let threshold = scale.value();
+
+        Self::new(move |value: &T| value.magnitude() >= threshold)
+    }
+}
+
+impl<T: ChineseFormat + 'static> Constraint<T> {
+    /// The [Simplified](Variant::Simplified) rendering includes none
+    /// of the given logograms.
+    pub fn without_logograms(logograms: &str) -> Self {
+        let logograms: Vec<char> = logograms.chars().collect();
+
+        Self::new(move |value: &T| {
+            !value
+                .to_chinese(Variant::Simplified)
+                .logograms
+                .contains(logograms.as_slice())
+        })
+    }
+
+    /// The [Simplified](Variant::Simplified) rendering includes none
+    /// of the [UNLUCKY_LOGOGRAMS] - suitable for any value,
+    /// like prices or digit sequences.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let fraction = generator.constrained(
+    ///     &Constraint::without_unlucky_logograms(),
+    ///     |generator| generator.fraction(1..=10, 1..=10)
+    /// )?;
+    /// assert!(!fraction.to_chinese(Variant::Simplified).logograms.contains('四'));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn without_unlucky_logograms() -> Self {
+        Self::without_logograms(UNLUCKY_LOGOGRAMS)
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Calls `generate` until it returns a value satisfying the [Constraint],
+    /// within the [retry budget](Self::with_max_retries) - beyond which
+    /// generation fails with
+    /// [ChineseRandError::GenerationExhausted](crate::ChineseRandError::GenerationExhausted).
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let count = generator.constrained(
+    ///     &Constraint::magnitude_at_least(Scale::Wan),
+    ///     |generator| generator.count(0..=99_999)
+    /// )?;
+    /// assert_eq!(count.to_chinese(Variant::Simplified), "五万八千九百三十二");
+    ///
+    /// let impossible = generator.constrained(
+    ///     &Constraint::new(|value: &i128| *value > 10),
+    ///     |generator| generator.integer(0..=10)
+    /// );
+    /// assert!(impossible.is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn constrained<T: 'static>(
+        &self,
+        constraint: &Constraint<T>,
+        mut generate: impl FnMut(&Self) -> ChineseRandResult<T>,
+    ) -> ChineseRandResult<T> {
+        self.try_retrying("Constrained value", || {
+            let value = generate(self)?;
+
+            Ok(constraint.accepts(&value).then_some(value))
+        })
+    }
+
+    /// [integer](Self::integer) satisfying the [Constraint].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let integer = generator.integer_where(
+    ///     0..=1000,
+    ///     &Constraint::contains_digit(4).not().and(Constraint::ends_with_zero())
+    /// )?;
+    /// assert_eq!(integer, 970);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn integer_where(
+        &self,
+        range: RangeInclusive<i128>,
+        constraint: &Constraint<i128>,
+    ) -> ChineseRandResult<i128> {
+        self.constrained(constraint, |generator| generator.integer(range.clone()))
+    }
+
+    /// [count](Self::count) satisfying the [Constraint].
+    pub fn count_where(
+        &self,
+        range: RangeInclusive<CountBase>,
+        constraint: &Constraint<Count>,
+    ) -> ChineseRandResult<Count> {
+        self.constrained(constraint, |generator| generator.count(range.clone()))
+    }
+}
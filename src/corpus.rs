@@ -0,0 +1,256 @@
+//! Large, seeded corpora of generated values - written in JSONL format,
+//! one [CorpusLine] per line.
+//!
+//! They are meant for regression-testing [chinese_format] itself,
+//! as well as for training and evaluating downstream NLP models.
+use crate::{ChineseFormatGenerator, ChineseRandError, ChineseRandResult, PlanItem, RawGenerator};
+
+#[cfg(feature = "serde")]
+use chinese_format::Variant;
+#[cfg(feature = "serde")]
+use std::io::Write;
+
+/// A kind of value in the mix of a corpus, with its relative weight.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CorpusEntry {
+    /// The kind of value, with its generation parameters.
+    pub item: PlanItem,
+
+    /// How often the item is chosen, relative to the other entries.
+    pub weight: u32,
+}
+
+/// Line of a corpus written by [ChineseFormatGenerator::write_corpus].
+///
+/// ```
+/// use chinese_rand::corpus::*;
+///
+/// let line: CorpusLine = serde_json::from_str(
+///     r#"{"kind":"count","value":"Count(2)","simplified":"两","traditional":"兩"}"#
+/// ).unwrap();
+///
+/// assert_eq!(line, CorpusLine {
+///     kind: "count".to_string(),
+///     value: "Count(2)".to_string(),
+///     simplified: "两".to_string(),
+///     traditional: "兩".to_string()
+/// });
+/// ```
+///
+/// **Required feature**: `serde`.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct CorpusLine {
+    /// The kind of the value - see [PlanItem::kind].
+    pub kind: String,
+
+    /// The debug representation of the value.
+    pub value: String,
+
+    /// The rendering in simplified Chinese.
+    pub simplified: String,
+
+    /// The rendering in traditional Chinese.
+    pub traditional: String,
+}
+
+/// Parameters for [ChineseFormatGenerator::write_corpus].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CorpusParams {
+    /// The weighted kinds of values - an entry with 0 weight is never chosen.
+    pub mix: Vec<CorpusEntry>,
+
+    /// The number of lines to write.
+    pub size: usize,
+
+    /// The seed from which the whole corpus is deterministically derived.
+    pub seed: u64,
+}
+
+/// By default, [CorpusParams] describes 1000 lines of integers,
+/// counts and fractions - equally weighted - with seed 0.
+///
+/// ```
+/// use chinese_rand::{*, corpus::*};
+///
+/// let default = CorpusParams::default();
+/// assert_eq!(default.mix.len(), 3);
+/// assert!(default.mix.iter().all(|entry| entry.weight == 1));
+/// assert_eq!(default.size, 1000);
+/// assert_eq!(default.seed, 0);
+///
+/// let customized = CorpusParams::default()
+///     .with_mix(vec![CorpusEntry {
+///         item: PlanItem::Integer { range: 0..=9 },
+///         weight: 1,
+///     }])
+///     .with_size(10)
+///     .with_seed(90);
+///
+/// assert_eq!(customized, CorpusParams {
+///     mix: vec![CorpusEntry {
+///         item: PlanItem::Integer { range: 0..=9 },
+///         weight: 1,
+///     }],
+///     size: 10,
+///     seed: 90
+/// });
+/// ```
+impl Default for CorpusParams {
+    fn default() -> Self {
+        Self {
+            mix: ["integer", "count", "fraction"]
+                .into_iter()
+                .map(|kind| CorpusEntry {
+                    item: PlanItem::from_kind(kind).expect("Known kind"),
+                    weight: 1,
+                })
+                .collect(),
+            size: 1000,
+            seed: 0,
+        }
+    }
+}
+
+impl CorpusParams {
+    /// Sets the weighted kinds of values.
+    pub fn with_mix(mut self, mix: Vec<CorpusEntry>) -> Self {
+        self.mix = mix;
+        self
+    }
+
+    /// Sets the number of lines.
+    pub fn with_size(mut self, size: usize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the seed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Writes a corpus to the given writer, as JSON-serialized [CorpusLine] items -
+    /// one per line; returns the number of written lines.
+    ///
+    /// The corpus only depends on the parameters: it is generated by a
+    /// [fork](Self::fork) - with the same [Level](crate::Level) - reseeded
+    /// from [CorpusParams::seed], so this generator is only advanced once.
+    ///
+    /// Fails with [ChineseRandError::InvalidRange](crate::ChineseRandError::InvalidRange)
+    /// if the total weight of the mix is 0.
+    ///
+    /// ```
+    /// use chinese_rand::{*, corpus::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// let params = CorpusParams::default()
+    ///     .with_mix(vec![CorpusEntry {
+    ///         item: PlanItem::Count { range: 2..=2 },
+    ///         weight: 1,
+    ///     }])
+    ///     .with_size(2);
+    ///
+    /// let mut output = Vec::new();
+    /// let lines = generator.write_corpus(&params, &mut output)?;
+    /// assert_eq!(lines, 2);
+    ///
+    /// let line = concat!(
+    ///     r#"{"kind":"count","value":"Count(2)","simplified":"两","traditional":"兩"}"#,
+    ///     "\n"
+    /// );
+    /// assert_eq!(String::from_utf8(output)?, line.repeat(2));
+    ///
+    /// let mut first = Vec::new();
+    /// generator.write_corpus(&CorpusParams::default().with_size(50), &mut first)?;
+    ///
+    /// let mut second = Vec::new();
+    /// generator.write_corpus(&CorpusParams::default().with_size(50), &mut second)?;
+    /// assert_eq!(first, second);
+    ///
+    /// let empty_mix = CorpusParams::default().with_mix(vec![]);
+    /// assert!(generator.write_corpus(&empty_mix, &mut Vec::new()).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `serde`.
+    pub fn write_corpus(
+        &self,
+        params: &CorpusParams,
+        mut writer: impl Write,
+    ) -> ChineseRandResult<usize> {
+        check_weights("corpus", &params.mix, |entry| entry.weight)?;
+
+        let stream = self.fork()?;
+        stream.seed(params.seed)?;
+
+        for _ in 0..params.size {
+            let entry = stream.weighted_pick(&params.mix, |entry| entry.weight);
+
+            let value = stream.generate_plan_item(&entry.item)?;
+
+            let line = CorpusLine {
+                kind: entry.item.kind().to_string(),
+                value: format!("{:?}", value),
+                simplified: value.to_chinese(Variant::Simplified).to_string(),
+                traditional: value.to_chinese(Variant::Traditional).to_string(),
+            };
+
+            serde_json::to_writer(&mut writer, &line)
+                .map_err(|err| ChineseRandError::Io(err.to_string()))?;
+            writeln!(writer)?;
+        }
+
+        writer.flush()?;
+
+        Ok(params.size)
+    }
+}
+
+/// Fails with [ChineseRandError::InvalidRange] if the total weight of the mix is 0.
+pub(crate) fn check_weights<T>(
+    target: &str,
+    mix: &[T],
+    weight: impl Fn(&T) -> u32,
+) -> ChineseRandResult<()> {
+    if mix.iter().map(|entry| weight(entry) as u64).sum::<u64>() == 0 {
+        return Err(ChineseRandError::InvalidRange(format!(
+            "The total weight of the {} mix is 0",
+            target
+        )));
+    }
+
+    Ok(())
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Picks an entry of the mix, with probability proportional to its weight;
+    /// the total weight must have been verified via [check_weights].
+    pub(crate) fn weighted_pick<'a, T>(&self, mix: &'a [T], weight: impl Fn(&T) -> u32) -> &'a T {
+        let total_weight: u64 = mix.iter().map(|entry| weight(entry) as u64).sum();
+
+        let mut ticket = self.raw_generator.u64(0..=(total_weight - 1));
+
+        mix.iter()
+            .find(|entry| {
+                let entry_weight = weight(entry) as u64;
+
+                if ticket < entry_weight {
+                    true
+                } else {
+                    ticket -= entry_weight;
+                    false
+                }
+            })
+            .expect("Ticket within the total weight")
+    }
+}
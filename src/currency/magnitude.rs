@@ -0,0 +1,61 @@
+use std::ops::RangeInclusive;
+
+/// Curated magnitude of believable prices - to be passed to
+/// [RenminbiParams::with_magnitude](crate::RenminbiParams::with_magnitude)
+/// instead of a raw yuan range.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// assert_eq!(YuanMagnitude::Groceries.yuan_range(), 1..=200);
+/// assert_eq!(YuanMagnitude::Salary.yuan_step(), 100);
+/// assert!(!YuanMagnitude::RealEstate.minor_units());
+/// ```
+///
+/// **Required feature**: `currency`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum YuanMagnitude {
+    /// Snacks and small change - up to `一百元`.
+    PocketMoney,
+
+    /// Daily shopping - up to `二百元`.
+    Groceries,
+
+    /// Appliances and devices - in hundreds of `元`.
+    Electronics,
+
+    /// Monthly wages - in hundreds of `元`.
+    Salary,
+
+    /// Houses and apartments - in tens of thousands of `元`.
+    RealEstate,
+}
+
+impl YuanMagnitude {
+    /// The range of the integer part.
+    pub fn yuan_range(&self) -> RangeInclusive<u64> {
+        match self {
+            YuanMagnitude::PocketMoney => 1..=100,
+            YuanMagnitude::Groceries => 1..=200,
+            YuanMagnitude::Electronics => 200..=20_000,
+            YuanMagnitude::Salary => 3_000..=50_000,
+            YuanMagnitude::RealEstate => 500_000..=20_000_000,
+        }
+    }
+
+    /// The step between the possible values of the integer part.
+    pub fn yuan_step(&self) -> u64 {
+        match self {
+            YuanMagnitude::PocketMoney | YuanMagnitude::Groceries => 1,
+            YuanMagnitude::Electronics | YuanMagnitude::Salary => 100,
+            YuanMagnitude::RealEstate => 10_000,
+        }
+    }
+
+    /// Whether `角` and `分` are believable at this magnitude.
+    pub fn minor_units(&self) -> bool {
+        matches!(self, YuanMagnitude::PocketMoney | YuanMagnitude::Groceries)
+    }
+}
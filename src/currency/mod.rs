@@ -1,3 +1,9 @@
+mod magnitude;
+mod monetary;
 mod renminbi;
+mod signed;
 
+pub use magnitude::*;
+pub use monetary::*;
 pub use renminbi::*;
+pub use signed::*;
@@ -0,0 +1,315 @@
+use crate::{
+    check_range, template::Word, ChineseFormatGenerator, ChineseRandError, ChineseRandResult,
+    RawGenerator,
+};
+use chinese_format::{
+    currency::CurrencyStyle, Chinese, ChineseFormat, ChineseVec, Count, Financial, Variant,
+};
+use std::ops::RangeInclusive;
+
+/// The major range used by the default [MonetaryParams].
+///
+/// **Required feature**: `currency`.
+pub const DEFAULT_MAJOR_RANGE: RangeInclusive<u64> = 0..=1000;
+
+/// Minor unit of a currency - like `美分` - with how many of them
+/// make up a major unit.
+///
+/// **Required feature**: `currency`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct MinorUnit {
+    /// The name of the unit.
+    pub word: Word,
+
+    /// How many minor units make up a major unit - at least 2.
+    pub per_major: u32,
+}
+
+/// Parameters for the random creation of [MonetaryAmount] -
+/// in any currency, real or imaginary.
+///
+/// **Required feature**: `currency`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct MonetaryParams {
+    /// The major unit - like `美元`, `英镑` or `金币`.
+    pub major_unit: Word,
+
+    /// The minor unit, if any - like `美分` or `便士`.
+    pub minor_unit: Option<MinorUnit>,
+
+    /// The range of the major part.
+    pub major_range: RangeInclusive<u64>,
+
+    /// The style: [Financial](CurrencyStyle::Financial) renders
+    /// financial numerals, whereas both everyday styles render plain ones.
+    #[cfg_attr(feature = "serde", serde(with = "crate::remote::CurrencyStyleDef"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::currency_style))]
+    pub style: CurrencyStyle,
+}
+
+/// By default, [MonetaryParams] describes an everyday amount of US dollars
+/// within [DEFAULT_MAJOR_RANGE].
+///
+/// ```
+/// use chinese_rand::{*, template::Word};
+/// use chinese_format::currency::CurrencyStyle;
+///
+/// assert_eq!(MonetaryParams::default(), MonetaryParams {
+///     major_unit: Word::new("美元", "美元"),
+///     minor_unit: Some(MinorUnit {
+///         word: Word::new("美分", "美分"),
+///         per_major: 100
+///     }),
+///     major_range: DEFAULT_MAJOR_RANGE,
+///     style: CurrencyStyle::Everyday { formal: true }
+/// });
+///
+/// let customized = MonetaryParams::default()
+///     .with_major_unit(Word::new("英镑", "英鎊"))
+///     .with_minor_unit(Word::new("便士", "便士"), 100)
+///     .with_major_range(1..=50)
+///     .with_style(CurrencyStyle::Financial);
+///
+/// assert_eq!(customized, MonetaryParams {
+///     major_unit: Word::new("英镑", "英鎊"),
+///     minor_unit: Some(MinorUnit {
+///         word: Word::new("便士", "便士"),
+///         per_major: 100
+///     }),
+///     major_range: 1..=50,
+///     style: CurrencyStyle::Financial
+/// });
+///
+/// let coins = MonetaryParams::default()
+///     .with_major_unit(Word::new("金币", "金幣"))
+///     .without_minor_unit();
+///
+/// assert_eq!(coins.minor_unit, None);
+/// ```
+///
+/// **Required feature**: `currency`.
+impl Default for MonetaryParams {
+    fn default() -> Self {
+        Self {
+            major_unit: Word::new("美元", "美元"),
+            minor_unit: Some(MinorUnit {
+                word: Word::new("美分", "美分"),
+                per_major: 100,
+            }),
+            major_range: DEFAULT_MAJOR_RANGE,
+            style: CurrencyStyle::Everyday { formal: true },
+        }
+    }
+}
+
+impl MonetaryParams {
+    /// Sets the major unit.
+    pub fn with_major_unit(mut self, major_unit: Word) -> Self {
+        self.major_unit = major_unit;
+        self
+    }
+
+    /// Sets the minor unit, with how many of them make up a major unit.
+    pub fn with_minor_unit(mut self, word: Word, per_major: u32) -> Self {
+        self.minor_unit = Some(MinorUnit { word, per_major });
+        self
+    }
+
+    /// Removes the minor unit - for currencies without subdivisions.
+    pub fn without_minor_unit(mut self) -> Self {
+        self.minor_unit = None;
+        self
+    }
+
+    /// Sets the range of the major part.
+    pub fn with_major_range(mut self, major_range: RangeInclusive<u64>) -> Self {
+        self.major_range = major_range;
+        self
+    }
+
+    /// Sets the [CurrencyStyle].
+    pub fn with_style(mut self, style: CurrencyStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Amount of money in arbitrary units - like `十二美元五十美分`,
+/// `两英镑五便士` or `三百金币`.
+///
+/// The minor part is omitted when zero, and so is the major part when
+/// only the minor one is present; the financial style renders
+/// financial numerals, ending with `整` - just like [RenminbiCurrency](chinese_format::currency::RenminbiCurrency).
+///
+/// ```
+/// use chinese_rand::{*, template::Word};
+/// use chinese_format::{ChineseFormat, Variant, currency::CurrencyStyle};
+///
+/// let amount = MonetaryAmount {
+///     major: 12,
+///     minor: 50,
+///     major_unit: Word::new("美元", "美元"),
+///     minor_unit: Some(Word::new("美分", "美分")),
+///     style: CurrencyStyle::Everyday { formal: true }
+/// };
+/// assert_eq!(amount.to_chinese(Variant::Simplified), "十二美元五十美分");
+///
+/// let amount = MonetaryAmount {
+///     major: 2,
+///     minor: 0,
+///     major_unit: Word::new("英镑", "英鎊"),
+///     minor_unit: Some(Word::new("便士", "便士")),
+///     style: CurrencyStyle::Everyday { formal: true }
+/// };
+/// assert_eq!(amount.to_chinese(Variant::Traditional), "兩英鎊");
+///
+/// let amount = MonetaryAmount {
+///     major: 0,
+///     minor: 5,
+///     major_unit: Word::new("英镑", "英鎊"),
+///     minor_unit: Some(Word::new("便士", "便士")),
+///     style: CurrencyStyle::Everyday { formal: true }
+/// };
+/// assert_eq!(amount.to_chinese(Variant::Simplified), "五便士");
+///
+/// let amount = MonetaryAmount {
+///     major: 300,
+///     minor: 0,
+///     major_unit: Word::new("金币", "金幣"),
+///     minor_unit: None,
+///     style: CurrencyStyle::Financial
+/// };
+/// assert_eq!(amount.to_chinese(Variant::Simplified), "叁佰金币整");
+/// ```
+///
+/// **Required feature**: `currency`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MonetaryAmount {
+    /// The major part.
+    pub major: u64,
+
+    /// The minor part - 0 when there is no minor unit.
+    pub minor: u32,
+
+    /// The major unit.
+    pub major_unit: Word,
+
+    /// The minor unit, if any.
+    pub minor_unit: Option<Word>,
+
+    /// The style.
+    pub style: CurrencyStyle,
+}
+
+impl MonetaryAmount {
+    fn numeral(&self, value: u64) -> Box<dyn ChineseFormat> {
+        match self.style {
+            CurrencyStyle::Everyday { .. } => Box::new(Count(value as u128)),
+            CurrencyStyle::Financial => Box::new(Financial(value)),
+        }
+    }
+}
+
+impl ChineseFormat for MonetaryAmount {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let minor_unit = self.minor_unit.as_ref().filter(|_| self.minor > 0);
+
+        let mut parts: Vec<Chinese> = Vec::new();
+
+        if self.major > 0 || minor_unit.is_none() {
+            parts.push(self.numeral(self.major).to_chinese(variant));
+            parts.push(self.major_unit.to_chinese(variant));
+        }
+
+        if let Some(minor_unit) = minor_unit {
+            parts.push(self.numeral(self.minor as u64).to_chinese(variant));
+            parts.push(minor_unit.to_chinese(variant));
+        }
+
+        if self.style == CurrencyStyle::Financial {
+            parts.push("整".to_chinese(variant));
+        }
+
+        let chinese_vector: ChineseVec = parts.into();
+
+        chinese_vector.collect()
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a [MonetaryAmount] in the units described by [MonetaryParams] -
+    /// with a random minor part, if the minor unit is present.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if the major range is empty,
+    /// and with [ChineseRandError::InvalidRange] if a major unit consists
+    /// of fewer than 2 minor units.
+    ///
+    /// ```
+    /// use chinese_rand::{*, template::Word};
+    /// use chinese_format::{ChineseFormat, Variant, currency::CurrencyStyle};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let dollars = generator.monetary(MonetaryParams::default())?;
+    /// assert_eq!(dollars.to_chinese(Variant::Simplified), "五百八十九美元三十四美分");
+    ///
+    /// let pounds = generator.monetary(
+    ///     MonetaryParams::default()
+    ///         .with_major_unit(Word::new("英镑", "英鎊"))
+    ///         .with_minor_unit(Word::new("便士", "便士"), 100)
+    ///         .with_major_range(1..=20)
+    /// )?;
+    /// assert_eq!(pounds.to_chinese(Variant::Traditional), "十四英鎊二十八便士");
+    ///
+    /// let coins = generator.monetary(
+    ///     MonetaryParams::default()
+    ///         .with_major_unit(Word::new("金币", "金幣"))
+    ///         .without_minor_unit()
+    ///         .with_style(CurrencyStyle::Financial)
+    /// )?;
+    /// assert_eq!(coins.to_chinese(Variant::Simplified), "叁佰肆拾柒金币整");
+    ///
+    /// let invalid = generator.monetary(
+    ///     MonetaryParams::default().with_minor_unit(Word::new("分", "分"), 1)
+    /// );
+    /// assert_eq!(
+    ///     invalid,
+    ///     Err(ChineseRandError::InvalidRange("Minor units per major unit: 1".to_string()))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `currency`.
+    pub fn monetary(&self, params: MonetaryParams) -> ChineseRandResult<MonetaryAmount> {
+        check_range(&params.major_range)?;
+
+        if let Some(minor_unit) = &params.minor_unit {
+            if minor_unit.per_major < 2 {
+                return Err(ChineseRandError::InvalidRange(format!(
+                    "Minor units per major unit: {}",
+                    minor_unit.per_major
+                )));
+            }
+        }
+
+        let major = self.raw_generator.u64(params.major_range);
+
+        let minor = params.minor_unit.as_ref().map_or(0, |minor_unit| {
+            self.raw_generator.u32(0..=(minor_unit.per_major - 1))
+        });
+
+        Ok(MonetaryAmount {
+            major,
+            minor,
+            major_unit: params.major_unit,
+            minor_unit: params.minor_unit.map(|minor_unit| minor_unit.word),
+            style: params.style,
+        })
+    }
+}
@@ -1,42 +1,214 @@
-use crate::ChineseFormatGenerator;
+use crate::{
+    check_range, statistics::magnitude_bucket, ChineseFormatGenerator, ChineseRandError,
+    ChineseRandResult, Level, RawGenerator, YuanMagnitude,
+};
 use chinese_format::currency::{CurrencyStyle, RenminbiCurrency, RenminbiCurrencyBuilder};
 use std::ops::RangeInclusive;
 
+/// The yuan range used by the default [RenminbiParams].
+///
+/// **Required feature**: `currency`.
+pub const DEFAULT_YUAN_RANGE: RangeInclusive<u64> = 0..=1000;
+
 /// Parameters for the random creation of [RenminbiCurrency].
 ///
 /// **Required feature**: `currency`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RenminbiParams {
-    /// The style of the generated currency - for example, financial.
-    pub style: CurrencyStyle,
+    /// The style of the generated currency - for example, financial;
+    /// if unset, the one in the [GeneratorConfig](crate::GeneratorConfig)
+    /// or, failing that, the formal everyday style.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::remote::option_currency_style")
+    )]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::option_currency_style))]
+    pub style: Option<CurrencyStyle>,
 
     /// The range of the integer part.
     pub yuan_range: RangeInclusive<u64>,
 
+    /// The step between the possible values of the integer part,
+    /// starting from the beginning of the range.
+    #[cfg_attr(feature = "serde", serde(default = "default_yuan_step"))]
+    pub yuan_step: u64,
+
     /// Whether the `角` part should be generated.
     pub include_dimes: bool,
 
     /// Whether the `分` part should be generated.
     pub include_cents: bool,
+
+    /// Whether the generated `角` and `分` must be nonzero - avoiding
+    /// outputs like `五元零角零分`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub nonzero_minor_units: bool,
+
+    /// Whether whole amounts of `元` are forbidden - so that at least one
+    /// of the generated `角` and `分` is nonzero.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub forbid_round_amounts: bool,
+}
+
+#[cfg(feature = "serde")]
+fn default_yuan_step() -> u64 {
+    1
 }
 
-impl ChineseFormatGenerator {
+/// By default, [RenminbiParams] describes an amount - in the configured style,
+/// otherwise formal everyday -
+/// within [DEFAULT_YUAN_RANGE] - with step 1 - including both `角` and `分` - which can be zero.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::currency::CurrencyStyle;
+///
+/// assert_eq!(RenminbiParams::default(), RenminbiParams {
+///     style: None,
+///     yuan_range: DEFAULT_YUAN_RANGE,
+///     yuan_step: 1,
+///     include_dimes: true,
+///     include_cents: true,
+///     nonzero_minor_units: false,
+///     forbid_round_amounts: false
+/// });
+///
+/// let customized = RenminbiParams::default()
+///     .with_style(CurrencyStyle::Financial)
+///     .with_yuan_range(10..=20)
+///     .with_dimes(false)
+///     .with_cents(false)
+///     .with_nonzero_minor_units(true)
+///     .with_forbid_round_amounts(true);
+///
+/// assert_eq!(customized, RenminbiParams {
+///     style: Some(CurrencyStyle::Financial),
+///     yuan_range: 10..=20,
+///     yuan_step: 1,
+///     include_dimes: false,
+///     include_cents: false,
+///     nonzero_minor_units: true,
+///     forbid_round_amounts: true
+/// });
+/// ```
+///
+/// **Required feature**: `currency`.
+impl Default for RenminbiParams {
+    fn default() -> Self {
+        Self {
+            style: None,
+            yuan_range: DEFAULT_YUAN_RANGE,
+            yuan_step: 1,
+            include_dimes: true,
+            include_cents: true,
+            nonzero_minor_units: false,
+            forbid_round_amounts: false,
+        }
+    }
+}
+
+impl RenminbiParams {
+    /// Sets the [CurrencyStyle].
+    pub fn with_style(mut self, style: CurrencyStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Sets the range of the integer part.
+    pub fn with_yuan_range(mut self, yuan_range: RangeInclusive<u64>) -> Self {
+        self.yuan_range = yuan_range;
+        self
+    }
+
+    /// Sets the step between the possible values of the integer part.
+    pub fn with_yuan_step(mut self, yuan_step: u64) -> Self {
+        self.yuan_step = yuan_step;
+        self
+    }
+
+    /// Sets the yuan range and step - as well as whether `角` and `分`
+    /// should be generated - according to the given [YuanMagnitude].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// assert_eq!(
+    ///     RenminbiParams::default().with_magnitude(YuanMagnitude::Salary),
+    ///     RenminbiParams::default()
+    ///         .with_yuan_range(3_000..=50_000)
+    ///         .with_yuan_step(100)
+    ///         .with_dimes(false)
+    ///         .with_cents(false)
+    /// );
+    /// ```
+    pub fn with_magnitude(self, magnitude: YuanMagnitude) -> Self {
+        self.with_yuan_range(magnitude.yuan_range())
+            .with_yuan_step(magnitude.yuan_step())
+            .with_dimes(magnitude.minor_units())
+            .with_cents(magnitude.minor_units())
+    }
+
+    /// Sets whether the `角` part should be generated.
+    pub fn with_dimes(mut self, include_dimes: bool) -> Self {
+        self.include_dimes = include_dimes;
+        self
+    }
+
+    /// Sets whether the `分` part should be generated.
+    pub fn with_cents(mut self, include_cents: bool) -> Self {
+        self.include_cents = include_cents;
+        self
+    }
+
+    /// Sets whether the generated `角` and `分` must be nonzero.
+    pub fn with_nonzero_minor_units(mut self, nonzero_minor_units: bool) -> Self {
+        self.nonzero_minor_units = nonzero_minor_units;
+        self
+    }
+
+    /// Sets whether whole amounts of `元` are forbidden.
+    pub fn with_forbid_round_amounts(mut self, forbid_round_amounts: bool) -> Self {
+        self.forbid_round_amounts = forbid_round_amounts;
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
     /// Creates a random instance of [RenminbiCurrency].
     ///
+    /// Fails with [ChineseRandError::EmptyRange] if the yuan range is empty,
+    /// with [ChineseRandError::InvalidRange] if the yuan step is 0,
+    /// and with [ChineseRandError::InvalidParams] if round amounts are forbidden
+    /// without including `角` or `分`.
+    ///
+    /// When the [Level] only allows whole amounts of `元`, no `角` or `分`
+    /// are generated - regardless of the flags.
+    ///
+    /// The style prescribed by the [Level], if any, prevails over the one
+    /// in the parameters, which in turn prevails over the one in the
+    /// [GeneratorConfig](crate::GeneratorConfig); the [RegionProfile](crate::RegionProfile)
+    /// can then adapt the result.
+    ///
     /// ```
     /// use chinese_rand::*;
     /// use chinese_format::{Variant, ChineseFormat, currency::{RenminbiCurrency, CurrencyStyle}};
     ///
+    /// # fn main() -> GenericResult<()> {
     /// let raw_generator = FastRandGenerator::new();
     /// let generator = ChineseFormatGenerator::new(raw_generator);
     ///
     /// fastrand::seed(90);
     /// let currency: RenminbiCurrency = generator.renminbi(RenminbiParams {
-    ///     style: CurrencyStyle::Everyday { formal: true },
+    ///     style: Some(CurrencyStyle::Everyday { formal: true }),
     ///     yuan_range: 0..=500,
+    ///     yuan_step: 1,
     ///     include_dimes: true,
-    ///     include_cents: true
-    /// });
+    ///     include_cents: true,
+    ///     nonzero_minor_units: false,
+    ///     forbid_round_amounts: false
+    /// })?;
     /// assert_eq!(
     ///     currency.to_chinese(Variant::Simplified),
     ///     "二百九十五元三角七分"
@@ -44,11 +216,14 @@ impl ChineseFormatGenerator {
     ///
     /// fastrand::seed(90);
     /// let currency: RenminbiCurrency = generator.renminbi(RenminbiParams {
-    ///     style: CurrencyStyle::Everyday { formal: true },
+    ///     style: Some(CurrencyStyle::Everyday { formal: true }),
     ///     yuan_range: 0..=500,
+    ///     yuan_step: 1,
     ///     include_dimes: false,
-    ///     include_cents: false
-    /// });
+    ///     include_cents: false,
+    ///     nonzero_minor_units: false,
+    ///     forbid_round_amounts: false
+    /// })?;
     /// assert_eq!(
     ///     currency.to_chinese(Variant::Simplified),
     ///     "二百九十五元"
@@ -56,11 +231,14 @@ impl ChineseFormatGenerator {
     ///
     /// fastrand::seed(90);
     /// let currency: RenminbiCurrency = generator.renminbi(RenminbiParams {
-    ///     style: CurrencyStyle::Everyday { formal: true },
+    ///     style: Some(CurrencyStyle::Everyday { formal: true }),
     ///     yuan_range: 0..=500,
+    ///     yuan_step: 1,
     ///     include_dimes: true,
-    ///     include_cents: false
-    /// });
+    ///     include_cents: false,
+    ///     nonzero_minor_units: false,
+    ///     forbid_round_amounts: false
+    /// })?;
     /// assert_eq!(
     ///     currency.to_chinese(Variant::Simplified),
     ///     "二百九十五元三角"
@@ -68,11 +246,14 @@ impl ChineseFormatGenerator {
     ///
     /// fastrand::seed(90);
     /// let currency: RenminbiCurrency = generator.renminbi(RenminbiParams {
-    ///     style: CurrencyStyle::Everyday { formal: true },
+    ///     style: Some(CurrencyStyle::Everyday { formal: true }),
     ///     yuan_range: 0..=500,
+    ///     yuan_step: 1,
     ///     include_dimes: false,
-    ///     include_cents: true
-    /// });
+    ///     include_cents: true,
+    ///     nonzero_minor_units: false,
+    ///     forbid_round_amounts: false
+    /// })?;
     /// assert_eq!(
     ///     currency.to_chinese(Variant::Simplified),
     ///     "二百九十五元三分"
@@ -80,11 +261,14 @@ impl ChineseFormatGenerator {
     ///
     /// fastrand::seed(90);
     /// let currency: RenminbiCurrency = generator.renminbi(RenminbiParams {
-    ///     style: CurrencyStyle::Everyday { formal: false },
+    ///     style: Some(CurrencyStyle::Everyday { formal: false }),
     ///     yuan_range: 0..=500,
+    ///     yuan_step: 1,
     ///     include_dimes: true,
-    ///     include_cents: true
-    /// });
+    ///     include_cents: true,
+    ///     nonzero_minor_units: false,
+    ///     forbid_round_amounts: false
+    /// })?;
     /// assert_eq!(
     ///     currency.to_chinese(Variant::Simplified),
     ///     "二百九十五块三毛七分"
@@ -92,11 +276,14 @@ impl ChineseFormatGenerator {
     ///
     /// fastrand::seed(90);
     /// let currency: RenminbiCurrency = generator.renminbi(RenminbiParams {
-    ///     style: CurrencyStyle::Financial,
+    ///     style: Some(CurrencyStyle::Financial),
     ///     yuan_range: 0..=500,
+    ///     yuan_step: 1,
     ///     include_dimes: true,
-    ///     include_cents: true
-    /// });
+    ///     include_cents: true,
+    ///     nonzero_minor_units: false,
+    ///     forbid_round_amounts: false
+    /// })?;
     /// assert_eq!(
     ///     currency.to_chinese(Variant::Simplified),
     ///     "贰佰玖拾伍元叁角柒分整"
@@ -104,11 +291,14 @@ impl ChineseFormatGenerator {
     ///
     /// fastrand::seed(90);
     /// let fixed_yuan = generator.renminbi(RenminbiParams {
-    ///     style: CurrencyStyle::Everyday { formal: true },
+    ///     style: Some(CurrencyStyle::Everyday { formal: true }),
     ///     yuan_range: 73..=73,
+    ///     yuan_step: 1,
     ///     include_dimes: true,
-    ///     include_cents: true
-    /// });
+    ///     include_cents: true,
+    ///     nonzero_minor_units: false,
+    ///     forbid_round_amounts: false
+    /// })?;
     /// assert_eq!(
     ///     fixed_yuan.to_chinese(Variant::Simplified),
     ///     "七十三元三角七分"
@@ -116,33 +306,241 @@ impl ChineseFormatGenerator {
     ///
     /// fastrand::seed(90);
     /// let zero = generator.renminbi(RenminbiParams {
-    ///     style: CurrencyStyle::Everyday { formal: true },
+    ///     style: Some(CurrencyStyle::Everyday { formal: true }),
     ///     yuan_range: 0..=0,
+    ///     yuan_step: 1,
     ///     include_dimes: false,
-    ///     include_cents: false
-    /// });
+    ///     include_cents: false,
+    ///     nonzero_minor_units: false,
+    ///     forbid_round_amounts: false
+    /// })?;
     /// assert_eq!(
     ///     zero.to_chinese(Variant::Simplified),
     ///     "零元"
     /// );
+    ///
+    /// fastrand::seed(90);
+    /// let priced = generator.renminbi(
+    ///     RenminbiParams::default()
+    ///         .with_yuan_range(0..=500)
+    ///         .with_nonzero_minor_units(true)
+    /// )?;
+    /// assert_eq!(
+    ///     priced.to_chinese(Variant::Simplified),
+    ///     "二百九十五元四角七分"
+    /// );
+    ///
+    /// fastrand::seed(90);
+    /// let non_round = generator.renminbi(
+    ///     RenminbiParams::default()
+    ///         .with_yuan_range(0..=500)
+    ///         .with_dimes(false)
+    ///         .with_forbid_round_amounts(true)
+    /// )?;
+    /// assert_eq!(
+    ///     non_round.to_chinese(Variant::Simplified),
+    ///     "二百九十五元四分"
+    /// );
+    ///
+    /// fastrand::seed(90);
+    /// let apartment = generator.renminbi(
+    ///     RenminbiParams::default().with_magnitude(YuanMagnitude::RealEstate)
+    /// )?;
+    /// assert_eq!(
+    ///     apartment.to_chinese(Variant::Simplified),
+    ///     "一千一百九十九万元"
+    /// );
+    ///
+    /// let financial = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    ///     .with_config(GeneratorConfig::default().with_currency_style(CurrencyStyle::Financial));
+    /// let configured = financial.renminbi(RenminbiParams::default().with_yuan_range(73..=73))?;
+    /// assert_eq!(configured.to_chinese(Variant::Simplified), "柒拾叁元叁角柒分整");
+    ///
+    /// let everyday = RenminbiParams::default()
+    ///     .with_yuan_range(73..=73)
+    ///     .with_style(CurrencyStyle::Everyday { formal: true });
+    /// let requested = financial.renminbi(everyday)?;
+    /// assert_eq!(requested.to_chinese(Variant::Simplified), "七十三元四角两分");
+    ///
+    /// let impossible = generator.renminbi(
+    ///     RenminbiParams::default()
+    ///         .with_dimes(false)
+    ///         .with_cents(false)
+    ///         .with_forbid_round_amounts(true)
+    /// );
+    /// assert_eq!(
+    ///     impossible,
+    ///     Err(ChineseRandError::InvalidParams(
+    ///         "Round amounts cannot be forbidden without 角 or 分".to_string()
+    ///     ))
+    /// );
+    ///
+    /// let inverted = generator.renminbi(
+    ///     RenminbiParams::default().with_yuan_range(10..=1)
+    /// );
+    /// assert_eq!(
+    ///     inverted,
+    ///     Err(ChineseRandError::EmptyRange("10..=1".to_string()))
+    /// );
+    /// # Ok(())
+    /// # }
     /// ```
     ///
     /// **Required feature**: `currency`.
-    pub fn renminbi(&self, params: RenminbiParams) -> RenminbiCurrency {
-        let mut builder = RenminbiCurrencyBuilder::new()
-            .with_style(params.style)
-            .with_yuan(self.raw_generator.u64(params.yuan_range));
+    pub fn renminbi(&self, params: RenminbiParams) -> ChineseRandResult<RenminbiCurrency> {
+        check_range(&params.yuan_range)?;
 
-        if params.include_dimes {
-            builder = builder.with_dimes(self.raw_generator.u8(0..=9))
+        if params.yuan_step == 0 {
+            return Err(ChineseRandError::InvalidRange("Yuan step: 0".to_string()));
         }
 
-        if params.include_cents {
-            builder = builder.with_cents(self.raw_generator.u8(0..=9))
+        if params.forbid_round_amounts && !params.include_dimes && !params.include_cents {
+            return Err(ChineseRandError::InvalidParams(
+                "Round amounts cannot be forbidden without 角 or 分".to_string(),
+            ));
+        }
+
+        let style = self
+            .level
+            .and_then(|level| level.currency_style())
+            .or(params.style)
+            .or(self.config.currency_style)
+            .unwrap_or(CurrencyStyle::Everyday { formal: true });
+
+        let style = match self.region {
+            Some(region) => region.currency_style(style),
+            None => style,
+        };
+
+        let whole_yuan = self.level.is_some_and(|level| level.whole_yuan());
+
+        let yuan_range = self.leveled(params.yuan_range, Level::yuan_range)?;
+
+        let yuan = if params.yuan_step == 1 {
+            self.raw_generator.u64(yuan_range)
+        } else {
+            let (start, end) = yuan_range.into_inner();
+
+            start + params.yuan_step * self.raw_generator.u64(0..=(end - start) / params.yuan_step)
+        };
+
+        self.record("renminbi", || magnitude_bucket(yuan as i128));
+
+        let mut builder = RenminbiCurrencyBuilder::new()
+            .with_style(style)
+            .with_yuan(yuan);
+
+        let include_dimes = params.include_dimes && !whole_yuan;
+        let include_cents = params.include_cents && !whole_yuan;
+
+        if include_dimes
+            && include_cents
+            && params.forbid_round_amounts
+            && !params.nonzero_minor_units
+        {
+            let minor_units = self.raw_generator.u8(1..=99);
+
+            builder = builder
+                .with_dimes(minor_units / 10)
+                .with_cents(minor_units % 10);
+        } else {
+            let digit_range = if params.nonzero_minor_units || params.forbid_round_amounts {
+                1..=9
+            } else {
+                0..=9
+            };
+
+            if include_dimes {
+                builder = builder.with_dimes(self.raw_generator.u8(digit_range.clone()))
+            }
+
+            if include_cents {
+                builder = builder.with_cents(self.raw_generator.u8(digit_range))
+            }
         }
 
-        builder
+        Ok(builder
             .build()
-            .expect("Renminbi params correct by construction")
+            .expect("Renminbi params correct by construction"))
+    }
+}
+
+/// A [RenminbiCurrency] together with its standard numeric form -
+/// like `¥295.37` - both coming from the very same random draw.
+///
+/// **Required feature**: `currency`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RenminbiAmount {
+    /// The generated currency.
+    pub currency: RenminbiCurrency,
+
+    /// The numeric form, always with 2 decimal digits.
+    pub numeral: String,
+}
+
+impl RenminbiAmount {
+    /// Pairs the given currency with its numeric form.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::currency::RenminbiCurrencyBuilder;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let currency = RenminbiCurrencyBuilder::new()
+    ///     .with_yuan(1024)
+    ///     .with_cents(5)
+    ///     .build()?;
+    ///
+    /// assert_eq!(RenminbiAmount::new(currency).numeral, "¥1024.05");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(currency: RenminbiCurrency) -> Self {
+        let numeral = format!(
+            "¥{}.{}{}",
+            currency.yuan(),
+            currency.dimes(),
+            currency.cents()
+        );
+
+        Self { currency, numeral }
     }
 }
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Like [renminbi](Self::renminbi), but also returning the numeric form
+    /// of the amount - for invoice fixtures where the two must agree.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let amount = generator.renminbi_amount(
+    ///     RenminbiParams::default().with_yuan_range(0..=500)
+    /// )?;
+    ///
+    /// assert_eq!(amount.currency.to_chinese(Variant::Simplified), "二百九十五元三角七分");
+    /// assert_eq!(amount.numeral, "¥295.37");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `currency`.
+    pub fn renminbi_amount(&self, params: RenminbiParams) -> ChineseRandResult<RenminbiAmount> {
+        self.renminbi(params).map(RenminbiAmount::new)
+    }
+}
+
+/// Builds the [RenminbiCurrency] corresponding to the given amount of `分`.
+pub(crate) fn renminbi_from_cents(cents: u64, style: CurrencyStyle) -> RenminbiCurrency {
+    RenminbiCurrencyBuilder::new()
+        .with_style(style)
+        .with_yuan(cents / 100)
+        .with_dimes((cents / 10 % 10) as u8)
+        .with_cents((cents % 10) as u8)
+        .build()
+        .expect("Renminbi valid by construction")
+}
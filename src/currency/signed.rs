@@ -0,0 +1,218 @@
+use crate::{
+    ChineseFormatGenerator, ChineseRandResult, RawGenerator, RenminbiAmount, RenminbiParams,
+};
+use chinese_format::{chinese_vec, currency::RenminbiCurrency, Chinese, ChineseFormat, Variant};
+
+/// How negative amounts are introduced.
+///
+/// **Required feature**: `currency`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum NegativePrefix {
+    /// `负` - the arithmetic sign, as in debits on a ledger.
+    #[default]
+    Fu,
+
+    /// `退` - as in refunds.
+    Tui,
+}
+
+/// Which signs can be drawn for a [SignedRenminbi].
+///
+/// **Required feature**: `currency`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum AmountSign {
+    /// Only non-negative amounts.
+    #[default]
+    NonNegative,
+
+    /// Only negative amounts - except zero, which has no sign.
+    Negative,
+
+    /// Both signs, with the same probability.
+    Any,
+}
+
+/// Parameters for the random creation of [SignedRenminbi].
+///
+/// **Required feature**: `currency`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SignedRenminbiParams {
+    /// The parameters of the absolute value.
+    pub renminbi: RenminbiParams,
+
+    /// The allowed signs.
+    pub sign: AmountSign,
+
+    /// The prefix of negative amounts.
+    pub negative_prefix: NegativePrefix,
+}
+
+impl SignedRenminbiParams {
+    /// Sets the parameters of the absolute value.
+    pub fn with_renminbi(mut self, renminbi: RenminbiParams) -> Self {
+        self.renminbi = renminbi;
+        self
+    }
+
+    /// Sets the allowed signs.
+    pub fn with_sign(mut self, sign: AmountSign) -> Self {
+        self.sign = sign;
+        self
+    }
+
+    /// Sets the prefix of negative amounts.
+    pub fn with_negative_prefix(mut self, negative_prefix: NegativePrefix) -> Self {
+        self.negative_prefix = negative_prefix;
+        self
+    }
+}
+
+/// [RenminbiCurrency] that can be negative - like refunds or debits in accounting data.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant, currency::RenminbiCurrencyBuilder};
+///
+/// # fn main() -> GenericResult<()> {
+/// let currency = RenminbiCurrencyBuilder::new().with_yuan(35).with_dimes(5).build()?;
+///
+/// let debit = SignedRenminbi {
+///     currency,
+///     negative: true,
+///     negative_prefix: NegativePrefix::Fu
+/// };
+/// assert_eq!(debit.to_chinese(Variant::Simplified), "负三十五元五角");
+/// assert_eq!(debit.to_chinese(Variant::Traditional), "負三十五元五角");
+/// assert_eq!(debit.numeral(), "-¥35.50");
+///
+/// let refund = SignedRenminbi {
+///     currency,
+///     negative: true,
+///     negative_prefix: NegativePrefix::Tui
+/// };
+/// assert_eq!(refund.to_chinese(Variant::Simplified), "退三十五元五角");
+///
+/// let credit = SignedRenminbi {
+///     currency,
+///     negative: false,
+///     negative_prefix: NegativePrefix::Tui
+/// };
+/// assert_eq!(credit.to_chinese(Variant::Simplified), "三十五元五角");
+/// assert_eq!(credit.numeral(), "¥35.50");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **Required feature**: `currency`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignedRenminbi {
+    /// The absolute value.
+    pub currency: RenminbiCurrency,
+
+    /// Whether the amount is negative.
+    pub negative: bool,
+
+    /// The prefix rendered when the amount is negative.
+    pub negative_prefix: NegativePrefix,
+}
+
+impl SignedRenminbi {
+    /// The numeric form, always with 2 decimal digits - like `-¥35.50`.
+    pub fn numeral(&self) -> String {
+        let sign = if self.negative { "-" } else { "" };
+
+        format!("{}{}", sign, RenminbiAmount::new(self.currency).numeral)
+    }
+}
+
+impl ChineseFormat for SignedRenminbi {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let prefix: &dyn ChineseFormat = match (self.negative, self.negative_prefix) {
+            (false, _) => &"",
+            (true, NegativePrefix::Fu) => &("负", "負"),
+            (true, NegativePrefix::Tui) => &"退",
+        };
+
+        chinese_vec!(variant, [prefix, self.currency]).collect()
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Creates a random [SignedRenminbi] - whose absolute value is generated
+    /// just like [renminbi](Self::renminbi), before drawing the sign.
+    ///
+    /// Zero amounts are never negative.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let params = SignedRenminbiParams::default()
+    ///     .with_renminbi(RenminbiParams::default().with_yuan_range(0..=500));
+    ///
+    /// let credit = generator.signed_renminbi(params.clone())?;
+    /// assert_eq!(credit.to_chinese(Variant::Simplified), "二百九十五元三角七分");
+    ///
+    /// let refund = generator.signed_renminbi(
+    ///     params
+    ///         .clone()
+    ///         .with_sign(AmountSign::Negative)
+    ///         .with_negative_prefix(NegativePrefix::Tui)
+    /// )?;
+    /// assert_eq!(refund.to_chinese(Variant::Simplified), "退三百二十一元四角两分");
+    ///
+    /// let amounts = (0..6)
+    ///     .map(|_| generator.signed_renminbi(params.clone().with_sign(AmountSign::Any)))
+    ///     .map(|amount| amount.map(|amount| amount.numeral()))
+    ///     .collect::<ChineseRandResult<Vec<_>>>()?;
+    /// assert_eq!(
+    ///     amounts,
+    ///     ["¥228.01", "-¥441.24", "¥376.88", "¥19.67", "-¥476.46", "-¥49.84"]
+    /// );
+    ///
+    /// let zero = generator.signed_renminbi(
+    ///     SignedRenminbiParams::default()
+    ///         .with_renminbi(
+    ///             RenminbiParams::default()
+    ///                 .with_yuan_range(0..=0)
+    ///                 .with_dimes(false)
+    ///                 .with_cents(false)
+    ///         )
+    ///         .with_sign(AmountSign::Negative)
+    /// )?;
+    /// assert_eq!(zero.to_chinese(Variant::Simplified), "零元");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `currency`.
+    pub fn signed_renminbi(
+        &self,
+        params: SignedRenminbiParams,
+    ) -> ChineseRandResult<SignedRenminbi> {
+        let currency = self.renminbi(params.renminbi)?;
+
+        let negative = match params.sign {
+            AmountSign::NonNegative => false,
+            AmountSign::Negative => true,
+            AmountSign::Any => self.raw_generator.bool(),
+        };
+
+        let zero = currency.yuan() == 0 && currency.dimes() == 0 && currency.cents() == 0;
+
+        Ok(SignedRenminbi {
+            currency,
+            negative: negative && !zero,
+            negative_prefix: params.negative_prefix,
+        })
+    }
+}
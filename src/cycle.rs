@@ -0,0 +1,64 @@
+use crate::{ChineseFormatGenerator, RawGenerator};
+
+/// Endless iterator yielding every value of a finite domain exactly once,
+/// in random order, before starting over with a new random order -
+/// so that, for example, a 7-question quiz covers all the week days.
+///
+/// Created via [ChineseFormatGenerator::shuffled_cycle]; it yields nothing
+/// only when the domain is empty.
+pub struct ShuffledCycle<'a, T, R: RawGenerator = Box<dyn RawGenerator>> {
+    generator: &'a ChineseFormatGenerator<R>,
+    values: Vec<T>,
+    pending: Vec<T>,
+}
+
+impl<T: Clone, R: RawGenerator> Iterator for ShuffledCycle<'_, T, R> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.pending.is_empty() {
+            self.pending = self.values.clone();
+
+            // Fisher-Yates shuffle
+            for index in (1..self.pending.len()).rev() {
+                let other = self.generator.raw_generator.u64(0..=index as u64) as usize;
+                self.pending.swap(index, other);
+            }
+        }
+
+        self.pending.pop()
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Creates a [ShuffledCycle] over the given values.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let mut digits: Vec<u8> = generator.shuffled_cycle(0..=9).take(10).collect();
+    /// digits.sort();
+    /// assert_eq!(digits, (0..=9).collect::<Vec<_>>());
+    ///
+    /// let mut cycle = generator.shuffled_cycle(["甲", "乙"]);
+    /// let first_round = [cycle.next().unwrap(), cycle.next().unwrap()];
+    /// let second_round = [cycle.next().unwrap(), cycle.next().unwrap()];
+    /// assert_ne!(first_round[0], first_round[1]);
+    /// assert_ne!(second_round[0], second_round[1]);
+    ///
+    /// let mut empty = generator.shuffled_cycle(Vec::<u8>::new());
+    /// assert_eq!(empty.next(), None);
+    /// ```
+    pub fn shuffled_cycle<T: Clone>(
+        &self,
+        values: impl IntoIterator<Item = T>,
+    ) -> ShuffledCycle<'_, T, R> {
+        ShuffledCycle {
+            generator: self,
+            values: values.into_iter().collect(),
+            pending: Vec::new(),
+        }
+    }
+}
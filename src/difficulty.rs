@@ -0,0 +1,104 @@
+use crate::{ChineseFormatGenerator, RawGenerator};
+use chinese_format::{ChineseFormat, Variant};
+use std::cmp::Ordering;
+
+const ZERO: char = '零';
+const LARGE_UNITS: [char; 2] = ['万', '亿'];
+
+/// How hard a value is to say, as estimated from its
+/// [Simplified](Variant::Simplified) rendering - so that exercises
+/// can be filtered or sorted by difficulty.
+///
+/// Values are ordered by [rating](Self::rating).
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+///
+/// let easy = generator.score(&7);
+/// assert_eq!(easy, Difficulty { logograms: 1, zeros: 0, large_units: 0 });
+/// assert_eq!(easy.rating(), 1);
+///
+/// let hard = generator.score(&100_020_003);
+/// // 一亿零二万零三
+/// assert_eq!(hard, Difficulty { logograms: 7, zeros: 2, large_units: 2 });
+/// assert_eq!(hard.rating(), 17);
+///
+/// assert!(easy < hard);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Difficulty {
+    /// How many logograms must be said.
+    pub logograms: usize,
+
+    /// How many `零` are needed - a classic stumbling block.
+    pub zeros: usize,
+
+    /// How many `万`/`亿` boundaries must be crossed.
+    pub large_units: usize,
+}
+
+impl Difficulty {
+    /// Estimates the difficulty of the given value.
+    pub fn of(value: &dyn ChineseFormat) -> Self {
+        let logograms = value.to_chinese(Variant::Simplified).logograms;
+
+        Self {
+            logograms: logograms.chars().count(),
+            zeros: logograms.chars().filter(|&c| c == ZERO).count(),
+            large_units: logograms
+                .chars()
+                .filter(|c| LARGE_UNITS.contains(c))
+                .count(),
+        }
+    }
+
+    /// Overall rating - the higher, the harder: each logogram counts 1,
+    /// each `零` 2 more and each `万`/`亿` 3 more.
+    pub fn rating(&self) -> usize {
+        self.logograms + 2 * self.zeros + 3 * self.large_units
+    }
+}
+
+impl PartialOrd for Difficulty {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Difficulty {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.rating(), self.logograms, self.zeros, self.large_units).cmp(&(
+            other.rating(),
+            other.logograms,
+            other.zeros,
+            other.large_units,
+        ))
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Estimates the [Difficulty] of a value - for example, one just generated.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let mut counts = (0..5)
+    ///     .map(|_| generator.count(0..=99_999))
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// counts.sort_by_key(|count| generator.score(count));
+    ///
+    /// let ratings: Vec<usize> = counts.iter().map(|count| generator.score(count).rating()).collect();
+    /// assert!(ratings.windows(2).all(|pair| pair[0] <= pair[1]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn score(&self, value: &dyn ChineseFormat) -> Difficulty {
+        Difficulty::of(value)
+    }
+}
@@ -1,47 +1,66 @@
-use crate::ChineseFormatGenerator;
+use crate::{check_range, ChineseFormatGenerator, ChineseRandResult, Level, RawGenerator};
 use chinese_format::{Decimal, IntegerPart};
 use digit_sequence::DigitSequence;
 use std::{iter::repeat_with, ops::RangeInclusive};
 
-impl ChineseFormatGenerator {
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
     /// Generates a random [DigitSequence] with length in the given range.
     ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
     /// ```
     /// use chinese_rand::*;
     /// use digit_sequence::DigitSequence;
     ///
+    /// # fn main() -> GenericResult<()> {
     /// fastrand::seed(90);
     /// let raw_generator = FastRandGenerator::new();
     /// let generator = ChineseFormatGenerator::new(raw_generator);
     ///
-    /// let sequence = generator.digit_sequence(0..=10);
+    /// let sequence = generator.digit_sequence(0..=10)?;
     /// assert_eq!(sequence, DigitSequence::from(3724260u32));
     ///
-    /// let fixed_length = generator.digit_sequence(5..=5);
+    /// let fixed_length = generator.digit_sequence(5..=5)?;
     /// assert_eq!(fixed_length, DigitSequence::from(85241u32));
     ///
-    /// let empty = generator.digit_sequence(0..=0);
+    /// let empty = generator.digit_sequence(0..=0)?;
     /// assert_eq!(empty, DigitSequence::new());
+    ///
+    /// assert!(generator.digit_sequence(5..=0).is_err());
+    /// # Ok(())
+    /// # }
     /// ```
     ///
     /// **Required feature**: `digit-sequence`.
-    pub fn digit_sequence(&self, length_range: RangeInclusive<u8>) -> DigitSequence {
-        let length = self.raw_generator.u8(length_range);
+    pub fn digit_sequence(
+        &self,
+        length_range: RangeInclusive<u8>,
+    ) -> ChineseRandResult<DigitSequence> {
+        check_range(&length_range)?;
+
+        let length = self
+            .raw_generator
+            .u8(self.leveled(length_range, Level::fractional_length_range)?);
 
         let digits: Vec<u8> = repeat_with(|| self.raw_generator.u8(0..=9))
             .take(length as usize)
             .collect();
 
-        digits.try_into().expect("Digits valid by construction")
+        Ok(digits.try_into().expect("Digits valid by construction"))
     }
 
     /// Generates a random [Decimal].
     ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if either range is empty.
+    ///
     /// ```
     /// use chinese_rand::*;
     /// use chinese_format::Decimal;
     /// use digit_sequence::DigitSequence;
     ///
+    /// # fn main() -> GenericResult<()> {
     /// fastrand::seed(90);
     /// let raw_generator = FastRandGenerator::new();
     /// let generator = ChineseFormatGenerator::new(raw_generator);
@@ -49,23 +68,27 @@ impl ChineseFormatGenerator {
     /// let decimal = generator.decimal(
     ///     i128::MIN..=i128::MAX,
     ///     0..=4
-    /// );
+    /// )?;
     /// assert_eq!(decimal, Decimal {
     ///     integer: -139744823884027955216713073977120108615,
     ///     fractional: 242u8.into()
     /// });
     ///
-    /// let fixed = generator.decimal(90..=90, 5..=5);
+    /// let fixed = generator.decimal(90..=90, 5..=5)?;
     /// assert_eq!(fixed, Decimal {
     ///     integer: 90,
     ///     fractional: 85241u32.into()
     /// });
     ///
-    /// let zero = generator.decimal(0..=0, 0..=0);
+    /// let zero = generator.decimal(0..=0, 0..=0)?;
     /// assert_eq!(zero, Decimal {
     ///     integer: 0,
     ///     fractional: DigitSequence::new()
     /// });
+    ///
+    /// assert!(generator.decimal(9..=0, 0..=0).is_err());
+    /// # Ok(())
+    /// # }
     /// ```
     ///
     /// **Required feature**: `digit-sequence`.
@@ -73,14 +96,19 @@ impl ChineseFormatGenerator {
         &self,
         integer_range: RangeInclusive<IntegerPart>,
         fractional_length_range: RangeInclusive<u8>,
-    ) -> Decimal {
-        let integer: IntegerPart = self.raw_generator.i128(integer_range);
+    ) -> ChineseRandResult<Decimal> {
+        check_range(&integer_range)?;
+        check_range(&fractional_length_range)?;
+
+        let integer: IntegerPart = self
+            .raw_generator
+            .i128(self.leveled(integer_range, Level::integer_range)?);
 
-        let fractional: DigitSequence = self.digit_sequence(fractional_length_range);
+        let fractional: DigitSequence = self.digit_sequence(fractional_length_range)?;
 
-        Decimal {
+        Ok(Decimal {
             integer,
             fractional,
-        }
+        })
     }
 }
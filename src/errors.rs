@@ -1,5 +1,7 @@
+use chinese_format::ZeroDenominator;
 use std::error::Error;
 use std::fmt::{Debug, Display};
+use std::ops::RangeInclusive;
 
 /// When a lower bound is not acceptable.
 ///
@@ -20,3 +22,134 @@ impl<T: Display> Display for InvalidLowerBound<T> {
 }
 
 impl<T: Display + Debug> Error for InvalidLowerBound<T> {}
+
+/// Every failure kind that can arise while generating values.
+///
+/// Each variant carries a human-readable description, also
+/// returned by its [Display] implementation:
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// let err = ChineseRandError::EmptyRange("10..=3".to_string());
+///
+/// assert_eq!(err.to_string(), "Empty range: 10..=3");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ChineseRandError {
+    /// A range contains values that cannot be accepted - like a 0 denominator.
+    InvalidRange(String),
+
+    /// A range contains no values at all.
+    EmptyRange(String),
+
+    /// A [chinese_format] builder rejected the generated components.
+    BuildFailed(String),
+
+    /// The requested generation is not supported.
+    Unsupported(String),
+
+    /// Writing the generated values failed.
+    Io(String),
+
+    /// No valid value was found within the retry budget - see
+    /// [ChineseFormatGenerator::with_max_retries](crate::ChineseFormatGenerator::with_max_retries).
+    GenerationExhausted(String),
+
+    /// A template is malformed, or refers to an unknown placeholder.
+    InvalidTemplate(String),
+
+    /// Parameters cannot be interpreted - because they are inconsistent,
+    /// or cannot be read from their serialized form.
+    InvalidParams(String),
+
+    /// The generation panicked - reported where unwinding is not allowed,
+    /// like across the C-compatible interface.
+    Panicked(String),
+
+    /// A check of the [testing](crate::testing) helpers failed - like a value
+    /// differing from its golden file.
+    AssertionFailed(String),
+}
+
+impl Display for ChineseRandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChineseRandError::InvalidRange(message) => write!(f, "Invalid range: {}", message),
+            ChineseRandError::EmptyRange(message) => write!(f, "Empty range: {}", message),
+            ChineseRandError::BuildFailed(message) => write!(f, "Build failed: {}", message),
+            ChineseRandError::Unsupported(message) => write!(f, "Unsupported: {}", message),
+            ChineseRandError::Io(message) => write!(f, "I/O error: {}", message),
+            ChineseRandError::GenerationExhausted(message) => {
+                write!(f, "Generation exhausted: {}", message)
+            }
+            ChineseRandError::InvalidTemplate(message) => {
+                write!(f, "Invalid template: {}", message)
+            }
+            ChineseRandError::InvalidParams(message) => {
+                write!(f, "Invalid params: {}", message)
+            }
+            ChineseRandError::Panicked(message) => write!(f, "Panicked: {}", message),
+            ChineseRandError::AssertionFailed(message) => {
+                write!(f, "Assertion failed: {}", message)
+            }
+        }
+    }
+}
+
+impl Error for ChineseRandError {}
+
+/// [InvalidLowerBound] maps to [ChineseRandError::InvalidRange].
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// let err: ChineseRandError = InvalidLowerBound(0).into();
+///
+/// assert_eq!(
+///     err,
+///     ChineseRandError::InvalidRange("Invalid lower bound: 0".to_string())
+/// );
+/// ```
+impl<T: Display> From<InvalidLowerBound<T>> for ChineseRandError {
+    fn from(value: InvalidLowerBound<T>) -> Self {
+        ChineseRandError::InvalidRange(value.to_string())
+    }
+}
+
+/// [ZeroDenominator] maps to [ChineseRandError::BuildFailed].
+impl From<ZeroDenominator> for ChineseRandError {
+    fn from(value: ZeroDenominator) -> Self {
+        ChineseRandError::BuildFailed(value.to_string())
+    }
+}
+
+/// The boxed errors returned by the [chinese_format] builders
+/// map to [ChineseRandError::BuildFailed].
+impl From<Box<dyn Error>> for ChineseRandError {
+    fn from(value: Box<dyn Error>) -> Self {
+        ChineseRandError::BuildFailed(value.to_string())
+    }
+}
+
+/// I/O errors - for example, while exporting - map to [ChineseRandError::Io].
+impl From<std::io::Error> for ChineseRandError {
+    fn from(value: std::io::Error) -> Self {
+        ChineseRandError::Io(value.to_string())
+    }
+}
+
+/// [Result] whose error is always a [ChineseRandError].
+pub type ChineseRandResult<T> = Result<T, ChineseRandError>;
+
+/// Fails with [ChineseRandError::EmptyRange] if the given range contains no values.
+pub(crate) fn check_range<T: PartialOrd + Debug>(
+    range: &RangeInclusive<T>,
+) -> ChineseRandResult<()> {
+    if range.is_empty() {
+        Err(ChineseRandError::EmptyRange(format!("{:?}", range)))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use crate::template::{FilledPart, Template};
+use crate::{ChineseFormatGenerator, ChineseRandError, ChineseRandResult, PlanValue, RawGenerator};
+
+/// Gap-fill exercise: a sentence where one generated value
+/// is replaced by a blank.
+#[derive(Debug)]
+pub struct Cloze {
+    /// The sentence, with the blank in place of the withheld value.
+    pub sentence: String,
+
+    /// The name of the placeholder whose value was withheld.
+    pub name: String,
+
+    /// The withheld value, rendered in Chinese.
+    pub answer: String,
+
+    /// The withheld value.
+    pub value: Box<dyn PlanValue>,
+}
+
+/// Parameters for the random creation of [Cloze].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClozeParams {
+    /// The text replacing the withheld value.
+    pub blank: String,
+
+    /// The name of the placeholder to withhold.
+    /// If set to [None], a random placeholder is chosen.
+    pub target: Option<String>,
+}
+
+/// By default, [ClozeParams] withholds a random placeholder,
+/// replaced by `＿＿＿`.
+///
+/// ```
+/// use chinese_rand::exercise::*;
+///
+/// assert_eq!(ClozeParams::default(), ClozeParams {
+///     blank: "＿＿＿".to_string(),
+///     target: None
+/// });
+///
+/// let customized = ClozeParams::default()
+///     .with_blank("（ ）")
+///     .with_target("price");
+///
+/// assert_eq!(customized, ClozeParams {
+///     blank: "（ ）".to_string(),
+///     target: Some("price".to_string())
+/// });
+/// ```
+impl Default for ClozeParams {
+    fn default() -> Self {
+        Self {
+            blank: "＿＿＿".to_string(),
+            target: None,
+        }
+    }
+}
+
+impl ClozeParams {
+    /// Sets the text replacing the withheld value.
+    pub fn with_blank(mut self, blank: &str) -> Self {
+        self.blank = blank.to_string();
+        self
+    }
+
+    /// Sets the name of the placeholder to withhold.
+    pub fn with_target(mut self, target: &str) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Fills the given [Template] - via [fill](Self::fill) - then withholds
+    /// one of the generated values, returning a [Cloze].
+    ///
+    /// If the target placeholder appears more than once, only its
+    /// first occurrence is withheld.
+    ///
+    /// Fails with [ChineseRandError::InvalidTemplate] if the template
+    /// has no placeholders or lacks the target one, or with any error
+    /// arising from the generation itself.
+    ///
+    /// ```
+    /// use chinese_rand::{*, exercise::*, template::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let template = Template::parse("他今年{age}，有{count}本书")?
+    ///     .with_placeholder("count", PlanItem::Count { range: 1..=9 });
+    ///
+    /// let cloze = generator.cloze(&template, ClozeParams::default())?;
+    /// assert_eq!(cloze.sentence, "他今年＿＿＿，有六本书");
+    /// assert_eq!(cloze.answer, "四十三岁");
+    ///
+    /// let cloze = generator.cloze(&template, ClozeParams::default().with_target("count"))?;
+    /// assert_eq!(cloze.sentence, "他今年二十三岁，有＿＿＿本书");
+    /// assert_eq!(cloze.answer, "一");
+    ///
+    /// assert!(generator
+    ///     .cloze(&template, ClozeParams::default().with_target("price"))
+    ///     .is_err());
+    /// assert!(generator
+    ///     .cloze(&Template::parse("没有空格")?, ClozeParams::default())
+    ///     .is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cloze(&self, template: &Template, params: ClozeParams) -> ChineseRandResult<Cloze> {
+        let filled = self.fill(template)?;
+
+        let names: Vec<&str> = filled.values().map(|value| value.name.as_str()).collect();
+
+        let withheld_index = match &params.target {
+            Some(target) => names
+                .iter()
+                .position(|name| name == target)
+                .ok_or_else(|| {
+                    ChineseRandError::InvalidTemplate(format!("Missing placeholder: {}", target))
+                })?,
+
+            None => {
+                if names.is_empty() {
+                    return Err(ChineseRandError::InvalidTemplate(
+                        "No placeholders".to_string(),
+                    ));
+                }
+
+                self.raw_generator.u64(0..=(names.len() as u64 - 1)) as usize
+            }
+        };
+
+        let mut sentence = String::new();
+        let mut withheld = None;
+        let mut value_index = 0;
+
+        for part in filled.parts {
+            match part {
+                FilledPart::Text(text) => sentence.push_str(&text),
+
+                FilledPart::Value(value) => {
+                    if value_index == withheld_index {
+                        sentence.push_str(&params.blank);
+                        withheld = Some(value);
+                    } else {
+                        sentence.push_str(&value.rendered);
+                    }
+
+                    value_index += 1;
+                }
+            }
+        }
+
+        let withheld = withheld.expect("Withheld value found by construction");
+
+        Ok(Cloze {
+            sentence,
+            name: withheld.name,
+            answer: withheld.rendered,
+            value: withheld.value,
+        })
+    }
+}
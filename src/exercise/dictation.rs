@@ -0,0 +1,278 @@
+use crate::corpus::check_weights;
+use crate::{ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use chinese_format::{ChineseFormat, Variant};
+
+#[cfg(feature = "currency")]
+use chinese_format::currency::CurrencyStyle;
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::clock_time;
+
+const INTEGER_RANGE_END: i128 = 99_999;
+#[cfg(feature = "gregorian")]
+const MINUTES_PER_DAY: u16 = 24 * 60;
+
+#[cfg(feature = "currency")]
+const RENMINBI_CENTS_END: u64 = 999_999;
+
+/// Kind of value to be written down in a dictation drill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DictationKind {
+    /// Integer from 0 to 99999 - like `12345` or `12,345`.
+    Integer,
+
+    /// Time of the day - like `16:20` or `4:20 PM`.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Time,
+
+    /// Date - like `2025-08-04` or `2025/8/4`.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Date,
+
+    /// Amount of Renminbi up to 9999.99 yuan - like `12.50` or `¥12.50`.
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    Renminbi,
+}
+
+/// A kind in the mix of a dictation pack, with its relative weight.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DictationEntry {
+    /// The kind of value.
+    pub kind: DictationKind,
+
+    /// How often the kind is chosen, relative to the other entries.
+    pub weight: u32,
+}
+
+/// Item of a dictation drill: the Chinese renderings - to be spoken,
+/// for example via TTS - and the notations accepted as answers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DictationItem {
+    /// The kind of value.
+    pub kind: DictationKind,
+
+    /// The rendering in simplified Chinese.
+    pub simplified: String,
+
+    /// The rendering in traditional Chinese.
+    pub traditional: String,
+
+    /// The expected answer, in Arabic numerals.
+    pub answer: String,
+
+    /// Other notations that should be accepted as well.
+    pub alternatives: Vec<String>,
+}
+
+impl DictationItem {
+    fn new(
+        kind: DictationKind,
+        value: &dyn ChineseFormat,
+        answer: String,
+        alternatives: Vec<String>,
+    ) -> Self {
+        Self {
+            kind,
+            simplified: value.to_chinese(Variant::Simplified).to_string(),
+            traditional: value.to_chinese(Variant::Traditional).to_string(),
+            answer,
+            alternatives,
+        }
+    }
+
+    /// Whether the given notation - ignoring surrounding whitespace - is
+    /// either the answer or one of the alternatives.
+    ///
+    /// ```
+    /// use chinese_rand::exercise::*;
+    ///
+    /// let item = DictationItem {
+    ///     kind: DictationKind::Integer,
+    ///     simplified: "一千二百".to_string(),
+    ///     traditional: "一千二百".to_string(),
+    ///     answer: "1200".to_string(),
+    ///     alternatives: vec!["1,200".to_string()]
+    /// };
+    ///
+    /// assert!(item.accepts("1200"));
+    /// assert!(item.accepts(" 1,200 "));
+    /// assert!(!item.accepts("120"));
+    /// ```
+    pub fn accepts(&self, notation: &str) -> bool {
+        let notation = notation.trim();
+
+        self.answer == notation
+            || self
+                .alternatives
+                .iter()
+                .any(|alternative| alternative == notation)
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a pack of dictation items - each kind being chosen
+    /// with probability proportional to its weight in the mix.
+    ///
+    /// Fails with [ChineseRandError::InvalidRange](crate::ChineseRandError::InvalidRange)
+    /// if the total weight of the mix is 0.
+    ///
+    /// ```
+    /// use chinese_rand::{*, exercise::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let pack = generator.dictation_pack(2, &[DictationEntry {
+    ///     kind: DictationKind::Integer,
+    ///     weight: 1
+    /// }])?;
+    ///
+    /// assert_eq!(pack.len(), 2);
+    /// assert_eq!(pack[0].simplified, "一百七十三");
+    /// assert_eq!(pack[0].answer, "173");
+    /// assert!(pack[0].alternatives.is_empty());
+    ///
+    /// assert_eq!(pack[1].simplified, "三万四千六百七十七");
+    /// assert_eq!(pack[1].answer, "34677");
+    /// assert_eq!(pack[1].alternatives, vec!["34,677"]);
+    ///
+    /// assert!(generator.dictation_pack(2, &[]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Times, dates and amounts come with their alternative notations:
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "currency", feature = "gregorian"))]
+    /// # {
+    /// use chinese_rand::{*, exercise::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let single = |kind| DictationEntry { kind, weight: 1 };
+    ///
+    /// let time = &generator.dictation_pack(1, &[single(DictationKind::Time)])?[0];
+    /// assert_eq!(time.simplified, "上午八点二十分");
+    /// assert_eq!(time.answer, "8:20");
+    /// assert_eq!(time.alternatives, vec!["08:20", "8:20 AM"]);
+    ///
+    /// let date = &generator.dictation_pack(1, &[single(DictationKind::Date)])?[0];
+    /// assert_eq!(date.simplified, "二零一九年一月二十一日");
+    /// assert_eq!(date.answer, "2019-01-21");
+    /// assert_eq!(date.alternatives, vec!["2019/1/21", "2019年1月21日"]);
+    ///
+    /// let amount = &generator.dictation_pack(1, &[single(DictationKind::Renminbi)])?[0];
+    /// assert_eq!(amount.simplified, "二千二百五十五元四角");
+    /// assert_eq!(amount.answer, "2255.40");
+    /// assert_eq!(amount.alternatives, vec!["¥2255.40", "2255.4"]);
+    /// # Ok(())
+    /// # }
+    /// # main().unwrap();
+    /// # }
+    /// ```
+    pub fn dictation_pack(
+        &self,
+        size: usize,
+        mix: &[DictationEntry],
+    ) -> ChineseRandResult<Vec<DictationItem>> {
+        check_weights("dictation", mix, |entry| entry.weight)?;
+
+        (0..size)
+            .map(|_| {
+                let entry = self.weighted_pick(mix, |entry| entry.weight);
+
+                self.dictation_item(entry.kind)
+            })
+            .collect()
+    }
+
+    fn dictation_item(&self, kind: DictationKind) -> ChineseRandResult<DictationItem> {
+        Ok(match kind {
+            DictationKind::Integer => {
+                let value = self.integer(0..=INTEGER_RANGE_END)?;
+
+                let alternatives = if value >= 1000 {
+                    vec![format!("{},{:03}", value / 1000, value % 1000)]
+                } else {
+                    vec![]
+                };
+
+                DictationItem::new(kind, &value, value.to_string(), alternatives)
+            }
+
+            #[cfg(feature = "gregorian")]
+            DictationKind::Time => {
+                let minute_of_day = self.raw_generator.u16(0..=(MINUTES_PER_DAY - 1));
+                let (hour, minute) = (minute_of_day / 60, minute_of_day % 60);
+
+                let mut alternatives = Vec::new();
+
+                if hour < 10 {
+                    alternatives.push(format!("{:02}:{:02}", hour, minute));
+                }
+
+                alternatives.push(format!(
+                    "{}:{:02} {}",
+                    (hour + 11) % 12 + 1,
+                    minute,
+                    if hour < 12 { "AM" } else { "PM" }
+                ));
+
+                DictationItem::new(
+                    kind,
+                    &clock_time(minute_of_day, true),
+                    format!("{}:{:02}", hour, minute),
+                    alternatives,
+                )
+            }
+
+            #[cfg(feature = "gregorian")]
+            DictationKind::Date => {
+                let date = self
+                    .gregorian()
+                    .calendar_date(self.config.year_range.clone())?;
+                let (year, month, day) = (date.year(), date.month(), date.day());
+
+                DictationItem::new(
+                    kind,
+                    &date.to_date(false, None),
+                    format!("{}-{:02}-{:02}", year, month, day),
+                    vec![
+                        format!("{}/{}/{}", year, month, day),
+                        format!("{}年{}月{}日", year, month, day),
+                    ],
+                )
+            }
+
+            #[cfg(feature = "currency")]
+            DictationKind::Renminbi => {
+                let cents = self.raw_generator.u64(1..=RENMINBI_CENTS_END);
+                let answer = format!("{}.{:02}", cents / 100, cents % 100);
+
+                let mut alternatives = vec![format!("¥{}", answer)];
+
+                let trimmed = answer.trim_end_matches('0').trim_end_matches('.');
+                if trimmed != answer {
+                    alternatives.push(trimmed.to_string());
+                }
+
+                DictationItem::new(
+                    kind,
+                    &crate::renminbi_from_cents(cents, CurrencyStyle::Everyday { formal: true }),
+                    answer,
+                    alternatives,
+                )
+            }
+        })
+    }
+}
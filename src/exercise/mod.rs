@@ -0,0 +1,16 @@
+//! Ready-made exercises for quiz apps - like gap-fill sentences
+//! built on top of [templates](crate::template), dictation drills,
+//! question forms or question-answer pairs.
+mod cloze;
+mod dictation;
+#[cfg(any(feature = "gregorian", feature = "currency"))]
+mod qa;
+#[cfg(any(feature = "gregorian", feature = "currency"))]
+mod question;
+
+pub use cloze::*;
+pub use dictation::*;
+#[cfg(any(feature = "gregorian", feature = "currency"))]
+pub use qa::*;
+#[cfg(any(feature = "gregorian", feature = "currency"))]
+pub use question::*;
@@ -0,0 +1,229 @@
+use crate::{ChineseFormatGenerator, ChineseRandResult, GeneratedValue, RawGenerator};
+use chinese_format::{Chinese, ChineseFormat, Variant};
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::{DateParams, LinearTimeParams};
+#[cfg(feature = "currency")]
+use crate::RenminbiParams;
+#[cfg(feature = "gregorian")]
+use chinese_format::gregorian::DatePattern;
+
+/// Category of a [QaPair] - determining its question, which is
+/// also the Chinese rendering of the category.
+///
+/// ```
+/// # #[cfg(all(feature = "currency", feature = "gregorian"))]
+/// # {
+/// use chinese_rand::exercise::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// assert_eq!(QaCategory::Date.to_chinese(Variant::Simplified), "几月几号？");
+/// assert_eq!(QaCategory::Time.to_chinese(Variant::Traditional), "幾點了？");
+/// assert_eq!(QaCategory::Price.to_chinese(Variant::Simplified), "多少钱？");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QaCategory {
+    /// Asks for a date - `几月几号？`.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Date,
+
+    /// Asks for the time - `几点了？`.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Time,
+
+    /// Asks for a price - `多少钱？`.
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    Price,
+}
+
+impl ChineseFormat for QaCategory {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            #[cfg(feature = "gregorian")]
+            QaCategory::Date => ("几月几号？", "幾月幾號？").to_chinese(variant),
+
+            #[cfg(feature = "gregorian")]
+            QaCategory::Time => ("几点了？", "幾點了？").to_chinese(variant),
+
+            #[cfg(feature = "currency")]
+            QaCategory::Price => ("多少钱？", "多少錢？").to_chinese(variant),
+        }
+    }
+}
+
+/// Parameters for the random creation of [QaPair] - one for each [QaCategory].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct QaParams {
+    /// How the answer to [QaCategory::Date] is generated.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    pub date: DateParams,
+
+    /// How the answer to [QaCategory::Time] is generated.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    pub time: LinearTimeParams,
+
+    /// How the answer to [QaCategory::Price] is generated.
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    pub price: RenminbiParams,
+}
+
+/// By default, [QaParams] describes formal dates made of month and day -
+/// matching `几月几号？` - plus the default time and price parameters.
+///
+/// ```
+/// # #[cfg(all(feature = "currency", feature = "gregorian"))]
+/// # {
+/// use chinese_rand::{*, exercise::*, gregorian::*};
+/// use chinese_format::gregorian::DatePattern;
+///
+/// assert_eq!(QaParams::default(), QaParams {
+///     date: DateParams::default()
+///         .with_pattern(DatePattern::MonthDay)
+///         .with_formal(true),
+///     time: LinearTimeParams::default(),
+///     price: RenminbiParams::default()
+/// });
+/// # }
+/// ```
+impl Default for QaParams {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "gregorian")]
+            date: DateParams::default()
+                .with_pattern(DatePattern::MonthDay)
+                .with_formal(true),
+
+            #[cfg(feature = "gregorian")]
+            time: LinearTimeParams::default(),
+
+            #[cfg(feature = "currency")]
+            price: RenminbiParams::default(),
+        }
+    }
+}
+
+impl QaParams {
+    /// Sets how the answer to [QaCategory::Date] is generated.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    pub fn with_date(mut self, date: DateParams) -> Self {
+        self.date = date;
+        self
+    }
+
+    /// Sets how the answer to [QaCategory::Time] is generated.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    pub fn with_time(mut self, time: LinearTimeParams) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// Sets how the answer to [QaCategory::Price] is generated.
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    pub fn with_price(mut self, price: RenminbiParams) -> Self {
+        self.price = price;
+        self
+    }
+}
+
+/// Conversational drill: a question, plus the generated value answering it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QaPair {
+    /// The category.
+    pub category: QaCategory,
+
+    /// The question - like `几点了？`.
+    pub question: String,
+
+    /// The generated value, rendered in Chinese.
+    pub answer: String,
+
+    /// The generated value.
+    pub value: GeneratedValue,
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a [QaPair] of the given [QaCategory] - whose answer is driven
+    /// by the matching field of the [QaParams] - rendered in the given [Variant].
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "currency", feature = "gregorian"))]
+    /// # {
+    /// use chinese_rand::{*, exercise::*, gregorian::*};
+    /// use chinese_format::Variant;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let params = QaParams::default()
+    ///     .with_time(LinearTimeParams::default().with_day_part(true))
+    ///     .with_price(RenminbiParams::default().with_yuan_range(1..=100));
+    ///
+    /// let time = generator.qa_pair(QaCategory::Time, &params, Variant::Simplified)?;
+    /// assert_eq!(time.question, "几点了？");
+    /// assert_eq!(time.answer, "下午四点二十分");
+    ///
+    /// let price = generator.qa_pair(QaCategory::Price, &params, Variant::Traditional)?;
+    /// assert_eq!(price.question, "多少錢？");
+    /// assert_eq!(price.answer, "六十六元兩角四分");
+    ///
+    /// let date = generator.qa_pair(QaCategory::Date, &params, Variant::Simplified)?;
+    /// assert_eq!(date.question, "几月几号？");
+    /// assert_eq!(date.answer, "三月二十一号");
+    /// assert_eq!(date.value.kind(), "date");
+    /// # Ok(())
+    /// # }
+    /// # main().unwrap();
+    /// # }
+    /// ```
+    pub fn qa_pair(
+        &self,
+        category: QaCategory,
+        params: &QaParams,
+        variant: Variant,
+    ) -> ChineseRandResult<QaPair> {
+        let value = match category {
+            #[cfg(feature = "gregorian")]
+            QaCategory::Date => GeneratedValue::Date(self.gregorian().date(params.date.clone())?),
+
+            #[cfg(feature = "gregorian")]
+            QaCategory::Time => {
+                GeneratedValue::LinearTime(self.gregorian().linear_time(params.time)?)
+            }
+
+            #[cfg(feature = "currency")]
+            QaCategory::Price => GeneratedValue::Renminbi(self.renminbi(params.price.clone())?),
+        };
+
+        Ok(QaPair {
+            category,
+            question: category.to_chinese(variant).to_string(),
+            answer: value.to_chinese(variant).to_string(),
+            value,
+        })
+    }
+}
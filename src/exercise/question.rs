@@ -0,0 +1,274 @@
+use crate::{ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use chinese_format::{ChineseFormat, Variant};
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::LinearTimeParams;
+#[cfg(feature = "gregorian")]
+use chinese_format::{
+    gregorian::{DateBuilder, DayPart, Hour12},
+    EmptyPlaceholder,
+};
+#[cfg(feature = "gregorian")]
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "currency")]
+use crate::RenminbiParams;
+
+/// Component of a value replaced by a question word in a [QuestionForm].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QuestionTarget {
+    /// The year of a date - asked via `哪年`.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Year,
+
+    /// The month of a date - asked via `几月`.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Month,
+
+    /// The day of a date - asked via `几号` or `几日`.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Day,
+
+    /// The hour of a time - asked via `几点`.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Hour,
+
+    /// The minute of a time - asked via `几分`.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Minute,
+
+    /// The whole amount of a price - asked via `多少钱`.
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    Price,
+}
+
+/// What a [QuestionForm] is about.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QuestionSource {
+    /// A date within the given year range - with `号` if `formal`,
+    /// `日` otherwise.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Date {
+        /// The year range.
+        year_range: RangeInclusive<u16>,
+
+        /// Whether the day is expressed via `号`.
+        formal: bool,
+    },
+
+    /// A time of the day.
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Time(LinearTimeParams),
+
+    /// A price.
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    Price(RenminbiParams),
+}
+
+impl QuestionSource {
+    /// The components that can be asked about - one of them being
+    /// randomly chosen for each [QuestionForm].
+    pub fn targets(&self) -> &'static [QuestionTarget] {
+        match self {
+            #[cfg(feature = "gregorian")]
+            QuestionSource::Date { .. } => &[
+                QuestionTarget::Year,
+                QuestionTarget::Month,
+                QuestionTarget::Day,
+            ],
+
+            #[cfg(feature = "gregorian")]
+            QuestionSource::Time(_) => &[QuestionTarget::Hour, QuestionTarget::Minute],
+
+            #[cfg(feature = "currency")]
+            QuestionSource::Price(_) => &[QuestionTarget::Price],
+        }
+    }
+}
+
+/// Question obtained by replacing a component of a generated value
+/// with a question word - like `二零一三年几月二十三号` - plus the full answer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QuestionForm {
+    /// The component asked about.
+    pub target: QuestionTarget,
+
+    /// The value, with the question word in lieu of the target component.
+    pub question: String,
+
+    /// The full value.
+    pub answer: String,
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a value from the given [QuestionSource], then replaces one
+    /// of its [targets](QuestionSource::targets) - randomly chosen - with
+    /// the matching question word; both the question and the answer are
+    /// rendered in the given [Variant].
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "currency", feature = "gregorian"))]
+    /// # {
+    /// use chinese_rand::{*, exercise::*, gregorian::*};
+    /// use chinese_format::Variant;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let date = generator.question_form(
+    ///     &QuestionSource::Date { year_range: 2000..=2030, formal: true },
+    ///     Variant::Simplified
+    /// )?;
+    /// assert_eq!(date, QuestionForm {
+    ///     target: QuestionTarget::Month,
+    ///     question: "二零零零年几月二十号".to_string(),
+    ///     answer: "二零零零年一月二十号".to_string()
+    /// });
+    ///
+    /// let time = generator.question_form(
+    ///     &QuestionSource::Time(LinearTimeParams::default().with_day_part(true)),
+    ///     Variant::Traditional
+    /// )?;
+    /// assert_eq!(time, QuestionForm {
+    ///     target: QuestionTarget::Minute,
+    ///     question: "早上六點幾分".to_string(),
+    ///     answer: "早上六點二十九分".to_string()
+    /// });
+    ///
+    /// let price = generator.question_form(
+    ///     &QuestionSource::Price(RenminbiParams::default()),
+    ///     Variant::Simplified
+    /// )?;
+    /// assert_eq!(price, QuestionForm {
+    ///     target: QuestionTarget::Price,
+    ///     question: "多少钱".to_string(),
+    ///     answer: "二百二十五元六角".to_string()
+    /// });
+    /// # Ok(())
+    /// # }
+    /// # main().unwrap();
+    /// # }
+    /// ```
+    pub fn question_form(
+        &self,
+        source: &QuestionSource,
+        variant: Variant,
+    ) -> ChineseRandResult<QuestionForm> {
+        let targets = source.targets();
+
+        let target = if targets.len() > 1 {
+            targets[self.raw_generator.u64(0..=(targets.len() as u64 - 1)) as usize]
+        } else {
+            targets[0]
+        };
+
+        let (question, answer) = match source {
+            #[cfg(feature = "gregorian")]
+            QuestionSource::Date { year_range, formal } => {
+                let date = self.gregorian().calendar_date(year_range.clone())?;
+
+                let render_part = |builder: DateBuilder| {
+                    builder
+                        .with_formal(*formal)
+                        .build()
+                        .expect("Date valid by construction")
+                        .to_chinese(variant)
+                        .to_string()
+                };
+
+                let mut year = render_part(DateBuilder::new().with_year(date.year()));
+                let mut month = render_part(DateBuilder::new().with_month(date.month()));
+                let mut day = render_part(DateBuilder::new().with_day(date.day()));
+
+                match target {
+                    QuestionTarget::Year => year = "哪年".to_chinese(variant).to_string(),
+
+                    QuestionTarget::Month => {
+                        month = ("几月", "幾月").to_chinese(variant).to_string()
+                    }
+
+                    _ if *formal => day = ("几号", "幾號").to_chinese(variant).to_string(),
+
+                    _ => day = ("几日", "幾日").to_chinese(variant).to_string(),
+                }
+
+                (
+                    [year, month, day].concat(),
+                    date.to_date(*formal, None).to_chinese(variant).to_string(),
+                )
+            }
+
+            #[cfg(feature = "gregorian")]
+            QuestionSource::Time(params) => {
+                let time = self.gregorian().linear_time(*params)?;
+
+                let (day_part, mut hour) = if time.day_part {
+                    (
+                        DayPart::from(time.hour).to_chinese(variant).to_string(),
+                        Hour12::from(time.hour).to_chinese(variant).to_string(),
+                    )
+                } else {
+                    (String::new(), time.hour.to_chinese(variant).to_string())
+                };
+
+                let mut minute = EmptyPlaceholder::new(&time.minute)
+                    .to_chinese(variant)
+                    .to_string();
+
+                let second = time
+                    .second
+                    .map(|second| {
+                        EmptyPlaceholder::new(&second)
+                            .to_chinese(variant)
+                            .to_string()
+                    })
+                    .unwrap_or_default();
+
+                match target {
+                    QuestionTarget::Hour => hour = ("几点", "幾點").to_chinese(variant).to_string(),
+
+                    _ => minute = ("几分", "幾分").to_chinese(variant).to_string(),
+                }
+
+                (
+                    [day_part, hour, minute, second].concat(),
+                    time.to_chinese(variant).to_string(),
+                )
+            }
+
+            #[cfg(feature = "currency")]
+            QuestionSource::Price(params) => (
+                ("多少钱", "多少錢").to_chinese(variant).to_string(),
+                self.renminbi(params.clone())?
+                    .to_chinese(variant)
+                    .to_string(),
+            ),
+        };
+
+        Ok(QuestionForm {
+            target,
+            question,
+            answer,
+        })
+    }
+}
@@ -0,0 +1,272 @@
+//! Export of generated values as flashcard decks - in CSV or TSV format,
+//! ready to be imported into tools like Anki.
+use crate::{
+    BilingualRendering, ChineseFormatGenerator, ChineseRandResult, GenerationPlan, RawGenerator,
+};
+use chinese_format::Variant;
+use std::fmt::Debug;
+use std::io::{self, Write};
+
+#[cfg(feature = "pinyin")]
+use crate::pinyin::{to_pinyin, ToneStyle};
+
+/// The separator between the fields of a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeckFormat {
+    /// Comma-separated values, quoted when needed.
+    #[default]
+    Csv,
+
+    /// Tab-separated values - the default format expected by Anki.
+    Tsv,
+}
+
+/// A single flashcard.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeckRow {
+    /// Description of the generated value - for example, its debug representation.
+    pub value: String,
+
+    /// The rendering in [Variant::Simplified].
+    pub simplified: String,
+
+    /// The rendering in [Variant::Traditional].
+    pub traditional: String,
+}
+
+/// Each [BilingualRendering] becomes a row whose value is the debug
+/// representation of the generated value.
+///
+/// ```
+/// use chinese_rand::{*, export::*};
+/// use chinese_format::Count;
+///
+/// let row: DeckRow = BilingualRendering::new(Count(2)).into();
+///
+/// assert_eq!(row, DeckRow {
+///     value: "Count(2)".to_string(),
+///     simplified: "两".to_string(),
+///     traditional: "兩".to_string()
+/// });
+/// ```
+impl<T: Debug> From<BilingualRendering<T>> for DeckRow {
+    fn from(rendering: BilingualRendering<T>) -> Self {
+        Self {
+            value: format!("{:?}", rendering.value),
+            simplified: rendering.simplified,
+            traditional: rendering.traditional,
+        }
+    }
+}
+
+/// Accumulates [DeckRow] instances, then writes them as a deck.
+///
+/// ```
+/// use chinese_rand::{*, export::*};
+/// use chinese_format::Variant;
+///
+/// # fn main() -> GenericResult<()> {
+/// fastrand::seed(90);
+/// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+///
+/// let plan = GenerationPlan {
+///     variant: Variant::Simplified,
+///     entries: vec![PlanEntry {
+///         item: PlanItem::Count { range: 10000..=99999 },
+///         count: 2,
+///     }],
+/// };
+///
+/// let deck = DeckBuilder::new(DeckFormat::Csv)
+///     .with_header(true)
+///     .plan(&generator, &plan)?
+///     .row(DeckRow {
+///         value: "a, \"quoted\" value".to_string(),
+///         simplified: "个".to_string(),
+///         traditional: "個".to_string(),
+///     })
+///     .build();
+///
+/// assert_eq!(deck, concat!(
+///     "value,simplified,traditional\n",
+///     "count,六万三千零三十九,六萬三千零三十九\n",
+///     "count,六万九千二百零五,六萬九千二百零五\n",
+///     "\"a, \"\"quoted\"\" value\",个,個\n"
+/// ));
+///
+/// let tsv = DeckBuilder::new(DeckFormat::Tsv)
+///     .rendering(generator.rendered_both().count(2..=2)?)
+///     .build();
+///
+/// assert_eq!(tsv, "Count(2)\t两\t兩\n");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeckBuilder {
+    format: DeckFormat,
+    include_header: bool,
+    #[cfg(feature = "pinyin")]
+    pinyin: Option<ToneStyle>,
+    rows: Vec<DeckRow>,
+}
+
+impl DeckBuilder {
+    /// Creates an empty deck, without header.
+    pub fn new(format: DeckFormat) -> Self {
+        Self {
+            format,
+            include_header: false,
+            #[cfg(feature = "pinyin")]
+            pinyin: None,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Sets whether the first line should contain the column names.
+    pub fn with_header(mut self, include_header: bool) -> Self {
+        self.include_header = include_header;
+        self
+    }
+
+    /// Adds a last column with the pinyin of the Simplified rendering.
+    ///
+    /// ```
+    /// use chinese_rand::{*, export::*, pinyin::*};
+    /// use chinese_format::Count;
+    ///
+    /// let deck = DeckBuilder::new(DeckFormat::Tsv)
+    ///     .with_header(true)
+    ///     .with_pinyin(ToneStyle::Marks)
+    ///     .rendering(BilingualRendering::new(Count(2)))
+    ///     .build();
+    ///
+    /// assert_eq!(deck, "value\tsimplified\ttraditional\tpinyin\nCount(2)\t两\t兩\tliǎng\n");
+    /// ```
+    ///
+    /// **Required feature**: `pinyin`.
+    #[cfg(feature = "pinyin")]
+    pub fn with_pinyin(mut self, style: ToneStyle) -> Self {
+        self.pinyin = Some(style);
+        self
+    }
+
+    /// Appends a row.
+    pub fn row(mut self, row: DeckRow) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Appends a [BilingualRendering] - see the related [DeckRow] conversion.
+    pub fn rendering<T: Debug>(self, rendering: BilingualRendering<T>) -> Self {
+        self.row(rendering.into())
+    }
+
+    /// Appends every [BilingualRendering] in the given iterator.
+    pub fn renderings<T: Debug>(
+        mut self,
+        renderings: impl IntoIterator<Item = BilingualRendering<T>>,
+    ) -> Self {
+        self.rows.extend(renderings.into_iter().map(DeckRow::from));
+        self
+    }
+
+    /// Generates all the values described by the plan - whose variant is ignored,
+    /// because both are exported; the value of each row is the [kind](crate::PlanItem::kind)
+    /// of its plan item.
+    pub fn plan<R: RawGenerator>(
+        mut self,
+        generator: &ChineseFormatGenerator<R>,
+        plan: &GenerationPlan,
+    ) -> ChineseRandResult<Self> {
+        for entry in &plan.entries {
+            for _ in 0..entry.count {
+                let value = generator.generate_plan_item(&entry.item)?;
+
+                self.rows.push(DeckRow {
+                    value: entry.item.kind().to_string(),
+                    simplified: value.to_chinese(Variant::Simplified).to_string(),
+                    traditional: value.to_chinese(Variant::Traditional).to_string(),
+                });
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Writes the deck - one line per row, header included if requested.
+    pub fn write_to(&self, mut writer: impl Write) -> io::Result<()> {
+        if self.include_header {
+            let header: Vec<String> = ["value", "simplified", "traditional"]
+                .into_iter()
+                .map(String::from)
+                .chain(self.pinyin_of("").map(|_| "pinyin".to_string()))
+                .collect();
+
+            self.write_line(&mut writer, &header)?;
+        }
+
+        for row in &self.rows {
+            let fields: Vec<String> = [
+                row.value.clone(),
+                row.simplified.clone(),
+                row.traditional.clone(),
+            ]
+            .into_iter()
+            .chain(self.pinyin_of(&row.simplified))
+            .collect();
+
+            self.write_line(&mut writer, &fields)?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "pinyin")]
+    fn pinyin_of(&self, logograms: &str) -> Option<String> {
+        self.pinyin.map(|style| to_pinyin(logograms, style))
+    }
+
+    #[cfg(not(feature = "pinyin"))]
+    fn pinyin_of(&self, _logograms: &str) -> Option<String> {
+        None
+    }
+
+    /// Returns the whole deck as a string.
+    pub fn build(&self) -> String {
+        let mut buffer = Vec::new();
+
+        self.write_to(&mut buffer)
+            .expect("Writing to memory cannot fail");
+
+        String::from_utf8(buffer).expect("Deck is valid UTF-8 by construction")
+    }
+
+    fn write_line(&self, writer: &mut impl Write, fields: &[String]) -> io::Result<()> {
+        let (separator, escape): (&str, fn(&str) -> String) = match self.format {
+            DeckFormat::Csv => (",", escape_csv),
+            DeckFormat::Tsv => ("\t", escape_tsv),
+        };
+
+        let line = fields
+            .iter()
+            .map(|field| escape(field))
+            .collect::<Vec<_>>()
+            .join(separator);
+
+        writeln!(writer, "{}", line)
+    }
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn escape_tsv(field: &str) -> String {
+    field.replace(['\t', '\n', '\r'], " ")
+}
@@ -0,0 +1,207 @@
+//! C-compatible interface - so that native apps, like Swift or Kotlin ones,
+//! can embed the crate for on-device generation.
+//!
+//! A generator is created via [chinese_rand_generator_new] and released
+//! via [chinese_rand_generator_free]; [chinese_rand_generate] returns
+//! UTF-8 strings that must be released via [chinese_rand_string_free].
+//!
+//! The `chinese-rand-ffi` workspace member links these functions into
+//! a dynamic and a static library - via `cargo build --release -p chinese-rand-ffi`.
+//!
+//! Values are requested by category - any type name of the [standard](crate::TypeRegistry::standard)
+//! [TypeRegistry](crate::TypeRegistry) - with parameters in JSON.
+//!
+//! ```
+//! use chinese_rand::ffi::*;
+//! use std::ffi::{CStr, CString};
+//!
+//! let category = CString::new("count").unwrap();
+//! let params = CString::new(r#"{ "start": 1, "end": 99 }"#).unwrap();
+//!
+//! unsafe {
+//!     let generator = chinese_rand_generator_new(90);
+//!
+//!     let value = chinese_rand_generate(generator, category.as_ptr(), params.as_ptr(), false);
+//!     assert_eq!(CStr::from_ptr(value).to_str().unwrap(), "五十九");
+//!     chinese_rand_string_free(value);
+//!
+//!     let unknown = CString::new("poem").unwrap();
+//!     let value = chinese_rand_generate(generator, unknown.as_ptr(), std::ptr::null(), false);
+//!     assert!(value.is_null());
+//!     assert_eq!(
+//!         CStr::from_ptr(chinese_rand_last_error()).to_str().unwrap(),
+//!         "Unsupported: Unknown type: poem"
+//!     );
+//!
+//!     chinese_rand_generator_free(generator);
+//! }
+//! ```
+//!
+//! **Required feature**: `ffi`.
+
+use crate::{ChineseFormatGenerator, ChineseRandError, ChineseRandResult, FastRandGenerator};
+use chinese_format::Variant;
+use serde_json::Value;
+use std::{
+    any::Any,
+    cell::RefCell,
+    ffi::{c_char, CStr, CString},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Opaque generator handle, based on [FastRandGenerator].
+///
+/// **Required feature**: `ffi`.
+pub struct FfiGenerator {
+    generator: ChineseFormatGenerator,
+}
+
+/// Creates a generator with the given seed - to be released
+/// via [chinese_rand_generator_free].
+///
+/// **Required feature**: `ffi`.
+#[no_mangle]
+pub extern "C" fn chinese_rand_generator_new(seed: u64) -> *mut FfiGenerator {
+    Box::into_raw(Box::new(FfiGenerator {
+        generator: ChineseFormatGenerator::new(FastRandGenerator::with_seed(seed)),
+    }))
+}
+
+/// Releases a generator; null pointers are ignored.
+///
+/// # Safety
+///
+/// The pointer must be null or returned by [chinese_rand_generator_new],
+/// and must not be used afterwards.
+///
+/// **Required feature**: `ffi`.
+#[no_mangle]
+pub unsafe extern "C" fn chinese_rand_generator_free(generator: *mut FfiGenerator) {
+    if !generator.is_null() {
+        drop(Box::from_raw(generator));
+    }
+}
+
+/// Generates a value of the given category - with the given parameters in JSON,
+/// or null if no parameters are required - rendered in traditional
+/// or simplified logograms.
+///
+/// Returns a UTF-8 string to be released via [chinese_rand_string_free] -
+/// or null on failure, describing the error via [chinese_rand_last_error];
+/// panics never unwind across the interface, being reported as
+/// [ChineseRandError::Panicked] instead.
+///
+/// # Safety
+///
+/// The generator must have been returned by [chinese_rand_generator_new];
+/// the strings must be valid and null-terminated - except `params_json`, which can be null.
+///
+/// **Required feature**: `ffi`.
+#[no_mangle]
+pub unsafe extern "C" fn chinese_rand_generate(
+    generator: *const FfiGenerator,
+    category: *const c_char,
+    params_json: *const c_char,
+    traditional: bool,
+) -> *mut c_char {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let generator = generator
+            .as_ref()
+            .ok_or_else(|| ChineseRandError::InvalidParams("Null generator".to_string()))?;
+
+        let category = read_string(category)?
+            .ok_or_else(|| ChineseRandError::InvalidParams("Null category".to_string()))?;
+
+        let params = match read_string(params_json)? {
+            Some(json) => serde_json::from_str(json)
+                .map_err(|err| ChineseRandError::InvalidParams(err.to_string()))?,
+            None => Value::Null,
+        };
+
+        let variant = if traditional {
+            Variant::Traditional
+        } else {
+            Variant::Simplified
+        };
+
+        let value = generator.generator.generate_by_name(category, params)?;
+
+        CString::new(value.to_chinese(variant).logograms)
+            .map_err(|err| ChineseRandError::InvalidParams(err.to_string()))
+    }))
+    .unwrap_or_else(|payload| Err(ChineseRandError::Panicked(panic_message(payload))));
+
+    match result {
+        Ok(value) => {
+            set_last_error(None);
+            value.into_raw()
+        }
+
+        Err(err) => {
+            set_last_error(Some(err));
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Releases a string returned by [chinese_rand_generate]; null pointers are ignored.
+///
+/// # Safety
+///
+/// The pointer must be null or returned by [chinese_rand_generate],
+/// and must not be used afterwards.
+///
+/// **Required feature**: `ffi`.
+#[no_mangle]
+pub unsafe extern "C" fn chinese_rand_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// The description of the last error occurred in the current thread -
+/// or null if the last generation succeeded.
+///
+/// The string is owned by the library, and remains valid until
+/// the next call to [chinese_rand_generate] in the same thread.
+///
+/// **Required feature**: `ffi`.
+#[no_mangle]
+pub extern "C" fn chinese_rand_last_error() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+unsafe fn read_string<'a>(string: *const c_char) -> ChineseRandResult<Option<&'a str>> {
+    if string.is_null() {
+        return Ok(None);
+    }
+
+    CStr::from_ptr(string)
+        .to_str()
+        .map(Some)
+        .map_err(|err| ChineseRandError::InvalidParams(err.to_string()))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Unknown panic".to_string())
+}
+
+fn set_last_error(err: Option<ChineseRandError>) {
+    LAST_ERROR.with(|last_error| {
+        *last_error.borrow_mut() = err.and_then(|err| CString::new(err.to_string()).ok());
+    });
+}
@@ -0,0 +1,302 @@
+use crate::{check_range, ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use chinese_format::{ChineseFormat, Financial, Variant};
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "currency")]
+use chinese_format::currency::{CurrencyStyle, RenminbiCurrency, RenminbiCurrencyBuilder};
+
+/// The range used by the default [FinancialParams].
+pub const DEFAULT_FINANCIAL_RANGE: RangeInclusive<u64> = 0..=100_000;
+
+/// How `拾` - ten - must appear in a financial numeral.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TenForm {
+    /// Leading `拾`, without `壹` - like in `拾伍` or `拾伍万`.
+    Bare,
+
+    /// `壹拾` within the numeral - like in `壹佰壹拾`.
+    Explicit,
+}
+
+/// Parameters for the generation of financial numerals (大写) -
+/// possibly forcing the digits that are tricky to write on a check.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct FinancialParams {
+    /// The range of the value - or of the yuan, for amounts.
+    pub range: RangeInclusive<u64>,
+
+    /// Whether the numeral must contain `零` - standing for a run of
+    /// zeros, like in `壹万零伍`.
+    pub zero_run: bool,
+
+    /// If set, the form in which `拾` must appear.
+    pub ten_form: Option<TenForm>,
+}
+
+/// By default, [FinancialParams] describes any value within
+/// [DEFAULT_FINANCIAL_RANGE], without forcing tricky digits.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// assert_eq!(FinancialParams::default(), FinancialParams {
+///     range: DEFAULT_FINANCIAL_RANGE,
+///     zero_run: false,
+///     ten_form: None
+/// });
+///
+/// let customized = FinancialParams::default()
+///     .with_range(100..=999)
+///     .with_zero_run(true)
+///     .with_ten_form(TenForm::Explicit);
+///
+/// assert_eq!(customized, FinancialParams {
+///     range: 100..=999,
+///     zero_run: true,
+///     ten_form: Some(TenForm::Explicit)
+/// });
+/// ```
+impl Default for FinancialParams {
+    fn default() -> Self {
+        Self {
+            range: DEFAULT_FINANCIAL_RANGE,
+            zero_run: false,
+            ten_form: None,
+        }
+    }
+}
+
+impl FinancialParams {
+    /// Sets the range of the value.
+    pub fn with_range(mut self, range: RangeInclusive<u64>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets whether the numeral must contain `零`.
+    pub fn with_zero_run(mut self, zero_run: bool) -> Self {
+        self.zero_run = zero_run;
+        self
+    }
+
+    /// Sets the form in which `拾` must appear.
+    pub fn with_ten_form(mut self, ten_form: TenForm) -> Self {
+        self.ten_form = Some(ten_form);
+        self
+    }
+}
+
+/// Random generator of financial numerals (大写) - like `壹万零伍` -
+/// and of the amounts written with them, for testing check-writing software.
+///
+/// Just like [GregorianGenerator](crate::gregorian::GregorianGenerator),
+/// it must be created via the [ChineseFormatGenerator::financial] method.
+pub struct FinancialGenerator<'a, R: RawGenerator = Box<dyn RawGenerator>> {
+    owner: &'a ChineseFormatGenerator<R>,
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Creates a reusable [FinancialGenerator] instance, for generating
+    /// values always rendered with financial numerals.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let financial = generator.financial();
+    ///
+    /// let value = financial.integer(FinancialParams::default())?;
+    /// assert_eq!(value.to_chinese(Variant::Simplified), "伍万捌仟玖佰叁拾叁");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn financial(&self) -> FinancialGenerator<'_, R> {
+        FinancialGenerator { owner: self }
+    }
+}
+
+impl<R: RawGenerator> FinancialGenerator<'_, R> {
+    /// Generates a [Financial] numeral within the range - containing
+    /// the tricky digits required by the [FinancialParams].
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty, and with
+    /// [ChineseRandError::GenerationExhausted](crate::ChineseRandError::GenerationExhausted)
+    /// if no value in the range can satisfy the requirements.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let financial = generator.financial();
+    ///
+    /// let with_zero = financial.integer(
+    ///     FinancialParams::default()
+    ///         .with_range(10_000..=99_999)
+    ///         .with_zero_run(true)
+    /// )?;
+    /// assert_eq!(with_zero.to_chinese(Variant::Simplified), "陆万零叁拾玖");
+    ///
+    /// let bare_ten = financial.integer(
+    ///     FinancialParams::default().with_ten_form(TenForm::Bare)
+    /// )?;
+    /// assert_eq!(bare_ten.to_chinese(Variant::Simplified), "拾万");
+    ///
+    /// let explicit_ten = financial.integer(
+    ///     FinancialParams::default().with_ten_form(TenForm::Explicit)
+    /// )?;
+    /// assert_eq!(explicit_ten.to_chinese(Variant::Simplified), "贰万贰仟伍佰壹拾肆");
+    ///
+    /// let impossible = financial.integer(
+    ///     FinancialParams::default()
+    ///         .with_range(0..=99)
+    ///         .with_zero_run(true)
+    /// );
+    /// assert!(impossible.is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn integer(&self, params: FinancialParams) -> ChineseRandResult<Financial> {
+        check_range(&params.range)?;
+
+        self.owner.retrying("Financial", || {
+            let value = self.candidate(&params)?;
+
+            params.satisfied_by(value).then_some(Financial(value))
+        })
+    }
+
+    /// Generates a financial-style [RenminbiCurrency] - like `壹万零伍元叁角贰分整` -
+    /// whose yuan satisfy the [FinancialParams], with random `角` and `分`.
+    ///
+    /// Fails just like [integer](Self::integer).
+    ///
+    /// ```
+    /// # #[cfg(feature = "currency")]
+    /// # {
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let amount = generator.financial().renminbi(
+    ///     FinancialParams::default().with_zero_run(true)
+    /// )?;
+    /// assert_eq!(amount.to_chinese(Variant::Simplified), "伍万零叁拾叁元贰角肆分整");
+    /// # Ok(())
+    /// # }
+    /// # main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    pub fn renminbi(&self, params: FinancialParams) -> ChineseRandResult<RenminbiCurrency> {
+        let yuan = self.integer(params)?;
+        let raw_generator = &self.owner.raw_generator;
+
+        Ok(RenminbiCurrencyBuilder::new()
+            .with_style(CurrencyStyle::Financial)
+            .with_yuan(yuan.0)
+            .with_dimes(raw_generator.u8(0..=9))
+            .with_cents(raw_generator.u8(0..=9))
+            .build()
+            .expect("Renminbi valid by construction"))
+    }
+
+    /// Draws a value whose digits are likely to satisfy the params,
+    /// by editing the digits of a random value in the range.
+    fn candidate(&self, params: &FinancialParams) -> Option<u64> {
+        let raw_generator = &self.owner.raw_generator;
+
+        let range = match params.ten_form {
+            Some(TenForm::Bare) => self.bare_ten_range(&params.range)?,
+            _ => params.range.clone(),
+        };
+
+        let mut digits: Vec<u8> = raw_generator
+            .u64(range)
+            .to_string()
+            .bytes()
+            .map(|digit| digit - b'0')
+            .collect();
+        let length = digits.len();
+
+        if params.zero_run && length >= 3 {
+            let run_start = raw_generator.u64(1..=(length as u64 - 2)) as usize;
+            let run_end = raw_generator.u64(run_start as u64..=(length as u64 - 2)) as usize;
+
+            digits[run_start..=run_end].fill(0);
+        }
+
+        if params.ten_form == Some(TenForm::Explicit) {
+            let tens: Vec<usize> = (1..length)
+                .filter(|position| (length - 1 - position) % 4 == 1)
+                .collect();
+
+            if !tens.is_empty() {
+                let index = raw_generator.u64(0..=(tens.len() as u64 - 1)) as usize;
+                digits[tens[index]] = 1;
+            }
+        }
+
+        let value = digits.iter().try_fold(0u64, |value, digit| {
+            value.checked_mul(10)?.checked_add(*digit as u64)
+        })?;
+
+        params.range.contains(&value).then_some(value)
+    }
+
+    /// A random sub-range whose values start with `拾` - that is,
+    /// beginning with `1` in the tens place of a 4-digit group.
+    fn bare_ten_range(&self, range: &RangeInclusive<u64>) -> Option<RangeInclusive<u64>> {
+        let candidates: Vec<RangeInclusive<u64>> = [1u32, 5, 9, 13, 17]
+            .into_iter()
+            .filter_map(|exponent| {
+                let lower = 10u64.pow(exponent);
+                let upper = lower.saturating_mul(2) - 1;
+
+                let start = lower.max(*range.start());
+                let end = upper.min(*range.end());
+
+                (start <= end).then_some(start..=end)
+            })
+            .collect();
+
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let index = self
+            .owner
+            .raw_generator
+            .u64(0..=(candidates.len() as u64 - 1)) as usize;
+
+        Some(candidates[index].clone())
+    }
+}
+
+impl FinancialParams {
+    fn satisfied_by(&self, value: u64) -> bool {
+        let logograms = Financial(value).to_chinese(Variant::Simplified).logograms;
+
+        let zero_satisfied = !self.zero_run || (value > 0 && logograms.contains('零'));
+
+        let ten_satisfied = match self.ten_form {
+            None => true,
+            Some(TenForm::Bare) => logograms.starts_with('拾'),
+            Some(TenForm::Explicit) => logograms.contains("壹拾"),
+        };
+
+        zero_satisfied && ten_satisfied
+    }
+}
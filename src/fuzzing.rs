@@ -0,0 +1,186 @@
+//! Structure-aware fuzzing via the [arbitrary](https://crates.io/crates/arbitrary) crate.
+//!
+//! The parameter structs - like [FractionParams](crate::FractionParams) -
+//! implement [Arbitrary], while [generate] turns the fuzzer input
+//! into [chinese_format] values, for testing downstream renderers.
+//!
+//! It is worth noting that arbitrary parameters are often inconsistent -
+//! for example, with empty ranges: in that case, the input is rejected
+//! via [arbitrary::Error::IncorrectFormat], just like any other generation failure.
+//!
+//! **Required feature**: `arbitrary`.
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::PatternChoice;
+use crate::{ByteSliceGenerator, ChineseFormatGenerator, ChineseRandResult, Level, RegionProfile};
+use arbitrary::{Arbitrary, Unstructured};
+#[cfg(feature = "currency")]
+use chinese_format::currency::CurrencyStyle;
+#[cfg(feature = "gregorian")]
+use chinese_format::gregorian::{DatePattern, DayPart, WeekDay, WeekFormat};
+
+/// [ChineseFormatGenerator] whose values are derived from the fuzzer input.
+///
+/// **Required feature**: `arbitrary`.
+pub type FuzzGenerator<'a> = ChineseFormatGenerator<ByteSliceGenerator<'a>>;
+
+/// Creates a [FuzzGenerator] from the fuzzer input - with arbitrary
+/// [Level] and [RegionProfile], if any.
+///
+/// **Required feature**: `arbitrary`.
+pub fn generator<'a>(u: &mut Unstructured<'a>) -> arbitrary::Result<FuzzGenerator<'a>> {
+    let level: Option<Level> = u.arbitrary()?;
+    let region: Option<RegionProfile> = u.arbitrary()?;
+    let bytes: &'a [u8] = u.arbitrary()?;
+
+    let mut generator = ChineseFormatGenerator::from_raw_generator(ByteSliceGenerator::new(bytes));
+    generator.level = level;
+    generator.region = region;
+
+    Ok(generator)
+}
+
+/// Generates a value by passing arbitrary parameters to the given function,
+/// along with a [FuzzGenerator] - both derived from the fuzzer input.
+///
+/// ```
+/// use chinese_rand::{*, fuzzing};
+/// use arbitrary::Unstructured;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// // In a fuzz target, the data would be provided by the fuzzer
+/// let data: Vec<u8> = (0..64u8).map(|index| index.wrapping_mul(7)).collect();
+///
+/// let tricky = fuzzing::generate(&mut Unstructured::new(&data), |generator, kind: TrickyKind| {
+///     Ok((kind, generator.tricky_numbers().integer(kind)?))
+/// }).unwrap();
+/// assert_eq!(tricky.0, TrickyKind::InternalZero);
+/// assert_eq!(tricky.1.to_chinese(Variant::Simplified), "九百零七");
+///
+/// let inconsistent = fuzzing::generate(&mut Unstructured::new(&[0; 64]), |generator, params: FractionParams| {
+///     generator.fraction_with(params)
+/// });
+/// assert_eq!(inconsistent, Err(arbitrary::Error::IncorrectFormat));
+/// ```
+///
+/// **Required feature**: `arbitrary`.
+pub fn generate<'a, P: Arbitrary<'a>, T>(
+    u: &mut Unstructured<'a>,
+    generate: impl FnOnce(&FuzzGenerator<'a>, P) -> ChineseRandResult<T>,
+) -> arbitrary::Result<T> {
+    let params = P::arbitrary(u)?;
+    let generator = generator(u)?;
+
+    generate(&generator, params).map_err(|_| arbitrary::Error::IncorrectFormat)
+}
+
+#[cfg(feature = "currency")]
+pub(crate) fn currency_style(u: &mut Unstructured) -> arbitrary::Result<CurrencyStyle> {
+    Ok(if u.arbitrary()? {
+        CurrencyStyle::Financial
+    } else {
+        CurrencyStyle::Everyday {
+            formal: u.arbitrary()?,
+        }
+    })
+}
+
+#[cfg(feature = "currency")]
+pub(crate) fn option_currency_style(
+    u: &mut Unstructured,
+) -> arbitrary::Result<Option<CurrencyStyle>> {
+    Ok(if u.arbitrary()? {
+        Some(currency_style(u)?)
+    } else {
+        None
+    })
+}
+
+#[cfg(feature = "gregorian")]
+pub(crate) fn date_pattern(u: &mut Unstructured) -> arbitrary::Result<DatePattern> {
+    u.choose(&[
+        DatePattern::Year,
+        DatePattern::Month,
+        DatePattern::Day,
+        DatePattern::WeekDay,
+        DatePattern::YearMonth,
+        DatePattern::YearMonthDay,
+        DatePattern::MonthDay,
+        DatePattern::MonthDayWeekDay,
+        DatePattern::DayWeekDay,
+        DatePattern::YearMonthDayWeekDay,
+    ])
+    .copied()
+}
+
+#[cfg(feature = "gregorian")]
+pub(crate) fn pattern_choice(u: &mut Unstructured) -> arbitrary::Result<PatternChoice> {
+    if u.arbitrary()? {
+        return Ok(PatternChoice::Fixed(date_pattern(u)?));
+    }
+
+    let length = u.int_in_range(0..=4)?;
+
+    (0..length)
+        .map(|_| date_pattern(u))
+        .collect::<arbitrary::Result<_>>()
+        .map(PatternChoice::RandomFrom)
+}
+
+#[cfg(feature = "gregorian")]
+fn week_format(u: &mut Unstructured) -> arbitrary::Result<WeekFormat> {
+    u.choose(&[WeekFormat::XingQi, WeekFormat::Zhou, WeekFormat::LiBai])
+        .copied()
+}
+
+#[cfg(feature = "gregorian")]
+pub(crate) fn option_week_format(u: &mut Unstructured) -> arbitrary::Result<Option<WeekFormat>> {
+    Ok(if u.arbitrary()? {
+        Some(week_format(u)?)
+    } else {
+        None
+    })
+}
+
+#[cfg(feature = "gregorian")]
+pub(crate) fn option_day_part(u: &mut Unstructured) -> arbitrary::Result<Option<DayPart>> {
+    if !u.arbitrary()? {
+        return Ok(None);
+    }
+
+    u.choose(&[
+        DayPart::EarlyMorning,
+        DayPart::Morning,
+        DayPart::Midday,
+        DayPart::Afternoon,
+        DayPart::EarlyEvening,
+        DayPart::Evening,
+        DayPart::Midnight,
+        DayPart::LateNight,
+    ])
+    .copied()
+    .map(Some)
+}
+
+#[cfg(feature = "gregorian")]
+pub(crate) fn vec_week_day(u: &mut Unstructured) -> arbitrary::Result<Vec<WeekDay>> {
+    const WEEK_DAYS: [WeekDay; 7] = [
+        WeekDay::Sunday,
+        WeekDay::Monday,
+        WeekDay::Tuesday,
+        WeekDay::Wednesday,
+        WeekDay::Thursday,
+        WeekDay::Friday,
+        WeekDay::Saturday,
+    ];
+
+    let mut week_days = Vec::new();
+
+    for week_day in WEEK_DAYS {
+        if u.arbitrary()? {
+            week_days.push(week_day);
+        }
+    }
+
+    Ok(week_days)
+}
@@ -0,0 +1,225 @@
+//! Games of chance - dice rolls and lottery tickets - the usual
+//! demo content for the vocabulary of probability.
+use crate::{
+    check_range, ChineseFormatGenerator, ChineseRandError, ChineseRandResult, RawGenerator,
+};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Count, Variant};
+use std::ops::RangeInclusive;
+
+/// Roll of one or more dice - rendered as the total of the pips,
+/// like `七点`.
+///
+/// ```
+/// use chinese_rand::games::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let roll = DiceRoll { values: vec![3, 4] };
+/// assert_eq!(roll.total(), 7);
+/// assert_eq!(roll.to_chinese(Variant::Simplified), "七点");
+///
+/// let roll = DiceRoll { values: vec![2] };
+/// assert_eq!(roll.to_chinese(Variant::Traditional), "兩點");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiceRoll {
+    /// The value of each die, in rolling order.
+    pub values: Vec<u8>,
+}
+
+impl DiceRoll {
+    /// The sum of the values of the dice.
+    pub fn total(&self) -> u16 {
+        self.values.iter().map(|&value| value as u16).sum()
+    }
+}
+
+impl ChineseFormat for DiceRoll {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [Count(self.total() as u128), ("点", "點")]).collect()
+    }
+}
+
+/// Parameters for the random creation of [LotteryTicket].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LotterySpec {
+    /// The range the numbers are drawn from.
+    pub number_range: RangeInclusive<u8>,
+
+    /// How many distinct numbers are drawn.
+    pub count: u8,
+}
+
+/// By default, [LotterySpec] describes 6 numbers out of 1..=33 -
+/// like the red balls of `双色球`.
+///
+/// ```
+/// use chinese_rand::games::*;
+///
+/// assert_eq!(LotterySpec::default(), LotterySpec {
+///     number_range: 1..=33,
+///     count: 6
+/// });
+///
+/// let customized = LotterySpec::default()
+///     .with_number_range(1..=90)
+///     .with_count(5);
+///
+/// assert_eq!(customized, LotterySpec {
+///     number_range: 1..=90,
+///     count: 5
+/// });
+/// ```
+impl Default for LotterySpec {
+    fn default() -> Self {
+        Self {
+            number_range: 1..=33,
+            count: 6,
+        }
+    }
+}
+
+impl LotterySpec {
+    /// Sets the range the numbers are drawn from.
+    pub fn with_number_range(mut self, number_range: RangeInclusive<u8>) -> Self {
+        self.number_range = number_range;
+        self
+    }
+
+    /// Sets how many distinct numbers are drawn.
+    pub fn with_count(mut self, count: u8) -> Self {
+        self.count = count;
+        self
+    }
+}
+
+/// Lottery ticket - distinct numbers in ascending order, rendered
+/// positionally and separated by `、`.
+///
+/// ```
+/// use chinese_rand::games::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let ticket = LotteryTicket { numbers: vec![2, 15, 30] };
+/// assert_eq!(ticket.to_chinese(Variant::Simplified), "二、十五、三十");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LotteryTicket {
+    /// The numbers, in ascending order.
+    pub numbers: Vec<u8>,
+}
+
+impl ChineseFormat for LotteryTicket {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let logograms = self
+            .numbers
+            .iter()
+            .map(|number| number.to_chinese(variant).logograms)
+            .collect::<Vec<_>>()
+            .join("、");
+
+        Chinese {
+            logograms,
+            omissible: false,
+        }
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Rolls the given number of dice, each having the given number of faces.
+    ///
+    /// Fails with [ChineseRandError::InvalidParams] if there are no dice
+    /// or if the dice have fewer than 2 faces.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let roll = generator.dice(2, 6)?;
+    /// assert_eq!(roll.values, [5, 3]);
+    /// assert_eq!(roll.to_chinese(Variant::Simplified), "八点");
+    ///
+    /// assert_eq!(
+    ///     generator.dice(0, 6),
+    ///     Err(ChineseRandError::InvalidParams("No dice".to_string()))
+    /// );
+    /// assert_eq!(
+    ///     generator.dice(1, 1),
+    ///     Err(ChineseRandError::InvalidParams("Dice faces fewer than 2: 1".to_string()))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dice(&self, n: u8, faces: u8) -> ChineseRandResult<DiceRoll> {
+        if n == 0 {
+            return Err(ChineseRandError::InvalidParams("No dice".to_string()));
+        }
+
+        if faces < 2 {
+            return Err(ChineseRandError::InvalidParams(format!(
+                "Dice faces fewer than 2: {}",
+                faces
+            )));
+        }
+
+        Ok(DiceRoll {
+            values: (0..n).map(|_| self.raw_generator.u8(1..=faces)).collect(),
+        })
+    }
+
+    /// Generates a random [LotteryTicket] according to the given [LotterySpec].
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if the range is empty,
+    /// and with [ChineseRandError::InvalidParams] if no numbers are requested
+    /// or if the range cannot provide enough distinct numbers.
+    ///
+    /// ```
+    /// use chinese_rand::{*, games::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let ticket = generator.lottery_ticket(LotterySpec::default())?;
+    /// assert_eq!(ticket.numbers, [1, 7, 11, 20, 21, 33]);
+    /// assert_eq!(ticket.to_chinese(Variant::Traditional), "一、七、十一、二十、二十一、三十三");
+    ///
+    /// assert_eq!(
+    ///     generator.lottery_ticket(LotterySpec::default().with_number_range(1..=5)),
+    ///     Err(ChineseRandError::InvalidParams(
+    ///         "Lottery numbers exceeding the range: 6 in 1..=5".to_string()
+    ///     ))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lottery_ticket(&self, spec: LotterySpec) -> ChineseRandResult<LotteryTicket> {
+        check_range(&spec.number_range)?;
+
+        if spec.count == 0 {
+            return Err(ChineseRandError::InvalidParams(
+                "No lottery numbers".to_string(),
+            ));
+        }
+
+        if spec.count as usize > spec.number_range.len() {
+            return Err(ChineseRandError::InvalidParams(format!(
+                "Lottery numbers exceeding the range: {} in {:?}",
+                spec.count, spec.number_range
+            )));
+        }
+
+        let mut numbers: Vec<u8> = self
+            .shuffled_cycle(spec.number_range)
+            .take(spec.count as usize)
+            .collect();
+        numbers.sort();
+
+        Ok(LotteryTicket { numbers })
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    labels::{
+        BusRoute, BusRouteParams, RoomNumber, RoomNumberParams, TrainNumber, TrainNumberParams,
+    },
+    ArithmeticParams, ArithmeticProblem, ChineseFormatGenerator, ChineseRandResult, ExamScore,
+    ExamScoreParams, FinancialParams, FractionParams, MixedNumber, MixedNumberParams, RawGenerator,
+    ShareParams, ShareStatement,
+};
+use chinese_format::{Count, CountBase, Financial, Fraction};
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::{
+    Appointment, AppointmentParams, ColloquialTime, ColloquialTimeParams, DatePair, DatePairParams,
+    DateParams, DeltaTimeParams, Duration, DurationParams, LinearTimeParams,
+};
+#[cfg(feature = "lunar")]
+use crate::lunar::{LunarDate, LunarDateParams};
+#[cfg(feature = "digit-sequence")]
+use crate::{Measurement, MeasurementParams};
+#[cfg(feature = "currency")]
+use crate::{
+    MonetaryAmount, MonetaryParams, RenminbiAmount, RenminbiParams, SignedRenminbi,
+    SignedRenminbiParams,
+};
+#[cfg(feature = "currency")]
+use chinese_format::currency::RenminbiCurrency;
+#[cfg(feature = "gregorian")]
+use chinese_format::gregorian::{Date, DeltaTime, LinearTime};
+
+/// Value that can be generated from parameters of a dedicated type -
+/// see [Generate].
+pub trait Generatable: Sized {
+    /// The parameters driving the generation - a range, for the simplest types.
+    type Params;
+}
+
+/// Generation of any [Generatable] type via its parameters - so that
+/// generic code can be written over anything generatable,
+/// instead of calling the dedicated methods.
+///
+/// Every implementation just calls the dedicated method - returning its errors.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Count, Fraction, Variant};
+///
+/// fn render_all<T: Generatable + ChineseFormat>(
+///     generator: &impl Generate<T>,
+///     params: T::Params,
+///     amount: usize,
+/// ) -> ChineseRandResult<Vec<String>>
+/// where
+///     T::Params: Clone,
+/// {
+///     (0..amount)
+///         .map(|_| {
+///             let value = generator.generate(params.clone())?;
+///             Ok(value.to_chinese(Variant::Simplified).logograms)
+///         })
+///         .collect()
+/// }
+///
+/// # fn main() -> GenericResult<()> {
+/// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+///
+/// let counts = render_all::<Count>(&generator, 1..=99, 2)?;
+/// assert_eq!(counts, ["五十九", "六十六"]);
+///
+/// let fractions = render_all::<Fraction>(&generator, FractionParams::default(), 2)?;
+/// assert_eq!(fractions, ["四分之五", "九分之九"]);
+///
+/// let integer: i128 = generator.generate(-9..=9)?;
+/// assert_eq!(integer, -7);
+/// # Ok(())
+/// # }
+/// ```
+pub trait Generate<T: Generatable> {
+    /// Generates a value driven by the given parameters.
+    fn generate(&self, params: T::Params) -> ChineseRandResult<T>;
+}
+
+macro_rules! impl_generate {
+    ($($(#[$attribute: meta])* $type: ty: $params: ty => |$generator: ident, $name: ident| $body: expr;)+) => {
+        $(
+            $(#[$attribute])*
+            impl Generatable for $type {
+                type Params = $params;
+            }
+
+            $(#[$attribute])*
+            impl<R: RawGenerator> Generate<$type> for ChineseFormatGenerator<R> {
+                fn generate(&self, $name: $params) -> ChineseRandResult<$type> {
+                    let $generator = self;
+                    $body
+                }
+            }
+        )+
+    };
+}
+
+impl_generate!(
+    i128: RangeInclusive<i128> => |generator, range| generator.integer(range);
+    Count: RangeInclusive<CountBase> => |generator, range| generator.count(range);
+    Fraction: FractionParams => |generator, params| generator.fraction_with(params);
+    MixedNumber: MixedNumberParams => |generator, params| generator.mixed_number(params);
+    Financial: FinancialParams => |generator, params| generator.financial().integer(params);
+    ArithmeticProblem: ArithmeticParams => |generator, params| generator.arithmetic(params);
+    ExamScore: ExamScoreParams => |generator, params| generator.exam_score_with(params);
+    ShareStatement: ShareParams => |generator, params| generator.share_statement(params);
+    RoomNumber: RoomNumberParams => |generator, params| generator.room_number(params);
+    BusRoute: BusRouteParams => |generator, params| generator.bus_route(params);
+    TrainNumber: TrainNumberParams => |generator, params| generator.train_number(params);
+
+    #[cfg(feature = "digit-sequence")]
+    Measurement: MeasurementParams => |generator, params| generator.measurement(params);
+
+    #[cfg(feature = "currency")]
+    RenminbiCurrency: RenminbiParams => |generator, params| generator.renminbi(params);
+    #[cfg(feature = "currency")]
+    RenminbiAmount: RenminbiParams => |generator, params| generator.renminbi_amount(params);
+    #[cfg(feature = "currency")]
+    SignedRenminbi: SignedRenminbiParams => |generator, params| generator.signed_renminbi(params);
+    #[cfg(feature = "currency")]
+    MonetaryAmount: MonetaryParams => |generator, params| generator.monetary(params);
+
+    #[cfg(feature = "gregorian")]
+    Date: DateParams => |generator, params| generator.gregorian().date(params);
+    #[cfg(feature = "gregorian")]
+    LinearTime: LinearTimeParams => |generator, params| generator.gregorian().linear_time(params);
+    #[cfg(feature = "gregorian")]
+    DeltaTime: DeltaTimeParams => |generator, params| generator.gregorian().delta_time_with(params);
+    #[cfg(feature = "gregorian")]
+    ColloquialTime: ColloquialTimeParams => |generator, params| generator.gregorian().colloquial_time(params);
+    #[cfg(feature = "gregorian")]
+    Duration: DurationParams => |generator, params| generator.gregorian().duration(params);
+    #[cfg(feature = "gregorian")]
+    DatePair: DatePairParams => |generator, params| generator.gregorian().date_pair(params);
+    #[cfg(feature = "gregorian")]
+    Appointment: AppointmentParams => |generator, params| generator.gregorian().appointment(params);
+
+    #[cfg(feature = "lunar")]
+    LunarDate: LunarDateParams => |generator, params| generator.lunar().date(params);
+);
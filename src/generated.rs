@@ -0,0 +1,317 @@
+use crate::{
+    Age, Approximation, ArithmeticProblem, ChineseFormatGenerator, ChineseRandResult, MixedNumber,
+    Ordinal, PlanItem, RangeExpression, RawGenerator, RoundedCount, Temperature,
+};
+use chinese_format::{Chinese, ChineseFormat, Count, Fraction, Variant};
+use std::fmt::Debug;
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::Duration;
+#[cfg(feature = "digit-sequence")]
+use crate::Measurement;
+#[cfg(feature = "currency")]
+use chinese_format::currency::RenminbiCurrency;
+#[cfg(feature = "gregorian")]
+use chinese_format::gregorian::{Date, DeltaTime, LinearTime};
+#[cfg(feature = "digit-sequence")]
+use chinese_format::Decimal;
+#[cfg(feature = "digit-sequence")]
+use digit_sequence::DigitSequence;
+
+macro_rules! generated_value {
+    ($($(#[doc = $doc: literal])* $(#[cfg($condition: meta)])? $variant: ident($type: ty) => $kind: literal,)+) => {
+        /// Value generated from a [PlanItem], tagged with its kind -
+        /// see [ChineseFormatGenerator::generate_value].
+        ///
+        /// With the `serde` feature, it is serialized - for example, via [to_json](Self::to_json) -
+        /// as an object having the kind as `type`, the debug representation as `value`
+        /// and the `simplified` and `traditional` renderings.
+        ///
+        /// Its debug representation is the one of the wrapped value.
+        #[derive(Clone, PartialEq)]
+        pub enum GeneratedValue {
+            $(
+                $(#[doc = $doc])*
+                $(#[cfg($condition)])?
+                $variant($type),
+            )+
+        }
+
+        impl GeneratedValue {
+            /// The kind of the value - the same as [PlanItem::kind].
+            pub fn kind(&self) -> &'static str {
+                match self {
+                    $(
+                        $(#[cfg($condition)])?
+                        GeneratedValue::$variant(_) => $kind,
+                    )+
+                }
+            }
+        }
+
+        impl Debug for GeneratedValue {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(
+                        $(#[cfg($condition)])?
+                        GeneratedValue::$variant(value) => value.fmt(f),
+                    )+
+                }
+            }
+        }
+
+        impl ChineseFormat for GeneratedValue {
+            fn to_chinese(&self, variant: Variant) -> Chinese {
+                match self {
+                    $(
+                        $(#[cfg($condition)])?
+                        GeneratedValue::$variant(value) => value.to_chinese(variant),
+                    )+
+                }
+            }
+        }
+    };
+}
+
+generated_value!(
+    /// Generated from [PlanItem::Integer].
+    Integer(i128) => "integer",
+
+    /// Generated from [PlanItem::Count].
+    Count(Count) => "count",
+
+    /// Generated from [PlanItem::CountRounded].
+    CountRounded(RoundedCount) => "count_rounded",
+
+    /// Generated from [PlanItem::Fraction].
+    Fraction(Fraction) => "fraction",
+
+    /// Generated from [PlanItem::MixedNumber].
+    MixedNumber(MixedNumber) => "mixed_number",
+
+    /// Generated from [PlanItem::Ordinal].
+    Ordinal(Ordinal) => "ordinal",
+
+    /// Generated from [PlanItem::RangeExpression].
+    RangeExpression(RangeExpression) => "range_expression",
+
+    /// Generated from [PlanItem::Age].
+    Age(Age) => "age",
+
+    /// Generated from [PlanItem::Temperature].
+    Temperature(Temperature) => "temperature",
+
+    /// Generated from [PlanItem::Approximate].
+    Approximate(Approximation) => "approximate",
+
+    /// Generated from [PlanItem::Arithmetic].
+    Arithmetic(ArithmeticProblem) => "arithmetic",
+
+    /// Generated from [PlanItem::DigitSequence].
+    ///
+    /// **Required feature**: `digit-sequence`.
+    #[cfg(feature = "digit-sequence")]
+    DigitSequence(DigitSequence) => "digit_sequence",
+
+    /// Generated from [PlanItem::Decimal].
+    ///
+    /// **Required feature**: `digit-sequence`.
+    #[cfg(feature = "digit-sequence")]
+    Decimal(Decimal) => "decimal",
+
+    /// Generated from [PlanItem::Measurement].
+    ///
+    /// **Required feature**: `digit-sequence`.
+    #[cfg(feature = "digit-sequence")]
+    Measurement(Measurement) => "measurement",
+
+    /// Generated from [PlanItem::Renminbi].
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    Renminbi(RenminbiCurrency) => "renminbi",
+
+    /// Generated from [PlanItem::Date].
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Date(Date) => "date",
+
+    /// Generated from [PlanItem::LinearTime].
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    LinearTime(LinearTime) => "linear_time",
+
+    /// Generated from [PlanItem::DeltaTime].
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    DeltaTime(DeltaTime) => "delta_time",
+
+    /// Generated from [PlanItem::Duration].
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Duration(Duration) => "duration",
+);
+
+#[cfg(feature = "serde")]
+impl GeneratedValue {
+    /// Converts the value to JSON, ready to be returned by services.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use serde_json::json;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let value = generator.generate_value(&PlanItem::Count { range: 1..=99 })?;
+    ///
+    /// assert_eq!(value.to_json(), json!({
+    ///     "type": "count",
+    ///     "value": "Count(59)",
+    ///     "simplified": "五十九",
+    ///     "traditional": "五十九"
+    /// }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `serde`.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("Serializing a generated value cannot fail")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for GeneratedValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("GeneratedValue", 4)?;
+        state.serialize_field("type", self.kind())?;
+        state.serialize_field("value", &format!("{:?}", self))?;
+        state.serialize_field(
+            "simplified",
+            &self.to_chinese(Variant::Simplified).to_string(),
+        )?;
+        state.serialize_field(
+            "traditional",
+            &self.to_chinese(Variant::Traditional).to_string(),
+        )?;
+        state.end()
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates the value described by the given [PlanItem],
+    /// tagged with its kind.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let value = generator.generate_value(&PlanItem::Age { profile: AgeProfile::Child })?;
+    ///
+    /// assert_eq!(value.kind(), "age");
+    /// assert_eq!(value, GeneratedValue::Age(Age(9)));
+    /// assert_eq!(value.to_chinese(Variant::Simplified), "九岁");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn generate_value(&self, item: &PlanItem) -> ChineseRandResult<GeneratedValue> {
+        Ok(match item {
+            PlanItem::Integer { range } => GeneratedValue::Integer(self.integer(range.clone())?),
+
+            PlanItem::Count { range } => GeneratedValue::Count(self.count(range.clone())?),
+
+            PlanItem::CountRounded {
+                range,
+                granularity,
+                hedge,
+            } => GeneratedValue::CountRounded(self.count_rounded(
+                range.clone(),
+                *granularity,
+                *hedge,
+            )?),
+
+            PlanItem::Fraction {
+                denominator_range,
+                numerator_range,
+            } => GeneratedValue::Fraction(
+                self.fraction(denominator_range.clone(), numerator_range.clone())?,
+            ),
+
+            PlanItem::MixedNumber(params) => {
+                GeneratedValue::MixedNumber(self.mixed_number(params.clone())?)
+            }
+
+            PlanItem::Ordinal { range, suffix } => {
+                GeneratedValue::Ordinal(self.ordinal(range.clone(), *suffix)?)
+            }
+
+            PlanItem::RangeExpression(params) => {
+                GeneratedValue::RangeExpression(self.range_expression(params.clone())?)
+            }
+
+            PlanItem::Age { profile } => GeneratedValue::Age(self.age(*profile)),
+
+            PlanItem::Temperature {
+                celsius_range,
+                explicit_scale,
+            } => GeneratedValue::Temperature(
+                self.temperature(celsius_range.clone(), *explicit_scale)?,
+            ),
+
+            PlanItem::Approximate { range, style } => {
+                GeneratedValue::Approximate(self.approximate(range.clone(), *style)?)
+            }
+
+            PlanItem::Arithmetic(params) => {
+                GeneratedValue::Arithmetic(self.arithmetic(params.clone())?)
+            }
+
+            #[cfg(feature = "digit-sequence")]
+            PlanItem::DigitSequence { length_range } => {
+                GeneratedValue::DigitSequence(self.digit_sequence(length_range.clone())?)
+            }
+
+            #[cfg(feature = "digit-sequence")]
+            PlanItem::Decimal {
+                integer_range,
+                fractional_length_range,
+            } => GeneratedValue::Decimal(
+                self.decimal(integer_range.clone(), fractional_length_range.clone())?,
+            ),
+
+            #[cfg(feature = "digit-sequence")]
+            PlanItem::Measurement(params) => {
+                GeneratedValue::Measurement(self.measurement(params.clone())?)
+            }
+
+            #[cfg(feature = "currency")]
+            PlanItem::Renminbi(params) => GeneratedValue::Renminbi(self.renminbi(params.clone())?),
+
+            #[cfg(feature = "gregorian")]
+            PlanItem::Date(params) => GeneratedValue::Date(self.gregorian().date(params.clone())?),
+
+            #[cfg(feature = "gregorian")]
+            PlanItem::LinearTime(params) => {
+                GeneratedValue::LinearTime(self.gregorian().linear_time(*params)?)
+            }
+
+            #[cfg(feature = "gregorian")]
+            PlanItem::DeltaTime => GeneratedValue::DeltaTime(self.gregorian().delta_time()?),
+
+            #[cfg(feature = "gregorian")]
+            PlanItem::Duration(params) => {
+                GeneratedValue::Duration(self.gregorian().duration(params.clone())?)
+            }
+        })
+    }
+}
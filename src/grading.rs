@@ -0,0 +1,133 @@
+//! Comparison of typed answers - in Arabic numerals, Chinese numerals
+//! or a mix of both - against generated values, tolerating the
+//! usual notations, full-width characters and whitespace.
+use crate::parsing::parse_integer;
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::CalendarDate;
+#[cfg(feature = "gregorian")]
+use crate::parsing::{parse_date, parse_time};
+
+#[cfg(feature = "currency")]
+use crate::parsing::parse_renminbi_cents;
+#[cfg(feature = "currency")]
+use chinese_format::currency::RenminbiCurrency;
+
+/// Whether the answer denotes the expected integer.
+///
+/// ```
+/// use chinese_rand::grading::*;
+///
+/// assert!(matches_integer("三百二十", 320));
+/// assert!(matches_integer("320", 320));
+/// assert!(matches_integer(" ３２０ ", 320));
+/// assert!(matches_integer("3百2十", 320));
+/// assert!(matches_integer("叁佰贰拾", 320));
+/// assert!(matches_integer("一万零五", 10_005));
+/// assert!(matches_integer("10,005", 10_005));
+/// assert!(matches_integer("两亿三千万", 230_000_000));
+/// assert!(matches_integer("负十二", -12));
+/// assert!(matches_integer("-12", -12));
+///
+/// assert!(!matches_integer("三百二", 320));
+/// assert!(!matches_integer("三百二十", 32));
+/// assert!(!matches_integer("三百个", 300));
+/// assert!(!matches_integer("", 0));
+/// ```
+pub fn matches_integer(answer: &str, expected: i128) -> bool {
+    parse_integer(answer) == Some(expected)
+}
+
+/// Whether the answer denotes the expected date - supporting notations
+/// like `2025-08-04`, `2025/8/4`, `2025.8.4`, `2025年8月4日`
+/// and `二零二五年八月四日`.
+///
+/// **Required feature**: `gregorian`.
+///
+/// ```
+/// use chinese_rand::{grading::*, gregorian::*};
+///
+/// let expected = CalendarDate::try_new(2025, 8, 4).unwrap();
+///
+/// assert!(matches_date("2025-08-04", &expected));
+/// assert!(matches_date("2025/8/4", &expected));
+/// assert!(matches_date("2025.8.4", &expected));
+/// assert!(matches_date("2025年8月4日", &expected));
+/// assert!(matches_date("二零二五年八月四日", &expected));
+/// assert!(matches_date("二〇二五年八月四号", &expected));
+///
+/// assert!(!matches_date("2025-04-08", &expected));
+/// assert!(!matches_date("2025年8月", &expected));
+/// ```
+#[cfg(feature = "gregorian")]
+pub fn matches_date(answer: &str, expected: &CalendarDate) -> bool {
+    parse_date(answer) == Some((expected.year(), expected.month(), expected.day()))
+}
+
+/// Whether the answer denotes the expected time of the day, given in
+/// 24-hour format - supporting notations like `16:20`, `4:20 PM`,
+/// `下午四点二十分`, `十六点二十`, `四点半` and `四点一刻`.
+///
+/// **Required feature**: `gregorian`.
+///
+/// ```
+/// use chinese_rand::grading::*;
+///
+/// assert!(matches_time("16:20", 16, 20));
+/// assert!(matches_time("4:20 PM", 16, 20));
+/// assert!(matches_time("下午四点二十分", 16, 20));
+/// assert!(matches_time("十六点二十", 16, 20));
+/// assert!(matches_time("16点20分", 16, 20));
+/// assert!(matches_time("下午四点半", 16, 30));
+/// assert!(matches_time("上午八点零五分", 8, 5));
+/// assert!(matches_time("中午一点一刻", 13, 15));
+/// assert!(matches_time("午夜十二点", 0, 0));
+/// assert!(matches_time("12:00 am", 0, 0));
+///
+/// assert!(!matches_time("4:20", 16, 20));
+/// assert!(!matches_time("四点二十分", 16, 20));
+/// assert!(!matches_time("25:00", 1, 0));
+/// ```
+#[cfg(feature = "gregorian")]
+pub fn matches_time(answer: &str, hour: u8, minute: u8) -> bool {
+    parse_time(answer).is_some_and(|reading| reading.hour == hour && reading.minute == minute)
+}
+
+/// Whether the answer denotes the expected amount of Renminbi - supporting
+/// notations like `12.50`, `¥12.5`, `十二元五角`, `十二块五`
+/// and `壹拾贰元伍角整`.
+///
+/// **Required feature**: `currency`.
+///
+/// ```
+/// use chinese_rand::grading::*;
+/// use chinese_format::currency::{CurrencyStyle, RenminbiCurrencyBuilder};
+///
+/// # fn main() -> chinese_rand::GenericResult<()> {
+/// let expected = RenminbiCurrencyBuilder::new()
+///     .with_style(CurrencyStyle::Everyday { formal: true })
+///     .with_yuan(12)
+///     .with_dimes(5)
+///     .build()?;
+///
+/// assert!(matches_renminbi("12.50", &expected));
+/// assert!(matches_renminbi("¥12.5", &expected));
+/// assert!(matches_renminbi("12.5元", &expected));
+/// assert!(matches_renminbi("十二元五角", &expected));
+/// assert!(matches_renminbi("十二块五", &expected));
+/// assert!(matches_renminbi("十二块五毛", &expected));
+/// assert!(matches_renminbi("壹拾贰元伍角整", &expected));
+///
+/// assert!(!matches_renminbi("12.05", &expected));
+/// assert!(!matches_renminbi("十二元五分", &expected));
+/// assert!(!matches_renminbi("12.500", &expected));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "currency")]
+pub fn matches_renminbi(answer: &str, expected: &RenminbiCurrency) -> bool {
+    let expected_cents =
+        expected.yuan() * 100 + expected.dimes() as u64 * 10 + expected.cents() as u64;
+
+    parse_renminbi_cents(answer) == Some(expected_cents)
+}
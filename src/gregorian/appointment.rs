@@ -0,0 +1,223 @@
+use super::{CalendarDate, GregorianGenerator, WeekDayFilter};
+use crate::{check_range, ChineseRandError, ChineseRandResult, RawGenerator};
+use chinese_format::{
+    chinese_vec,
+    gregorian::{Date, Hour24, LinearTime, Minute, WeekFormat},
+    Chinese, ChineseFormat, Variant,
+};
+use std::ops::RangeInclusive;
+
+/// Parameters for the random creation of [Appointment].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AppointmentParams {
+    /// The year range of the date.
+    /// If set to [None], then the year range of the [GeneratorConfig](crate::GeneratorConfig)
+    /// is used - by default, [DEFAULT_YEAR_RANGE](crate::gregorian::DEFAULT_YEAR_RANGE).
+    pub year_range: Option<RangeInclusive<u16>>,
+
+    /// The working days.
+    pub week_day_filter: WeekDayFilter,
+
+    /// The hours - in 24-hour format - at which an appointment can begin.
+    pub hour_range: RangeInclusive<u8>,
+
+    /// The granularity of the minutes - for example, 30 for `:00` and `:30` only.
+    pub minute_step: u8,
+
+    /// If set to `true`, the time includes the day part,
+    /// plus a 12-hour format; otherwise, the 24-hour format applies.
+    pub day_part: bool,
+
+    /// Applies to the date format - for example,
+    /// 号 instead of 日 after the day ordinal.
+    pub formal: bool,
+
+    /// If set, the rendered date includes its week day, in the given format.
+    #[cfg_attr(feature = "serde", serde(with = "crate::remote::option_week_format"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::option_week_format))]
+    pub week_format: Option<WeekFormat>,
+}
+
+/// By default, [AppointmentParams] describes appointments from Monday
+/// to Friday, beginning between 9:00 and 16:30 on the hour or half hour,
+/// with day part and week day.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::gregorian::WeekFormat;
+///
+/// assert_eq!(AppointmentParams::default(), AppointmentParams {
+///     year_range: None,
+///     week_day_filter: WeekDayFilter::WeekdaysOnly,
+///     hour_range: 9..=16,
+///     minute_step: 30,
+///     day_part: true,
+///     formal: false,
+///     week_format: Some(WeekFormat::XingQi)
+/// });
+///
+/// let customized = AppointmentParams::default()
+///     .with_year_range(2024..=2025)
+///     .with_week_day_filter(WeekDayFilter::WeekendsOnly)
+///     .with_hour_range(10..=12)
+///     .with_minute_step(15);
+///
+/// assert_eq!(customized, AppointmentParams {
+///     year_range: Some(2024..=2025),
+///     week_day_filter: WeekDayFilter::WeekendsOnly,
+///     hour_range: 10..=12,
+///     minute_step: 15,
+///     day_part: true,
+///     formal: false,
+///     week_format: Some(WeekFormat::XingQi)
+/// });
+/// ```
+impl Default for AppointmentParams {
+    fn default() -> Self {
+        Self {
+            year_range: None,
+            week_day_filter: WeekDayFilter::WeekdaysOnly,
+            hour_range: 9..=16,
+            minute_step: 30,
+            day_part: true,
+            formal: false,
+            week_format: Some(WeekFormat::XingQi),
+        }
+    }
+}
+
+impl AppointmentParams {
+    /// Sets the year range.
+    pub fn with_year_range(mut self, year_range: RangeInclusive<u16>) -> Self {
+        self.year_range = Some(year_range);
+        self
+    }
+
+    /// Sets the working days.
+    pub fn with_week_day_filter(mut self, week_day_filter: WeekDayFilter) -> Self {
+        self.week_day_filter = week_day_filter;
+        self
+    }
+
+    /// Sets the hours at which an appointment can begin.
+    pub fn with_hour_range(mut self, hour_range: RangeInclusive<u8>) -> Self {
+        self.hour_range = hour_range;
+        self
+    }
+
+    /// Sets the granularity of the minutes.
+    pub fn with_minute_step(mut self, minute_step: u8) -> Self {
+        self.minute_step = minute_step;
+        self
+    }
+}
+
+/// A date and a time, for scheduling exercises.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Appointment {
+    /// The day, as calendar value.
+    pub calendar_date: CalendarDate,
+
+    /// The day, ready for rendering.
+    pub date: Date,
+
+    /// The beginning of the appointment.
+    pub time: LinearTime,
+}
+
+impl ChineseFormat for Appointment {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.date, self.time]).collect()
+    }
+}
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
+    /// Generates a random [Appointment] - on a working day, within the hour
+    /// range and with the requested minute granularity.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if a range is empty, with
+    /// [ChineseRandError::InvalidRange] if the hours exceed 23 or the minute
+    /// step is not in the 1..=60 range, and with
+    /// [ChineseRandError::GenerationExhausted] if no working day is found.
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    /// let gregorian = generator.gregorian();
+    ///
+    /// let appointment = gregorian.appointment(
+    ///     AppointmentParams::default().with_year_range(2024..=2024)
+    /// )?;
+    /// assert_eq!(
+    ///     appointment.to_chinese(Variant::Simplified),
+    ///     "二零二四年一月一日星期一下午两点"
+    /// );
+    ///
+    /// let invalid = gregorian.appointment(
+    ///     AppointmentParams::default().with_hour_range(20..=24)
+    /// );
+    /// assert!(invalid.is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn appointment(&self, params: AppointmentParams) -> ChineseRandResult<Appointment> {
+        let year_range = params
+            .year_range
+            .clone()
+            .unwrap_or_else(|| self.owner.config.year_range.clone());
+
+        check_range(&year_range)?;
+        check_range(&params.hour_range)?;
+
+        if *params.hour_range.end() > 23 {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Hours beyond 23: {:?}",
+                params.hour_range
+            )));
+        }
+
+        if !(1..=60).contains(&params.minute_step) {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Minute step not in 1..=60: {}",
+                params.minute_step
+            )));
+        }
+
+        let calendar_date = self.owner.try_retrying("Appointment", || {
+            let candidate = self.calendar_date(year_range.clone())?;
+
+            Ok(params
+                .week_day_filter
+                .accepts(candidate.week_day())
+                .then_some(candidate))
+        })?;
+
+        let hour: Hour24 = self
+            .raw_generator
+            .u8(params.hour_range.clone())
+            .try_into()
+            .expect("Hour valid by construction");
+
+        let minute: Minute = (self.raw_generator.u8(0..=(59 / params.minute_step))
+            * params.minute_step)
+            .try_into()
+            .expect("Minute valid by construction");
+
+        Ok(Appointment {
+            calendar_date,
+            date: calendar_date.to_date(params.formal, params.week_format),
+            time: LinearTime {
+                day_part: params.day_part,
+                hour,
+                minute,
+                second: None,
+            },
+        })
+    }
+}
@@ -0,0 +1,279 @@
+use super::GregorianGenerator;
+use crate::{check_range, ChineseRandError, ChineseRandResult, RawGenerator};
+use chinese_format::gregorian::{Date, DateBuilder, WeekDay, WeekFormat};
+use std::ops::RangeInclusive;
+
+/// Day of the proleptic Gregorian calendar, supporting the arithmetic
+/// needed to keep generated dates mutually consistent - whereas
+/// [Date] is just dedicated to the Chinese rendering.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::gregorian::WeekDay;
+///
+/// let date = CalendarDate::try_new(2024, 2, 28).unwrap();
+///
+/// assert_eq!(date.add_days(1), CalendarDate::try_new(2024, 2, 29));
+/// assert_eq!(date.add_days(2), CalendarDate::try_new(2024, 3, 1));
+/// assert_eq!(date.week_day(), WeekDay::Wednesday);
+///
+/// assert_eq!(CalendarDate::try_new(2023, 2, 29), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "CalendarDateFields")
+)]
+pub struct CalendarDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+/// The fields of a [CalendarDate], validated via [CalendarDate::try_new]
+/// when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct CalendarDateFields {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CalendarDateFields> for CalendarDate {
+    type Error = ChineseRandError;
+
+    fn try_from(fields: CalendarDateFields) -> Result<Self, Self::Error> {
+        Self::try_new(fields.year, fields.month, fields.day).ok_or_else(|| {
+            ChineseRandError::InvalidParams(format!(
+                "Invalid calendar date: {}-{:02}-{:02}",
+                fields.year, fields.month, fields.day
+            ))
+        })
+    }
+}
+
+impl CalendarDate {
+    /// Creates a date, returning [None] if the components are not
+    /// consistent in the Gregorian calendar.
+    pub fn try_new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    /// The year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The month, in the 1..=12 range.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month, starting from 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The day of the week, computed according to the calendar.
+    pub fn week_day(&self) -> WeekDay {
+        ((self.day_number() + 4).rem_euclid(7) as u8)
+            .try_into()
+            .expect("Weekday valid by construction")
+    }
+
+    /// Number of days from `self` to `other` - negative if `other` comes before.
+    ///
+    /// ```
+    /// use chinese_rand::gregorian::*;
+    ///
+    /// let first = CalendarDate::try_new(2023, 12, 30).unwrap();
+    /// let second = CalendarDate::try_new(2024, 1, 2).unwrap();
+    ///
+    /// assert_eq!(first.days_until(&second), 3);
+    /// assert_eq!(second.days_until(&first), -3);
+    /// ```
+    pub fn days_until(&self, other: &CalendarDate) -> i64 {
+        other.day_number() - self.day_number()
+    }
+
+    /// Moves the date by the given number of days - returning [None]
+    /// if the resulting year is outside the [u16] range.
+    ///
+    /// ```
+    /// use chinese_rand::gregorian::*;
+    ///
+    /// let date = CalendarDate::try_new(0, 1, 1).unwrap();
+    ///
+    /// assert_eq!(date.add_days(31), CalendarDate::try_new(0, 2, 1));
+    /// assert_eq!(date.add_days(-1), None);
+    /// assert_eq!(date.add_days(i64::MAX), None);
+    /// ```
+    pub fn add_days(&self, days: i64) -> Option<Self> {
+        Self::from_day_number(self.day_number().checked_add(days)?)
+    }
+
+    /// Moves the date by the given number of months; the day of the month
+    /// is clamped to the length of the resulting month - so that, for example,
+    /// January 31 plus one month is the last day of February.
+    ///
+    /// Returns [None] if the resulting year is outside the [u16] range.
+    ///
+    /// ```
+    /// use chinese_rand::gregorian::*;
+    ///
+    /// let date = CalendarDate::try_new(2024, 1, 31).unwrap();
+    ///
+    /// assert_eq!(date.add_months(1), CalendarDate::try_new(2024, 2, 29));
+    /// assert_eq!(date.add_months(-2), CalendarDate::try_new(2023, 11, 30));
+    /// assert_eq!(date.add_months(-12 * 2025), None);
+    /// ```
+    pub fn add_months(&self, months: i64) -> Option<Self> {
+        let month_index = (self.year as i64 * 12 + (self.month as i64 - 1)).checked_add(months)?;
+
+        let year: u16 = month_index.div_euclid(12).try_into().ok()?;
+        let month = month_index.rem_euclid(12) as u8 + 1;
+        let day = self.day.min(days_in_month(year, month));
+
+        Some(Self { year, month, day })
+    }
+
+    /// Converts to a chinese_format [Date] having year, month and day -
+    /// plus the calendar-consistent [WeekDay], if a [WeekFormat] is passed.
+    pub fn to_date(&self, formal: bool, week_format: Option<WeekFormat>) -> Date {
+        let mut builder = DateBuilder::new()
+            .with_formal(formal)
+            .with_year(self.year)
+            .with_month(self.month)
+            .with_day(self.day);
+
+        if let Some(week_format) = week_format {
+            builder = builder
+                .with_week_format(week_format)
+                .with_week_day(self.week_day());
+        }
+
+        builder.build().expect("Date valid by construction")
+    }
+
+    /// Days since 1970-01-01 - see Howard Hinnant's `days_from_civil`.
+    pub(crate) fn day_number(&self) -> i64 {
+        let year = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let shifted_month = (self.month as i64 + 9) % 12;
+        let day_of_year = (153 * shifted_month + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Inverse of [Self::day_number] - see Howard Hinnant's `civil_from_days` -
+    /// returning [None] if the year is outside the [u16] range.
+    pub(crate) fn from_day_number(day_number: i64) -> Option<Self> {
+        let shifted = day_number.checked_add(719_468)?;
+        let era = shifted.div_euclid(146_097);
+        let day_of_era = shifted - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u8;
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        Some(Self {
+            year: year.try_into().ok()?,
+            month,
+            day,
+        })
+    }
+}
+
+/// The error returned when the given offset moves the date
+/// outside the [u16] range of years.
+pub(crate) fn beyond_calendar(date: &CalendarDate, amount: i64, unit: &str) -> ChineseRandError {
+    ChineseRandError::InvalidRange(format!(
+        "Date beyond the calendar: {}-{:02}-{:02} moved by {} {}",
+        date.year, date.month, date.day, amount, unit
+    ))
+}
+
+pub(crate) fn is_leap(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+pub(crate) fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
+    /// Generates a random [CalendarDate] - uniformly distributed
+    /// over all the days of the given year range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_rand::gregorian::CalendarDate;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let date = gregorian.calendar_date(2000..=2019)?;
+    /// assert_eq!(date, CalendarDate::try_new(2011, 10, 15).unwrap());
+    ///
+    /// assert_eq!(
+    ///     gregorian.calendar_date(2019..=2000),
+    ///     Err(ChineseRandError::EmptyRange("2019..=2000".to_string()))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn calendar_date(
+        &self,
+        year_range: RangeInclusive<u16>,
+    ) -> ChineseRandResult<CalendarDate> {
+        check_range(&year_range)?;
+
+        let (first_year, last_year) = year_range.into_inner();
+
+        let first_day = CalendarDate {
+            year: first_year,
+            month: 1,
+            day: 1,
+        }
+        .day_number();
+
+        let last_day = CalendarDate {
+            year: last_year,
+            month: 12,
+            day: 31,
+        }
+        .day_number();
+
+        Ok(
+            CalendarDate::from_day_number(self.raw_generator.i64(first_day..=last_day))
+                .expect("Day valid by construction"),
+        )
+    }
+}
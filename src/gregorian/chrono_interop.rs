@@ -0,0 +1,107 @@
+use super::{CalendarDate, GregorianGenerator};
+use crate::{check_range, ChineseRandError, ChineseRandResult, RawGenerator};
+use chrono::{Datelike, NaiveDate};
+use std::ops::RangeInclusive;
+
+impl CalendarDate {
+    /// Converts a [chrono] date - failing with [ChineseRandError::InvalidRange]
+    /// if its year is negative or beyond [u16::MAX].
+    ///
+    /// **Required feature**: `chrono`.
+    ///
+    /// ```
+    /// use chinese_rand::gregorian::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    /// use chrono::NaiveDate;
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    ///
+    /// let date = CalendarDate::from_chrono(chrono_date).unwrap();
+    /// assert_eq!(date, CalendarDate::try_new(2024, 2, 29).unwrap());
+    /// assert_eq!(date.to_chrono(), chrono_date);
+    /// assert_eq!(
+    ///     date.to_date(false, None).to_chinese(Variant::Simplified),
+    ///     "二零二四年二月二十九日"
+    /// );
+    ///
+    /// assert!(CalendarDate::from_chrono(NaiveDate::from_ymd_opt(-1, 1, 1).unwrap()).is_err());
+    /// ```
+    pub fn from_chrono(date: NaiveDate) -> ChineseRandResult<Self> {
+        let year: u16 = date.year().try_into().map_err(|_| {
+            ChineseRandError::InvalidRange(format!("Year out of range: {}", date.year()))
+        })?;
+
+        Ok(Self::try_new(year, date.month() as u8, date.day() as u8)
+            .expect("Date valid by construction"))
+    }
+
+    /// Converts to a [chrono] date.
+    ///
+    /// **Required feature**: `chrono`.
+    pub fn to_chrono(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year() as i32, self.month() as u32, self.day() as u32)
+            .expect("Date valid by construction")
+    }
+}
+
+impl TryFrom<NaiveDate> for CalendarDate {
+    type Error = ChineseRandError;
+
+    fn try_from(date: NaiveDate) -> ChineseRandResult<Self> {
+        Self::from_chrono(date)
+    }
+}
+
+impl From<CalendarDate> for NaiveDate {
+    fn from(date: CalendarDate) -> Self {
+        date.to_chrono()
+    }
+}
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
+    /// Generates a random [CalendarDate] - uniformly distributed
+    /// over the days of the given [chrono] range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if the range is empty, and
+    /// with [ChineseRandError::InvalidRange] if a bound cannot be converted
+    /// via [CalendarDate::from_chrono].
+    ///
+    /// **Required feature**: `chrono`.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    /// use chrono::NaiveDate;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let gregorian = generator.gregorian();
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+    ///
+    /// let date = gregorian.date_in(start..=end)?;
+    /// assert_eq!(
+    ///     date.to_date(false, None).to_chinese(Variant::Simplified),
+    ///     "二零二四年三月十九日"
+    /// );
+    /// assert!((start..=end).contains(&date.to_chrono()));
+    ///
+    /// assert!(gregorian.date_in(end..=start).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn date_in(&self, range: RangeInclusive<NaiveDate>) -> ChineseRandResult<CalendarDate> {
+        check_range(&range)?;
+
+        let (start, end) = range.into_inner();
+
+        let first_day = CalendarDate::from_chrono(start)?.day_number();
+        let last_day = CalendarDate::from_chrono(end)?.day_number();
+
+        Ok(
+            CalendarDate::from_day_number(self.raw_generator.i64(first_day..=last_day))
+                .expect("Day valid by construction"),
+        )
+    }
+}
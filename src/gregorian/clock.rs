@@ -0,0 +1,124 @@
+use super::{calendar::beyond_calendar, CalendarDate, GregorianGenerator};
+use crate::{check_range, ChineseRandResult, RawGenerator};
+use std::ops::RangeInclusive;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Source of the current day - injectable, so that generations
+/// relative to today can be tested deterministically.
+pub trait Clock {
+    /// The current day.
+    fn today(&self) -> CalendarDate;
+}
+
+/// [Clock] based on the system time, in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn today(&self) -> CalendarDate {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("System time before the Unix epoch")
+            .as_secs();
+
+        CalendarDate::from_day_number((seconds / SECONDS_PER_DAY) as i64)
+            .expect("System date beyond the year 65535")
+    }
+}
+
+/// [Clock] always returning the same day.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+///
+/// let today = CalendarDate::try_new(2024, 5, 20).unwrap();
+///
+/// assert_eq!(FixedClock(today).today(), today);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedClock(pub CalendarDate);
+
+impl Clock for FixedClock {
+    fn today(&self) -> CalendarDate {
+        self.0
+    }
+}
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
+    /// Generates a random [CalendarDate] whose offset from today - according
+    /// to [SystemClock] - is within the given range of days; useful for
+    /// exercises about 明天 or 上个星期, requiring temporal locality.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty, and with [ChineseRandError::InvalidRange](crate::ChineseRandError::InvalidRange)
+    /// if the offset moves the date beyond the [u16] range of years.
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let gregorian = generator.gregorian();
+    ///
+    /// let today = SystemClock.today();
+    /// let date = gregorian.date_near_today(-7..=7)?;
+    /// assert!((-7..=7).contains(&today.days_until(&date)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn date_near_today(
+        &self,
+        offset_range_days: RangeInclusive<i64>,
+    ) -> ChineseRandResult<CalendarDate> {
+        self.date_near_today_with(&SystemClock, offset_range_days)
+    }
+
+    /// Like [date_near_today](Self::date_near_today), but getting
+    /// today from the given [Clock].
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let gregorian = generator.gregorian();
+    ///
+    /// let clock = FixedClock(CalendarDate::try_new(2024, 12, 30).unwrap());
+    ///
+    /// let date = gregorian.date_near_today_with(&clock, 1..=3)?;
+    /// assert_eq!(
+    ///     date.to_date(false, None).to_chinese(Variant::Simplified),
+    ///     "二零二五年一月一日"
+    /// );
+    ///
+    /// assert!(gregorian.date_near_today_with(&clock, 3..=1).is_err());
+    ///
+    /// let origin = FixedClock(CalendarDate::try_new(0, 1, 1).unwrap());
+    /// assert_eq!(
+    ///     gregorian.date_near_today_with(&origin, -1..=-1),
+    ///     Err(ChineseRandError::InvalidRange(
+    ///         "Date beyond the calendar: 0-01-01 moved by -1 days".to_string()
+    ///     ))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn date_near_today_with(
+        &self,
+        clock: &dyn Clock,
+        offset_range_days: RangeInclusive<i64>,
+    ) -> ChineseRandResult<CalendarDate> {
+        check_range(&offset_range_days)?;
+
+        let offset = self.raw_generator.i64(offset_range_days);
+
+        let today = clock.today();
+
+        today
+            .add_days(offset)
+            .ok_or_else(|| beyond_calendar(&today, offset, "days"))
+    }
+}
@@ -0,0 +1,221 @@
+use super::{GregorianGenerator, LinearTimeParams};
+use crate::{ChineseRandError, ChineseRandResult, RawGenerator};
+use chinese_format::gregorian::LinearTime;
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Variant};
+
+/// Parameters for the random creation of [ColloquialTime].
+///
+/// **Required feature**: `gregorian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ColloquialTimeParams {
+    /// How the time is generated - the *colloquial* ones never having seconds.
+    pub time_params: LinearTimeParams,
+
+    /// The probability - in the 0..=100 range - of a colloquial time,
+    /// like `十点半` or `十点一刻`, instead of a minute-precise one.
+    pub colloquial_percent: u8,
+
+    /// Whether `三刻` - the least common form - can be generated.
+    pub include_three_quarters: bool,
+}
+
+/// By default, [ColloquialTimeParams] describes colloquial times - including
+/// `三刻` - in half the cases.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+///
+/// assert_eq!(ColloquialTimeParams::default(), ColloquialTimeParams {
+///     time_params: LinearTimeParams::default(),
+///     colloquial_percent: 50,
+///     include_three_quarters: true
+/// });
+///
+/// let customized = ColloquialTimeParams::default()
+///     .with_time_params(LinearTimeParams::default().with_day_part(true))
+///     .with_colloquial_percent(100)
+///     .with_three_quarters(false);
+///
+/// assert_eq!(customized, ColloquialTimeParams {
+///     time_params: LinearTimeParams::default().with_day_part(true),
+///     colloquial_percent: 100,
+///     include_three_quarters: false
+/// });
+/// ```
+impl Default for ColloquialTimeParams {
+    fn default() -> Self {
+        Self {
+            time_params: LinearTimeParams::default(),
+            colloquial_percent: 50,
+            include_three_quarters: true,
+        }
+    }
+}
+
+impl ColloquialTimeParams {
+    /// Sets how the time is generated.
+    pub fn with_time_params(mut self, time_params: LinearTimeParams) -> Self {
+        self.time_params = time_params;
+        self
+    }
+
+    /// Sets the probability - in the 0..=100 range - of a colloquial time.
+    pub fn with_colloquial_percent(mut self, colloquial_percent: u8) -> Self {
+        self.colloquial_percent = colloquial_percent;
+        self
+    }
+
+    /// Sets whether `三刻` can be generated.
+    pub fn with_three_quarters(mut self, include_three_quarters: bool) -> Self {
+        self.include_three_quarters = include_three_quarters;
+        self
+    }
+}
+
+/// [LinearTime] that, when `colloquial` is `true`, renders the minutes
+/// 15, 30 and 45 as in spoken usage - `一刻`, `半` and `三刻`.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::{ChineseFormat, Variant, gregorian::*};
+///
+/// # fn main() -> chinese_rand::GenericResult<()> {
+/// let half = ColloquialTime {
+///     time: LinearTime {
+///         day_part: true,
+///         hour: 22.try_into()?,
+///         minute: 30.try_into()?,
+///         second: None
+///     },
+///     colloquial: true
+/// };
+/// assert_eq!(half.to_chinese(Variant::Simplified), "晚上十点半");
+///
+/// let quarter = ColloquialTime {
+///     time: LinearTime {
+///         day_part: false,
+///         hour: 10.try_into()?,
+///         minute: 15.try_into()?,
+///         second: None
+///     },
+///     colloquial: true
+/// };
+/// assert_eq!(quarter.to_chinese(Variant::Simplified), "十点一刻");
+///
+/// let three_quarters = ColloquialTime {
+///     time: LinearTime {
+///         day_part: false,
+///         hour: 10.try_into()?,
+///         minute: 45.try_into()?,
+///         second: None
+///     },
+///     colloquial: true
+/// };
+/// assert_eq!(three_quarters.to_chinese(Variant::Traditional), "十點三刻");
+///
+/// let precise = ColloquialTime {
+///     colloquial: false,
+///     ..half
+/// };
+/// assert_eq!(precise.to_chinese(Variant::Simplified), "晚上十点三十分");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **Required feature**: `gregorian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ColloquialTime {
+    /// The time.
+    pub time: LinearTime,
+
+    /// Whether the quarters of the hour are rendered colloquially.
+    pub colloquial: bool,
+}
+
+impl ChineseFormat for ColloquialTime {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let suffix = match u8::from(self.time.minute) {
+            15 => "一刻",
+            30 => "半",
+            45 => "三刻",
+            _ => return self.time.to_chinese(variant),
+        };
+
+        if !self.colloquial || self.time.second.is_some() {
+            return self.time.to_chinese(variant);
+        }
+
+        let hour_time = LinearTime {
+            minute: 0.try_into().expect("Minute valid by construction"),
+            ..self.time
+        };
+
+        chinese_vec!(variant, [hour_time, suffix]).collect()
+    }
+}
+
+impl<R: RawGenerator> GregorianGenerator<'_, R> {
+    /// Generates a random [ColloquialTime] - which, with the probability
+    /// set in the [ColloquialTimeParams], is on the half or on a quarter of the hour,
+    /// overriding the minute policy of the underlying [LinearTimeParams].
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let gregorian = generator.gregorian();
+    ///
+    /// let times = (0..6)
+    ///     .map(|_| gregorian.colloquial_time(ColloquialTimeParams::default()))
+    ///     .map(|time| time.map(|time| time.to_chinese(Variant::Simplified).to_string()))
+    ///     .collect::<ChineseRandResult<Vec<_>>>()?;
+    /// assert_eq!(
+    ///     times,
+    ///     ["八点四十三分", "十一点半", "四点半", "十一点一刻", "十九点十八分", "十四点一刻"]
+    /// );
+    ///
+    /// let invalid = gregorian.colloquial_time(
+    ///     ColloquialTimeParams::default().with_colloquial_percent(101)
+    /// );
+    /// assert_eq!(
+    ///     invalid,
+    ///     Err(ChineseRandError::InvalidParams(
+    ///         "Colloquial percentage out of 0..=100: 101".to_string()
+    ///     ))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `gregorian`.
+    pub fn colloquial_time(
+        &self,
+        params: ColloquialTimeParams,
+    ) -> ChineseRandResult<ColloquialTime> {
+        if params.colloquial_percent > 100 {
+            return Err(ChineseRandError::InvalidParams(format!(
+                "Colloquial percentage out of 0..=100: {}",
+                params.colloquial_percent
+            )));
+        }
+
+        let colloquial = self.raw_generator.u8(1..=100) <= params.colloquial_percent;
+
+        let mut time = self.linear_time(params.time_params)?;
+
+        if colloquial {
+            let quarters = if params.include_three_quarters { 3 } else { 2 };
+
+            time.minute = (15 * self.raw_generator.u8(1..=quarters))
+                .try_into()
+                .expect("Minute valid by construction");
+            time.second = None;
+        }
+
+        Ok(ColloquialTime { time, colloquial })
+    }
+}
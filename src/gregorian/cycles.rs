@@ -0,0 +1,82 @@
+use super::GregorianGenerator;
+use crate::{RawGenerator, ShuffledCycle};
+use chinese_format::gregorian::{Date, DateBuilder, Hour24, WeekDay, WeekFormat};
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
+    /// Creates a [ShuffledCycle] over the 12 months,
+    /// as [Date] instances having just the month component.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{Variant, ChineseFormat};
+    /// use std::collections::HashSet;
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let months: HashSet<String> = generator
+    ///     .gregorian()
+    ///     .month_cycle()
+    ///     .take(12)
+    ///     .map(|month| month.to_chinese(Variant::Simplified).to_string())
+    ///     .collect();
+    /// assert_eq!(months.len(), 12);
+    /// assert!(months.contains("十二月"));
+    /// ```
+    pub fn month_cycle(&self) -> ShuffledCycle<'a, Date, R> {
+        self.owner.shuffled_cycle((1..=12).map(|month| {
+            DateBuilder::new()
+                .with_month(month)
+                .build()
+                .expect("Month valid by construction")
+        }))
+    }
+
+    /// Creates a [ShuffledCycle] over the 7 days of the week,
+    /// as [Date] instances rendered according to the given [WeekFormat].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{Variant, ChineseFormat, gregorian::WeekFormat};
+    /// use std::collections::HashSet;
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let week_days: HashSet<String> = generator
+    ///     .gregorian()
+    ///     .week_day_cycle(WeekFormat::XingQi)
+    ///     .take(7)
+    ///     .map(|week_day| week_day.to_chinese(Variant::Simplified).to_string())
+    ///     .collect();
+    /// assert_eq!(week_days.len(), 7);
+    /// assert!(week_days.contains("星期天"));
+    /// ```
+    pub fn week_day_cycle(&self, week_format: WeekFormat) -> ShuffledCycle<'a, Date, R> {
+        self.owner.shuffled_cycle((0..=6).map(|ordinal| {
+            let week_day: WeekDay = ordinal.try_into().expect("Weekday valid by construction");
+
+            DateBuilder::new()
+                .with_week_format(week_format)
+                .with_week_day(week_day)
+                .build()
+                .expect("Weekday valid by construction")
+        }))
+    }
+
+    /// Creates a [ShuffledCycle] over the 24 hours of the day.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::gregorian::Hour24;
+    /// use std::collections::HashSet;
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let hours: HashSet<Hour24> = generator.gregorian().hour24_cycle().take(24).collect();
+    /// assert_eq!(hours.len(), 24);
+    /// ```
+    pub fn hour24_cycle(&self) -> ShuffledCycle<'a, Hour24, R> {
+        self.owner.shuffled_cycle(
+            (0..=23).map(|hour: u8| Hour24::try_from(hour).expect("Hour valid by construction")),
+        )
+    }
+}
@@ -1,5 +1,9 @@
-use super::GregorianGenerator;
-use chinese_format::gregorian::{Date, DateBuilder, DatePattern, WeekFormat};
+use super::{
+    CalendarDate, Clock, Generation, GregorianGenerator, HolidayFilter, PatternChoice, SystemClock,
+    WeekDayFilter,
+};
+use crate::{check_range, ChineseRandError, ChineseRandResult, RawGenerator};
+use chinese_format::gregorian::{Date, DateBuilder, DatePattern, WeekDay, WeekFormat};
 use std::ops::RangeInclusive;
 
 /// The year range used when the related parameter is missing.
@@ -7,12 +11,17 @@ pub const DEFAULT_YEAR_RANGE: RangeInclusive<u16> = 1800..=2140;
 
 /// Parameters for the random creation of [Date].
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DateParams {
-    /// How the date should appear - for example, [YearMonthDay](DatePattern::YearMonthDay).
-    pub pattern: DatePattern,
+    /// How the date should appear - for example, always [YearMonthDay](DatePattern::YearMonthDay),
+    /// or randomly chosen among several patterns.
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::pattern_choice))]
+    pub pattern: PatternChoice,
 
     /// The year range, if applicable in the pattern.
-    /// If set to [None], then [DEFAULT_YEAR_RANGE] is used.
+    /// If set to [None], then the year range of the [GeneratorConfig](crate::GeneratorConfig)
+    /// is used - by default, [DEFAULT_YEAR_RANGE](crate::gregorian::DEFAULT_YEAR_RANGE).
     pub year_range: Option<RangeInclusive<u16>>,
 
     /// Applies to the date format - for example,
@@ -21,32 +30,378 @@ pub struct DateParams {
 
     /// How *week* should be translated into logograms, if applicable.
     /// If set to [None], then [WeekFormat]'s default value is applied.
+    #[cfg_attr(feature = "serde", serde(with = "crate::remote::option_week_format"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::option_week_format))]
     pub week_format: Option<WeekFormat>,
+
+    /// Whether to generate only holidays, or no holidays at all.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub holiday_filter: HolidayFilter,
+
+    /// Which days of the week are accepted.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub week_day_filter: WeekDayFilter,
+
+    /// If set, the year - if applicable in the pattern - belongs to
+    /// the [Generation], regardless of `year_range`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub generation: Option<Generation>,
+}
+
+/// By default, [DateParams] describes an informal [YearMonthDay](DatePattern::YearMonthDay)
+/// date within [DEFAULT_YEAR_RANGE], with the default [WeekFormat], no [HolidayFilter], no [WeekDayFilter] and no [Generation].
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::gregorian::{DatePattern, WeekFormat};
+///
+/// let params = DateParams::default();
+///
+/// assert_eq!(params, DateParams {
+///     pattern: PatternChoice::Fixed(DatePattern::YearMonthDay),
+///     year_range: None,
+///     formal: false,
+///     week_format: None,
+///     holiday_filter: HolidayFilter::Any,
+///     week_day_filter: WeekDayFilter::Any,
+///     generation: None
+/// });
+///
+/// let customized = DateParams::default()
+///     .with_pattern(DatePattern::YearMonthDayWeekDay)
+///     .with_year_range(2000..=2019)
+///     .with_formal(true)
+///     .with_week_format(WeekFormat::Zhou)
+///     .with_holiday_filter(HolidayFilter::ExcludeHolidays(HolidayTable::default()))
+///     .with_week_day_filter(WeekDayFilter::WeekendsOnly)
+///     .with_generation(Generation(1990));
+///
+/// assert_eq!(customized, DateParams {
+///     pattern: PatternChoice::Fixed(DatePattern::YearMonthDayWeekDay),
+///     year_range: Some(2000..=2019),
+///     formal: true,
+///     week_format: Some(WeekFormat::Zhou),
+///     holiday_filter: HolidayFilter::ExcludeHolidays(HolidayTable::default()),
+///     week_day_filter: WeekDayFilter::WeekendsOnly,
+///     generation: Some(Generation(1990))
+/// });
+/// ```
+impl Default for DateParams {
+    fn default() -> Self {
+        Self {
+            pattern: PatternChoice::Fixed(DatePattern::YearMonthDay),
+            year_range: None,
+            formal: false,
+            week_format: None,
+            holiday_filter: HolidayFilter::Any,
+            week_day_filter: WeekDayFilter::Any,
+            generation: None,
+        }
+    }
 }
 
-impl<'a> GregorianGenerator<'a> {
+impl DateParams {
+    /// Sets the [PatternChoice] - also accepting a single [DatePattern].
+    pub fn with_pattern(mut self, pattern: impl Into<PatternChoice>) -> Self {
+        self.pattern = pattern.into();
+        self
+    }
+
+    /// Sets the year range.
+    pub fn with_year_range(mut self, year_range: RangeInclusive<u16>) -> Self {
+        self.year_range = Some(year_range);
+        self
+    }
+
+    /// Sets whether the date format is formal.
+    pub fn with_formal(mut self, formal: bool) -> Self {
+        self.formal = formal;
+        self
+    }
+
+    /// Sets the [WeekFormat].
+    pub fn with_week_format(mut self, week_format: WeekFormat) -> Self {
+        self.week_format = Some(week_format);
+        self
+    }
+
+    /// Sets the [HolidayFilter].
+    pub fn with_holiday_filter(mut self, holiday_filter: HolidayFilter) -> Self {
+        self.holiday_filter = holiday_filter;
+        self
+    }
+
+    /// Sets the [WeekDayFilter].
+    pub fn with_week_day_filter(mut self, week_day_filter: WeekDayFilter) -> Self {
+        self.week_day_filter = week_day_filter;
+        self
+    }
+
+    /// Sets the [Generation] to which the year belongs.
+    pub fn with_generation(mut self, generation: Generation) -> Self {
+        self.generation = Some(generation);
+        self
+    }
+
+    /// Default [DateParams] whose year range spans the last `n_years`
+    /// up to the current year - according to [SystemClock].
+    ///
+    /// ```
+    /// use chinese_rand::gregorian::*;
+    ///
+    /// let this_year = SystemClock.today().year();
+    ///
+    /// assert_eq!(
+    ///     DateParams::recent_past(10),
+    ///     DateParams::default().with_year_range(this_year - 10..=this_year)
+    /// );
+    /// ```
+    pub fn recent_past(n_years: u16) -> Self {
+        Self::recent_past_with(&SystemClock, n_years)
+    }
+
+    /// Like [recent_past](Self::recent_past), but getting
+    /// the current year from the given [Clock].
+    ///
+    /// ```
+    /// use chinese_rand::gregorian::*;
+    ///
+    /// let clock = FixedClock(CalendarDate::try_new(2024, 5, 20).unwrap());
+    ///
+    /// assert_eq!(
+    ///     DateParams::recent_past_with(&clock, 5),
+    ///     DateParams::default().with_year_range(2019..=2024)
+    /// );
+    /// ```
+    pub fn recent_past_with(clock: &dyn Clock, n_years: u16) -> Self {
+        let this_year = clock.today().year();
+
+        Self::default().with_year_range(this_year.saturating_sub(n_years)..=this_year)
+    }
+
+    /// Default [DateParams] whose year range spans from the current year -
+    /// according to [SystemClock] - to the next `n_years`.
+    ///
+    /// ```
+    /// use chinese_rand::gregorian::*;
+    ///
+    /// let this_year = SystemClock.today().year();
+    ///
+    /// assert_eq!(
+    ///     DateParams::near_future(3),
+    ///     DateParams::default().with_year_range(this_year..=this_year + 3)
+    /// );
+    /// ```
+    pub fn near_future(n_years: u16) -> Self {
+        Self::near_future_with(&SystemClock, n_years)
+    }
+
+    /// Like [near_future](Self::near_future), but getting
+    /// the current year from the given [Clock].
+    ///
+    /// ```
+    /// use chinese_rand::gregorian::*;
+    ///
+    /// let clock = FixedClock(CalendarDate::try_new(2024, 5, 20).unwrap());
+    ///
+    /// assert_eq!(
+    ///     DateParams::near_future_with(&clock, 5),
+    ///     DateParams::default().with_year_range(2024..=2029)
+    /// );
+    /// ```
+    pub fn near_future_with(clock: &dyn Clock, n_years: u16) -> Self {
+        let this_year = clock.today().year();
+
+        Self::default().with_year_range(this_year..=this_year.saturating_add(n_years))
+    }
+}
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
+    /// Generates a random year in the given range, as a [Date]
+    /// having just the year component - because
+    /// [chinese_format] does not expose standalone date components.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{Variant, ChineseFormat};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let year = gregorian.year(2000..=2019)?;
+    /// assert_eq!(
+    ///     year.to_chinese(Variant::Simplified),
+    ///     "二零一三年"
+    /// );
+    ///
+    /// assert!(gregorian.year(2019..=2000).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn year(&self, range: RangeInclusive<u16>) -> ChineseRandResult<Date> {
+        check_range(&range)?;
+
+        Ok(DateBuilder::new()
+            .with_year(self.raw_generator.u16(range))
+            .build()
+            .expect("Year valid by construction"))
+    }
+
+    /// Generates a random month, as a [Date] having just the month component.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{Variant, ChineseFormat};
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let month = gregorian.month();
+    /// assert_eq!(
+    ///     month.to_chinese(Variant::Simplified),
+    ///     "九月"
+    /// );
+    /// ```
+    pub fn month(&self) -> Date {
+        DateBuilder::new()
+            .with_month(self.raw_generator.u8(1..=12))
+            .build()
+            .expect("Month valid by construction")
+    }
+
+    /// Generates a random day of the month - in the 1..=31 range -
+    /// as a [Date] having just the day component,
+    /// with formal (号) or informal (日) unit.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{Variant, ChineseFormat};
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let day = gregorian.day(true);
+    /// assert_eq!(
+    ///     day.to_chinese(Variant::Simplified),
+    ///     "二十二号"
+    /// );
+    ///
+    /// fastrand::seed(90);
+    /// let day = gregorian.day(false);
+    /// assert_eq!(
+    ///     day.to_chinese(Variant::Simplified),
+    ///     "二十二日"
+    /// );
+    /// ```
+    pub fn day(&self, formal: bool) -> Date {
+        DateBuilder::new()
+            .with_formal(formal)
+            .with_day(self.raw_generator.u8(1..=31))
+            .build()
+            .expect("Day valid by construction")
+    }
+
+    /// Generates a random [WeekDay].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::gregorian::WeekDay;
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let week_day = gregorian.week_day_value();
+    /// assert_eq!(week_day, WeekDay::Thursday);
+    /// ```
+    pub fn week_day_value(&self) -> WeekDay {
+        self.raw_generator
+            .u8(0..=6)
+            .try_into()
+            .expect("Weekday valid by construction")
+    }
+
+    /// Generates a random day of the week, as a [Date] having
+    /// just the week day component, rendered according to the given [WeekFormat].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{Variant, ChineseFormat, gregorian::WeekFormat};
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let week_day = gregorian.week_day(WeekFormat::XingQi);
+    /// assert_eq!(
+    ///     week_day.to_chinese(Variant::Simplified),
+    ///     "星期四"
+    /// );
+    ///
+    /// fastrand::seed(90);
+    /// let week_day = gregorian.week_day(WeekFormat::LiBai);
+    /// assert_eq!(
+    ///     week_day.to_chinese(Variant::Traditional),
+    ///     "禮拜四"
+    /// );
+    /// ```
+    pub fn week_day(&self, week_format: WeekFormat) -> Date {
+        DateBuilder::new()
+            .with_week_format(week_format)
+            .with_week_day(self.week_day_value())
+            .build()
+            .expect("Weekday valid by construction")
+    }
+
     /// Generates a random [Date] using the given parameters.
     ///
     /// The date generated is always consistent in the context of the Gregorian calendar,
     /// with the exception of its [WeekDay](chinese_format::gregorian::WeekDay) part,
     /// if present - because it is created entirely at random, within its own range
-    /// of validity.
+    /// of validity; the only exception is when a [WeekDayFilter] is set, in which case
+    /// the week day is computed from the calendar.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if the year range is empty,
+    /// and with [ChineseRandError::Unsupported] if a [HolidayFilter] is
+    /// requested for a pattern lacking month or day - or if a [WeekDayFilter]
+    /// is requested for a pattern lacking year, month or day.
+    ///
+    /// When several patterns are available via [PatternChoice::RandomFrom], one of them
+    /// is drawn for each date - and the filters must support all of them; an empty
+    /// choice fails with [ChineseRandError::InvalidParams].
     ///
     /// ```
     /// use chinese_rand::{*, gregorian::*};
     /// use chinese_format::{Variant, ChineseFormat, gregorian::{Date, DatePattern, WeekFormat}};
     ///
+    /// # fn main() -> GenericResult<()> {
     /// let raw_generator = FastRandGenerator::new();
     /// let generator = ChineseFormatGenerator::new(raw_generator);
     /// let gregorian = generator.gregorian();
     ///
     /// fastrand::seed(90);
     /// let date: Date = gregorian.date(DateParams {
-    ///     pattern: DatePattern::YearMonthDayWeekDay,
+    ///     pattern: PatternChoice::Fixed(DatePattern::YearMonthDayWeekDay),
     ///     year_range: Some(2000..=2019),
     ///     formal: true,
-    ///     week_format: Some(WeekFormat::Zhou)
-    /// });
+    ///     week_format: Some(WeekFormat::Zhou),
+    ///     holiday_filter: HolidayFilter::Any,
+    ///     week_day_filter: WeekDayFilter::Any,
+    ///     generation: None
+    /// })?;
     /// assert_eq!(
     ///     date.to_chinese(Variant::Simplified),
     ///     "二零一三年五月二十三号周一"
@@ -54,11 +409,14 @@ impl<'a> GregorianGenerator<'a> {
     ///
     /// fastrand::seed(90);
     /// let date: Date = gregorian.date(DateParams {
-    ///     pattern: DatePattern::YearMonthDayWeekDay,
+    ///     pattern: PatternChoice::Fixed(DatePattern::YearMonthDayWeekDay),
     ///     year_range: Some(2000..=2019),
     ///     formal: false,
-    ///     week_format: Some(WeekFormat::XingQi)
-    /// });
+    ///     week_format: Some(WeekFormat::XingQi),
+    ///     holiday_filter: HolidayFilter::Any,
+    ///     week_day_filter: WeekDayFilter::Any,
+    ///     generation: None
+    /// })?;
     /// assert_eq!(
     ///     date.to_chinese(Variant::Simplified),
     ///     "二零一三年五月二十三日星期一"
@@ -66,11 +424,14 @@ impl<'a> GregorianGenerator<'a> {
     ///
     /// fastrand::seed(90);
     /// let date: Date = gregorian.date(DateParams {
-    ///     pattern: DatePattern::YearMonthDay,
+    ///     pattern: PatternChoice::Fixed(DatePattern::YearMonthDay),
     ///     year_range: Some(2000..=2019),
     ///     formal: false,
-    ///     week_format: None
-    /// });
+    ///     week_format: None,
+    ///     holiday_filter: HolidayFilter::Any,
+    ///     week_day_filter: WeekDayFilter::Any,
+    ///     generation: None
+    /// })?;
     /// assert_eq!(
     ///     date.to_chinese(Variant::Simplified),
     ///     "二零一三年五月二十三日"
@@ -78,11 +439,14 @@ impl<'a> GregorianGenerator<'a> {
     ///
     /// fastrand::seed(90);
     /// let date: Date = gregorian.date(DateParams {
-    ///     pattern: DatePattern::YearMonth,
+    ///     pattern: PatternChoice::Fixed(DatePattern::YearMonth),
     ///     year_range: Some(2000..=2019),
     ///     formal: false,
-    ///     week_format: None
-    /// });
+    ///     week_format: None,
+    ///     holiday_filter: HolidayFilter::Any,
+    ///     week_day_filter: WeekDayFilter::Any,
+    ///     generation: None
+    /// })?;
     /// assert_eq!(
     ///     date.to_chinese(Variant::Simplified),
     ///     "二零一三年五月"
@@ -90,11 +454,14 @@ impl<'a> GregorianGenerator<'a> {
     ///
     /// fastrand::seed(90);
     /// let date: Date = gregorian.date(DateParams {
-    ///     pattern: DatePattern::Year,
+    ///     pattern: PatternChoice::Fixed(DatePattern::Year),
     ///     year_range: Some(2000..=2019),
     ///     formal: true,
-    ///     week_format: None
-    /// });
+    ///     week_format: None,
+    ///     holiday_filter: HolidayFilter::Any,
+    ///     week_day_filter: WeekDayFilter::Any,
+    ///     generation: None
+    /// })?;
     /// assert_eq!(
     ///     date.to_chinese(Variant::Simplified),
     ///     "二零一三年"
@@ -102,11 +469,14 @@ impl<'a> GregorianGenerator<'a> {
     ///
     /// fastrand::seed(90);
     /// let date: Date = gregorian.date(DateParams {
-    ///     pattern: DatePattern::Year,
+    ///     pattern: PatternChoice::Fixed(DatePattern::Year),
     ///     year_range: Some(2007..=2007),
     ///     formal: true,
-    ///     week_format: None
-    /// });
+    ///     week_format: None,
+    ///     holiday_filter: HolidayFilter::Any,
+    ///     week_day_filter: WeekDayFilter::Any,
+    ///     generation: None
+    /// })?;
     /// assert_eq!(
     ///     date.to_chinese(Variant::Simplified),
     ///     "二零零七年"
@@ -114,11 +484,14 @@ impl<'a> GregorianGenerator<'a> {
     ///  
     /// fastrand::seed(90);
     /// let date: Date = gregorian.date(DateParams {
-    ///     pattern: DatePattern::Month,
+    ///     pattern: PatternChoice::Fixed(DatePattern::Month),
     ///     year_range: None,
     ///     formal: true,
-    ///     week_format: None
-    /// });
+    ///     week_format: None,
+    ///     holiday_filter: HolidayFilter::Any,
+    ///     week_day_filter: WeekDayFilter::Any,
+    ///     generation: None
+    /// })?;
     /// assert_eq!(
     ///     date.to_chinese(Variant::Simplified),
     ///     "九月"
@@ -126,11 +499,14 @@ impl<'a> GregorianGenerator<'a> {
     ///
     /// fastrand::seed(90);
     /// let date: Date = gregorian.date(DateParams {
-    ///     pattern: DatePattern::Day,
+    ///     pattern: PatternChoice::Fixed(DatePattern::Day),
     ///     year_range: None,
     ///     formal: true,
-    ///     week_format: None
-    /// });
+    ///     week_format: None,
+    ///     holiday_filter: HolidayFilter::Any,
+    ///     week_day_filter: WeekDayFilter::Any,
+    ///     generation: None
+    /// })?;
     /// assert_eq!(
     ///     date.to_chinese(Variant::Simplified),
     ///     "二十二号"
@@ -138,11 +514,14 @@ impl<'a> GregorianGenerator<'a> {
     ///
     /// fastrand::seed(90);
     /// let date: Date = gregorian.date(DateParams {
-    ///     pattern: DatePattern::Day,
+    ///     pattern: PatternChoice::Fixed(DatePattern::Day),
     ///     year_range: None,
     ///     formal: false,
-    ///     week_format: None
-    /// });
+    ///     week_format: None,
+    ///     holiday_filter: HolidayFilter::Any,
+    ///     week_day_filter: WeekDayFilter::Any,
+    ///     generation: None
+    /// })?;
     /// assert_eq!(
     ///     date.to_chinese(Variant::Simplified),
     ///     "二十二日"
@@ -150,52 +529,185 @@ impl<'a> GregorianGenerator<'a> {
     ///
     /// fastrand::seed(90);
     /// let date: Date = gregorian.date(DateParams {
-    ///     pattern: DatePattern::WeekDay,
+    ///     pattern: PatternChoice::Fixed(DatePattern::WeekDay),
     ///     year_range: None,
     ///     formal: true,
-    ///     week_format: Some(WeekFormat::Zhou)
-    /// });
+    ///     week_format: Some(WeekFormat::Zhou),
+    ///     holiday_filter: HolidayFilter::Any,
+    ///     week_day_filter: WeekDayFilter::Any,
+    ///     generation: None
+    /// })?;
     /// assert_eq!(
     ///     date.to_chinese(Variant::Simplified),
     ///     "周四"
     /// );
+    ///
+    /// fastrand::seed(90);
+    /// let holiday: Date = gregorian.date(
+    ///     DateParams::default()
+    ///         .with_year_range(2024..=2024)
+    ///         .with_holiday_filter(HolidayFilter::OnlyHolidays(HolidayTable::default()))
+    /// )?;
+    /// assert_eq!(
+    ///     holiday.to_chinese(Variant::Simplified),
+    ///     "二零二四年十月一日"
+    /// );
+    ///
+    /// fastrand::seed(90);
+    /// let weekend: Date = gregorian.date(
+    ///     DateParams::default()
+    ///         .with_pattern(DatePattern::YearMonthDayWeekDay)
+    ///         .with_year_range(2024..=2024)
+    ///         .with_week_day_filter(WeekDayFilter::WeekendsOnly)
+    /// )?;
+    /// assert_eq!(
+    ///     weekend.to_chinese(Variant::Simplified),
+    ///     "二零二四年一月六日星期六"
+    /// );
+    ///
+    /// fastrand::seed(90);
+    /// let mixed = (0..4)
+    ///     .map(|_| gregorian.date(
+    ///         DateParams::default()
+    ///             .with_pattern(PatternChoice::RandomFrom(vec![
+    ///                 DatePattern::YearMonthDay,
+    ///                 DatePattern::MonthDay
+    ///             ]))
+    ///             .with_year_range(2000..=2019)
+    ///     ))
+    ///     .map(|date| date.map(|date| date.to_chinese(Variant::Simplified).logograms))
+    ///     .collect::<ChineseRandResult<Vec<_>>>()?;
+    /// assert_eq!(mixed, ["五月二十三日", "六月七日", "二零零一年三月二十七日", "四月十五日"]);
+    ///
+    /// let no_patterns = gregorian.date(
+    ///     DateParams::default().with_pattern(PatternChoice::RandomFrom(vec![]))
+    /// );
+    /// assert_eq!(
+    ///     no_patterns,
+    ///     Err(ChineseRandError::InvalidParams("No date patterns to choose from".to_string()))
+    /// );
+    ///
+    /// let month_only = gregorian.date(
+    ///     DateParams::default()
+    ///         .with_pattern(DatePattern::Month)
+    ///         .with_holiday_filter(HolidayFilter::OnlyHolidays(HolidayTable::default()))
+    /// );
+    /// assert!(month_only.is_err());
+    ///
+    /// let inverted = gregorian.date(
+    ///     DateParams::default().with_year_range(2019..=2000)
+    /// );
+    /// assert!(inverted.is_err());
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn date(&self, params: DateParams) -> Date {
-        loop {
+    pub fn date(&self, params: DateParams) -> ChineseRandResult<Date> {
+        if let Some(year_range) = &params.year_range {
+            check_range(year_range)?;
+        }
+
+        let patterns = params.pattern.patterns();
+
+        if patterns.is_empty() {
+            return Err(ChineseRandError::InvalidParams(
+                "No date patterns to choose from".to_string(),
+            ));
+        }
+
+        let filters_week_days = params.week_day_filter != WeekDayFilter::Any;
+
+        for pattern in patterns {
+            if params.holiday_filter != HolidayFilter::Any
+                && !(pattern.has_month() && pattern.has_day())
+            {
+                return Err(ChineseRandError::Unsupported(
+                    "Holiday filter on a date pattern without month and day".to_string(),
+                ));
+            }
+
+            if filters_week_days
+                && !(pattern.has_year() && pattern.has_month() && pattern.has_day())
+            {
+                return Err(ChineseRandError::Unsupported(
+                    "Week day filter on a date pattern without year, month and day".to_string(),
+                ));
+            }
+        }
+
+        let pattern = match patterns {
+            [pattern] => *pattern,
+            _ => patterns[self.raw_generator.u64(0..=(patterns.len() as u64 - 1)) as usize],
+        };
+
+        let mut month = None;
+
+        let date = self.owner.retrying("Date", || {
             let mut builder = DateBuilder::new()
                 .with_formal(params.formal)
-                .with_week_format(params.week_format.unwrap_or_default());
+                .with_week_format(
+                    self.regional_week_format(params.week_format)
+                        .unwrap_or_default(),
+                );
 
-            let pattern = &params.pattern;
+            let year = pattern.has_year().then(|| {
+                let actual_year_range = match params.generation {
+                    Some(generation) => generation.years(),
+                    None => params
+                        .year_range
+                        .clone()
+                        .unwrap_or_else(|| self.owner.config.year_range.clone()),
+                };
 
-            if pattern.has_year() {
-                let actual_year_range = params.year_range.clone().unwrap_or(DEFAULT_YEAR_RANGE);
+                self.raw_generator.u16(actual_year_range)
+            });
 
-                builder = builder.with_year(self.raw_generator.u16(actual_year_range))
+            if let Some(year) = year {
+                builder = builder.with_year(year);
             }
 
-            if pattern.has_month() {
-                builder = builder.with_month(self.raw_generator.u8(1..=12));
+            month = pattern.has_month().then(|| self.raw_generator.u8(1..=12));
+
+            if let Some(month) = month {
+                builder = builder.with_month(month);
             }
 
-            if pattern.has_day() {
-                builder = builder.with_day(self.raw_generator.u8(1..=31))
+            let day = pattern.has_day().then(|| self.raw_generator.u8(1..=31));
+
+            if let Some(day) = day {
+                builder = builder.with_day(day);
             }
 
-            if pattern.has_week_day() {
-                builder = builder.with_week_day(
-                    self.raw_generator
-                        .u8(0..=6)
-                        .try_into()
-                        .expect("Weekday valid by construction"),
-                );
+            if filters_week_days {
+                let calendar_date = CalendarDate::try_new(year?, month?, day?)?;
+                let week_day = calendar_date.week_day();
+
+                if !params.week_day_filter.accepts(week_day) {
+                    return None;
+                }
+
+                if pattern.has_week_day() {
+                    builder = builder.with_week_day(week_day);
+                }
+            } else if pattern.has_week_day() {
+                builder = builder.with_week_day(self.week_day_value());
             }
 
-            let date_result = builder.build();
+            let date = builder.build().ok()?;
+
+            match (month, day) {
+                (Some(month), Some(day)) if !params.holiday_filter.accepts(year, month, day) => {
+                    None
+                }
 
-            if let Ok(date) = date_result {
-                break date;
+                _ => Some(date),
             }
-        }
+        })?;
+
+        self.owner.record("date", || match month {
+            Some(month) => format!("{:02}", month),
+            None => "none".to_string(),
+        });
+
+        Ok(date)
     }
 }
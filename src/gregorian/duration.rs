@@ -0,0 +1,279 @@
+use super::GregorianGenerator;
+use crate::{check_range, level::narrow, ChineseRandResult, RawGenerator};
+use chinese_format::{
+    chinese_vec, define_count_measure, Chinese, ChineseFormat, EmptyPlaceholder, Variant,
+};
+use std::ops::RangeInclusive;
+
+define_count_measure!(DaysMeasure, "天");
+define_count_measure!(HoursMeasure, ("小时", "小時"));
+define_count_measure!(MinutesMeasure, ("分钟", "分鐘"));
+define_count_measure!(SecondsMeasure, "秒");
+
+/// Time span - such as `一天三小时二十分钟` - answering *how long* something takes.
+///
+/// Components set to [None] are not part of the duration format,
+/// whereas zero-valued components are just omitted when rendering;
+/// if all the components are zero, the smallest unit in the format is rendered with `零`.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let duration = Duration {
+///     days: Some(1),
+///     hours: 2,
+///     minutes: Some(0),
+///     seconds: Some(45)
+/// };
+/// assert_eq!(duration.to_chinese(Variant::Simplified), "一天两小时四十五秒");
+/// assert_eq!(duration.to_chinese(Variant::Traditional), "一天兩小時四十五秒");
+///
+/// let zero = Duration {
+///     days: None,
+///     hours: 0,
+///     minutes: Some(0),
+///     seconds: None
+/// };
+/// assert_eq!(zero.to_chinese(Variant::Simplified), "零分钟");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration {
+    /// The days, if in the format.
+    pub days: Option<u32>,
+
+    /// The hours - always in the format.
+    pub hours: u32,
+
+    /// The minutes, if in the format.
+    pub minutes: Option<u8>,
+
+    /// The seconds, if in the format.
+    pub seconds: Option<u8>,
+}
+
+impl ChineseFormat for Duration {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let days = self.days.map(|days| DaysMeasure::new(days as u128));
+        let hours = HoursMeasure::new(self.hours as u128);
+        let minutes = self
+            .minutes
+            .map(|minutes| MinutesMeasure::new(minutes as u128));
+        let seconds = self
+            .seconds
+            .map(|seconds| SecondsMeasure::new(seconds as u128));
+
+        let chinese = chinese_vec!(
+            variant,
+            [
+                EmptyPlaceholder::new(&days),
+                EmptyPlaceholder::new(&hours),
+                EmptyPlaceholder::new(&minutes),
+                EmptyPlaceholder::new(&seconds)
+            ]
+        )
+        .collect();
+
+        if !chinese.omissible {
+            return chinese;
+        }
+
+        let smallest_unit: &dyn ChineseFormat = if seconds.is_some() {
+            &SecondsMeasure::new(0)
+        } else if minutes.is_some() {
+            &MinutesMeasure::new(0)
+        } else {
+            &hours
+        };
+
+        smallest_unit.to_chinese(variant)
+    }
+}
+
+/// A [Duration] can be converted to the standard [std::time::Duration].
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+///
+/// let duration = Duration {
+///     days: Some(1),
+///     hours: 2,
+///     minutes: Some(3),
+///     seconds: None
+/// };
+///
+/// let std_duration: std::time::Duration = duration.into();
+/// assert_eq!(std_duration.as_secs(), 93_780);
+/// ```
+impl From<Duration> for std::time::Duration {
+    fn from(duration: Duration) -> Self {
+        let total_seconds = duration.days.unwrap_or(0) as u64 * 86_400
+            + duration.hours as u64 * 3_600
+            + duration.minutes.unwrap_or(0) as u64 * 60
+            + duration.seconds.unwrap_or(0) as u64;
+
+        std::time::Duration::from_secs(total_seconds)
+    }
+}
+
+/// Parameters for the random creation of [Duration].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DurationParams {
+    /// The range of the days; if set to [None], days are not in the format.
+    pub days_range: Option<RangeInclusive<u32>>,
+
+    /// The range of the hours; when days are in the format,
+    /// it is narrowed to 0..=23 - which it must overlap.
+    pub hours_range: RangeInclusive<u32>,
+
+    /// Whether minutes are in the format.
+    pub include_minutes: bool,
+
+    /// Whether seconds are in the format.
+    pub include_seconds: bool,
+}
+
+/// By default, [DurationParams] describes a span of hours and minutes,
+/// shorter than a day.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+///
+/// assert_eq!(DurationParams::default(), DurationParams {
+///     days_range: None,
+///     hours_range: 0..=23,
+///     include_minutes: true,
+///     include_seconds: false
+/// });
+///
+/// let customized = DurationParams::default()
+///     .with_days_range(1..=3)
+///     .with_hours_range(0..=5)
+///     .with_minutes(false)
+///     .with_seconds(true);
+///
+/// assert_eq!(customized, DurationParams {
+///     days_range: Some(1..=3),
+///     hours_range: 0..=5,
+///     include_minutes: false,
+///     include_seconds: true
+/// });
+/// ```
+impl Default for DurationParams {
+    fn default() -> Self {
+        Self {
+            days_range: None,
+            hours_range: 0..=23,
+            include_minutes: true,
+            include_seconds: false,
+        }
+    }
+}
+
+impl DurationParams {
+    /// Sets the range of the days.
+    pub fn with_days_range(mut self, days_range: RangeInclusive<u32>) -> Self {
+        self.days_range = Some(days_range);
+        self
+    }
+
+    /// Sets the range of the hours.
+    pub fn with_hours_range(mut self, hours_range: RangeInclusive<u32>) -> Self {
+        self.hours_range = hours_range;
+        self
+    }
+
+    /// Sets whether minutes are in the format.
+    pub fn with_minutes(mut self, include_minutes: bool) -> Self {
+        self.include_minutes = include_minutes;
+        self
+    }
+
+    /// Sets whether seconds are in the format.
+    pub fn with_seconds(mut self, include_seconds: bool) -> Self {
+        self.include_seconds = include_seconds;
+        self
+    }
+}
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
+    /// Generates a random [Duration], given the provided [DurationParams].
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if either range is empty, and with [ChineseRandError::InvalidRange](crate::ChineseRandError::InvalidRange)
+    /// if days are in the format but the hours lie beyond 23.
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let duration = gregorian.duration(DurationParams {
+    ///     days_range: None,
+    ///     hours_range: 1..=5,
+    ///     include_minutes: true,
+    ///     include_seconds: true
+    /// })?;
+    /// assert_eq!(duration.to_chinese(Variant::Simplified), "四小时二十分钟四十三秒");
+    ///
+    /// fastrand::seed(90);
+    /// let duration = gregorian.duration(DurationParams {
+    ///     days_range: Some(1..=3),
+    ///     hours_range: 0..=48,
+    ///     include_minutes: false,
+    ///     include_seconds: false
+    /// })?;
+    /// assert_eq!(duration.to_chinese(Variant::Traditional), "三天八小時");
+    ///
+    /// assert_eq!(
+    ///     gregorian.duration(DurationParams::default().with_days_range(1..=3).with_hours_range(30..=48)),
+    ///     Err(ChineseRandError::InvalidRange("30..=48 outside 0..=23".to_string()))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn duration(&self, params: DurationParams) -> ChineseRandResult<Duration> {
+        if let Some(days_range) = &params.days_range {
+            check_range(days_range)?;
+        }
+        check_range(&params.hours_range)?;
+
+        let days = params
+            .days_range
+            .map(|days_range| self.raw_generator.u32(days_range));
+
+        let hours_range = if days.is_some() {
+            narrow(params.hours_range, 0..=23)?
+        } else {
+            params.hours_range
+        };
+
+        let hours = self.raw_generator.u32(hours_range);
+
+        let minutes = if params.include_minutes {
+            Some(self.raw_generator.u8(0..=59))
+        } else {
+            None
+        };
+
+        let seconds = if params.include_seconds {
+            Some(self.raw_generator.u8(0..=59))
+        } else {
+            None
+        };
+
+        Ok(Duration {
+            days,
+            hours,
+            minutes,
+            seconds,
+        })
+    }
+}
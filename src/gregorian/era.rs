@@ -0,0 +1,320 @@
+use super::GregorianGenerator;
+use crate::{check_range, ChineseRandResult, InvalidLowerBound, RawGenerator};
+use chinese_format::{
+    chinese_vec, gregorian::DateBuilder, Chinese, ChineseFormat, EmptyPlaceholder, Variant,
+};
+use std::ops::RangeInclusive;
+
+/// Century - such as `二十一世纪`.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// assert_eq!(Century(21).to_chinese(Variant::Simplified), "二十一世纪");
+/// assert_eq!(Century(2).to_chinese(Variant::Traditional), "二世紀");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Century(pub u8);
+
+impl ChineseFormat for Century {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.0, ("世纪", "世紀")]).collect()
+    }
+}
+
+/// Decade - such as `九十年代` - optionally preceded by its [Century],
+/// as in `二十世纪九十年代`.
+///
+/// The first two decades of a century are read digit by digit,
+/// according to modern usage - for example, `零零年代` and `一零年代`.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let decade = Decade { start_year: 1990, include_century: false };
+/// assert_eq!(decade.to_chinese(Variant::Simplified), "九十年代");
+///
+/// let decade = Decade { start_year: 1990, include_century: true };
+/// assert_eq!(decade.to_chinese(Variant::Traditional), "二十世紀九十年代");
+/// assert_eq!(decade.century(), Some(Century(20)));
+///
+/// let decade = Decade { start_year: 2010, include_century: true };
+/// assert_eq!(decade.to_chinese(Variant::Simplified), "二十一世纪一零年代");
+///
+/// let decade = Decade { start_year: 65530, include_century: true };
+/// assert_eq!(decade.to_chinese(Variant::Simplified), "六百五十六世纪三十年代");
+/// assert_eq!(decade.century(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decade {
+    /// The first year of the decade - a multiple of 10.
+    pub start_year: u16,
+
+    /// Whether the century is rendered as well.
+    pub include_century: bool,
+}
+
+impl Decade {
+    /// The century to which the decade conventionally belongs - [None]
+    /// for the decades from 25600, beyond the range of [Century].
+    pub fn century(&self) -> Option<Century> {
+        u8::try_from(self.century_number()).ok().map(Century)
+    }
+
+    fn century_number(&self) -> u16 {
+        self.start_year / 100 + 1
+    }
+}
+
+impl ChineseFormat for Decade {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let century = self.include_century.then(|| {
+            chinese_vec!(variant, [self.century_number(), ("世纪", "世紀")])
+                .collect()
+                .logograms
+        });
+
+        let tens = (self.start_year % 100 / 10) as u8;
+
+        let tens_logograms = match tens {
+            0 => "零零".to_string(),
+            1 => "一零".to_string(),
+            _ => (tens * 10).to_chinese(variant).logograms,
+        };
+
+        chinese_vec!(
+            variant,
+            [EmptyPlaceholder::new(&century), tens_logograms, "年代"]
+        )
+        .collect()
+    }
+}
+
+/// Generation of people born in a decade - such as `八零后`,
+/// for those born from 1980 to 1989.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// assert_eq!(Generation(1980).to_chinese(Variant::Simplified), "八零后");
+/// assert_eq!(Generation(2000).to_chinese(Variant::Traditional), "零零後");
+/// assert_eq!(Generation(1990).years(), 1990..=1999);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Generation(pub u16);
+
+impl Generation {
+    /// The birth years of the generation - the decade including the inner year.
+    pub fn years(&self) -> RangeInclusive<u16> {
+        let start = self.0 - self.0 % 10;
+
+        start..=start.saturating_add(9)
+    }
+}
+
+impl ChineseFormat for Generation {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                ShortYear(*self.years().start()).digits(variant),
+                ("后", "後")
+            ]
+        )
+        .collect()
+    }
+}
+
+/// Year abbreviated to its last two digits, read one by one -
+/// such as `九八年` for 1998 or `零五年` for 2005.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// assert_eq!(ShortYear(1998).to_chinese(Variant::Simplified), "九八年");
+/// assert_eq!(ShortYear(2005).to_chinese(Variant::Simplified), "零五年");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShortYear(pub u16);
+
+impl ShortYear {
+    fn digits(&self, variant: Variant) -> String {
+        let last_two = self.0 % 100;
+
+        [last_two / 10, last_two % 10]
+            .into_iter()
+            .map(|digit| (digit as u8).to_chinese(variant).logograms)
+            .collect()
+    }
+}
+
+impl ChineseFormat for ShortYear {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.digits(variant), "年"]).collect()
+    }
+}
+
+/// Birth year belonging to a [Generation] - rendered in full, like `一九八五年`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BirthYear {
+    /// The year.
+    pub year: u16,
+
+    /// The generation including the year.
+    pub generation: Generation,
+}
+
+impl BirthYear {
+    /// The abbreviated form of the year - like `八五年`.
+    pub fn short(&self) -> ShortYear {
+        ShortYear(self.year)
+    }
+}
+
+impl ChineseFormat for BirthYear {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        DateBuilder::new()
+            .with_year(self.year)
+            .build()
+            .expect("Year valid by construction")
+            .to_chinese(variant)
+    }
+}
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
+    /// Generates a random [ShortYear] in the given range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let year = gregorian.short_year(1950..=2020)?;
+    /// assert_eq!(year.to_chinese(Variant::Simplified), "九九年");
+    ///
+    /// assert_eq!(
+    ///     gregorian.short_year(2020..=1950),
+    ///     Err(ChineseRandError::EmptyRange("2020..=1950".to_string()))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn short_year(&self, year_range: RangeInclusive<u16>) -> ChineseRandResult<ShortYear> {
+        check_range(&year_range)?;
+
+        Ok(ShortYear(self.raw_generator.u16(year_range)))
+    }
+
+    /// Generates a random [BirthYear] of the given [Generation] -
+    /// for demographic-themed content.
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let birth_year = gregorian.birth_year(Generation(1980));
+    /// assert_eq!(birth_year.to_chinese(Variant::Simplified), "一九八六年");
+    /// assert_eq!(birth_year.short().to_chinese(Variant::Simplified), "八六年");
+    /// assert_eq!(birth_year.generation.to_chinese(Variant::Simplified), "八零后");
+    /// ```
+    pub fn birth_year(&self, generation: Generation) -> BirthYear {
+        BirthYear {
+            year: self.raw_generator.u16(generation.years()),
+            generation,
+        }
+    }
+
+    /// Generates a random [Decade] including a year in the given range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let decade = gregorian.decade(1900..=1999, false)?;
+    /// assert_eq!(decade.to_chinese(Variant::Simplified), "六十年代");
+    ///
+    /// fastrand::seed(90);
+    /// let decade = gregorian.decade(1800..=2099, true)?;
+    /// assert_eq!(decade.to_chinese(Variant::Simplified), "二十一世纪零零年代");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decade(
+        &self,
+        year_range: RangeInclusive<u16>,
+        include_century: bool,
+    ) -> ChineseRandResult<Decade> {
+        check_range(&year_range)?;
+
+        let year = self.raw_generator.u16(year_range);
+
+        Ok(Decade {
+            start_year: year - year % 10,
+            include_century,
+        })
+    }
+
+    /// Generates a random [Century] in the given range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty, and with [ChineseRandError::InvalidRange](crate::ChineseRandError::InvalidRange)
+    /// if it starts from 0 - as there is no 零世纪.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let century = gregorian.century(15..=21)?;
+    /// assert_eq!(century.to_chinese(Variant::Simplified), "十九世纪");
+    ///
+    /// assert_eq!(
+    ///     gregorian.century(0..=21),
+    ///     Err(ChineseRandError::InvalidRange("Invalid lower bound: 0".to_string()))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn century(&self, range: RangeInclusive<u8>) -> ChineseRandResult<Century> {
+        check_range(&range)?;
+
+        if *range.start() == 0 {
+            return Err(InvalidLowerBound(0).into());
+        }
+
+        Ok(Century(self.raw_generator.u8(range)))
+    }
+}
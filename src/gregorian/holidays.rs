@@ -0,0 +1,362 @@
+use super::{CalendarDate, GregorianGenerator};
+use crate::{check_range, BilingualLabel, ChineseRandError, ChineseRandResult, RawGenerator};
+use chinese_format::gregorian::Date;
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Variant};
+use std::ops::RangeInclusive;
+
+/// First days of 春节 - the Spring Festival - in the default [HolidayTable]:
+/// the other years have no 春节.
+const SPRING_FESTIVAL_STARTS: [(u16, u8, u8); 11] = [
+    (2020, 1, 25),
+    (2021, 2, 12),
+    (2022, 2, 1),
+    (2023, 1, 22),
+    (2024, 2, 10),
+    (2025, 1, 29),
+    (2026, 2, 17),
+    (2027, 2, 6),
+    (2028, 1, 26),
+    (2029, 2, 13),
+    (2030, 2, 3),
+];
+
+/// The name of a [Holiday], in both variants - as a [BilingualLabel].
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let name = HolidayName::new("劳动节", "勞動節");
+///
+/// assert_eq!(name.to_chinese(Variant::Simplified), "劳动节");
+/// assert_eq!(name.to_chinese(Variant::Traditional), "勞動節");
+/// ```
+pub type HolidayName = BilingualLabel;
+
+/// Day of a [Holiday], followed by the holiday name -
+/// like `二零二四年十月一日国庆节`.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let holiday_date = HolidayDate {
+///     date: CalendarDate::try_new(2024, 5, 3).unwrap().to_date(false, None),
+///     name: HolidayName::new("劳动节", "勞動節"),
+/// };
+///
+/// assert_eq!(holiday_date.to_chinese(Variant::Simplified), "二零二四年五月三日劳动节");
+/// assert_eq!(holiday_date.to_chinese(Variant::Traditional), "二零二四年五月三日勞動節");
+/// ```
+///
+/// **Required feature**: `gregorian`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HolidayDate {
+    /// The day of the holiday.
+    pub date: Date,
+
+    /// The name of the holiday.
+    pub name: HolidayName,
+}
+
+impl ChineseFormat for HolidayDate {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.date, self.name]).collect()
+    }
+}
+
+/// When a [Holiday] begins.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HolidayStart {
+    /// Same month and day every year - like 国庆节.
+    Yearly { month: u8, day: u8 },
+
+    /// Explicit first days, for holidays following the lunar calendar - like 春节.
+    Dated(Vec<CalendarDate>),
+}
+
+/// A holiday period.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Holiday {
+    /// The name of the holiday.
+    pub name: HolidayName,
+
+    /// When the holiday begins.
+    pub start: HolidayStart,
+
+    /// How many consecutive days the holiday lasts - at least 1.
+    pub length: u8,
+}
+
+impl Holiday {
+    fn starts_in(&self, year: u16) -> Vec<CalendarDate> {
+        match &self.start {
+            HolidayStart::Yearly { month, day } => CalendarDate::try_new(year, *month, *day)
+                .into_iter()
+                .collect(),
+
+            HolidayStart::Dated(starts) => starts
+                .iter()
+                .filter(|start| start.year() == year)
+                .copied()
+                .collect(),
+        }
+    }
+
+    fn includes(&self, date: &CalendarDate) -> bool {
+        [date.year().checked_sub(1), Some(date.year())]
+            .into_iter()
+            .flatten()
+            .flat_map(|year| self.starts_in(year))
+            .any(|start| (0..self.length as i64).contains(&start.days_until(date)))
+    }
+}
+
+/// Configurable list of [Holiday].
+///
+/// By default, it contains the main Chinese public holidays: 元旦,
+/// 春节, 劳动节 and 国庆节 - the dates of 春节 being only
+/// available from 2020 to 2030, so that it is never matched
+/// nor generated in the other years.
+///
+/// ```
+/// use chinese_rand::{*, gregorian::*};
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let table = HolidayTable::default();
+///
+/// let national_day = CalendarDate::try_new(2024, 10, 3).unwrap();
+/// assert_eq!(
+///     table.holiday_on(&national_day).unwrap().name.to_chinese(Variant::Simplified),
+///     "国庆节"
+/// );
+///
+/// let spring_festival = CalendarDate::try_new(2024, 2, 12).unwrap();
+/// assert_eq!(
+///     table.holiday_on(&spring_festival).unwrap().name.to_chinese(Variant::Traditional),
+///     "春節"
+/// );
+///
+/// let ordinary_day = CalendarDate::try_new(2024, 3, 12).unwrap();
+/// assert_eq!(table.holiday_on(&ordinary_day), None);
+///
+/// let uncovered_spring_festival = CalendarDate::try_new(2031, 1, 23).unwrap();
+/// assert_eq!(table.holiday_on(&uncovered_spring_festival), None);
+///
+/// let teachers_day = Holiday {
+///     name: HolidayName::new("教师节", "教師節"),
+///     start: HolidayStart::Yearly { month: 9, day: 10 },
+///     length: 1,
+/// };
+/// let custom_table = HolidayTable::new().with_holiday(teachers_day.clone()).unwrap();
+/// assert!(custom_table.holiday_on(&CalendarDate::try_new(2024, 9, 10).unwrap()).is_some());
+///
+/// assert_eq!(
+///     HolidayTable::new().with_holiday(Holiday { length: 0, ..teachers_day }),
+///     Err(ChineseRandError::InvalidParams("Zero-length holiday: 教师节".to_string()))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HolidayTable {
+    /// The holidays in the table.
+    pub holidays: Vec<Holiday>,
+}
+
+impl Default for HolidayTable {
+    fn default() -> Self {
+        Self {
+            holidays: vec![
+                Holiday {
+                    name: HolidayName::new("元旦", "元旦"),
+                    start: HolidayStart::Yearly { month: 1, day: 1 },
+                    length: 1,
+                },
+                Holiday {
+                    name: HolidayName::new("春节", "春節"),
+                    start: HolidayStart::Dated(
+                        SPRING_FESTIVAL_STARTS
+                            .iter()
+                            .map(|&(year, month, day)| {
+                                CalendarDate::try_new(year, month, day)
+                                    .expect("Date valid by construction")
+                            })
+                            .collect(),
+                    ),
+                    length: 7,
+                },
+                Holiday {
+                    name: HolidayName::new("劳动节", "勞動節"),
+                    start: HolidayStart::Yearly { month: 5, day: 1 },
+                    length: 5,
+                },
+                Holiday {
+                    name: HolidayName::new("国庆节", "國慶節"),
+                    start: HolidayStart::Yearly { month: 10, day: 1 },
+                    length: 7,
+                },
+            ],
+        }
+    }
+}
+
+impl HolidayTable {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self {
+            holidays: Vec::new(),
+        }
+    }
+
+    /// Adds a holiday to the table.
+    ///
+    /// Fails with [ChineseRandError::InvalidParams] if the holiday lasts 0 days.
+    pub fn with_holiday(mut self, holiday: Holiday) -> ChineseRandResult<Self> {
+        if holiday.length == 0 {
+            return Err(ChineseRandError::InvalidParams(format!(
+                "Zero-length holiday: {}",
+                holiday.name.simplified
+            )));
+        }
+
+        self.holidays.push(holiday);
+        Ok(self)
+    }
+
+    /// The first holiday including the given date, if any.
+    pub fn holiday_on(&self, date: &CalendarDate) -> Option<&Holiday> {
+        self.holidays.iter().find(|holiday| holiday.includes(date))
+    }
+
+    /// Like [holiday_on](Self::holiday_on) - but, when the year is missing,
+    /// only considering the [Yearly](HolidayStart::Yearly) holidays.
+    pub(crate) fn holiday_on_parts(
+        &self,
+        year: Option<u16>,
+        month: u8,
+        day: u8,
+    ) -> Option<&Holiday> {
+        match year {
+            Some(year) => {
+                CalendarDate::try_new(year, month, day).and_then(|date| self.holiday_on(&date))
+            }
+
+            None => {
+                // A leap year, so that February 29 is a valid date
+                let date = CalendarDate::try_new(2000, month, day)?;
+
+                self.holidays
+                    .iter()
+                    .filter(|holiday| matches!(holiday.start, HolidayStart::Yearly { .. }))
+                    .find(|holiday| holiday.includes(&date))
+            }
+        }
+    }
+}
+
+/// How [DateParams](super::DateParams) deals with holidays.
+///
+/// Filtering requires a pattern with both month and day.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HolidayFilter {
+    /// Holidays are not taken into account.
+    #[default]
+    Any,
+
+    /// Only dates within a holiday of the table.
+    OnlyHolidays(HolidayTable),
+
+    /// Only dates outside all the holidays of the table.
+    ExcludeHolidays(HolidayTable),
+}
+
+impl HolidayFilter {
+    pub(crate) fn accepts(&self, year: Option<u16>, month: u8, day: u8) -> bool {
+        match self {
+            HolidayFilter::Any => true,
+            HolidayFilter::OnlyHolidays(table) => {
+                table.holiday_on_parts(year, month, day).is_some()
+            }
+            HolidayFilter::ExcludeHolidays(table) => {
+                table.holiday_on_parts(year, month, day).is_none()
+            }
+        }
+    }
+}
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
+    /// Generates a random day - in the given year range - belonging
+    /// to one of the holidays in the table, together with the holiday name.
+    ///
+    /// Holidays lasting 0 days - which can only be added by
+    /// directly editing [HolidayTable::holidays] - are ignored.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the year range is empty, and with
+    /// [ChineseRandError::GenerationExhausted](crate::ChineseRandError::GenerationExhausted)
+    /// if no holiday of the table falls within the year range.
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let gregorian = generator.gregorian();
+    ///
+    /// let table = HolidayTable::new().with_holiday(Holiday {
+    ///     name: HolidayName::new("国庆节", "國慶節"),
+    ///     start: HolidayStart::Yearly { month: 10, day: 1 },
+    ///     length: 1,
+    /// })?;
+    ///
+    /// let holiday_date = gregorian.holiday(2024..=2024, &table)?;
+    /// assert_eq!(holiday_date.to_chinese(Variant::Simplified), "二零二四年十月一日国庆节");
+    /// assert_eq!(holiday_date.date.to_chinese(Variant::Simplified), "二零二四年十月一日");
+    /// assert_eq!(holiday_date.name.to_chinese(Variant::Simplified), "国庆节");
+    ///
+    /// assert!(gregorian.holiday(2024..=2024, &HolidayTable::new()).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn holiday(
+        &self,
+        year_range: RangeInclusive<u16>,
+        table: &HolidayTable,
+    ) -> ChineseRandResult<HolidayDate> {
+        check_range(&year_range)?;
+
+        self.owner.retrying("Holiday", || {
+            let year = self.raw_generator.u16(year_range.clone());
+
+            let periods: Vec<(&Holiday, CalendarDate)> = table
+                .holidays
+                .iter()
+                .filter(|holiday| holiday.length > 0)
+                .flat_map(|holiday| {
+                    holiday
+                        .starts_in(year)
+                        .into_iter()
+                        .map(move |start| (holiday, start))
+                })
+                .collect();
+
+            if periods.is_empty() {
+                return None;
+            }
+
+            let (holiday, start) =
+                periods[self.raw_generator.u64(0..=(periods.len() as u64 - 1)) as usize];
+
+            let offset = self.raw_generator.u8(0..=(holiday.length - 1));
+
+            Some(HolidayDate {
+                date: start.add_days(offset as i64)?.to_date(false, None),
+                name: holiday.name.clone(),
+            })
+        })
+    }
+}
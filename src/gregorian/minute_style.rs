@@ -0,0 +1,174 @@
+use super::{GregorianGenerator, LinearTimeParams};
+use crate::{ChineseRandResult, RawGenerator};
+use chinese_format::gregorian::{LinearTime, Minute, Second};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, EmptyPlaceholder, Variant};
+
+/// How the minutes of a [StyledLinearTime] are rendered.
+///
+/// **Required feature**: `gregorian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct MinuteStyle {
+    /// If set to `true`, times on the hour end with `整` - like `十点整`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub zheng_on_the_hour: bool,
+
+    /// If set to `true`, the minutes in the 1..=9 range are introduced by `零` -
+    /// like `十点零五分`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub zero_before_single_digit: bool,
+}
+
+impl MinuteStyle {
+    /// Sets whether times on the hour end with `整`.
+    pub fn with_zheng_on_the_hour(mut self, zheng_on_the_hour: bool) -> Self {
+        self.zheng_on_the_hour = zheng_on_the_hour;
+        self
+    }
+
+    /// Sets whether the minutes in the 1..=9 range are introduced by `零`.
+    pub fn with_zero_before_single_digit(mut self, zero_before_single_digit: bool) -> Self {
+        self.zero_before_single_digit = zero_before_single_digit;
+        self
+    }
+}
+
+/// [LinearTime] rendered according to a [MinuteStyle].
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::{ChineseFormat, Variant, gregorian::*};
+///
+/// # fn main() -> chinese_rand::GenericResult<()> {
+/// let style = MinuteStyle::default()
+///     .with_zheng_on_the_hour(true)
+///     .with_zero_before_single_digit(true);
+///
+/// let on_the_hour = StyledLinearTime {
+///     time: LinearTime {
+///         day_part: true,
+///         hour: 22.try_into()?,
+///         minute: 0.try_into()?,
+///         second: None
+///     },
+///     style
+/// };
+/// assert_eq!(on_the_hour.to_chinese(Variant::Simplified), "晚上十点整");
+///
+/// let single_digit = StyledLinearTime {
+///     time: LinearTime {
+///         day_part: false,
+///         hour: 10.try_into()?,
+///         minute: 5.try_into()?,
+///         second: Some(30.try_into()?)
+///     },
+///     style
+/// };
+/// assert_eq!(single_digit.to_chinese(Variant::Simplified), "十点零五分三十秒");
+///
+/// let plain = StyledLinearTime {
+///     time: single_digit.time,
+///     style: MinuteStyle::default()
+/// };
+/// assert_eq!(plain.to_chinese(Variant::Simplified), "十点五分三十秒");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **Required feature**: `gregorian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StyledLinearTime {
+    /// The time.
+    pub time: LinearTime,
+
+    /// How its minutes are rendered.
+    pub style: MinuteStyle,
+}
+
+impl ChineseFormat for StyledLinearTime {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let minute_value = u8::from(self.time.minute);
+
+        let hour_time = LinearTime {
+            minute: 0.try_into().expect("Minute valid by construction"),
+            second: None,
+            ..self.time
+        };
+
+        let second: Option<Second> = self.time.second.filter(|second| u8::from(*second) > 0);
+
+        let minute: Option<Minute> = (minute_value > 0).then_some(self.time.minute);
+
+        let zheng: Option<&str> =
+            (self.style.zheng_on_the_hour && minute.is_none() && second.is_none()).then_some("整");
+
+        let zero: Option<&str> = (self.style.zero_before_single_digit
+            && (1..=9).contains(&minute_value))
+        .then_some("零");
+
+        chinese_vec!(
+            variant,
+            [
+                hour_time,
+                EmptyPlaceholder::new(&zheng),
+                EmptyPlaceholder::new(&zero),
+                EmptyPlaceholder::new(&minute),
+                EmptyPlaceholder::new(&second)
+            ]
+        )
+        .collect()
+    }
+}
+
+impl<R: RawGenerator> GregorianGenerator<'_, R> {
+    /// Generates a random [LinearTime] - just like [linear_time](Self::linear_time) -
+    /// rendered according to the given [MinuteStyle].
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let gregorian = generator.gregorian();
+    ///
+    /// let style = MinuteStyle::default().with_zheng_on_the_hour(true);
+    ///
+    /// let time = gregorian.styled_linear_time(
+    ///     LinearTimeParams::default().with_on_the_hour(OnTheHour::Only),
+    ///     style
+    /// )?;
+    /// assert_eq!(time.to_chinese(Variant::Simplified), "十六点整");
+    ///
+    /// let times: Vec<String> = (0..4)
+    ///     .map(|_| {
+    ///         gregorian.styled_linear_time(
+    ///             LinearTimeParams::default()
+    ///                 .with_day_part(true)
+    ///                 .with_on_the_hour(OnTheHour::Excluded),
+    ///             style.with_zero_before_single_digit(true)
+    ///         )
+    ///     })
+    ///     .map(|time| time.map(|time| time.to_chinese(Variant::Simplified).to_string()))
+    ///     .collect::<ChineseRandResult<_>>()?;
+    /// assert_eq!(
+    ///     times,
+    ///     ["上午八点四十四分", "早上六点二十九分", "早上五点三十九分", "午夜一点十一分"]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `gregorian`.
+    pub fn styled_linear_time(
+        &self,
+        params: LinearTimeParams,
+        style: MinuteStyle,
+    ) -> ChineseRandResult<StyledLinearTime> {
+        Ok(StyledLinearTime {
+            time: self.linear_time(params)?,
+            style,
+        })
+    }
+}
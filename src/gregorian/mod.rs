@@ -2,13 +2,45 @@
 //! according to the Gregorian calendar.
 //!
 //! **Required feature**: `gregorian`.
+mod appointment;
+mod calendar;
+#[cfg(feature = "chrono")]
+mod chrono_interop;
+mod clock;
+mod colloquial;
+mod cycles;
 mod date;
+mod duration;
+mod era;
+mod holidays;
+mod minute_style;
+mod pair;
+mod pattern_choice;
+mod stopwatch;
 mod time;
+mod week_day_filter;
+mod zodiac_year;
+mod zoned_time;
 
+pub use appointment::*;
+pub use calendar::*;
+pub use clock::*;
+pub use colloquial::*;
 pub use date::*;
+pub use duration::*;
+pub use era::*;
+pub use holidays::*;
+pub use minute_style::*;
+pub use pair::*;
+pub use pattern_choice::*;
+pub use stopwatch::*;
 pub use time::*;
+pub use week_day_filter::*;
+pub use zodiac_year::*;
+pub use zoned_time::*;
 
-use crate::{ChineseFormatGenerator, RawGenerator};
+use crate::{ChineseFormatGenerator, Level, RawGenerator};
+use chinese_format::gregorian::WeekFormat;
 
 /// Random generator dedicated to the date/time concepts
 /// in the Gregorian calendar.
@@ -17,11 +49,13 @@ use crate::{ChineseFormatGenerator, RawGenerator};
 /// [ChineseFormatGenerator::gregorian] method;
 /// furthermore, it actually just keeps a reference to
 /// the [RawGenerator] owned by [ChineseFormatGenerator].
-pub struct GregorianGenerator<'a> {
-    raw_generator: &'a dyn RawGenerator,
+pub struct GregorianGenerator<'a, R: RawGenerator = Box<dyn RawGenerator>> {
+    raw_generator: &'a R,
+    level: Option<Level>,
+    owner: &'a ChineseFormatGenerator<R>,
 }
 
-impl ChineseFormatGenerator {
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
     /// Creates a reusable [GregorianGenerator] instance, for generating
     /// date/time values according to the Gregorian calendar.
     ///
@@ -29,17 +63,21 @@ impl ChineseFormatGenerator {
     /// use chinese_rand::{*, gregorian::*};
     /// use chinese_format::{ChineseFormat, Variant, gregorian::*};
     ///
+    /// # fn main() -> GenericResult<()> {
     /// let raw_generator = FastRandGenerator::new();
     /// let generator = ChineseFormatGenerator::new(raw_generator);
     /// let gregorian = generator.gregorian();
     ///
     /// fastrand::seed(90);
     /// let date = gregorian.date(DateParams {
-    ///     pattern: DatePattern::YearMonthDayWeekDay,
+    ///     pattern: PatternChoice::Fixed(DatePattern::YearMonthDayWeekDay),
     ///     year_range: Some(2000..=2019),
     ///     formal: true,
-    ///     week_format: Some(WeekFormat::Zhou)
-    /// });
+    ///     week_format: Some(WeekFormat::Zhou),
+    ///     holiday_filter: HolidayFilter::Any,
+    ///     week_day_filter: WeekDayFilter::Any,
+    ///     generation: None
+    /// })?;
     /// assert_eq!(
     ///     date.to_chinese(Variant::Simplified),
     ///     "二零一三年五月二十三号周一"
@@ -48,15 +86,34 @@ impl ChineseFormatGenerator {
     /// fastrand::seed(90);
     /// let time = gregorian.linear_time(LinearTimeParams {
     ///     day_part: true,
-    ///     include_second: true
-    /// });
+    ///     include_second: true,
+    ///     pinned_day_part: None,
+    ///     on_the_hour: OnTheHour::Allowed,
+    ///     pinned_hour: None,
+    ///     pinned_minute: None,
+    ///     second_step: 1
+    /// })?;
     /// assert_eq!(time.to_chinese(Variant::Simplified), "下午四点二十分四十三秒");
+    /// # Ok(())
+    /// # }
     /// ```
     ///
     /// **Required feature**: `gregorian`.
-    pub fn gregorian(&self) -> GregorianGenerator {
+    pub fn gregorian(&self) -> GregorianGenerator<'_, R> {
         GregorianGenerator {
-            raw_generator: self.raw_generator.as_ref(),
+            raw_generator: &self.raw_generator,
+            level: self.level,
+            owner: self,
         }
     }
 }
+
+impl<R: RawGenerator> GregorianGenerator<'_, R> {
+    /// The given week format or, if unset, the one in the configuration -
+    /// falling back to the one preferred by the region.
+    fn regional_week_format(&self, week_format: Option<WeekFormat>) -> Option<WeekFormat> {
+        week_format
+            .or(self.owner.config.week_format)
+            .or_else(|| self.owner.region.map(|region| region.week_format()))
+    }
+}
@@ -0,0 +1,230 @@
+use super::{calendar::beyond_calendar, CalendarDate, GregorianGenerator};
+use crate::{check_range, ChineseRandResult, RawGenerator};
+use chinese_format::{
+    chinese_vec,
+    gregorian::{Date, WeekFormat},
+    Chinese, ChineseFormat, Count, Variant,
+};
+use std::ops::RangeInclusive;
+
+/// Unit of the distance between the dates of a [DatePair].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum OffsetUnit {
+    Days,
+    Weeks,
+    Months,
+}
+
+/// Signed distance between two dates - rendered, for example,
+/// as `三天以后` or `两个星期以前`.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let offset = DateOffset { amount: 3, unit: OffsetUnit::Days };
+/// assert_eq!(offset.to_chinese(Variant::Simplified), "三天以后");
+///
+/// let offset = DateOffset { amount: -2, unit: OffsetUnit::Weeks };
+/// assert_eq!(offset.to_chinese(Variant::Simplified), "两个星期以前");
+///
+/// let offset = DateOffset { amount: 1, unit: OffsetUnit::Months };
+/// assert_eq!(offset.to_chinese(Variant::Traditional), "一個月以後");
+///
+/// let offset = DateOffset { amount: 0, unit: OffsetUnit::Days };
+/// assert_eq!(offset.to_chinese(Variant::Simplified), "当天");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateOffset {
+    /// How many units - negative when going back in time.
+    pub amount: i64,
+
+    /// The unit of the offset.
+    pub unit: OffsetUnit,
+}
+
+impl ChineseFormat for DateOffset {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        if self.amount == 0 {
+            return ("当天", "當天").to_chinese(variant);
+        }
+
+        let unit: &dyn ChineseFormat = match self.unit {
+            OffsetUnit::Days => &"天",
+            OffsetUnit::Weeks => &("个星期", "個星期"),
+            OffsetUnit::Months => &("个月", "個月"),
+        };
+
+        let direction: &dyn ChineseFormat = if self.amount > 0 {
+            &("以后", "以後")
+        } else {
+            &"以前"
+        };
+
+        chinese_vec!(
+            variant,
+            [Count(self.amount.unsigned_abs() as u128), unit, direction]
+        )
+        .collect()
+    }
+}
+
+/// Parameters for the random creation of [DatePair].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DatePairParams {
+    /// The year range of the first date.
+    /// If set to [None], then the year range of the [GeneratorConfig](crate::GeneratorConfig)
+    /// is used - by default, [DEFAULT_YEAR_RANGE](crate::gregorian::DEFAULT_YEAR_RANGE).
+    pub year_range: Option<RangeInclusive<u16>>,
+
+    /// The unit of the distance between the dates.
+    pub unit: OffsetUnit,
+
+    /// The range of the distance, in units - negative values
+    /// making the second date precede the first one.
+    pub offset_range: RangeInclusive<i64>,
+
+    /// Applies to the date format - for example,
+    /// 号 instead of 日 after the day ordinal.
+    pub formal: bool,
+
+    /// If set, the rendered dates include their calendar-consistent
+    /// week day, in the given format.
+    #[cfg_attr(feature = "serde", serde(with = "crate::remote::option_week_format"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::option_week_format))]
+    pub week_format: Option<WeekFormat>,
+}
+
+/// By default, [DatePairParams] describes informal dates
+/// from 1 to 7 days apart, without week day.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+///
+/// assert_eq!(DatePairParams::default(), DatePairParams {
+///     year_range: None,
+///     unit: OffsetUnit::Days,
+///     offset_range: 1..=7,
+///     formal: false,
+///     week_format: None
+/// });
+/// ```
+impl Default for DatePairParams {
+    fn default() -> Self {
+        Self {
+            year_range: None,
+            unit: OffsetUnit::Days,
+            offset_range: 1..=7,
+            formal: false,
+            week_format: None,
+        }
+    }
+}
+
+/// Two dates at a known distance, for relative-time exercises.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DatePair {
+    /// The reference date, as calendar value.
+    pub first: CalendarDate,
+
+    /// The date obtained by applying the offset to the first one.
+    pub second: CalendarDate,
+
+    /// The distance from the first to the second date.
+    pub offset: DateOffset,
+
+    /// The first date, ready for rendering.
+    pub first_date: Date,
+
+    /// The second date, ready for rendering.
+    pub second_date: Date,
+}
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
+    /// Generates two dates a random number of days, weeks or months apart,
+    /// together with the offset - always consistent with the calendar.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if either range is empty, and with [ChineseRandError::InvalidRange](crate::ChineseRandError::InvalidRange)
+    /// if the offset moves the second date beyond the [u16] range of years.
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant, gregorian::WeekFormat};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let pair = gregorian.date_pair(DatePairParams {
+    ///     year_range: Some(2024..=2024),
+    ///     unit: OffsetUnit::Days,
+    ///     offset_range: -10..=10,
+    ///     formal: true,
+    ///     week_format: Some(WeekFormat::Zhou)
+    /// })?;
+    /// assert_eq!(pair.first_date.to_chinese(Variant::Simplified), "二零二四年八月三号周六");
+    /// assert_eq!(pair.offset.to_chinese(Variant::Simplified), "十天以前");
+    /// assert_eq!(pair.second_date.to_chinese(Variant::Simplified), "二零二四年七月二十四号周三");
+    /// assert_eq!(pair.first.days_until(&pair.second), pair.offset.amount);
+    ///
+    /// let origin = gregorian.date_pair(DatePairParams {
+    ///     year_range: Some(0..=0),
+    ///     unit: OffsetUnit::Months,
+    ///     offset_range: -24..=-13,
+    ///     ..DatePairParams::default()
+    /// });
+    /// assert!(matches!(origin, Err(ChineseRandError::InvalidRange(_))));
+    ///
+    /// fastrand::seed(90);
+    /// let pair = gregorian.date_pair(DatePairParams {
+    ///     unit: OffsetUnit::Months,
+    ///     offset_range: 1..=3,
+    ///     ..DatePairParams::default()
+    /// })?;
+    /// assert_eq!(pair.first_date.to_chinese(Variant::Simplified), "二零零零年十二月十七日");
+    /// assert_eq!(pair.offset.to_chinese(Variant::Simplified), "一个月以后");
+    /// assert_eq!(pair.second_date.to_chinese(Variant::Simplified), "二零零一年一月十七日");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn date_pair(&self, params: DatePairParams) -> ChineseRandResult<DatePair> {
+        check_range(&params.offset_range)?;
+
+        let first = self.calendar_date(
+            params
+                .year_range
+                .unwrap_or_else(|| self.owner.config.year_range.clone()),
+        )?;
+
+        let amount = self.raw_generator.i64(params.offset_range);
+
+        let (second, unit_name) = match params.unit {
+            OffsetUnit::Days => (first.add_days(amount), "days"),
+            OffsetUnit::Weeks => (
+                amount.checked_mul(7).and_then(|days| first.add_days(days)),
+                "weeks",
+            ),
+            OffsetUnit::Months => (first.add_months(amount), "months"),
+        };
+        let second = second.ok_or_else(|| beyond_calendar(&first, amount, unit_name))?;
+
+        Ok(DatePair {
+            first,
+            second,
+            offset: DateOffset {
+                amount,
+                unit: params.unit,
+            },
+            first_date: first.to_date(params.formal, self.regional_week_format(params.week_format)),
+            second_date: second
+                .to_date(params.formal, self.regional_week_format(params.week_format)),
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use chinese_format::gregorian::DatePattern;
+use std::slice;
+
+/// Which [DatePattern] a generated date should have - see
+/// [DateParams](super::DateParams).
+///
+/// With the `serde` feature, a fixed pattern is just its name - like
+/// `"MonthDay"` - while a random choice is an array of names.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::gregorian::DatePattern;
+///
+/// let fixed: PatternChoice = DatePattern::MonthDay.into();
+/// assert_eq!(fixed, PatternChoice::Fixed(DatePattern::MonthDay));
+/// assert_eq!(fixed.patterns(), [DatePattern::MonthDay]);
+///
+/// let mixed = PatternChoice::RandomFrom(vec![DatePattern::YearMonthDay, DatePattern::MonthDay]);
+/// assert_eq!(mixed.patterns(), [DatePattern::YearMonthDay, DatePattern::MonthDay]);
+/// ```
+///
+/// **Required feature**: `gregorian`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+pub enum PatternChoice {
+    /// Always the given pattern.
+    Fixed(
+        #[cfg_attr(feature = "serde", serde(with = "crate::remote::DatePatternDef"))] DatePattern,
+    ),
+
+    /// A pattern drawn from the given ones - each time a date is generated.
+    RandomFrom(
+        #[cfg_attr(feature = "serde", serde(with = "crate::remote::vec_date_pattern"))]
+        Vec<DatePattern>,
+    ),
+}
+
+impl PatternChoice {
+    /// The patterns that can be chosen.
+    pub fn patterns(&self) -> &[DatePattern] {
+        match self {
+            PatternChoice::Fixed(pattern) => slice::from_ref(pattern),
+            PatternChoice::RandomFrom(patterns) => patterns,
+        }
+    }
+}
+
+impl From<DatePattern> for PatternChoice {
+    fn from(pattern: DatePattern) -> Self {
+        PatternChoice::Fixed(pattern)
+    }
+}
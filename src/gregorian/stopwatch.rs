@@ -0,0 +1,298 @@
+use super::GregorianGenerator;
+use crate::{check_range, ChineseRandResult, RawGenerator};
+use chinese_format::{Chinese, ChineseFormat, Count, Variant};
+use std::ops::RangeInclusive;
+
+const DIGITS: [char; 10] = ['零', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+/// How the fraction of a second is rendered in a [StopwatchTime].
+///
+/// **Required feature**: `gregorian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StopwatchPrecision {
+    /// Whole seconds - like `十二分零五秒`.
+    #[default]
+    Seconds,
+
+    /// One digit after the seconds - like `九秒五`.
+    Tenths,
+
+    /// Two digits after the seconds - like `九秒五八`.
+    Hundredths,
+
+    /// Milliseconds as a unit of their own - like `九秒五百八十毫秒` -
+    /// omitted when zero.
+    Milliseconds,
+}
+
+/// Result measured by a stopwatch - like `一小时二十三分四十五秒`
+/// or `十二秒八八` - as read in sports commentary.
+///
+/// Hours and minutes are rendered only when non-zero or preceded by
+/// a larger unit; single-digit minutes and seconds following a larger
+/// unit are introduced by `零`.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let time = StopwatchTime {
+///     hours: 0,
+///     minutes: 12,
+///     seconds: 5,
+///     milliseconds: 0,
+///     precision: StopwatchPrecision::Seconds
+/// };
+/// assert_eq!(time.to_chinese(Variant::Simplified), "十二分零五秒");
+///
+/// let time = StopwatchTime {
+///     hours: 1,
+///     minutes: 23,
+///     seconds: 45,
+///     ..time
+/// };
+/// assert_eq!(time.to_chinese(Variant::Traditional), "一小時二十三分四十五秒");
+///
+/// let time = StopwatchTime {
+///     hours: 0,
+///     minutes: 0,
+///     seconds: 12,
+///     milliseconds: 880,
+///     precision: StopwatchPrecision::Hundredths
+/// };
+/// assert_eq!(time.to_chinese(Variant::Simplified), "十二秒八八");
+///
+/// let time = StopwatchTime {
+///     precision: StopwatchPrecision::Milliseconds,
+///     ..time
+/// };
+/// assert_eq!(time.to_chinese(Variant::Simplified), "十二秒八百八十毫秒");
+/// ```
+///
+/// **Required feature**: `gregorian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StopwatchTime {
+    /// The hours.
+    pub hours: u32,
+
+    /// The minutes - within 0..=59.
+    pub minutes: u8,
+
+    /// The seconds - within 0..=59.
+    pub seconds: u8,
+
+    /// The milliseconds - within 0..=999; only the digits
+    /// allowed by the precision are rendered.
+    pub milliseconds: u16,
+
+    /// How the fraction of a second is rendered.
+    pub precision: StopwatchPrecision,
+}
+
+impl ChineseFormat for StopwatchTime {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let mut logograms = String::new();
+
+        let render_unit = |logograms: &mut String, value: u32, padded: bool, unit: &str| {
+            if padded && value < 10 {
+                logograms.push('零');
+                logograms.push_str(&value.to_chinese(variant).logograms);
+            } else {
+                logograms.push_str(&Count(value as u128).to_chinese(variant).logograms);
+            }
+
+            logograms.push_str(unit);
+        };
+
+        if self.hours > 0 {
+            render_unit(
+                &mut logograms,
+                self.hours,
+                false,
+                &("小时", "小時").to_chinese(variant).logograms,
+            );
+        }
+
+        if self.hours > 0 || self.minutes > 0 {
+            render_unit(&mut logograms, self.minutes as u32, self.hours > 0, "分");
+        }
+
+        render_unit(
+            &mut logograms,
+            self.seconds as u32,
+            self.hours > 0 || self.minutes > 0,
+            "秒",
+        );
+
+        let fraction_digits = match self.precision {
+            StopwatchPrecision::Seconds => 0,
+            StopwatchPrecision::Tenths => 1,
+            StopwatchPrecision::Hundredths => 2,
+
+            StopwatchPrecision::Milliseconds => {
+                if self.milliseconds > 0 {
+                    logograms.push_str(&self.milliseconds.to_chinese(variant).logograms);
+                    logograms.push_str("毫秒");
+                }
+
+                0
+            }
+        };
+
+        logograms.extend(
+            format!("{:03}", self.milliseconds)
+                .chars()
+                .take(fraction_digits)
+                .map(|digit| DIGITS[digit.to_digit(10).expect("Decimal digit") as usize]),
+        );
+
+        Chinese {
+            logograms,
+            omissible: false,
+        }
+    }
+}
+
+/// A [StopwatchTime] can be converted to the standard [std::time::Duration] -
+/// regardless of its precision.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+///
+/// let time = StopwatchTime {
+///     hours: 1,
+///     minutes: 2,
+///     seconds: 3,
+///     milliseconds: 450,
+///     precision: StopwatchPrecision::Tenths
+/// };
+///
+/// let std_duration: std::time::Duration = time.into();
+/// assert_eq!(std_duration.as_millis(), 3_723_450);
+/// ```
+impl From<StopwatchTime> for std::time::Duration {
+    fn from(time: StopwatchTime) -> Self {
+        let total_seconds =
+            time.hours as u64 * 3_600 + time.minutes as u64 * 60 + time.seconds as u64;
+
+        std::time::Duration::from_secs(total_seconds)
+            + std::time::Duration::from_millis(time.milliseconds as u64)
+    }
+}
+
+/// Parameters for the random creation of [StopwatchTime].
+///
+/// **Required feature**: `gregorian`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StopwatchParams {
+    /// The range of the whole seconds measured.
+    pub seconds_range: RangeInclusive<u32>,
+
+    /// How the fraction of a second is generated and rendered.
+    pub precision: StopwatchPrecision,
+}
+
+/// By default, [StopwatchParams] describes results up to 2 hours,
+/// in whole seconds.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+///
+/// assert_eq!(StopwatchParams::default(), StopwatchParams {
+///     seconds_range: 1..=7_200,
+///     precision: StopwatchPrecision::Seconds
+/// });
+///
+/// let customized = StopwatchParams::default()
+///     .with_seconds_range(9..=12)
+///     .with_precision(StopwatchPrecision::Hundredths);
+///
+/// assert_eq!(customized, StopwatchParams {
+///     seconds_range: 9..=12,
+///     precision: StopwatchPrecision::Hundredths
+/// });
+/// ```
+impl Default for StopwatchParams {
+    fn default() -> Self {
+        Self {
+            seconds_range: 1..=7_200,
+            precision: StopwatchPrecision::Seconds,
+        }
+    }
+}
+
+impl StopwatchParams {
+    /// Sets the range of the whole seconds measured.
+    pub fn with_seconds_range(mut self, seconds_range: RangeInclusive<u32>) -> Self {
+        self.seconds_range = seconds_range;
+        self
+    }
+
+    /// Sets how the fraction of a second is generated and rendered.
+    pub fn with_precision(mut self, precision: StopwatchPrecision) -> Self {
+        self.precision = precision;
+        self
+    }
+}
+
+impl<R: RawGenerator> GregorianGenerator<'_, R> {
+    /// Generates a random [StopwatchTime], given the provided [StopwatchParams] -
+    /// the fraction of a second having no more digits than the precision allows.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let gregorian = generator.gregorian();
+    ///
+    /// let marathon = gregorian.stopwatch(StopwatchParams::default().with_seconds_range(7_000..=9_000))?;
+    /// assert_eq!(marathon.to_chinese(Variant::Simplified), "两小时十九分四十九秒");
+    ///
+    /// let sprint = gregorian.stopwatch(
+    ///     StopwatchParams::default()
+    ///         .with_seconds_range(9..=11)
+    ///         .with_precision(StopwatchPrecision::Hundredths)
+    /// )?;
+    /// assert_eq!(sprint.to_chinese(Variant::Simplified), "十秒七三");
+    ///
+    /// let lap = gregorian.stopwatch(
+    ///     StopwatchParams::default()
+    ///         .with_seconds_range(50..=70)
+    ///         .with_precision(StopwatchPrecision::Milliseconds)
+    /// )?;
+    /// assert_eq!(lap.to_chinese(Variant::Traditional), "五十五秒四百八十八毫秒");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `gregorian`.
+    pub fn stopwatch(&self, params: StopwatchParams) -> ChineseRandResult<StopwatchTime> {
+        check_range(&params.seconds_range)?;
+
+        let total_seconds = self.raw_generator.u32(params.seconds_range);
+
+        let milliseconds = match params.precision {
+            StopwatchPrecision::Seconds => 0,
+            StopwatchPrecision::Tenths => self.raw_generator.u16(0..=9) * 100,
+            StopwatchPrecision::Hundredths => self.raw_generator.u16(0..=99) * 10,
+            StopwatchPrecision::Milliseconds => self.raw_generator.u16(0..=999),
+        };
+
+        Ok(StopwatchTime {
+            hours: total_seconds / 3_600,
+            minutes: (total_seconds % 3_600 / 60) as u8,
+            seconds: (total_seconds % 60) as u8,
+            milliseconds,
+            precision: params.precision,
+        })
+    }
+}
@@ -1,8 +1,11 @@
 use super::GregorianGenerator;
-use chinese_format::gregorian::{DeltaTime, Hour12, Hour24, LinearTime, Minute, Second};
+use crate::{ChineseRandError, ChineseRandResult, RawGenerator};
+use chinese_format::gregorian::{DayPart, DeltaTime, Hour12, Hour24, LinearTime, Minute, Second};
 
 /// Parameters for the random creation of [LinearTime].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct LinearTimeParams {
     /// If set to `true`, the result will include the day part,
     /// plus a 12-hour format; otherwise, the 24-hour format applies.
@@ -10,9 +13,222 @@ pub struct LinearTimeParams {
 
     /// If set to `true`, the *second* part will be generated.
     pub include_second: bool,
+
+    /// If set, the hour is generated so as to belong to the given [DayPart] -
+    /// for example, [Evening](DayPart::Evening) implies 20..=22;
+    /// the day part is actually rendered only when `day_part` is `true`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::remote::option_day_part")
+    )]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::option_day_part))]
+    pub pinned_day_part: Option<DayPart>,
+
+    /// Whether times on the hour - with minute 0 - can be generated.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub on_the_hour: OnTheHour,
+
+    /// If set, every time has this hour - in 24-hour format, from 0 to 23 -
+    /// overriding `pinned_day_part`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pinned_hour: Option<u8>,
+
+    /// If set, every time has this minute - from 0 to 59 -
+    /// overriding `on_the_hour`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pinned_minute: Option<u8>,
+
+    /// The granularity of the seconds - from 1 to 60; for example,
+    /// 5 for multiples of 5 only.
+    #[cfg_attr(feature = "serde", serde(default = "default_second_step"))]
+    pub second_step: u8,
+}
+
+#[cfg(feature = "serde")]
+fn default_second_step() -> u8 {
+    1
+}
+
+/// Whether a [LinearTime] can be on the hour - that is, with minute 0.
+///
+/// To render such times as `整点` - or the minutes below 10 as `零五分` -
+/// see [StyledLinearTime](super::StyledLinearTime).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum OnTheHour {
+    /// Minute 0 is just as likely as any other minute.
+    #[default]
+    Allowed,
+
+    /// Minute 0 is never generated.
+    Excluded,
+
+    /// Only minute 0 is generated.
+    Only,
+}
+
+/// By default, [LinearTimeParams] describes a 24-hour time without seconds.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::gregorian::DayPart;
+///
+/// assert_eq!(LinearTimeParams::default(), LinearTimeParams {
+///     day_part: false,
+///     include_second: false,
+///     pinned_day_part: None,
+///     on_the_hour: OnTheHour::Allowed,
+///     pinned_hour: None,
+///     pinned_minute: None,
+///     second_step: 1
+/// });
+///
+/// let customized = LinearTimeParams::default()
+///     .with_day_part(true)
+///     .with_second(true)
+///     .with_pinned_day_part(DayPart::Evening);
+///
+/// assert_eq!(customized, LinearTimeParams {
+///     day_part: true,
+///     include_second: true,
+///     pinned_day_part: Some(DayPart::Evening),
+///     on_the_hour: OnTheHour::Allowed,
+///     pinned_hour: None,
+///     pinned_minute: None,
+///     second_step: 1
+/// });
+/// ```
+impl Default for LinearTimeParams {
+    fn default() -> Self {
+        Self {
+            day_part: false,
+            include_second: false,
+            pinned_day_part: None,
+            on_the_hour: OnTheHour::Allowed,
+            pinned_hour: None,
+            pinned_minute: None,
+            second_step: 1,
+        }
+    }
+}
+
+impl LinearTimeParams {
+    /// Sets whether the day part - with the 12-hour format - is included.
+    pub fn with_day_part(mut self, day_part: bool) -> Self {
+        self.day_part = day_part;
+        self
+    }
+
+    /// Sets whether the *second* part is generated.
+    pub fn with_second(mut self, include_second: bool) -> Self {
+        self.include_second = include_second;
+        self
+    }
+
+    /// Sets the [DayPart] to which the generated hour must belong.
+    pub fn with_pinned_day_part(mut self, day_part: DayPart) -> Self {
+        self.pinned_day_part = Some(day_part);
+        self
+    }
+
+    /// Sets whether times on the hour can be generated.
+    pub fn with_on_the_hour(mut self, on_the_hour: OnTheHour) -> Self {
+        self.on_the_hour = on_the_hour;
+        self
+    }
+
+    /// Sets the hour - in 24-hour format - of every generated time.
+    pub fn with_pinned_hour(mut self, hour: u8) -> Self {
+        self.pinned_hour = Some(hour);
+        self
+    }
+
+    /// Sets the minute of every generated time.
+    pub fn with_pinned_minute(mut self, minute: u8) -> Self {
+        self.pinned_minute = Some(minute);
+        self
+    }
+
+    /// Sets the granularity of the seconds.
+    pub fn with_second_step(mut self, second_step: u8) -> Self {
+        self.second_step = second_step;
+        self
+    }
+}
+
+/// Which construction a [DeltaTime] uses - besides 刻 and 半.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum DeltaStyle {
+    /// Any minute.
+    #[default]
+    Any,
+
+    /// Only minutes past the hour expressed via 过 - like `九点过二十分`.
+    Guo,
+
+    /// Only minutes to the hour expressed via 差 - like `五点差六分`.
+    Cha,
+}
+
+/// Parameters for the random creation of [DeltaTime].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DeltaTimeParams {
+    /// The construction to target.
+    pub style: DeltaStyle,
+
+    /// Whether times on the hour - like `九点钟` - can be generated
+    /// with [DeltaStyle::Any].
+    pub include_on_the_hour: bool,
 }
 
-impl<'a> GregorianGenerator<'a> {
+/// By default, [DeltaTimeParams] describes any [DeltaTime].
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+///
+/// assert_eq!(DeltaTimeParams::default(), DeltaTimeParams {
+///     style: DeltaStyle::Any,
+///     include_on_the_hour: true
+/// });
+///
+/// let customized = DeltaTimeParams::default()
+///     .with_style(DeltaStyle::Cha)
+///     .with_on_the_hour(false);
+///
+/// assert_eq!(customized, DeltaTimeParams {
+///     style: DeltaStyle::Cha,
+///     include_on_the_hour: false
+/// });
+/// ```
+impl Default for DeltaTimeParams {
+    fn default() -> Self {
+        Self {
+            style: DeltaStyle::Any,
+            include_on_the_hour: true,
+        }
+    }
+}
+
+impl DeltaTimeParams {
+    /// Sets the [DeltaStyle].
+    pub fn with_style(mut self, style: DeltaStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets whether times on the hour can be generated.
+    pub fn with_on_the_hour(mut self, include_on_the_hour: bool) -> Self {
+        self.include_on_the_hour = include_on_the_hour;
+        self
+    }
+}
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
     /// Generates a random [Hour24].
     ///
     /// ```
@@ -31,10 +247,63 @@ impl<'a> GregorianGenerator<'a> {
     /// );
     /// ```
     pub fn hour24(&self) -> Hour24 {
-        self.raw_generator
-            .u8(0..=23)
-            .try_into()
-            .expect("Hour valid by construction")
+        to_hour24(self.raw_generator.u8(0..=23))
+    }
+
+    /// Generates a random [Hour24] belonging to the given [DayPart] -
+    /// each day part lasting 3 hours, starting from
+    /// [EarlyMorning](DayPart::EarlyMorning) at 5.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{Variant, ChineseFormat, gregorian::DayPart};
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let hour = gregorian.hour24_in(DayPart::Evening);
+    /// assert_eq!(
+    ///     hour.to_chinese(Variant::Simplified),
+    ///     "二十二点"
+    /// );
+    ///
+    /// let hour = gregorian.hour24_in(DayPart::Midnight);
+    /// assert_eq!(
+    ///     hour.to_chinese(Variant::Simplified),
+    ///     "零点"
+    /// );
+    /// ```
+    pub fn hour24_in(&self, day_part: DayPart) -> Hour24 {
+        to_hour24(self.hour_in(day_part))
+    }
+
+    fn hour_in(&self, day_part: DayPart) -> u8 {
+        let first_hour = 5 + 3 * day_part as u8;
+
+        (first_hour + self.raw_generator.u8(0..=2)) % 24
+    }
+
+    /// Generates a random [DayPart].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{Variant, ChineseFormat};
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let day_part = gregorian.day_part();
+    /// assert_eq!(
+    ///     day_part.to_chinese(Variant::Simplified),
+    ///     "下午"
+    /// );
+    /// ```
+    pub fn day_part(&self) -> DayPart {
+        self.hour24().into()
     }
 
     /// Generates a random [Hour12].
@@ -111,10 +380,15 @@ impl<'a> GregorianGenerator<'a> {
 
     /// Generates a random [LinearTime], given the provided [LinearTimeParams].
     ///
+    /// Fails with [ChineseRandError::InvalidRange](crate::ChineseRandError::InvalidRange)
+    /// if the pinned hour is above 23, the pinned minute above 59
+    /// or the second step not in 1..=60.
+    ///
     /// ```
     /// use chinese_rand::{*, gregorian::*};
     /// use chinese_format::{ChineseFormat, Variant, gregorian::*};
     ///
+    /// # fn main() -> GenericResult<()> {
     /// let raw_generator = FastRandGenerator::new();
     /// let generator = ChineseFormatGenerator::new(raw_generator);
     /// let gregorian = generator.gregorian();
@@ -122,48 +396,169 @@ impl<'a> GregorianGenerator<'a> {
     /// fastrand::seed(90);
     /// let time: LinearTime = gregorian.linear_time(LinearTimeParams {
     ///     day_part: true,
-    ///     include_second: true
-    /// });
+    ///     include_second: true,
+    ///     pinned_day_part: None,
+    ///     on_the_hour: OnTheHour::Allowed,
+    ///     pinned_hour: None,
+    ///     pinned_minute: None,
+    ///     second_step: 1
+    /// })?;
     /// assert_eq!(time.to_chinese(Variant::Simplified), "下午四点二十分四十三秒");
     ///
     /// fastrand::seed(90);
     /// let time: LinearTime = gregorian.linear_time(LinearTimeParams {
     ///     day_part: false,
-    ///     include_second: true
-    /// });
+    ///     include_second: true,
+    ///     pinned_day_part: None,
+    ///     on_the_hour: OnTheHour::Allowed,
+    ///     pinned_hour: None,
+    ///     pinned_minute: None,
+    ///     second_step: 1
+    /// })?;
     /// assert_eq!(time.to_chinese(Variant::Simplified), "十六点二十分四十三秒");  
     ///
     /// fastrand::seed(90);
     /// let time: LinearTime = gregorian.linear_time(LinearTimeParams {
     ///     day_part: true,
-    ///     include_second: false
-    /// });
+    ///     include_second: false,
+    ///     pinned_day_part: None,
+    ///     on_the_hour: OnTheHour::Allowed,
+    ///     pinned_hour: None,
+    ///     pinned_minute: None,
+    ///     second_step: 1
+    /// })?;
     /// assert_eq!(time.to_chinese(Variant::Simplified), "下午四点二十分");
     ///
     /// fastrand::seed(90);
     /// let time: LinearTime = gregorian.linear_time(LinearTimeParams {
     ///     day_part: false,
-    ///     include_second: false
-    /// });
+    ///     include_second: false,
+    ///     pinned_day_part: None,
+    ///     on_the_hour: OnTheHour::Allowed,
+    ///     pinned_hour: None,
+    ///     pinned_minute: None,
+    ///     second_step: 1
+    /// })?;
     /// assert_eq!(time.to_chinese(Variant::Simplified), "十六点二十分");
+    ///
+    /// fastrand::seed(90);
+    /// let time: LinearTime = gregorian.linear_time(LinearTimeParams {
+    ///     day_part: true,
+    ///     include_second: false,
+    ///     pinned_day_part: Some(DayPart::Evening),
+    ///     on_the_hour: OnTheHour::Allowed,
+    ///     pinned_hour: None,
+    ///     pinned_minute: None,
+    ///     second_step: 1
+    /// })?;
+    /// assert_eq!(time.to_chinese(Variant::Simplified), "晚上十点二十分");
+    ///
+    /// let drill = LinearTimeParams::default()
+    ///     .with_second(true)
+    ///     .with_pinned_hour(9)
+    ///     .with_pinned_minute(7)
+    ///     .with_second_step(5);
+    /// let times: Vec<String> = (0..4)
+    ///     .map(|_| gregorian.linear_time(drill).map(|time| time.to_chinese(Variant::Simplified).to_string()))
+    ///     .collect::<ChineseRandResult<_>>()?;
+    /// assert_eq!(times, ["九点七分四十秒", "九点七分十五秒", "九点七分二十五秒", "九点七分十秒"]);
+    ///
+    /// assert_eq!(
+    ///     gregorian.linear_time(LinearTimeParams::default().with_pinned_hour(24)),
+    ///     Err(ChineseRandError::InvalidRange("Pinned hour not in 0..=23: 24".to_string()))
+    /// );
+    /// assert_eq!(
+    ///     gregorian.linear_time(LinearTimeParams::default().with_pinned_minute(60)),
+    ///     Err(ChineseRandError::InvalidRange("Pinned minute not in 0..=59: 60".to_string()))
+    /// );
+    /// assert_eq!(
+    ///     gregorian.linear_time(LinearTimeParams::default().with_second_step(0)),
+    ///     Err(ChineseRandError::InvalidRange("Second step not in 1..=60: 0".to_string()))
+    /// );
+    /// assert!(gregorian
+    ///     .linear_time(LinearTimeParams::default().with_second_step(61))
+    ///     .is_err());
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn linear_time(&self, params: LinearTimeParams) -> LinearTime {
-        let hour: Hour24 = self.hour24();
+    pub fn linear_time(&self, params: LinearTimeParams) -> ChineseRandResult<LinearTime> {
+        Ok(self.linear_time_with_hour(params)?.0)
+    }
+
+    /// Like [linear_time](Self::linear_time), also returning
+    /// the generated hour as a number in the 0..=23 range.
+    pub(crate) fn linear_time_with_hour(
+        &self,
+        params: LinearTimeParams,
+    ) -> ChineseRandResult<(LinearTime, u8)> {
+        if let Some(hour) = params.pinned_hour.filter(|hour| *hour > 23) {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Pinned hour not in 0..=23: {}",
+                hour
+            )));
+        }
 
-        let minute: Minute = self.minute();
+        if let Some(minute) = params.pinned_minute.filter(|minute| *minute > 59) {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Pinned minute not in 0..=59: {}",
+                minute
+            )));
+        }
+
+        if !(1..=60).contains(&params.second_step) {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Second step not in 1..=60: {}",
+                params.second_step
+            )));
+        }
 
-        let second: Option<Second> = if params.include_second {
-            Some(self.second())
-        } else {
-            None
+        let hour: u8 = match (params.pinned_hour, params.pinned_day_part) {
+            (Some(hour), _) => hour,
+            (None, Some(day_part)) => self.hour_in(day_part),
+            (None, None) => self.raw_generator.u8(0..=23),
         };
 
-        LinearTime {
-            day_part: params.day_part,
-            hour,
-            minute,
-            second,
+        let minute: Minute = match (params.pinned_minute, params.on_the_hour) {
+            (Some(minute), _) => minute,
+            (None, OnTheHour::Allowed) => self.raw_generator.u8(0..=59),
+            (None, OnTheHour::Excluded) => self.raw_generator.u8(1..=59),
+            (None, OnTheHour::Only) => 0,
         }
+        .try_into()
+        .expect("Minute valid by construction");
+
+        let include_second = self
+            .level
+            .and_then(|level| level.include_second())
+            .unwrap_or(params.include_second);
+
+        let second_step = params.second_step;
+
+        let second: Option<Second> = match (include_second, second_step) {
+            (false, _) => None,
+
+            (true, 1) => Some(self.second()),
+
+            (true, _) => Some(
+                (self.raw_generator.u8(0..=(59 / second_step)) * second_step)
+                    .try_into()
+                    .expect("Second valid by construction"),
+            ),
+        };
+
+        Ok((
+            LinearTime {
+                day_part: params.day_part
+                    || self
+                        .owner
+                        .region
+                        .is_some_and(|region| region.twelve_hour_clock()),
+                hour: to_hour24(hour),
+                minute,
+                second,
+            },
+            hour,
+        ))
     }
 
     /// Generates a random [DeltaTime].
@@ -172,29 +567,111 @@ impl<'a> GregorianGenerator<'a> {
     /// use chinese_rand::*;
     /// use chinese_format::{Variant, ChineseFormat, gregorian::DeltaTime};
     ///
+    /// # fn main() -> GenericResult<()> {
     /// let raw_generator = FastRandGenerator::new();
     /// let generator = ChineseFormatGenerator::new(raw_generator);
     /// let gregorian = generator.gregorian();
     ///
     /// fastrand::seed(90);
-    /// let delta_time = gregorian.delta_time();
+    /// let delta_time = gregorian.delta_time()?;
     /// assert_eq!(
     ///     delta_time.to_chinese(Variant::Simplified),
     ///     "九点过二十分"
     /// );
     ///
     /// fastrand::seed(91);
-    /// let delta_time = gregorian.delta_time();
+    /// let delta_time = gregorian.delta_time()?;
     /// assert_eq!(
     ///     delta_time.to_chinese(Variant::Simplified),
     ///     "五点差六分"
     /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delta_time(&self) -> ChineseRandResult<DeltaTime> {
+        self.delta_time_with(DeltaTimeParams::default())
+    }
+
+    /// Generates a random [DeltaTime] according to the given [DeltaTimeParams] -
+    /// for example, to practice one construction at a time.
+    ///
     /// ```
-    pub fn delta_time(&self) -> DeltaTime {
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{Variant, ChineseFormat};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let gregorian = generator.gregorian();
+    ///
+    /// fastrand::seed(90);
+    /// let delta_time = gregorian.delta_time_with(
+    ///     DeltaTimeParams::default().with_style(DeltaStyle::Guo)
+    /// )?;
+    /// assert_eq!(
+    ///     delta_time.to_chinese(Variant::Simplified),
+    ///     "九点过十分"
+    /// );
+    ///
+    /// fastrand::seed(90);
+    /// let delta_time = gregorian.delta_time_with(
+    ///     DeltaTimeParams::default().with_style(DeltaStyle::Cha)
+    /// )?;
+    /// assert_eq!(
+    ///     delta_time.to_chinese(Variant::Traditional),
+    ///     "十點差二十分"
+    /// );
+    ///
+    /// for _ in 0..100 {
+    ///     let delta_time = gregorian.delta_time_with(
+    ///         DeltaTimeParams::default().with_on_the_hour(false)
+    ///     )?;
+    ///     assert_ne!(u8::from(delta_time.minute), 0);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delta_time_with(&self, params: DeltaTimeParams) -> ChineseRandResult<DeltaTime> {
         let hour: Hour12 = self.hour12();
 
-        let minute: Minute = self.minute();
+        let minute: Minute = match params.style {
+            DeltaStyle::Any if params.include_on_the_hour => self.raw_generator.u8(0..=59),
+
+            DeltaStyle::Any => self.raw_generator.u8(1..=59),
+
+            DeltaStyle::Guo => self.minute_avoiding_15(1),
+
+            DeltaStyle::Cha => self.minute_avoiding_15(31),
+        }
+        .try_into()
+        .expect("Minute valid by construction");
+
+        Ok(DeltaTime { hour, minute })
+    }
+
+    /// Draws among the 28 minutes from `start` - excluding `start + 14`,
+    /// rendered via 刻.
+    fn minute_avoiding_15(&self, start: u8) -> u8 {
+        let offset = self.raw_generator.u8(0..=27);
+
+        start + if offset >= 14 { offset + 1 } else { offset }
+    }
+}
+
+pub(crate) fn to_hour24(hour: u8) -> Hour24 {
+    hour.try_into().expect("Hour valid by construction")
+}
+
+/// The [LinearTime] of the given minute of the day.
+pub(crate) fn clock_time(minute_of_day: u16, day_part: bool) -> LinearTime {
+    let minute: Minute = ((minute_of_day % 60) as u8)
+        .try_into()
+        .expect("Minute valid by construction");
 
-        DeltaTime { hour, minute }
+    LinearTime {
+        day_part,
+        hour: to_hour24((minute_of_day / 60) as u8),
+        minute,
+        second: None,
     }
 }
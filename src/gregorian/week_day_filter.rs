@@ -0,0 +1,59 @@
+use chinese_format::gregorian::WeekDay;
+
+/// Which days of the week [DateParams](super::DateParams) accepts - according
+/// to the calendar, so that the dates are consistent with their week day.
+///
+/// Filtering requires a pattern with year, month and day; if the pattern
+/// also has the week day, it is the one computed from the calendar.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::gregorian::WeekDay;
+///
+/// assert!(WeekDayFilter::Any.accepts(WeekDay::Monday));
+///
+/// assert!(WeekDayFilter::WeekdaysOnly.accepts(WeekDay::Friday));
+/// assert!(!WeekDayFilter::WeekdaysOnly.accepts(WeekDay::Sunday));
+///
+/// assert!(WeekDayFilter::WeekendsOnly.accepts(WeekDay::Saturday));
+/// assert!(!WeekDayFilter::WeekendsOnly.accepts(WeekDay::Wednesday));
+///
+/// let specific = WeekDayFilter::Specific(vec![WeekDay::Tuesday, WeekDay::Thursday]);
+/// assert!(specific.accepts(WeekDay::Thursday));
+/// assert!(!specific.accepts(WeekDay::Monday));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum WeekDayFilter {
+    /// Every day of the week.
+    #[default]
+    Any,
+
+    /// From Monday to Friday.
+    WeekdaysOnly,
+
+    /// Saturday and Sunday.
+    WeekendsOnly,
+
+    /// Only the listed days.
+    Specific(
+        #[cfg_attr(feature = "serde", serde(with = "crate::remote::vec_week_day"))]
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::vec_week_day))]
+        Vec<WeekDay>,
+    ),
+}
+
+impl WeekDayFilter {
+    /// Whether the given day of the week is accepted.
+    pub fn accepts(&self, week_day: WeekDay) -> bool {
+        let is_weekend = matches!(week_day, WeekDay::Saturday | WeekDay::Sunday);
+
+        match self {
+            WeekDayFilter::Any => true,
+            WeekDayFilter::WeekdaysOnly => !is_weekend,
+            WeekDayFilter::WeekendsOnly => is_weekend,
+            WeekDayFilter::Specific(week_days) => week_days.contains(&week_day),
+        }
+    }
+}
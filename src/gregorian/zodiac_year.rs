@@ -0,0 +1,110 @@
+use super::GregorianGenerator;
+use crate::{check_range, ChineseRandResult, RawGenerator, Zodiac};
+use chinese_format::gregorian::{Date, DateBuilder};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Variant};
+use std::ops::RangeInclusive;
+
+/// Gregorian year paired with its [Zodiac] animal - rendered like `二零二四年属龙`.
+///
+/// The animal is computed via [Zodiac::from_gregorian_year], so it is
+/// always consistent with the displayed year.
+///
+/// ```
+/// use chinese_rand::{*, gregorian::*};
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let zodiac_year = ZodiacYear::new(2024);
+///
+/// assert_eq!(zodiac_year.animal(), Zodiac::Dragon);
+/// assert_eq!(zodiac_year.to_chinese(Variant::Simplified), "二零二四年属龙");
+/// assert_eq!(zodiac_year.to_chinese(Variant::Traditional), "二零二四年屬龍");
+///
+/// assert_eq!(zodiac_year.date().to_chinese(Variant::Simplified), "二零二四年");
+/// assert_eq!(zodiac_year.sign().to_chinese(Variant::Simplified), "属龙");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZodiacYear(u16);
+
+impl ZodiacYear {
+    /// Creates the pair for the given year.
+    pub fn new(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// The year, as number.
+    pub fn year(&self) -> u16 {
+        self.0
+    }
+
+    /// The zodiac animal of the year.
+    pub fn animal(&self) -> Zodiac {
+        Zodiac::from_gregorian_year(self.0)
+    }
+
+    /// The year, ready for rendering - like `二零二四年`.
+    pub fn date(&self) -> Date {
+        DateBuilder::new()
+            .with_year(self.0)
+            .build()
+            .expect("Year valid by construction")
+    }
+
+    /// The zodiac sign - like `属龙`.
+    pub fn sign(&self) -> ZodiacSign {
+        ZodiacSign(self.animal())
+    }
+}
+
+impl ChineseFormat for ZodiacYear {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.date(), self.sign()]).collect()
+    }
+}
+
+/// The 属X expression stating the [Zodiac] animal of a person or of a year.
+///
+/// ```
+/// use chinese_rand::{*, gregorian::*};
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// assert_eq!(ZodiacSign(Zodiac::Horse).to_chinese(Variant::Simplified), "属马");
+/// assert_eq!(ZodiacSign(Zodiac::Horse).to_chinese(Variant::Traditional), "屬馬");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZodiacSign(pub Zodiac);
+
+impl ChineseFormat for ZodiacSign {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [("属", "屬"), self.0]).collect()
+    }
+}
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
+    /// Generates a random [ZodiacYear] in the given year range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let gregorian = generator.gregorian();
+    ///
+    /// let zodiac_year = gregorian.zodiac_year(1950..=2030)?;
+    /// assert_eq!(zodiac_year.to_chinese(Variant::Simplified), "二零零六年属狗");
+    ///
+    /// assert!(gregorian.zodiac_year(2030..=1950).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn zodiac_year(&self, year_range: RangeInclusive<u16>) -> ChineseRandResult<ZodiacYear> {
+        check_range(&year_range)?;
+
+        Ok(ZodiacYear(self.raw_generator.u16(year_range)))
+    }
+}
@@ -0,0 +1,222 @@
+use super::{to_hour24, GregorianGenerator, LinearTimeParams};
+use crate::{BilingualLabel, ChineseRandError, ChineseRandResult, RawGenerator};
+use chinese_format::gregorian::LinearTime;
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Variant};
+
+/// [BilingualLabel] - like 北京时间 - stating the region whose clock a time refers to.
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// assert_eq!(RegionLabel::beijing().to_chinese(Variant::Simplified), "北京时间");
+/// assert_eq!(RegionLabel::beijing().to_chinese(Variant::Traditional), "北京時間");
+///
+/// let label = RegionLabel::new("伦敦时间", "倫敦時間");
+/// assert_eq!(label.to_chinese(Variant::Traditional), "倫敦時間");
+/// ```
+pub type RegionLabel = BilingualLabel;
+
+impl RegionLabel {
+    /// The 北京时间 region label - China Standard Time.
+    ///
+    /// **Required feature**: `gregorian`.
+    pub fn beijing() -> Self {
+        Self::new("北京时间", "北京時間")
+    }
+}
+
+/// [LinearTime] prefixed by a [RegionLabel] - like `北京时间十六点二十分`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZonedTime {
+    /// The region.
+    pub label: RegionLabel,
+
+    /// The clock time in the region.
+    pub time: LinearTime,
+}
+
+impl ChineseFormat for ZonedTime {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.label, self.time]).collect()
+    }
+}
+
+/// Parameters for the random creation of [TimeDifference].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeDifferenceParams {
+    /// How the local time is generated.
+    pub time_params: LinearTimeParams,
+
+    /// The label of the local time.
+    pub local_label: RegionLabel,
+
+    /// The label of the remote time.
+    pub remote_label: RegionLabel,
+
+    /// The hours to add to the local time to obtain the remote time -
+    /// within the -23..=23 range.
+    pub offset_hours: i8,
+}
+
+/// By default, [TimeDifferenceParams] describes 北京时间 and 伦敦时间 -
+/// 8 hours behind - with the default [LinearTimeParams].
+///
+/// ```
+/// use chinese_rand::gregorian::*;
+///
+/// assert_eq!(TimeDifferenceParams::default(), TimeDifferenceParams {
+///     time_params: LinearTimeParams::default(),
+///     local_label: RegionLabel::beijing(),
+///     remote_label: RegionLabel::new("伦敦时间", "倫敦時間"),
+///     offset_hours: -8
+/// });
+///
+/// let customized = TimeDifferenceParams::default()
+///     .with_time_params(LinearTimeParams::default().with_day_part(true))
+///     .with_remote_label(RegionLabel::new("东京时间", "東京時間"))
+///     .with_offset_hours(1);
+///
+/// assert_eq!(customized, TimeDifferenceParams {
+///     time_params: LinearTimeParams::default().with_day_part(true),
+///     local_label: RegionLabel::beijing(),
+///     remote_label: RegionLabel::new("东京时间", "東京時間"),
+///     offset_hours: 1
+/// });
+/// ```
+impl Default for TimeDifferenceParams {
+    fn default() -> Self {
+        Self {
+            time_params: LinearTimeParams::default(),
+            local_label: RegionLabel::beijing(),
+            remote_label: RegionLabel::new("伦敦时间", "倫敦時間"),
+            offset_hours: -8,
+        }
+    }
+}
+
+impl TimeDifferenceParams {
+    /// Sets how the local time is generated.
+    pub fn with_time_params(mut self, time_params: LinearTimeParams) -> Self {
+        self.time_params = time_params;
+        self
+    }
+
+    /// Sets the label of the local time.
+    pub fn with_local_label(mut self, local_label: RegionLabel) -> Self {
+        self.local_label = local_label;
+        self
+    }
+
+    /// Sets the label of the remote time.
+    pub fn with_remote_label(mut self, remote_label: RegionLabel) -> Self {
+        self.remote_label = remote_label;
+        self
+    }
+
+    /// Sets the hours to add to the local time to obtain the remote time.
+    pub fn with_offset_hours(mut self, offset_hours: i8) -> Self {
+        self.offset_hours = offset_hours;
+        self
+    }
+}
+
+/// The same instant on the clocks of two regions - for 时差 exercises.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimeDifference {
+    /// The local time.
+    pub local: ZonedTime,
+
+    /// The remote time.
+    pub remote: ZonedTime,
+
+    /// The hours added to the local time to obtain the remote time.
+    pub offset_hours: i8,
+}
+
+impl<'a, R: RawGenerator> GregorianGenerator<'a, R> {
+    /// Generates a random [LinearTime], prefixed by the given [RegionLabel].
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let gregorian = generator.gregorian();
+    ///
+    /// let time = gregorian.zoned_time(RegionLabel::beijing(), LinearTimeParams::default())?;
+    /// assert_eq!(time.to_chinese(Variant::Simplified), "北京时间十六点二十分");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn zoned_time(
+        &self,
+        label: RegionLabel,
+        params: LinearTimeParams,
+    ) -> ChineseRandResult<ZonedTime> {
+        Ok(ZonedTime {
+            label,
+            time: self.linear_time(params)?,
+        })
+    }
+
+    /// Generates a random [TimeDifference] - drawing just the local time,
+    /// then shifting its hour by the requested offset.
+    ///
+    /// Fails with [ChineseRandError::InvalidRange] if the offset
+    /// is not within -23..=23, or if the time parameters are invalid -
+    /// as in [linear_time](Self::linear_time).
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let gregorian = generator.gregorian();
+    ///
+    /// let difference = gregorian.time_difference(TimeDifferenceParams::default())?;
+    /// assert_eq!(difference.local.to_chinese(Variant::Simplified), "北京时间十六点二十分");
+    /// assert_eq!(difference.remote.to_chinese(Variant::Simplified), "伦敦时间八点二十分");
+    ///
+    /// assert!(gregorian
+    ///     .time_difference(TimeDifferenceParams::default().with_offset_hours(24))
+    ///     .is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn time_difference(
+        &self,
+        params: TimeDifferenceParams,
+    ) -> ChineseRandResult<TimeDifference> {
+        if !(-23..=23).contains(&params.offset_hours) {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Offset hours not in -23..=23: {}",
+                params.offset_hours
+            )));
+        }
+
+        let (local_time, local_hour) = self.linear_time_with_hour(params.time_params)?;
+
+        let remote_hour = (local_hour as i8 + params.offset_hours).rem_euclid(24) as u8;
+
+        let remote_time = LinearTime {
+            hour: to_hour24(remote_hour),
+            ..local_time
+        };
+
+        Ok(TimeDifference {
+            local: ZonedTime {
+                label: params.local_label,
+                time: local_time,
+            },
+            remote: ZonedTime {
+                label: params.remote_label,
+                time: remote_time,
+            },
+            offset_hours: params.offset_hours,
+        })
+    }
+}
@@ -0,0 +1,545 @@
+//! Numeric labels of everyday life - room numbers, floors, bus routes
+//! and train numbers - each rendered the way it is actually read.
+use crate::{
+    check_range, ChineseFormatGenerator, ChineseRandError, ChineseRandResult, RawGenerator,
+};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Variant};
+use std::ops::RangeInclusive;
+
+const DIGITS: [char; 10] = ['零', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+const YAO: char = '幺';
+
+/// How the number within a label is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumberReading {
+    /// With positional units - like `三百零二`.
+    Positional,
+
+    /// One digit after another - like `八零五`; if `yao` is `true`,
+    /// 1 is read `幺` - as usual in room and train numbers.
+    DigitByDigit { yao: bool },
+}
+
+impl NumberReading {
+    fn render(&self, number: u16, variant: Variant) -> String {
+        match self {
+            NumberReading::Positional => number.to_chinese(variant).logograms,
+
+            NumberReading::DigitByDigit { yao } => number
+                .to_string()
+                .chars()
+                .map(|digit| match digit {
+                    '1' if *yao => YAO,
+                    _ => DIGITS[digit.to_digit(10).expect("Decimal digit") as usize],
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Room number - like `八零五房间`, for room 5 at the 8th floor.
+///
+/// ```
+/// use chinese_rand::labels::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let room = RoomNumber {
+///     floor: 8,
+///     room: 5,
+///     reading: NumberReading::DigitByDigit { yao: false }
+/// };
+/// assert_eq!(room.number(), 805);
+/// assert_eq!(room.to_chinese(Variant::Simplified), "八零五房间");
+/// assert_eq!(room.to_chinese(Variant::Traditional), "八零五房間");
+///
+/// let room = RoomNumber {
+///     floor: 11,
+///     room: 12,
+///     reading: NumberReading::DigitByDigit { yao: true }
+/// };
+/// assert_eq!(room.to_chinese(Variant::Simplified), "幺幺幺二房间");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RoomNumber {
+    /// The floor - the leading digits.
+    pub floor: u8,
+
+    /// The room within the floor - the last two digits.
+    pub room: u8,
+
+    /// How the number is read.
+    pub reading: NumberReading,
+}
+
+impl RoomNumber {
+    /// The full number - like 805.
+    pub fn number(&self) -> u16 {
+        self.floor as u16 * 100 + self.room as u16
+    }
+}
+
+impl ChineseFormat for RoomNumber {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                self.reading.render(self.number(), variant),
+                ("房间", "房間")
+            ]
+        )
+        .collect()
+    }
+}
+
+/// Floor of a building - like `十二楼`.
+///
+/// ```
+/// use chinese_rand::labels::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// assert_eq!(Floor(12).to_chinese(Variant::Simplified), "十二楼");
+/// assert_eq!(Floor(2).to_chinese(Variant::Traditional), "二樓");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Floor(pub u8);
+
+impl ChineseFormat for Floor {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                NumberReading::Positional.render(self.0 as u16, variant),
+                ("楼", "樓")
+            ]
+        )
+        .collect()
+    }
+}
+
+/// Bus route - like `三百零二路`.
+///
+/// ```
+/// use chinese_rand::labels::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let route = BusRoute { number: 302, reading: NumberReading::Positional };
+/// assert_eq!(route.to_chinese(Variant::Simplified), "三百零二路");
+///
+/// let route = BusRoute { number: 302, reading: NumberReading::DigitByDigit { yao: false } };
+/// assert_eq!(route.to_chinese(Variant::Simplified), "三零二路");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BusRoute {
+    /// The route number.
+    pub number: u16,
+
+    /// How the number is read.
+    pub reading: NumberReading,
+}
+
+impl ChineseFormat for BusRoute {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.reading.render(self.number, variant), "路"]).collect()
+    }
+}
+
+/// Category of Chinese train, identified by the 字头 letter of the train number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrainKind {
+    /// 高铁 - high-speed.
+    G,
+
+    /// 动车 - electric multiple unit.
+    D,
+
+    /// 城际 - intercity.
+    C,
+
+    /// 直达 - direct express.
+    Z,
+
+    /// 特快 - express.
+    T,
+
+    /// 快速 - fast.
+    K,
+}
+
+impl TrainKind {
+    /// All the kinds.
+    pub const ALL: [TrainKind; 6] = [
+        TrainKind::G,
+        TrainKind::D,
+        TrainKind::C,
+        TrainKind::Z,
+        TrainKind::T,
+        TrainKind::K,
+    ];
+
+    /// The 字头 letter.
+    pub fn letter(&self) -> char {
+        match self {
+            TrainKind::G => 'G',
+            TrainKind::D => 'D',
+            TrainKind::C => 'C',
+            TrainKind::Z => 'Z',
+            TrainKind::T => 'T',
+            TrainKind::K => 'K',
+        }
+    }
+}
+
+/// Train number - like `G幺二三四次`.
+///
+/// ```
+/// use chinese_rand::labels::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let train = TrainNumber {
+///     kind: TrainKind::G,
+///     number: 1234,
+///     reading: NumberReading::DigitByDigit { yao: true }
+/// };
+/// assert_eq!(train.to_chinese(Variant::Simplified), "G幺二三四次");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TrainNumber {
+    /// The category of the train.
+    pub kind: TrainKind,
+
+    /// The number following the 字头 letter.
+    pub number: u16,
+
+    /// How the number is read.
+    pub reading: NumberReading,
+}
+
+impl ChineseFormat for TrainNumber {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                self.kind.letter().to_string(),
+                self.reading.render(self.number, variant),
+                "次"
+            ]
+        )
+        .collect()
+    }
+}
+
+/// Parameters for the random creation of [RoomNumber].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoomNumberParams {
+    /// The range of the floors.
+    pub floor_range: RangeInclusive<u8>,
+
+    /// The range of the rooms within each floor - within 1..=99.
+    pub room_range: RangeInclusive<u8>,
+
+    /// How the number is read.
+    pub reading: NumberReading,
+}
+
+/// By default, [RoomNumberParams] describes rooms 1 to 30 on floors
+/// 1 to 20, read digit by digit without `幺`.
+///
+/// ```
+/// use chinese_rand::labels::*;
+///
+/// assert_eq!(RoomNumberParams::default(), RoomNumberParams {
+///     floor_range: 1..=20,
+///     room_range: 1..=30,
+///     reading: NumberReading::DigitByDigit { yao: false }
+/// });
+///
+/// let customized = RoomNumberParams::default()
+///     .with_floor_range(2..=3)
+///     .with_room_range(1..=9)
+///     .with_reading(NumberReading::DigitByDigit { yao: true });
+///
+/// assert_eq!(customized, RoomNumberParams {
+///     floor_range: 2..=3,
+///     room_range: 1..=9,
+///     reading: NumberReading::DigitByDigit { yao: true }
+/// });
+/// ```
+impl Default for RoomNumberParams {
+    fn default() -> Self {
+        Self {
+            floor_range: 1..=20,
+            room_range: 1..=30,
+            reading: NumberReading::DigitByDigit { yao: false },
+        }
+    }
+}
+
+impl RoomNumberParams {
+    /// Sets the range of the floors.
+    pub fn with_floor_range(mut self, floor_range: RangeInclusive<u8>) -> Self {
+        self.floor_range = floor_range;
+        self
+    }
+
+    /// Sets the range of the rooms within each floor.
+    pub fn with_room_range(mut self, room_range: RangeInclusive<u8>) -> Self {
+        self.room_range = room_range;
+        self
+    }
+
+    /// Sets how the number is read.
+    pub fn with_reading(mut self, reading: NumberReading) -> Self {
+        self.reading = reading;
+        self
+    }
+}
+
+/// Parameters for the random creation of [BusRoute].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BusRouteParams {
+    /// The range of the route numbers.
+    pub number_range: RangeInclusive<u16>,
+
+    /// How the number is read.
+    pub reading: NumberReading,
+}
+
+/// By default, [BusRouteParams] describes routes 1 to 999, read positionally.
+///
+/// ```
+/// use chinese_rand::labels::*;
+///
+/// assert_eq!(BusRouteParams::default(), BusRouteParams {
+///     number_range: 1..=999,
+///     reading: NumberReading::Positional
+/// });
+///
+/// let customized = BusRouteParams::default()
+///     .with_number_range(1..=99)
+///     .with_reading(NumberReading::DigitByDigit { yao: false });
+///
+/// assert_eq!(customized, BusRouteParams {
+///     number_range: 1..=99,
+///     reading: NumberReading::DigitByDigit { yao: false }
+/// });
+/// ```
+impl Default for BusRouteParams {
+    fn default() -> Self {
+        Self {
+            number_range: 1..=999,
+            reading: NumberReading::Positional,
+        }
+    }
+}
+
+impl BusRouteParams {
+    /// Sets the range of the route numbers.
+    pub fn with_number_range(mut self, number_range: RangeInclusive<u16>) -> Self {
+        self.number_range = number_range;
+        self
+    }
+
+    /// Sets how the number is read.
+    pub fn with_reading(mut self, reading: NumberReading) -> Self {
+        self.reading = reading;
+        self
+    }
+}
+
+/// Parameters for the random creation of [TrainNumber].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrainNumberParams {
+    /// The kinds of train to choose from.
+    pub kinds: Vec<TrainKind>,
+
+    /// The range of the numbers following the 字头 letter.
+    pub number_range: RangeInclusive<u16>,
+
+    /// How the number is read.
+    pub reading: NumberReading,
+}
+
+/// By default, [TrainNumberParams] describes all the kinds of train,
+/// with numbers from 1 to 9999 read digit by digit with `幺`.
+///
+/// ```
+/// use chinese_rand::labels::*;
+///
+/// assert_eq!(TrainNumberParams::default(), TrainNumberParams {
+///     kinds: TrainKind::ALL.to_vec(),
+///     number_range: 1..=9999,
+///     reading: NumberReading::DigitByDigit { yao: true }
+/// });
+///
+/// let customized = TrainNumberParams::default()
+///     .with_kinds(vec![TrainKind::G, TrainKind::D])
+///     .with_number_range(1..=999)
+///     .with_reading(NumberReading::Positional);
+///
+/// assert_eq!(customized, TrainNumberParams {
+///     kinds: vec![TrainKind::G, TrainKind::D],
+///     number_range: 1..=999,
+///     reading: NumberReading::Positional
+/// });
+/// ```
+impl Default for TrainNumberParams {
+    fn default() -> Self {
+        Self {
+            kinds: TrainKind::ALL.to_vec(),
+            number_range: 1..=9999,
+            reading: NumberReading::DigitByDigit { yao: true },
+        }
+    }
+}
+
+impl TrainNumberParams {
+    /// Sets the kinds of train to choose from.
+    pub fn with_kinds(mut self, kinds: Vec<TrainKind>) -> Self {
+        self.kinds = kinds;
+        self
+    }
+
+    /// Sets the range of the numbers.
+    pub fn with_number_range(mut self, number_range: RangeInclusive<u16>) -> Self {
+        self.number_range = number_range;
+        self
+    }
+
+    /// Sets how the number is read.
+    pub fn with_reading(mut self, reading: NumberReading) -> Self {
+        self.reading = reading;
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [RoomNumber].
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if a range is empty, and
+    /// with [ChineseRandError::InvalidRange] if the rooms are not within 1..=99.
+    ///
+    /// ```
+    /// use chinese_rand::{*, labels::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let room = generator.room_number(RoomNumberParams::default())?;
+    /// assert_eq!(room.to_chinese(Variant::Simplified), "一四一一房间");
+    ///
+    /// let lucky_room = generator.constrained(
+    ///     &Constraint::without_unlucky_logograms(),
+    ///     |generator| generator.room_number(RoomNumberParams::default())
+    /// )?;
+    /// assert!(!lucky_room.number().to_string().contains('4'));
+    ///
+    /// assert!(generator.room_number(RoomNumberParams::default().with_room_range(0..=100)).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn room_number(&self, params: RoomNumberParams) -> ChineseRandResult<RoomNumber> {
+        check_range(&params.floor_range)?;
+        check_range(&params.room_range)?;
+
+        if *params.room_range.start() == 0 || *params.room_range.end() > 99 {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Rooms not in 1..=99: {:?}",
+                params.room_range
+            )));
+        }
+
+        Ok(RoomNumber {
+            floor: self.raw_generator.u8(params.floor_range),
+            room: self.raw_generator.u8(params.room_range),
+            reading: params.reading,
+        })
+    }
+
+    /// Generates a random [Floor] in the given range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let floor = generator.floor(1..=30)?;
+    /// assert_eq!(floor.to_chinese(Variant::Simplified), "二十一楼");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn floor(&self, range: RangeInclusive<u8>) -> ChineseRandResult<Floor> {
+        check_range(&range)?;
+
+        Ok(Floor(self.raw_generator.u8(range)))
+    }
+
+    /// Generates a random [BusRoute].
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::{*, labels::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let route = generator.bus_route(BusRouteParams::default())?;
+    /// assert_eq!(route.to_chinese(Variant::Simplified), "六百九十四路");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bus_route(&self, params: BusRouteParams) -> ChineseRandResult<BusRoute> {
+        check_range(&params.number_range)?;
+
+        Ok(BusRoute {
+            number: self.raw_generator.u16(params.number_range),
+            reading: params.reading,
+        })
+    }
+
+    /// Generates a random [TrainNumber].
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if the range is empty,
+    /// and with [ChineseRandError::InvalidRange] if there are no kinds.
+    ///
+    /// ```
+    /// use chinese_rand::{*, labels::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let train = generator.train_number(TrainNumberParams::default())?;
+    /// assert_eq!(train.to_chinese(Variant::Simplified), "Z三四七四次");
+    ///
+    /// assert!(generator.train_number(TrainNumberParams::default().with_kinds(vec![])).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn train_number(&self, params: TrainNumberParams) -> ChineseRandResult<TrainNumber> {
+        check_range(&params.number_range)?;
+
+        if params.kinds.is_empty() {
+            return Err(ChineseRandError::InvalidRange("No train kinds".to_string()));
+        }
+
+        let kind_index = self.raw_generator.u64(0..=(params.kinds.len() as u64 - 1)) as usize;
+
+        Ok(TrainNumber {
+            kind: params.kinds[kind_index],
+            number: self.raw_generator.u16(params.number_range),
+            reading: params.reading,
+        })
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{check_range, ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+/// Wraps a [RawGenerator] with cross-cutting behavior - such as logging,
+/// biasing or clamping the draws - much like a middleware.
+///
+/// Layers are applied via [ChineseFormatGenerator::with_layer] and can be
+/// composed: the last applied layer is the outermost one.
+pub trait GeneratorLayer {
+    /// Returns a [RawGenerator] wrapping the given one.
+    fn layer<'a>(&self, inner: Box<dyn RawGenerator + 'a>) -> Box<dyn RawGenerator + 'a>;
+}
+
+impl<'a, R: RawGenerator + 'a> ChineseFormatGenerator<R> {
+    /// Wraps the underlying [RawGenerator] with the given [GeneratorLayer] -
+    /// boxing it, so that layers can be stacked on any generator.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let sink = log.clone();
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    ///     .with_layer(ClampLayer::new(0, 5)?)
+    ///     .with_layer(LoggingLayer::new(move |line| sink.borrow_mut().push(line.to_string())));
+    ///
+    /// for _ in 0..10 {
+    ///     assert!(generator.integer(0..=1000)? <= 5);
+    /// }
+    ///
+    /// assert_eq!(log.borrow().len(), 10);
+    /// assert!(log.borrow()[0].starts_with("i128(0..=1000) -> "));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_layer(
+        self,
+        layer: impl GeneratorLayer,
+    ) -> ChineseFormatGenerator<Box<dyn RawGenerator + 'a>> {
+        ChineseFormatGenerator {
+            raw_generator: layer.layer(Box::new(self.raw_generator)),
+            level: self.level,
+            region: self.region,
+            max_retries: self.max_retries,
+            stats: self.stats,
+            observer: self.observer,
+            config: self.config,
+            #[cfg(feature = "stats")]
+            statistics: self.statistics,
+        }
+    }
+}
+
+macro_rules! delegate_draws {
+    ($wrap: ident, $($method: ident: $type: ty),+) => {
+        $(
+            fn $method(&self, range: RangeInclusive<$type>) -> $type {
+                self.$wrap(stringify!($method), range, |inner, range| inner.$method(range))
+            }
+        )+
+    };
+}
+
+macro_rules! impl_raw_generator {
+    ($generator: ident) => {
+        impl RawGenerator for $generator<'_> {
+            delegate_draws!(
+                draw,
+                u8: u8,
+                u16: u16,
+                u32: u32,
+                u64: u64,
+                u128: u128,
+                i8: i8,
+                i16: i16,
+                i32: i32,
+                i64: i64,
+                i128: i128,
+                f64: f64
+            );
+
+            fn bool(&self) -> bool {
+                self.draw_bool()
+            }
+
+            fn seed(&self, seed: u64) -> ChineseRandResult<()> {
+                self.inner.seed(seed)
+            }
+
+            fn get_seed(&self) -> ChineseRandResult<u64> {
+                self.inner.get_seed()
+            }
+
+            fn fork(&self) -> ChineseRandResult<Box<dyn RawGenerator>> {
+                Ok(self.layer.layer(self.inner.fork()?))
+            }
+        }
+    };
+}
+
+/// [GeneratorLayer] passing a description of every draw -
+/// like `u32(0..=10) -> 7` - to a sink.
+#[derive(Clone)]
+pub struct LoggingLayer {
+    sink: Rc<dyn Fn(&str)>,
+}
+
+impl LoggingLayer {
+    /// Creates a layer passing each description to the given sink.
+    pub fn new(sink: impl Fn(&str) + 'static) -> Self {
+        Self {
+            sink: Rc::new(sink),
+        }
+    }
+
+    /// Creates a layer printing each description to the standard error.
+    pub fn stderr() -> Self {
+        Self::new(|line| eprintln!("{}", line))
+    }
+}
+
+impl GeneratorLayer for LoggingLayer {
+    fn layer<'a>(&self, inner: Box<dyn RawGenerator + 'a>) -> Box<dyn RawGenerator + 'a> {
+        Box::new(LoggingGenerator {
+            inner,
+            layer: self.clone(),
+        })
+    }
+}
+
+struct LoggingGenerator<'a> {
+    inner: Box<dyn RawGenerator + 'a>,
+    layer: LoggingLayer,
+}
+
+impl LoggingGenerator<'_> {
+    fn draw<T: std::fmt::Debug>(
+        &self,
+        method: &str,
+        range: RangeInclusive<T>,
+        generate: impl FnOnce(&dyn RawGenerator, RangeInclusive<T>) -> T,
+    ) -> T {
+        let description = format!("{}({:?})", method, range);
+
+        let value = generate(self.inner.as_ref(), range);
+
+        (self.layer.sink)(&format!("{} -> {:?}", description, value));
+
+        value
+    }
+
+    fn draw_bool(&self) -> bool {
+        let value = self.inner.bool();
+
+        (self.layer.sink)(&format!("bool() -> {}", value));
+
+        value
+    }
+}
+
+impl_raw_generator!(LoggingGenerator);
+
+/// [GeneratorLayer] restricting every requested range to the given bounds -
+/// leaving it unchanged if the two do not overlap.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+///     .with_layer(ClampLayer::new(10, 20)?);
+///
+/// assert!((10..=20).contains(&generator.integer(-1000..=1000)?));
+/// assert!((30..=40).contains(&generator.integer(30..=40)?));
+///
+/// assert_eq!(
+///     ClampLayer::new(20, 10),
+///     Err(ChineseRandError::EmptyRange("20..=10".to_string()))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClampLayer {
+    min: i128,
+    max: i128,
+}
+
+impl ClampLayer {
+    /// Creates a layer clamping the ranges within `min..=max` -
+    /// failing with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if `min` is greater than `max`.
+    pub fn new(min: i128, max: i128) -> ChineseRandResult<Self> {
+        check_range(&(min..=max))?;
+
+        Ok(Self { min, max })
+    }
+}
+
+impl GeneratorLayer for ClampLayer {
+    fn layer<'a>(&self, inner: Box<dyn RawGenerator + 'a>) -> Box<dyn RawGenerator + 'a> {
+        Box::new(ClampGenerator {
+            inner,
+            layer: *self,
+        })
+    }
+}
+
+struct ClampGenerator<'a> {
+    inner: Box<dyn RawGenerator + 'a>,
+    layer: ClampLayer,
+}
+
+trait Clampable: Copy + PartialOrd + Sized {
+    fn clamp_within(range: RangeInclusive<Self>, min: i128, max: i128) -> RangeInclusive<Self>;
+}
+
+macro_rules! impl_clampable {
+    ($($type: ty),+) => {
+        $(
+            impl Clampable for $type {
+                fn clamp_within(range: RangeInclusive<Self>, min: i128, max: i128) -> RangeInclusive<Self> {
+                    let (Some(start), Some(end)) = (
+                        i128::try_from(*range.start()).ok(),
+                        i128::try_from(*range.end()).ok(),
+                    )
+                    else {
+                        return range;
+                    };
+
+                    let (clamped_start, clamped_end) = (start.max(min), end.min(max));
+
+                    if clamped_start > clamped_end {
+                        return range;
+                    }
+
+                    Self::try_from(clamped_start).expect("Within the original range")
+                        ..=Self::try_from(clamped_end).expect("Within the original range")
+                }
+            }
+        )+
+    };
+}
+
+impl_clampable!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Clampable for f64 {
+    fn clamp_within(range: RangeInclusive<Self>, min: i128, max: i128) -> RangeInclusive<Self> {
+        let (clamped_start, clamped_end) =
+            (range.start().max(min as f64), range.end().min(max as f64));
+
+        if clamped_start > clamped_end {
+            range
+        } else {
+            clamped_start..=clamped_end
+        }
+    }
+}
+
+impl ClampGenerator<'_> {
+    fn draw<T: Clampable>(
+        &self,
+        _method: &str,
+        range: RangeInclusive<T>,
+        generate: impl FnOnce(&dyn RawGenerator, RangeInclusive<T>) -> T,
+    ) -> T {
+        generate(
+            self.inner.as_ref(),
+            T::clamp_within(range, self.layer.min, self.layer.max),
+        )
+    }
+
+    fn draw_bool(&self) -> bool {
+        self.inner.bool()
+    }
+}
+
+impl_raw_generator!(ClampGenerator);
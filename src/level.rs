@@ -0,0 +1,126 @@
+use crate::{ChineseRandError, ChineseRandResult};
+use std::{fmt::Debug, ops::RangeInclusive};
+
+#[cfg(feature = "currency")]
+use chinese_format::currency::CurrencyStyle;
+
+/// Course level to which a [ChineseFormatGenerator](crate::ChineseFormatGenerator)
+/// can be tuned via [with_level](crate::ChineseFormatGenerator::with_level).
+///
+/// Once a level is set, the ranges passed to the generator methods
+/// are narrowed to the ones returned by the level - for example,
+/// small integers and whole-yuan prices for beginners, but
+/// 万/亿-scale numbers, seconds and financial currency style
+/// for advanced students.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// assert_eq!(Level::Beginner.integer_range(), 0..=99);
+/// assert_eq!(Level::Advanced.count_range(), 0..=999_999_999_999);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Level {
+    /// Small numbers, whole-yuan prices, no seconds.
+    Beginner,
+
+    /// Numbers up to the thousands, prices with `角` and `分`.
+    Intermediate,
+
+    /// 万/亿-scale numbers, financial currency style, seconds.
+    Advanced,
+}
+
+impl Level {
+    /// The range of the integers - also used for numerators.
+    pub fn integer_range(&self) -> RangeInclusive<i128> {
+        match self {
+            Level::Beginner => 0..=99,
+            Level::Intermediate => -9_999..=9_999,
+            Level::Advanced => -999_999_999_999..=999_999_999_999,
+        }
+    }
+
+    /// The range of the counts.
+    pub fn count_range(&self) -> RangeInclusive<u128> {
+        match self {
+            Level::Beginner => 0..=99,
+            Level::Intermediate => 0..=9_999,
+            Level::Advanced => 0..=999_999_999_999,
+        }
+    }
+
+    /// The range of the fraction denominators.
+    pub fn denominator_range(&self) -> RangeInclusive<u128> {
+        match self {
+            Level::Beginner => 1..=10,
+            Level::Intermediate => 1..=100,
+            Level::Advanced => 1..=10_000,
+        }
+    }
+
+    /// The range of the number of digits after the decimal point.
+    pub fn fractional_length_range(&self) -> RangeInclusive<u8> {
+        match self {
+            Level::Beginner => 0..=1,
+            Level::Intermediate => 0..=2,
+            Level::Advanced => 0..=4,
+        }
+    }
+
+    /// The range of the integer part of currency amounts.
+    pub fn yuan_range(&self) -> RangeInclusive<u64> {
+        match self {
+            Level::Beginner => 0..=100,
+            Level::Intermediate => 0..=10_000,
+            Level::Advanced => 0..=100_000_000,
+        }
+    }
+
+    /// Whether currency amounts must be whole - without `角` and `分`.
+    pub fn whole_yuan(&self) -> bool {
+        *self == Level::Beginner
+    }
+
+    /// The currency style imposed by the level, if any.
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    pub fn currency_style(&self) -> Option<CurrencyStyle> {
+        match self {
+            Level::Advanced => Some(CurrencyStyle::Financial),
+            _ => None,
+        }
+    }
+
+    /// Whether the *second* part of times is imposed (`Some(true)`),
+    /// forbidden (`Some(false)`) or left to the parameters (`None`).
+    pub fn include_second(&self) -> Option<bool> {
+        match self {
+            Level::Beginner => Some(false),
+            Level::Intermediate => None,
+            Level::Advanced => Some(true),
+        }
+    }
+}
+
+/// Narrows `range` so that it fits within `bounds` - failing with
+/// [ChineseRandError::InvalidRange] if the two ranges do not overlap.
+pub(crate) fn narrow<T: Ord + Copy + Debug>(
+    range: RangeInclusive<T>,
+    bounds: RangeInclusive<T>,
+) -> ChineseRandResult<RangeInclusive<T>> {
+    let start = (*range.start()).max(*bounds.start());
+    let end = (*range.end()).min(*bounds.end());
+
+    if start > end {
+        return Err(ChineseRandError::InvalidRange(format!(
+            "{:?} outside {:?}",
+            range, bounds
+        )));
+    }
+
+    Ok(start..=end)
+}
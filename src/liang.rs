@@ -0,0 +1,127 @@
+use crate::{ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use chinese_format::{Chinese, ChineseFormat, CountBase, Variant};
+use std::ops::RangeInclusive;
+
+const LIANG_MAGNITUDES: [char; 6] = ['百', '千', '万', '萬', '亿', '億'];
+
+/// Whether quantities should be phrased with `两` or `二` -
+/// a distinction often drilled in class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TwoPreference {
+    /// `两` - like in `两`, `两百` or `两万三千`.
+    Liang,
+
+    /// `二` - like in `二`, `二百` or `二万三千`.
+    Er,
+
+    /// Either of the two, randomly chosen for each value.
+    Random,
+}
+
+/// Count whose leading 2 is phrased either with `两` or with `二`.
+///
+/// Only the leading digit is affected - when it stands alone or precedes
+/// `百`, `千`, `万` or `亿` - since `二` is the only option elsewhere,
+/// like in `二十` or `一千零二`.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let count = PhrasedCount { value: 2, liang: true };
+/// assert_eq!(count.to_chinese(Variant::Simplified), "两");
+///
+/// let count = PhrasedCount { value: 2, liang: false };
+/// assert_eq!(count.to_chinese(Variant::Simplified), "二");
+///
+/// let count = PhrasedCount { value: 22_000, liang: true };
+/// assert_eq!(count.to_chinese(Variant::Traditional), "兩萬二千");
+///
+/// let count = PhrasedCount { value: 222, liang: true };
+/// assert_eq!(count.to_chinese(Variant::Simplified), "两百二十二");
+///
+/// let count = PhrasedCount { value: 22, liang: true };
+/// assert_eq!(count.to_chinese(Variant::Simplified), "二十二");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PhrasedCount {
+    /// The value.
+    pub value: CountBase,
+
+    /// Whether the leading 2 is phrased with `两`.
+    pub liang: bool,
+}
+
+impl ChineseFormat for PhrasedCount {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let chinese = self.value.to_chinese(variant);
+
+        if !self.liang {
+            return chinese;
+        }
+
+        let mut characters = chinese.logograms.chars();
+
+        let logograms = match (characters.next(), characters.next()) {
+            (Some('二'), second) if second.is_none_or(|unit| LIANG_MAGNITUDES.contains(&unit)) => {
+                let liang = match variant {
+                    Variant::Simplified => "两",
+                    Variant::Traditional => "兩",
+                };
+
+                format!("{}{}", liang, &chinese.logograms['二'.len_utf8()..])
+            }
+
+            _ => chinese.logograms,
+        };
+
+        Chinese {
+            logograms,
+            omissible: chinese.omissible,
+        }
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [PhrasedCount] in the given range - just like
+    /// [count](Self::count) - phrased according to the [TwoPreference].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let liang = generator.phrased_count(200..=299, TwoPreference::Liang)?;
+    /// assert_eq!(liang.to_chinese(Variant::Simplified), "两百五十八");
+    ///
+    /// let er = generator.phrased_count(2..=2, TwoPreference::Er)?;
+    /// assert_eq!(er.to_chinese(Variant::Simplified), "二");
+    ///
+    /// let random = generator.phrased_count(2000..=2999, TwoPreference::Random)?;
+    /// assert_eq!(random.to_chinese(Variant::Simplified), "两千三百四十六");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn phrased_count(
+        &self,
+        range: RangeInclusive<CountBase>,
+        preference: TwoPreference,
+    ) -> ChineseRandResult<PhrasedCount> {
+        let count = self.count(range)?;
+
+        let liang = match preference {
+            TwoPreference::Liang => true,
+            TwoPreference::Er => false,
+            TwoPreference::Random => self.raw_generator.bool(),
+        };
+
+        Ok(PhrasedCount {
+            value: count.0,
+            liang,
+        })
+    }
+}
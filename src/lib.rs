@@ -35,40 +35,221 @@
 //!
 //! - `fastrand`: enables [FastRandGenerator], based on [fastrand]. **Enabled by default**.
 //!
+//! - `arbitrary`: implements [Arbitrary](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html) for the parameter structs, enabling the [fuzzing] module - for structure-aware fuzzing of downstream renderers.
+//!
+//! - `async`: enables the [asynchronous] module, drawing entropy from asynchronous sources.
+//!
+//! - `cli`: builds the `chinese-rand` binary, printing generated values - for example, `chinese-rand --type date --count 20 --seed 90`.
+//!
+//!   _Also enables_: `fastrand`, `currency`, `gregorian`, `serde`.
+//!
+//! - `chrono`: enables conversions between [CalendarDate](gregorian::CalendarDate) and [chrono](https://crates.io/crates/chrono) dates, as well as the generation of dates within a `chrono` range.
+//!
+//!   _Also enables_: `gregorian`.
+//!
 //! - `digit-sequence`: enables random generation of data types - like [Decimal](chinese_format::Decimal) - based on [DigitSequence](digit_sequence::DigitSequence).
 //!
+//! - `ffi`: enables the [ffi] module, providing a C-compatible interface - to embed the crate in native apps, like Swift or Kotlin ones.
+//!
+//!   _Also enables_: `fastrand`, `registry`.
+//!
 //! - `currency`: enables the random generation of data types in the [currency](chinese_format::currency) module.
 //!
 //! - `gregorian`: enables the random generation of data types in the [gregorian](chinese_format::gregorian) module, which is dedicated to dates and times.
 //!
 //!   _Also enables_: `digit-sequence`.
+//!
+//! - `lunar`: enables the [lunar] module, dedicated to the Chinese lunar calendar, solar terms and traditional festivals.
+//!
+//! - `parse`: enables the [parse] module, reading Chinese text back into values - for round-trip tests.
+//!
+//! - `pinyin`: enables the [pinyin] module, transcribing the generated logograms - with tone marks or numbers.
+//!
+//! - `python`: enables the [python] module, providing Python bindings via [pyo3](https://crates.io/crates/pyo3) - to call the same seeded generators from Python.
+//!
+//!   _Also enables_: `fastrand`, `registry`.
+//!
+//! - `registry`: enables [TypeRegistry], generating values by type name - with parameters in JSON.
+//!
+//!   _Also enables_: `serde`.
+//!
+//! - `scenario`: enables the [scenario] module, combining several values into consistent bundles - like receipts.
+//!
+//!   _Also enables_: `currency`, `gregorian`.
+//!
+//! - `secure`: enables [SecureGenerator], a cryptographically secure [RawGenerator] - for masking data that must not be predictable.
+//!
+//! - `serde`: makes the parameter structs - like `DateParams` - serializable and deserializable via [serde](https://crates.io/crates/serde), also enabling the conversion of [GeneratedValue] to JSON.
+//!
+//! - `ssml`: enables the [ssml] module, exporting generated values for speech synthesis.
+//!
+//! - `stats`: records the distribution of the generated values - see [ChineseFormatGenerator::statistics].
+//!
+//! - `testing`: enables the [testing] module, providing golden-file snapshots of generated values and statistical distribution assertions.
+//!
+//!   _Also enables_: `serde`.
 
+mod age;
+mod approximate;
+mod arithmetic;
+#[cfg(feature = "async")]
+pub mod asynchronous;
+mod bilingual;
+mod builder;
+mod bulk;
+mod config;
+mod constraint;
+pub mod corpus;
 #[cfg(feature = "currency")]
 mod currency;
+mod cycle;
+mod difficulty;
 #[cfg(feature = "digit-sequence")]
 mod digit_sequences;
 mod errors;
+pub mod exercise;
+pub mod export;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod financial;
+#[cfg(feature = "arbitrary")]
+pub mod fuzzing;
+pub mod games;
+mod generate;
+mod generated;
+pub mod grading;
 #[cfg(feature = "gregorian")]
 pub mod gregorian;
+pub mod labels;
+mod layers;
+mod level;
+mod liang;
+#[cfg(feature = "lunar")]
+pub mod lunar;
+#[cfg(feature = "digit-sequence")]
+mod measurement;
+mod mixed_number;
+mod mixed_script;
 mod numeric;
+mod ordinal;
+#[cfg(feature = "parse")]
+pub mod parse;
+mod parsing;
+#[cfg(feature = "pinyin")]
+pub mod pinyin;
+mod plan;
+#[cfg(feature = "python")]
+pub mod python;
+mod range_expression;
 mod raw;
+mod region;
+#[cfg(feature = "serde")]
+mod remote;
+mod retries;
+mod rounded_count;
+#[cfg(feature = "scenario")]
+pub mod scenario;
+mod scores;
+mod seeding;
+mod session;
+mod share;
+#[cfg(feature = "ssml")]
+pub mod ssml;
+mod statistics;
+mod streams;
+mod temperature;
+pub mod template;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod tricky;
+#[cfg(feature = "registry")]
+mod type_registry;
+mod zodiac;
 
 use std::error::Error;
 
+pub use age::*;
+pub use approximate::*;
+pub use arithmetic::*;
+pub use bilingual::*;
+pub use builder::*;
+pub use bulk::*;
+pub use config::*;
+pub use constraint::*;
 #[cfg(feature = "currency")]
 pub use currency::*;
+pub use cycle::*;
+pub use difficulty::*;
 pub use errors::*;
+pub use financial::*;
+pub use generate::*;
+pub use generated::*;
+pub use layers::*;
+pub use level::*;
+pub use liang::*;
+#[cfg(feature = "digit-sequence")]
+pub use measurement::*;
+pub use mixed_number::*;
+pub use mixed_script::*;
+pub use numeric::*;
+pub use ordinal::*;
+pub use plan::*;
+pub use range_expression::*;
 pub use raw::*;
+pub use region::*;
+pub use retries::*;
+pub use rounded_count::*;
+pub use scores::*;
+pub use seeding::*;
+pub use session::*;
+pub use share::*;
+#[cfg(feature = "stats")]
+pub use statistics::*;
+pub use streams::*;
+pub use temperature::*;
+pub use tricky::*;
+#[cfg(feature = "registry")]
+pub use type_registry::*;
+pub use zodiac::*;
+
+use retries::EventObserver;
+use std::cell::Cell;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
 
 /// The most generic [Error]-based [Result].
+///
+/// Fallible generator methods return the more specific [ChineseRandResult],
+/// whose [ChineseRandError] can be matched by failure kind.
 pub type GenericResult<T> = Result<T, Box<dyn Error>>;
 
 /// Parametrically generates random instances of the data structures
 /// provided by [chinese_format].
-pub struct ChineseFormatGenerator {
-    pub(crate) raw_generator: Box<dyn RawGenerator>,
+///
+/// By default, the [RawGenerator] is boxed - so that any implementation
+/// can be plugged in via [new](Self::new) without affecting the type;
+/// on the other hand, [from_raw_generator](Self::from_raw_generator)
+/// keeps its concrete type, enabling monomorphized, inline-able
+/// generation in hot paths - even via a borrowed [RawGenerator].
+///
+/// [Sessions](SessionGenerator) require the boxed [RawGenerator], whereas
+/// [layers](GeneratorLayer) box the one they wrap.
+pub struct ChineseFormatGenerator<R: RawGenerator = Box<dyn RawGenerator>> {
+    pub(crate) raw_generator: R,
+    pub(crate) level: Option<Level>,
+    pub(crate) region: Option<RegionProfile>,
+    pub(crate) max_retries: usize,
+    pub(crate) stats: Cell<GenerationStats>,
+    pub(crate) observer: Option<Rc<EventObserver>>,
+    pub(crate) config: GeneratorConfig,
+    #[cfg(feature = "stats")]
+    pub(crate) statistics: std::cell::RefCell<Statistics>,
 }
 
+/// The [ChineseFormatGenerator] backed by a boxed [RawGenerator] -
+/// the default choice, named explicitly.
+pub type BoxedChineseFormatGenerator = ChineseFormatGenerator<Box<dyn RawGenerator>>;
+
 impl ChineseFormatGenerator {
     /// Creating a [ChineseFormatGenerator] requires an object
     /// implementing the [RawGenerator] interface.
@@ -76,6 +257,7 @@ impl ChineseFormatGenerator {
     /// ```
     /// use chinese_rand::*;
     ///
+    /// # fn main() -> GenericResult<()> {
     /// // Now setting the random seed just in order to
     /// //predict the generated values
     /// fastrand::seed(90);
@@ -83,12 +265,128 @@ impl ChineseFormatGenerator {
     /// let raw_generator = FastRandGenerator::new();
     /// let generator = ChineseFormatGenerator::new(raw_generator);
     ///
-    /// let integer = generator.integer(i128::MIN..=i128::MAX);
+    /// let integer = generator.integer(i128::MIN..=i128::MAX)?;
     /// assert_eq!(integer, -139744823884027955216713073977120108615);
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn new(raw_generator: impl RawGenerator + 'static) -> Self {
+        Self::from_raw_generator(Box::new(raw_generator))
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Creates a [ChineseFormatGenerator] keeping the concrete type
+    /// of its [RawGenerator] - which can also be borrowed.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let raw_generator = FastRandGenerator::with_seed(90);
+    ///
+    /// let generator: ChineseFormatGenerator<&FastRandGenerator> =
+    ///     ChineseFormatGenerator::from_raw_generator(&raw_generator);
+    /// assert_eq!(generator.integer(0..=99)?, 58);
+    ///
+    /// let same_sequence = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// assert_eq!(same_sequence.integer(0..=99)?, 58);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_raw_generator(raw_generator: R) -> Self {
         Self {
-            raw_generator: Box::new(raw_generator),
+            raw_generator,
+            level: None,
+            region: None,
+            max_retries: DEFAULT_MAX_RETRIES,
+            stats: Cell::new(GenerationStats::default()),
+            observer: None,
+            config: GeneratorConfig::default(),
+            #[cfg(feature = "stats")]
+            statistics: Default::default(),
+        }
+    }
+
+    /// Tunes all the generator methods for the given [Level],
+    /// by narrowing the ranges they receive - a range lying entirely
+    /// outside the one of the level making the generation fail with
+    /// [ChineseRandError::InvalidRange].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new())
+    ///     .with_level(Level::Beginner);
+    /// assert_eq!(generator.level(), Some(Level::Beginner));
+    ///
+    /// let integer = generator.integer(i128::MIN..=i128::MAX)?;
+    /// assert_eq!(integer, 58);
+    ///
+    /// assert_eq!(
+    ///     generator.integer(500..=600),
+    ///     Err(ChineseRandError::InvalidRange("500..=600 outside 0..=99".to_string()))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// The [Level] currently set, if any.
+    pub fn level(&self) -> Option<Level> {
+        self.level
+    }
+
+    /// Tunes the generator methods for the given [RegionProfile] -
+    /// steering the choices not imposed by their parameters.
+    ///
+    /// ```
+    /// # #[cfg(feature = "gregorian")]
+    /// # {
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, gregorian::DatePattern};
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    ///     .with_region(RegionProfile::HongKong);
+    /// assert_eq!(generator.region(), Some(RegionProfile::HongKong));
+    ///
+    /// let region = generator.region().unwrap();
+    ///
+    /// let date = generator.gregorian().date(
+    ///     DateParams::default().with_pattern(DatePattern::YearMonthDayWeekDay)
+    /// ).unwrap();
+    /// assert_eq!(date.to_chinese(region.variant()), "二零三六年五月二十三日禮拜一");
+    ///
+    /// let time = generator.gregorian().linear_time(LinearTimeParams::default()).unwrap();
+    /// assert_eq!(time.to_chinese(region.variant()), "中午十一點十二分");
+    /// # }
+    /// ```
+    pub fn with_region(mut self, region: RegionProfile) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// The [RegionProfile] currently set, if any.
+    pub fn region(&self) -> Option<RegionProfile> {
+        self.region
+    }
+
+    /// Narrows the given range to the one selected from the current [Level], if any -
+    /// failing with [ChineseRandError::InvalidRange] if the two ranges do not overlap.
+    pub(crate) fn leveled<T: Ord + Copy + std::fmt::Debug>(
+        &self,
+        range: RangeInclusive<T>,
+        level_range: impl Fn(&Level) -> RangeInclusive<T>,
+    ) -> ChineseRandResult<RangeInclusive<T>> {
+        match &self.level {
+            Some(level) => level::narrow(range, level_range(level)),
+            None => Ok(range),
         }
     }
 }
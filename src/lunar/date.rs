@@ -0,0 +1,366 @@
+use super::LunarGenerator;
+use crate::{ChineseRandResult, RawGenerator, Zodiac};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, EmptyPlaceholder, Variant};
+
+const HEAVENLY_STEMS: [&str; 10] = ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
+
+const EARTHLY_BRANCHES: [&str; 12] = [
+    "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
+];
+
+/// Year of the sexagenary cycle (干支纪年) - such as `甲辰年`.
+///
+/// ```
+/// use chinese_rand::{*, lunar::*};
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let year = SexagenaryYear::from_gregorian_year(2024);
+///
+/// assert_eq!(year.to_chinese(Variant::Simplified), "甲辰年");
+/// assert_eq!(year.zodiac(), Zodiac::Dragon);
+///
+/// assert_eq!(SexagenaryYear::try_new(60), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SexagenaryYear(u8);
+
+impl SexagenaryYear {
+    /// Creates a year from its 0-based position in the cycle - 0 being `甲子`.
+    pub fn try_new(index: u8) -> Option<Self> {
+        (index < 60).then_some(Self(index))
+    }
+
+    /// The lunar year mostly overlapping the given Gregorian year.
+    pub fn from_gregorian_year(year: u16) -> Self {
+        Self((year as i32 - 4).rem_euclid(60) as u8)
+    }
+
+    /// The 0-based position in the cycle.
+    pub fn index(&self) -> u8 {
+        self.0
+    }
+
+    /// The zodiac animal of the year.
+    pub fn zodiac(&self) -> Zodiac {
+        Zodiac::ALL[(self.0 % 12) as usize]
+    }
+}
+
+impl ChineseFormat for SexagenaryYear {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                HEAVENLY_STEMS[(self.0 % 10) as usize],
+                EARTHLY_BRANCHES[(self.0 % 12) as usize],
+                "年"
+            ]
+        )
+        .collect()
+    }
+}
+
+/// Month of the lunar calendar - from `正月` to `腊月`, possibly leap (`闰`).
+///
+/// ```
+/// use chinese_rand::lunar::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let month = LunarMonth::try_new(1, false).unwrap();
+/// assert_eq!(month.to_chinese(Variant::Simplified), "正月");
+///
+/// let month = LunarMonth::try_new(4, true).unwrap();
+/// assert_eq!(month.to_chinese(Variant::Traditional), "閏四月");
+///
+/// let month = LunarMonth::try_new(12, false).unwrap();
+/// assert_eq!(month.to_chinese(Variant::Traditional), "臘月");
+///
+/// assert_eq!(LunarMonth::try_new(13, false), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LunarMonth {
+    ordinal: u8,
+    leap: bool,
+}
+
+impl LunarMonth {
+    /// Creates a month - whose ordinal must be in the 1..=12 range.
+    pub fn try_new(ordinal: u8, leap: bool) -> Option<Self> {
+        (1..=12)
+            .contains(&ordinal)
+            .then_some(Self { ordinal, leap })
+    }
+
+    /// The ordinal, in the 1..=12 range.
+    pub fn ordinal(&self) -> u8 {
+        self.ordinal
+    }
+
+    /// Whether it is an intercalary month.
+    pub fn is_leap(&self) -> bool {
+        self.leap
+    }
+}
+
+impl ChineseFormat for LunarMonth {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let leap: &dyn ChineseFormat = if self.leap { &("闰", "閏") } else { &"" };
+
+        let name = match self.ordinal {
+            1 => "正月".to_chinese(variant),
+            11 => "冬月".to_chinese(variant),
+            12 => ("腊月", "臘月").to_chinese(variant),
+            _ => chinese_vec!(variant, [self.ordinal, "月"]).collect(),
+        };
+
+        chinese_vec!(variant, [leap, name.logograms]).collect()
+    }
+}
+
+/// Day of a lunar month - from `初一` to `三十`.
+///
+/// ```
+/// use chinese_rand::lunar::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// assert_eq!(LunarDay::try_new(5).unwrap().to_chinese(Variant::Simplified), "初五");
+/// assert_eq!(LunarDay::try_new(10).unwrap().to_chinese(Variant::Simplified), "初十");
+/// assert_eq!(LunarDay::try_new(15).unwrap().to_chinese(Variant::Simplified), "十五");
+/// assert_eq!(LunarDay::try_new(20).unwrap().to_chinese(Variant::Simplified), "二十");
+/// assert_eq!(LunarDay::try_new(23).unwrap().to_chinese(Variant::Simplified), "廿三");
+/// assert_eq!(LunarDay::try_new(30).unwrap().to_chinese(Variant::Simplified), "三十");
+///
+/// assert_eq!(LunarDay::try_new(0), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LunarDay(u8);
+
+impl LunarDay {
+    /// Creates a day - whose ordinal must be in the 1..=30 range.
+    pub fn try_new(ordinal: u8) -> Option<Self> {
+        (1..=30).contains(&ordinal).then_some(Self(ordinal))
+    }
+
+    /// The ordinal, in the 1..=30 range.
+    pub fn ordinal(&self) -> u8 {
+        self.0
+    }
+}
+
+impl ChineseFormat for LunarDay {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self.0 {
+            1..=10 => chinese_vec!(variant, ["初", self.0]).collect(),
+            21..=29 => chinese_vec!(variant, ["廿", self.0 - 20]).collect(),
+            _ => self.0.to_chinese(variant),
+        }
+    }
+}
+
+/// Date of the lunar calendar - such as `农历甲辰年正月初五`.
+///
+/// ```
+/// use chinese_rand::lunar::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let date = LunarDate {
+///     year: Some(SexagenaryYear::from_gregorian_year(2024)),
+///     month: LunarMonth::try_new(8, false).unwrap(),
+///     day: LunarDay::try_new(15).unwrap()
+/// };
+/// assert_eq!(date.to_chinese(Variant::Simplified), "农历甲辰年八月十五");
+/// assert_eq!(date.to_chinese(Variant::Traditional), "農曆甲辰年八月十五");
+///
+/// let date = LunarDate {
+///     year: None,
+///     month: LunarMonth::try_new(1, false).unwrap(),
+///     day: LunarDay::try_new(1).unwrap()
+/// };
+/// assert_eq!(date.to_chinese(Variant::Simplified), "农历正月初一");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LunarDate {
+    /// The year, if in the format.
+    pub year: Option<SexagenaryYear>,
+
+    /// The month.
+    pub month: LunarMonth,
+
+    /// The day of the month.
+    pub day: LunarDay,
+}
+
+impl ChineseFormat for LunarDate {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                ("农历", "農曆"),
+                EmptyPlaceholder::new(&self.year),
+                self.month,
+                self.day
+            ]
+        )
+        .collect()
+    }
+}
+
+/// Parameters for the random creation of [LunarDate].
+///
+/// Since no astronomical tables are involved, the generated dates
+/// are not checked against actual years: for example, day `三十`
+/// can appear in any month, even though only long months have it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LunarDateParams {
+    /// Whether the sexagenary year is in the format.
+    pub include_year: bool,
+
+    /// Whether intercalary (`闰`) months can be generated.
+    pub allow_leap_month: bool,
+}
+
+/// By default, [LunarDateParams] describes month and day, without leap months.
+///
+/// ```
+/// use chinese_rand::lunar::*;
+///
+/// assert_eq!(LunarDateParams::default(), LunarDateParams {
+///     include_year: false,
+///     allow_leap_month: false
+/// });
+///
+/// let customized = LunarDateParams::default()
+///     .with_year(true)
+///     .with_leap_month(true);
+///
+/// assert_eq!(customized, LunarDateParams {
+///     include_year: true,
+///     allow_leap_month: true
+/// });
+/// ```
+impl Default for LunarDateParams {
+    fn default() -> Self {
+        Self {
+            include_year: false,
+            allow_leap_month: false,
+        }
+    }
+}
+
+impl LunarDateParams {
+    /// Sets whether the sexagenary year is in the format.
+    pub fn with_year(mut self, include_year: bool) -> Self {
+        self.include_year = include_year;
+        self
+    }
+
+    /// Sets whether intercalary months can be generated.
+    pub fn with_leap_month(mut self, allow_leap_month: bool) -> Self {
+        self.allow_leap_month = allow_leap_month;
+        self
+    }
+}
+
+impl<'a, R: RawGenerator> LunarGenerator<'a, R> {
+    /// Generates a random [SexagenaryYear].
+    ///
+    /// ```
+    /// use chinese_rand::{*, lunar::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let lunar = generator.lunar();
+    ///
+    /// fastrand::seed(90);
+    /// let year = lunar.year();
+    /// assert_eq!(year.to_chinese(Variant::Simplified), "乙巳年");
+    /// assert_eq!(year.zodiac(), Zodiac::Snake);
+    /// ```
+    pub fn year(&self) -> SexagenaryYear {
+        SexagenaryYear(self.raw_generator.u8(0..=59))
+    }
+
+    /// Generates a random [LunarMonth] - leap months being
+    /// drawn with probability 1/13 when allowed.
+    ///
+    /// ```
+    /// use chinese_rand::{*, lunar::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let lunar = generator.lunar();
+    ///
+    /// fastrand::seed(90);
+    /// let month = lunar.month(false);
+    /// assert_eq!(month.to_chinese(Variant::Simplified), "九月");
+    /// ```
+    pub fn month(&self, allow_leap: bool) -> LunarMonth {
+        let ordinal = self.raw_generator.u8(1..=12);
+
+        let leap = allow_leap && self.raw_generator.u8(0..=12) == 0;
+
+        LunarMonth { ordinal, leap }
+    }
+
+    /// Generates a random [LunarDay].
+    ///
+    /// ```
+    /// use chinese_rand::{*, lunar::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let lunar = generator.lunar();
+    ///
+    /// fastrand::seed(90);
+    /// let day = lunar.day();
+    /// assert_eq!(day.to_chinese(Variant::Simplified), "廿一");
+    /// ```
+    pub fn day(&self) -> LunarDay {
+        LunarDay(self.raw_generator.u8(1..=30))
+    }
+
+    /// Generates a random [LunarDate], given the provided [LunarDateParams].
+    ///
+    /// ```
+    /// use chinese_rand::{*, lunar::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let lunar = generator.lunar();
+    ///
+    /// fastrand::seed(90);
+    /// let date = lunar.date(LunarDateParams {
+    ///     include_year: true,
+    ///     allow_leap_month: false
+    /// })?;
+    /// assert_eq!(date.to_chinese(Variant::Simplified), "农历乙巳年五月廿二");
+    ///
+    /// fastrand::seed(90);
+    /// let date = lunar.date(LunarDateParams {
+    ///     include_year: false,
+    ///     allow_leap_month: true
+    /// })?;
+    /// assert_eq!(date.to_chinese(Variant::Traditional), "農曆九月廿二");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn date(&self, params: LunarDateParams) -> ChineseRandResult<LunarDate> {
+        let year = if params.include_year {
+            Some(self.year())
+        } else {
+            None
+        };
+
+        Ok(LunarDate {
+            year,
+            month: self.month(params.allow_leap_month),
+            day: self.day(),
+        })
+    }
+}
@@ -0,0 +1,53 @@
+//! Module supporting the random generation of dates
+//! according to the Chinese lunar calendar (农历),
+//! as well as of solar terms (节气) and traditional festivals.
+//!
+//! Since [chinese_format] does not provide lunar types,
+//! this module defines its own - all implementing [ChineseFormat](chinese_format::ChineseFormat).
+//!
+//! **Required feature**: `lunar`.
+mod date;
+mod terms;
+
+pub use date::*;
+pub use terms::*;
+
+use crate::{ChineseFormatGenerator, RawGenerator};
+
+/// Random generator dedicated to the concepts of the Chinese lunar calendar.
+///
+/// Just like [GregorianGenerator](crate::gregorian::GregorianGenerator),
+/// it must be created via the [ChineseFormatGenerator::lunar] method
+/// and it just keeps a reference to the [RawGenerator].
+pub struct LunarGenerator<'a, R: RawGenerator = Box<dyn RawGenerator>> {
+    raw_generator: &'a R,
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Creates a reusable [LunarGenerator] instance, for generating
+    /// values according to the Chinese lunar calendar.
+    ///
+    /// ```
+    /// use chinese_rand::{*, lunar::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let lunar = generator.lunar();
+    ///
+    /// fastrand::seed(90);
+    /// let date = lunar.date(LunarDateParams::default()).unwrap();
+    /// assert_eq!(date.to_chinese(Variant::Simplified), "农历九月十一");
+    ///
+    /// fastrand::seed(90);
+    /// let festival = lunar.festival();
+    /// assert_eq!(festival.to_chinese(Variant::Simplified), "中秋节");
+    /// ```
+    ///
+    /// **Required feature**: `lunar`.
+    pub fn lunar(&self) -> LunarGenerator<'_, R> {
+        LunarGenerator {
+            raw_generator: &self.raw_generator,
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use super::{LunarDate, LunarDay, LunarGenerator, LunarMonth};
+use crate::RawGenerator;
+use chinese_format::{Chinese, ChineseFormat, Variant};
+
+/// The 24 solar terms (二十四节气), in yearly order starting from `立春`.
+///
+/// ```
+/// use chinese_rand::lunar::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// assert_eq!(SolarTerm::StartOfSpring.to_chinese(Variant::Simplified), "立春");
+/// assert_eq!(SolarTerm::AwakeningOfInsects.to_chinese(Variant::Traditional), "驚蟄");
+/// assert_eq!(SolarTerm::ALL.len(), 24);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SolarTerm {
+    StartOfSpring,
+    RainWater,
+    AwakeningOfInsects,
+    SpringEquinox,
+    PureBrightness,
+    GrainRain,
+    StartOfSummer,
+    GrainBuds,
+    GrainInEar,
+    SummerSolstice,
+    MinorHeat,
+    MajorHeat,
+    StartOfAutumn,
+    EndOfHeat,
+    WhiteDew,
+    AutumnEquinox,
+    ColdDew,
+    FrostDescent,
+    StartOfWinter,
+    MinorSnow,
+    MajorSnow,
+    WinterSolstice,
+    MinorCold,
+    MajorCold,
+}
+
+impl SolarTerm {
+    /// All the solar terms, in yearly order.
+    pub const ALL: [SolarTerm; 24] = [
+        SolarTerm::StartOfSpring,
+        SolarTerm::RainWater,
+        SolarTerm::AwakeningOfInsects,
+        SolarTerm::SpringEquinox,
+        SolarTerm::PureBrightness,
+        SolarTerm::GrainRain,
+        SolarTerm::StartOfSummer,
+        SolarTerm::GrainBuds,
+        SolarTerm::GrainInEar,
+        SolarTerm::SummerSolstice,
+        SolarTerm::MinorHeat,
+        SolarTerm::MajorHeat,
+        SolarTerm::StartOfAutumn,
+        SolarTerm::EndOfHeat,
+        SolarTerm::WhiteDew,
+        SolarTerm::AutumnEquinox,
+        SolarTerm::ColdDew,
+        SolarTerm::FrostDescent,
+        SolarTerm::StartOfWinter,
+        SolarTerm::MinorSnow,
+        SolarTerm::MajorSnow,
+        SolarTerm::WinterSolstice,
+        SolarTerm::MinorCold,
+        SolarTerm::MajorCold,
+    ];
+}
+
+impl ChineseFormat for SolarTerm {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            SolarTerm::StartOfSpring => "立春".to_chinese(variant),
+            SolarTerm::RainWater => "雨水".to_chinese(variant),
+            SolarTerm::AwakeningOfInsects => ("惊蛰", "驚蟄").to_chinese(variant),
+            SolarTerm::SpringEquinox => "春分".to_chinese(variant),
+            SolarTerm::PureBrightness => "清明".to_chinese(variant),
+            SolarTerm::GrainRain => ("谷雨", "穀雨").to_chinese(variant),
+            SolarTerm::StartOfSummer => "立夏".to_chinese(variant),
+            SolarTerm::GrainBuds => ("小满", "小滿").to_chinese(variant),
+            SolarTerm::GrainInEar => ("芒种", "芒種").to_chinese(variant),
+            SolarTerm::SummerSolstice => "夏至".to_chinese(variant),
+            SolarTerm::MinorHeat => "小暑".to_chinese(variant),
+            SolarTerm::MajorHeat => "大暑".to_chinese(variant),
+            SolarTerm::StartOfAutumn => "立秋".to_chinese(variant),
+            SolarTerm::EndOfHeat => ("处暑", "處暑").to_chinese(variant),
+            SolarTerm::WhiteDew => "白露".to_chinese(variant),
+            SolarTerm::AutumnEquinox => "秋分".to_chinese(variant),
+            SolarTerm::ColdDew => "寒露".to_chinese(variant),
+            SolarTerm::FrostDescent => "霜降".to_chinese(variant),
+            SolarTerm::StartOfWinter => "立冬".to_chinese(variant),
+            SolarTerm::MinorSnow => "小雪".to_chinese(variant),
+            SolarTerm::MajorSnow => "大雪".to_chinese(variant),
+            SolarTerm::WinterSolstice => "冬至".to_chinese(variant),
+            SolarTerm::MinorCold => "小寒".to_chinese(variant),
+            SolarTerm::MajorCold => "大寒".to_chinese(variant),
+        }
+    }
+}
+
+/// Traditional festivals having a fixed lunar date.
+///
+/// ```
+/// use chinese_rand::lunar::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let festival = Festival::MidAutumn;
+///
+/// assert_eq!(festival.to_chinese(Variant::Simplified), "中秋节");
+/// assert_eq!(festival.to_chinese(Variant::Traditional), "中秋節");
+/// assert_eq!(festival.date().to_chinese(Variant::Simplified), "农历八月十五");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Festival {
+    SpringFestival,
+    Lantern,
+    DragonBoat,
+    Qixi,
+    MidAutumn,
+    DoubleNinth,
+    Laba,
+}
+
+impl Festival {
+    /// All the festivals, in yearly order.
+    pub const ALL: [Festival; 7] = [
+        Festival::SpringFestival,
+        Festival::Lantern,
+        Festival::DragonBoat,
+        Festival::Qixi,
+        Festival::MidAutumn,
+        Festival::DoubleNinth,
+        Festival::Laba,
+    ];
+
+    /// The lunar date of the festival - without year.
+    pub fn date(&self) -> LunarDate {
+        let (month, day) = match self {
+            Festival::SpringFestival => (1, 1),
+            Festival::Lantern => (1, 15),
+            Festival::DragonBoat => (5, 5),
+            Festival::Qixi => (7, 7),
+            Festival::MidAutumn => (8, 15),
+            Festival::DoubleNinth => (9, 9),
+            Festival::Laba => (12, 8),
+        };
+
+        LunarDate {
+            year: None,
+            month: LunarMonth::try_new(month, false).expect("Month valid by construction"),
+            day: LunarDay::try_new(day).expect("Day valid by construction"),
+        }
+    }
+}
+
+impl ChineseFormat for Festival {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            Festival::SpringFestival => ("春节", "春節").to_chinese(variant),
+            Festival::Lantern => ("元宵节", "元宵節").to_chinese(variant),
+            Festival::DragonBoat => ("端午节", "端午節").to_chinese(variant),
+            Festival::Qixi => ("七夕节", "七夕節").to_chinese(variant),
+            Festival::MidAutumn => ("中秋节", "中秋節").to_chinese(variant),
+            Festival::DoubleNinth => ("重阳节", "重陽節").to_chinese(variant),
+            Festival::Laba => ("腊八节", "臘八節").to_chinese(variant),
+        }
+    }
+}
+
+impl<'a, R: RawGenerator> LunarGenerator<'a, R> {
+    /// Generates a random [SolarTerm].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let lunar = generator.lunar();
+    ///
+    /// fastrand::seed(90);
+    /// let term = lunar.solar_term();
+    /// assert_eq!(term.to_chinese(Variant::Simplified), "寒露");
+    /// ```
+    pub fn solar_term(&self) -> SolarTerm {
+        SolarTerm::ALL[self.raw_generator.u8(0..=23) as usize]
+    }
+
+    /// Generates a random [Festival] - whose lunar date is available
+    /// via [Festival::date].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    /// let lunar = generator.lunar();
+    ///
+    /// fastrand::seed(90);
+    /// let festival = lunar.festival();
+    /// assert_eq!(festival.to_chinese(Variant::Simplified), "中秋节");
+    /// assert_eq!(festival.date().to_chinese(Variant::Simplified), "农历八月十五");
+    /// ```
+    pub fn festival(&self) -> Festival {
+        Festival::ALL[self.raw_generator.u8(0..=6) as usize]
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{check_range, ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Count, Decimal, Variant};
+use digit_sequence::DigitSequence;
+use std::{iter::repeat_with, ops::RangeInclusive};
+
+/// Unit of a [Measurement].
+///
+/// **Required feature**: `digit-sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum MeasureUnit {
+    Millimeter,
+    Centimeter,
+    Meter,
+    Kilometer,
+    Gram,
+    HalfKilogram,
+    Kilogram,
+    Milliliter,
+    Liter,
+}
+
+impl ChineseFormat for MeasureUnit {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            MeasureUnit::Millimeter => "毫米".to_chinese(variant),
+            MeasureUnit::Centimeter => ("厘米", "釐米").to_chinese(variant),
+            MeasureUnit::Meter => "米".to_chinese(variant),
+            MeasureUnit::Kilometer => "公里".to_chinese(variant),
+            MeasureUnit::Gram => "克".to_chinese(variant),
+            MeasureUnit::HalfKilogram => "斤".to_chinese(variant),
+            MeasureUnit::Kilogram => "公斤".to_chinese(variant),
+            MeasureUnit::Milliliter => "毫升".to_chinese(variant),
+            MeasureUnit::Liter => "升".to_chinese(variant),
+        }
+    }
+}
+
+/// Physical quantity - a value followed by its [MeasureUnit],
+/// such as `一点七五米` or `两公斤`.
+///
+/// Whole values are rendered as counts - hence `两` instead of `二`.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Decimal, Variant};
+///
+/// let height = Measurement {
+///     value: Decimal { integer: 1, fractional: 75u8.into() },
+///     unit: MeasureUnit::Meter
+/// };
+/// assert_eq!(height.to_chinese(Variant::Simplified), "一点七五米");
+///
+/// let weight = Measurement {
+///     value: Decimal { integer: 2, fractional: Default::default() },
+///     unit: MeasureUnit::Kilogram
+/// };
+/// assert_eq!(weight.to_chinese(Variant::Traditional), "兩公斤");
+/// ```
+///
+/// **Required feature**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Measurement {
+    /// The numeric value.
+    pub value: Decimal,
+
+    /// The unit of measurement.
+    pub unit: MeasureUnit,
+}
+
+impl ChineseFormat for Measurement {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let value = if self.value.fractional.is_empty() && self.value.integer >= 0 {
+            Count(self.value.integer as u128).to_chinese(variant)
+        } else {
+            self.value.to_chinese(variant)
+        };
+
+        chinese_vec!(variant, [value.logograms, self.unit]).collect()
+    }
+}
+
+/// Parameters for the random creation of [Measurement].
+///
+/// **Required feature**: `digit-sequence`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct MeasurementParams {
+    /// The unit of measurement.
+    pub unit: MeasureUnit,
+
+    /// The range of the integer part of the value.
+    pub value_range: RangeInclusive<u32>,
+
+    /// The exact number of digits after the decimal point - the last one
+    /// never being zero, as in actual measurements.
+    pub decimal_places: u8,
+}
+
+/// By default, [MeasurementParams] describes whole meters from 1 to 10.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// assert_eq!(MeasurementParams::default(), MeasurementParams {
+///     unit: MeasureUnit::Meter,
+///     value_range: 1..=10,
+///     decimal_places: 0
+/// });
+///
+/// let customized = MeasurementParams::default()
+///     .with_unit(MeasureUnit::Liter)
+///     .with_value_range(0..=3)
+///     .with_decimal_places(1);
+///
+/// assert_eq!(customized, MeasurementParams {
+///     unit: MeasureUnit::Liter,
+///     value_range: 0..=3,
+///     decimal_places: 1
+/// });
+/// ```
+///
+/// **Required feature**: `digit-sequence`.
+impl Default for MeasurementParams {
+    fn default() -> Self {
+        Self {
+            unit: MeasureUnit::Meter,
+            value_range: 1..=10,
+            decimal_places: 0,
+        }
+    }
+}
+
+impl MeasurementParams {
+    /// Sets the unit of measurement.
+    pub fn with_unit(mut self, unit: MeasureUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Sets the range of the integer part.
+    pub fn with_value_range(mut self, value_range: RangeInclusive<u32>) -> Self {
+        self.value_range = value_range;
+        self
+    }
+
+    /// Sets the number of digits after the decimal point.
+    pub fn with_decimal_places(mut self, decimal_places: u8) -> Self {
+        self.decimal_places = decimal_places;
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [Measurement], given the provided [MeasurementParams].
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    ///
+    /// let height = generator.measurement(MeasurementParams {
+    ///     unit: MeasureUnit::Meter,
+    ///     value_range: 1..=1,
+    ///     decimal_places: 2
+    /// })?;
+    /// assert_eq!(height.to_chinese(Variant::Simplified), "一点三七米");
+    ///
+    /// let distance = generator.measurement(MeasurementParams {
+    ///     unit: MeasureUnit::Kilometer,
+    ///     value_range: 1..=500,
+    ///     decimal_places: 0
+    /// })?;
+    /// assert_eq!(distance.to_chinese(Variant::Simplified), "一百四十三公里");
+    ///
+    /// let weight = generator.measurement(MeasurementParams {
+    ///     unit: MeasureUnit::HalfKilogram,
+    ///     value_range: 0..=5,
+    ///     decimal_places: 1
+    /// })?;
+    /// assert_eq!(weight.to_chinese(Variant::Traditional), "二點二斤");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `digit-sequence`.
+    pub fn measurement(&self, params: MeasurementParams) -> ChineseRandResult<Measurement> {
+        check_range(&params.value_range)?;
+
+        let integer = self.raw_generator.u32(params.value_range);
+
+        let mut digits: Vec<u8> = repeat_with(|| self.raw_generator.u8(0..=9))
+            .take(params.decimal_places.saturating_sub(1) as usize)
+            .collect();
+
+        if params.decimal_places > 0 {
+            digits.push(self.raw_generator.u8(1..=9));
+        }
+
+        let fractional: DigitSequence = digits.try_into().expect("Digits valid by construction");
+
+        Ok(Measurement {
+            value: Decimal {
+                integer: integer as i128,
+                fractional,
+            },
+            unit: params.unit,
+        })
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{
+    check_range, ChineseFormatGenerator, ChineseRandResult, FractionParams, InvalidLowerBound,
+    RawGenerator,
+};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Fraction, Variant};
+use std::ops::RangeInclusive;
+
+/// Integer part followed by a proper fraction - such as `三又四分之一`.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Fraction, Variant};
+///
+/// let mixed = MixedNumber {
+///     integer: 3,
+///     fraction: Fraction::try_new(4, 1).unwrap(),
+/// };
+/// assert_eq!(mixed.to_chinese(Variant::Simplified), "三又四分之一");
+///
+/// let mixed = MixedNumber {
+///     integer: -2,
+///     fraction: Fraction::try_new(3, 2).unwrap(),
+/// };
+/// assert_eq!(mixed.to_chinese(Variant::Simplified), "负二又三分之二");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MixedNumber {
+    /// The integer part, also carrying the sign.
+    pub integer: i128,
+
+    /// The fractional part - expected to be proper and positive.
+    pub fraction: Fraction,
+}
+
+impl ChineseFormat for MixedNumber {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.integer, "又", self.fraction]).collect()
+    }
+}
+
+/// Parameters for the random creation of [MixedNumber].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct MixedNumberParams {
+    /// The range of the integer part.
+    pub integer_range: RangeInclusive<i128>,
+
+    /// The range of the denominator - whose lower bound must be at least 2.
+    pub denominator_range: RangeInclusive<u128>,
+
+    /// Whether the fractional part must be reduced.
+    pub reduced_only: bool,
+}
+
+/// By default, [MixedNumberParams] describes an integer part
+/// between 1 and 10, followed by a reduced fraction whose
+/// denominator is between 2 and 10.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// assert_eq!(MixedNumberParams::default(), MixedNumberParams {
+///     integer_range: 1..=10,
+///     denominator_range: 2..=10,
+///     reduced_only: true
+/// });
+///
+/// let customized = MixedNumberParams::default()
+///     .with_integer_range(-5..=5)
+///     .with_denominator_range(2..=4)
+///     .with_reduced_only(false);
+///
+/// assert_eq!(customized, MixedNumberParams {
+///     integer_range: -5..=5,
+///     denominator_range: 2..=4,
+///     reduced_only: false
+/// });
+/// ```
+impl Default for MixedNumberParams {
+    fn default() -> Self {
+        Self {
+            integer_range: 1..=10,
+            denominator_range: 2..=10,
+            reduced_only: true,
+        }
+    }
+}
+
+impl MixedNumberParams {
+    /// Sets the range of the integer part.
+    pub fn with_integer_range(mut self, integer_range: RangeInclusive<i128>) -> Self {
+        self.integer_range = integer_range;
+        self
+    }
+
+    /// Sets the range of the denominator.
+    pub fn with_denominator_range(mut self, denominator_range: RangeInclusive<u128>) -> Self {
+        self.denominator_range = denominator_range;
+        self
+    }
+
+    /// Sets whether the fractional part must be reduced.
+    pub fn with_reduced_only(mut self, reduced_only: bool) -> Self {
+        self.reduced_only = reduced_only;
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [MixedNumber].
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if either range is empty, and with [ChineseRandError::InvalidRange](crate::ChineseRandError::InvalidRange)
+    /// if the lower bound of the denominator is less than 2.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// let mixed = generator.mixed_number(MixedNumberParams::default())?;
+    /// assert_eq!(mixed.to_chinese(Variant::Simplified), "六又七分之三");
+    ///
+    /// let mixed = generator.mixed_number(MixedNumberParams::default())?;
+    /// assert_eq!(mixed.to_chinese(Variant::Simplified), "五又九分之八");
+    ///
+    /// let invalid = generator.mixed_number(
+    ///     MixedNumberParams::default().with_denominator_range(1..=10)
+    /// );
+    /// assert_eq!(
+    ///     invalid,
+    ///     Err(ChineseRandError::InvalidRange("Invalid lower bound: 1".to_string()))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mixed_number(&self, params: MixedNumberParams) -> ChineseRandResult<MixedNumber> {
+        check_range(&params.integer_range)?;
+        check_range(&params.denominator_range)?;
+
+        let denominator_start = *params.denominator_range.start();
+
+        if denominator_start < 2 {
+            return Err(InvalidLowerBound(denominator_start).into());
+        }
+
+        let integer = self.integer(params.integer_range)?;
+
+        let fraction = self.fraction_with(
+            FractionParams::default()
+                .with_denominator_range(params.denominator_range)
+                .with_numerator_range(1..=i128::MAX)
+                .with_proper_only(true)
+                .with_reduced_only(params.reduced_only),
+        )?;
+
+        Ok(MixedNumber { integer, fraction })
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use chinese_format::{ChineseFormat, Variant};
+
+/// A generated value rendered in a randomly chosen [Variant] -
+/// reported alongside the rendering, for mixed-script reading practice.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::Variant;
+///
+/// let rendering = MixedScriptRendering::new(("个", "個"), Variant::Traditional);
+///
+/// assert_eq!(rendering.variant, Variant::Traditional);
+/// assert_eq!(rendering.rendered, "個");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MixedScriptRendering<T> {
+    /// The generated value.
+    pub value: T,
+
+    /// The variant chosen for the rendering.
+    pub variant: Variant,
+
+    /// The rendering in the chosen variant.
+    pub rendered: String,
+}
+
+impl<T: ChineseFormat> MixedScriptRendering<T> {
+    /// Renders the given value in the given variant.
+    pub fn new(value: T, variant: Variant) -> Self {
+        let rendered = value.to_chinese(variant).to_string();
+
+        Self {
+            value,
+            variant,
+            rendered,
+        }
+    }
+}
+
+/// Wraps a [ChineseFormatGenerator] so that every generated value
+/// is returned as a [MixedScriptRendering] - in a variant drawn per item.
+///
+/// It must be created via [ChineseFormatGenerator::rendered_mixed].
+pub struct MixedScriptGenerator<'a, R: RawGenerator = Box<dyn RawGenerator>> {
+    generator: &'a ChineseFormatGenerator<R>,
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [Variant] - both being equally likely.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::Variant;
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let traditional = (0..1000)
+    ///     .filter(|_| generator.variant() == Variant::Traditional)
+    ///     .count();
+    /// assert_eq!(traditional, 502);
+    /// ```
+    pub fn variant(&self) -> Variant {
+        if self.raw_generator.bool() {
+            Variant::Traditional
+        } else {
+            Variant::Simplified
+        }
+    }
+
+    /// Creates a [MixedScriptGenerator] - for mixed-script reading practice.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::Variant;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let renderings = (0..4)
+    ///     .map(|_| generator.rendered_mixed().try_render(|generator| generator.count(0..=100_000)))
+    ///     .collect::<ChineseRandResult<Vec<_>>>()?;
+    ///
+    /// let traced: Vec<(Variant, &str)> = renderings
+    ///     .iter()
+    ///     .map(|rendering| (rendering.variant, rendering.rendered.as_str()))
+    ///     .collect();
+    /// assert_eq!(
+    ///     traced,
+    ///     [
+    ///         (Variant::Simplified, "五万八千九百三十三"),
+    ///         (Variant::Simplified, "六万四千二百三十九"),
+    ///         (Variant::Traditional, "四萬五千五百四十七"),
+    ///         (Variant::Traditional, "一萬零九百三十三")
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rendered_mixed(&self) -> MixedScriptGenerator<'_, R> {
+        MixedScriptGenerator { generator: self }
+    }
+}
+
+impl<R: RawGenerator> MixedScriptGenerator<'_, R> {
+    /// Renders the value returned by any generator method in a random variant -
+    /// drawn after the value itself.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::Variant;
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let rendering = generator
+    ///     .rendered_mixed()
+    ///     .render(|generator| generator.ordinal(1..=10, OrdinalSuffix::Ge).unwrap());
+    /// assert_eq!(rendering.variant, Variant::Simplified);
+    /// assert_eq!(rendering.rendered, "第六个");
+    /// ```
+    pub fn render<T: ChineseFormat>(
+        &self,
+        generate: impl FnOnce(&ChineseFormatGenerator<R>) -> T,
+    ) -> MixedScriptRendering<T> {
+        let value = generate(self.generator);
+
+        MixedScriptRendering::new(value, self.generator.variant())
+    }
+
+    /// Like [render](Self::render), but for fallible generator methods.
+    pub fn try_render<T: ChineseFormat>(
+        &self,
+        generate: impl FnOnce(&ChineseFormatGenerator<R>) -> ChineseRandResult<T>,
+    ) -> ChineseRandResult<MixedScriptRendering<T>> {
+        let value = generate(self.generator)?;
+
+        Ok(MixedScriptRendering::new(value, self.generator.variant()))
+    }
+}
@@ -1,31 +1,149 @@
-use crate::{ChineseFormatGenerator, InvalidLowerBound};
+use crate::{
+    check_range, statistics::magnitude_bucket, ChineseFormatGenerator, ChineseRandResult,
+    InvalidLowerBound, Level, RawGenerator,
+};
 use chinese_format::{Count, CountBase, Fraction};
 use std::ops::RangeInclusive;
 
-impl ChineseFormatGenerator {
+/// Parameters for the random creation of [Fraction].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct FractionParams {
+    /// The range of the denominator - whose lower bound cannot be 0.
+    pub denominator_range: RangeInclusive<u128>,
+
+    /// The range of the numerator.
+    pub numerator_range: RangeInclusive<i128>,
+
+    /// Whether fractions like `七分之零` should be discarded.
+    pub forbid_zero_numerator: bool,
+
+    /// Whether the absolute value of the numerator must be less than the denominator.
+    pub proper_only: bool,
+
+    /// Whether numerator and denominator must be coprime - discarding, for example, `四分之二`.
+    pub reduced_only: bool,
+}
+
+/// By default, [FractionParams] describes any fraction
+/// having both components between 1 and 10.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// assert_eq!(FractionParams::default(), FractionParams {
+///     denominator_range: 1..=10,
+///     numerator_range: 1..=10,
+///     forbid_zero_numerator: false,
+///     proper_only: false,
+///     reduced_only: false
+/// });
+///
+/// let customized = FractionParams::default()
+///     .with_denominator_range(2..=5)
+///     .with_numerator_range(-5..=5)
+///     .with_forbid_zero_numerator(true)
+///     .with_proper_only(true)
+///     .with_reduced_only(true);
+///
+/// assert_eq!(customized, FractionParams {
+///     denominator_range: 2..=5,
+///     numerator_range: -5..=5,
+///     forbid_zero_numerator: true,
+///     proper_only: true,
+///     reduced_only: true
+/// });
+/// ```
+impl Default for FractionParams {
+    fn default() -> Self {
+        Self {
+            denominator_range: 1..=10,
+            numerator_range: 1..=10,
+            forbid_zero_numerator: false,
+            proper_only: false,
+            reduced_only: false,
+        }
+    }
+}
+
+impl FractionParams {
+    /// Sets the range of the denominator.
+    pub fn with_denominator_range(mut self, denominator_range: RangeInclusive<u128>) -> Self {
+        self.denominator_range = denominator_range;
+        self
+    }
+
+    /// Sets the range of the numerator.
+    pub fn with_numerator_range(mut self, numerator_range: RangeInclusive<i128>) -> Self {
+        self.numerator_range = numerator_range;
+        self
+    }
+
+    /// Sets whether a zero numerator should be discarded.
+    pub fn with_forbid_zero_numerator(mut self, forbid_zero_numerator: bool) -> Self {
+        self.forbid_zero_numerator = forbid_zero_numerator;
+        self
+    }
+
+    /// Sets whether only proper fractions should be generated.
+    pub fn with_proper_only(mut self, proper_only: bool) -> Self {
+        self.proper_only = proper_only;
+        self
+    }
+
+    /// Sets whether only reduced fractions should be generated.
+    pub fn with_reduced_only(mut self, reduced_only: bool) -> Self {
+        self.reduced_only = reduced_only;
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
     /// Generates a random [i128] in the given range.
     ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty - for example, inverted.
+    ///
     /// ```
     /// use chinese_rand::*;
     ///
+    /// # fn main() -> GenericResult<()> {
     /// fastrand::seed(90);
     /// let raw_generator = FastRandGenerator::new();
     /// let generator = ChineseFormatGenerator::new(raw_generator);
     ///
-    /// let integer = generator.integer(i128::MIN..=i128::MAX);
+    /// let integer = generator.integer(i128::MIN..=i128::MAX)?;
     /// assert_eq!(integer, -139744823884027955216713073977120108615);
     ///
-    /// let fixed = generator.integer(90..=90);
+    /// let fixed = generator.integer(90..=90)?;
     /// assert_eq!(fixed, 90);
+    ///
+    /// let inverted = generator.integer(10..=1);
+    /// assert_eq!(
+    ///     inverted,
+    ///     Err(ChineseRandError::EmptyRange("10..=1".to_string()))
+    /// );
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn integer(&self, range: RangeInclusive<i128>) -> i128 {
-        self.raw_generator.i128(range)
+    pub fn integer(&self, range: RangeInclusive<i128>) -> ChineseRandResult<i128> {
+        check_range(&range)?;
+
+        let integer = self
+            .raw_generator
+            .i128(self.leveled(range, Level::integer_range)?);
+
+        self.record("integer", || magnitude_bucket(integer));
+
+        Ok(integer)
     }
 
     /// Generates a [Fraction] having its components in the given ranges.
     ///
-    /// The lower bound for the denominator cannot be 0, or the function
-    /// will fail with [InvalidLowerBound].
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if either range is empty; furthermore, the lower bound for the
+    /// denominator cannot be 0, or the function will fail with [ChineseRandError::InvalidRange](crate::ChineseRandError::InvalidRange).
     ///
     /// ```
     /// use chinese_rand::*;
@@ -57,7 +175,13 @@ impl ChineseFormatGenerator {
     /// let fraction_result = generator.fraction(0..=5, 4..=4);
     /// assert_eq!(
     ///     fraction_result,
-    ///     Err(InvalidLowerBound(0))
+    ///     Err(ChineseRandError::InvalidRange("Invalid lower bound: 0".to_string()))
+    /// );
+    ///
+    /// let inverted_result = generator.fraction(5..=1, 4..=4);
+    /// assert_eq!(
+    ///     inverted_result,
+    ///     Err(ChineseRandError::EmptyRange("5..=1".to_string()))
     /// );
     ///
     /// # Ok(())
@@ -67,38 +191,148 @@ impl ChineseFormatGenerator {
         &self,
         denominator_range: RangeInclusive<u128>,
         numerator_range: RangeInclusive<i128>,
-    ) -> Result<Fraction, InvalidLowerBound<u128>> {
-        if *denominator_range.start() == 0 {
-            return Err(InvalidLowerBound(0));
+    ) -> ChineseRandResult<Fraction> {
+        self.fraction_with(FractionParams {
+            denominator_range,
+            numerator_range,
+            ..FractionParams::default()
+        })
+    }
+
+    /// Generates a [Fraction] according to the given [FractionParams] -
+    /// for example, to obtain only the fractions a textbook would show.
+    ///
+    /// Fails like [fraction](Self::fraction); additionally, fails with
+    /// [ChineseRandError::GenerationExhausted](crate::ChineseRandError::GenerationExhausted)
+    /// when no fraction satisfying the options is found within the
+    /// [retry budget](Self::with_max_retries).
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// let params = FractionParams::default()
+    ///     .with_denominator_range(2..=10)
+    ///     .with_numerator_range(0..=10)
+    ///     .with_forbid_zero_numerator(true)
+    ///     .with_proper_only(true)
+    ///     .with_reduced_only(true);
+    ///
+    /// let fraction = generator.fraction_with(params.clone())?;
+    /// assert_eq!(fraction.to_chinese(Variant::Simplified), "七分之四");
+    ///
+    /// let fraction = generator.fraction_with(params)?;
+    /// assert_eq!(fraction.to_chinese(Variant::Simplified), "五分之二");
+    ///
+    /// let impossible = generator.fraction_with(
+    ///     FractionParams::default()
+    ///         .with_denominator_range(4..=4)
+    ///         .with_numerator_range(2..=2)
+    ///         .with_reduced_only(true)
+    /// );
+    /// assert!(impossible.is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fraction_with(&self, params: FractionParams) -> ChineseRandResult<Fraction> {
+        check_range(&params.denominator_range)?;
+        check_range(&params.numerator_range)?;
+
+        if *params.denominator_range.start() == 0 {
+            return Err(InvalidLowerBound(0).into());
         }
 
-        let denominator = self.raw_generator.u128(denominator_range);
+        let denominator_range = self.leveled(params.denominator_range, Level::denominator_range)?;
+        let numerator_range = self.leveled(params.numerator_range, Level::integer_range)?;
+
+        let fraction = self.retrying("Fraction", || {
+            let denominator = self.raw_generator.u128(denominator_range.clone());
+
+            let actual_numerator_range = if params.proper_only {
+                let bound = (denominator - 1).min(i128::MAX as u128) as i128;
+
+                let start = (*numerator_range.start()).max(-bound);
+                let end = (*numerator_range.end()).min(bound);
 
-        let numerator = self.raw_generator.i128(numerator_range);
+                if start > end {
+                    return None;
+                }
 
-        Ok(
-            Fraction::try_new(denominator, numerator)
-                .expect("Denominator non-zero by construction"),
-        )
+                start..=end
+            } else {
+                numerator_range.clone()
+            };
+
+            let numerator = self.raw_generator.i128(actual_numerator_range);
+
+            if params.forbid_zero_numerator && numerator == 0 {
+                return None;
+            }
+
+            if params.reduced_only && gcd(numerator.unsigned_abs(), denominator) != 1 {
+                return None;
+            }
+
+            Some(
+                Fraction::try_new(denominator, numerator)
+                    .expect("Denominator non-zero by construction"),
+            )
+        })?;
+
+        self.record("fraction", || fraction.denominator().to_string());
+
+        Ok(fraction)
     }
 
     /// Generates a random [Count] in the given range.
     ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
     /// ```
     /// use chinese_rand::*;
     /// use chinese_format::{Count, CountBase};
     ///
+    /// # fn main() -> GenericResult<()> {
     /// fastrand::seed(90);
     /// let raw_generator = FastRandGenerator::new();
     /// let generator = ChineseFormatGenerator::new(raw_generator);
     ///
-    /// let count = generator.count(0..=CountBase::MAX);
+    /// let count = generator.count(0..=CountBase::MAX)?;
     /// assert_eq!(count, Count(200537543036910508246661533454648102841));
     ///
-    /// let fixed = generator.count(90..=90);
+    /// let fixed = generator.count(90..=90)?;
     /// assert_eq!(fixed, Count(90));
+    ///
+    /// assert!(generator.count(90..=1).is_err());
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn count(&self, range: RangeInclusive<CountBase>) -> Count {
-        Count(self.raw_generator.u128(range))
+    pub fn count(&self, range: RangeInclusive<CountBase>) -> ChineseRandResult<Count> {
+        check_range(&range)?;
+
+        let count = Count(
+            self.raw_generator
+                .u128(self.leveled(range, Level::count_range)?),
+        );
+
+        self.record("count", || {
+            magnitude_bucket(count.0.min(i128::MAX as u128) as i128)
+        });
+
+        Ok(count)
     }
 }
+
+/// Greatest common divisor - where `gcd(n, 0) == n`.
+pub(crate) fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
@@ -0,0 +1,92 @@
+use crate::{check_range, ChineseFormatGenerator, ChineseRandResult, Level, RawGenerator};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Variant};
+use std::ops::RangeInclusive;
+
+/// Measure word optionally following an [Ordinal].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrdinalSuffix {
+    /// No suffix - as in `第三`.
+    Bare,
+
+    /// For rankings - as in `第三名`.
+    Ming,
+
+    /// Generic measure word - as in `第三个`.
+    Ge,
+}
+
+/// Ordinal number - such as `第二` or `第十五名`.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let ordinal = Ordinal { value: 2, suffix: OrdinalSuffix::Bare };
+/// assert_eq!(ordinal.to_chinese(Variant::Simplified), "第二");
+///
+/// let ordinal = Ordinal { value: 15, suffix: OrdinalSuffix::Ming };
+/// assert_eq!(ordinal.to_chinese(Variant::Simplified), "第十五名");
+///
+/// let ordinal = Ordinal { value: 3, suffix: OrdinalSuffix::Ge };
+/// assert_eq!(ordinal.to_chinese(Variant::Traditional), "第三個");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ordinal {
+    /// The position.
+    pub value: u128,
+
+    /// The measure word following the number.
+    pub suffix: OrdinalSuffix,
+}
+
+impl ChineseFormat for Ordinal {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let suffix: &dyn ChineseFormat = match self.suffix {
+            OrdinalSuffix::Bare => &"",
+            OrdinalSuffix::Ming => &"名",
+            OrdinalSuffix::Ge => &("个", "個"),
+        };
+
+        chinese_vec!(variant, ["第", self.value, suffix]).collect()
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [Ordinal] in the given range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    ///
+    /// let ordinal = generator.ordinal(1..=20, OrdinalSuffix::Bare)?;
+    /// assert_eq!(ordinal.to_chinese(Variant::Simplified), "第十二");
+    ///
+    /// let ordinal = generator.ordinal(1..=10, OrdinalSuffix::Ming)?;
+    /// assert_eq!(ordinal.to_chinese(Variant::Simplified), "第七名");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ordinal(
+        &self,
+        range: RangeInclusive<u128>,
+        suffix: OrdinalSuffix,
+    ) -> ChineseRandResult<Ordinal> {
+        check_range(&range)?;
+
+        Ok(Ordinal {
+            value: self
+                .raw_generator
+                .u128(self.leveled(range, Level::count_range)?),
+            suffix,
+        })
+    }
+}
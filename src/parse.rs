@@ -0,0 +1,191 @@
+//! Reading Chinese text back into values of the categories generated by
+//! this crate - mainly to support round-trip tests, where a value is
+//! generated, rendered, parsed and compared to the original.
+//!
+//! Every parser is lenient, accepting both simplified and traditional
+//! logograms, financial numerals and Arabic numerals; it returns [None]
+//! when the text cannot be read.
+//!
+//! **Required feature**: `parse`.
+use crate::parsing::{parse_fraction, parse_integer};
+use chinese_format::Fraction;
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::CalendarDate;
+#[cfg(feature = "gregorian")]
+use crate::parsing::{parse_date, parse_time};
+#[cfg(feature = "gregorian")]
+use chinese_format::gregorian::LinearTime;
+
+#[cfg(feature = "currency")]
+use crate::parsing::parse_renminbi_cents;
+#[cfg(feature = "currency")]
+use chinese_format::currency::{CurrencyStyle, RenminbiCurrency};
+
+/// Reads an integer.
+///
+/// **Required feature**: `parse`.
+///
+/// ```
+/// use chinese_rand::parse;
+///
+/// assert_eq!(parse::integer("三百二十"), Some(320));
+/// assert_eq!(parse::integer("負一億零一十"), Some(-100_000_010));
+/// assert_eq!(parse::integer("壹仟零贰"), Some(1002));
+/// assert_eq!(parse::integer("十二京三千四百五十六兆"), Some(12_3456_0000_0000_0000));
+/// assert_eq!(parse::integer("1,024"), Some(1024));
+/// assert_eq!(parse::integer("三百个"), None);
+/// ```
+pub fn integer(text: &str) -> Option<i128> {
+    parse_integer(text)
+}
+
+/// Reads a [Fraction] - written in Chinese or as `numerator/denominator`.
+///
+/// **Required feature**: `parse`.
+///
+/// ```
+/// use chinese_rand::parse;
+/// use chinese_format::Fraction;
+///
+/// assert_eq!(parse::fraction("六分之七"), Fraction::try_new(6, 7).ok());
+/// assert_eq!(parse::fraction("負三分之十一"), Fraction::try_new(3, -11).ok());
+/// assert_eq!(parse::fraction("7/6"), Fraction::try_new(6, 7).ok());
+/// assert_eq!(parse::fraction("零分之七"), None);
+/// assert_eq!(parse::fraction("七"), None);
+/// ```
+pub fn fraction(text: &str) -> Option<Fraction> {
+    let (denominator, numerator) = parse_fraction(text)?;
+
+    Fraction::try_new(denominator, numerator).ok()
+}
+
+/// Reads a [CalendarDate] - like `二零二五年八月四日`, `2025年8月4日`
+/// or `2025-08-04`.
+///
+/// **Required features**: `parse`, `gregorian`.
+///
+/// ```
+/// use chinese_rand::{gregorian::CalendarDate, parse};
+///
+/// assert_eq!(
+///     parse::calendar_date("二零二五年八月四日"),
+///     CalendarDate::try_new(2025, 8, 4)
+/// );
+/// assert_eq!(
+///     parse::calendar_date("2024/2/29"),
+///     CalendarDate::try_new(2024, 2, 29)
+/// );
+/// assert_eq!(parse::calendar_date("2025年2月29日"), None);
+/// ```
+#[cfg(feature = "gregorian")]
+pub fn calendar_date(text: &str) -> Option<CalendarDate> {
+    let (year, month, day) = parse_date(text)?;
+
+    CalendarDate::try_new(year, month, day)
+}
+
+/// Reads a [LinearTime] - like `傍晚七点二十四分`, `二十二点四十八分三十七秒`
+/// or `7:24 PM`.
+///
+/// The [day_part](LinearTime::day_part) is set when the text mentions it;
+/// the [second](LinearTime::second) only when the text includes it.
+///
+/// **Required features**: `parse`, `gregorian`.
+///
+/// ```
+/// use chinese_rand::parse;
+/// use chinese_format::gregorian::LinearTime;
+///
+/// # fn main() -> chinese_rand::GenericResult<()> {
+/// assert_eq!(
+///     parse::linear_time("傍晚七点二十四分"),
+///     Some(LinearTime {
+///         day_part: true,
+///         hour: 19.try_into()?,
+///         minute: 24.try_into()?,
+///         second: None
+///     })
+/// );
+///
+/// assert_eq!(
+///     parse::linear_time("二十二點四十八分三十七秒"),
+///     Some(LinearTime {
+///         day_part: false,
+///         hour: 22.try_into()?,
+///         minute: 48.try_into()?,
+///         second: Some(37.try_into()?)
+///     })
+/// );
+///
+/// assert_eq!(parse::linear_time("二十五点"), None);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "gregorian")]
+pub fn linear_time(text: &str) -> Option<LinearTime> {
+    let reading = parse_time(text)?;
+
+    Some(LinearTime {
+        day_part: reading.day_part,
+        hour: reading.hour.try_into().ok()?,
+        minute: reading.minute.try_into().ok()?,
+        second: reading
+            .second
+            .map(|second| second.try_into())
+            .transpose()
+            .ok()?,
+    })
+}
+
+/// Reads a [RenminbiCurrency] - like `十二元五角`, `十二块五毛`,
+/// `拾贰元伍角整` or `¥12.50`.
+///
+/// The [CurrencyStyle] is inferred from the text: financial numerals
+/// or a final `整` denote [Financial](CurrencyStyle::Financial), while `块` and `毛`
+/// denote the informal [Everyday](CurrencyStyle::Everyday) register.
+///
+/// **Required features**: `parse`, `currency`.
+///
+/// ```
+/// use chinese_rand::parse;
+/// use chinese_format::currency::CurrencyStyle;
+///
+/// let amount = parse::renminbi("十二块五毛").unwrap();
+/// assert_eq!(amount.yuan(), 12);
+/// assert_eq!(amount.dimes(), 5);
+/// assert_eq!(amount.cents(), 0);
+/// assert_eq!(amount.style(), CurrencyStyle::Everyday { formal: false });
+///
+/// let amount = parse::renminbi("壹仟零贰元贰角贰分整").unwrap();
+/// assert_eq!(amount.yuan(), 1002);
+/// assert_eq!(amount.dimes(), 2);
+/// assert_eq!(amount.cents(), 2);
+/// assert_eq!(amount.style(), CurrencyStyle::Financial);
+///
+/// let amount = parse::renminbi("¥0.03").unwrap();
+/// assert_eq!(amount.cents(), 3);
+/// assert_eq!(amount.style(), CurrencyStyle::Everyday { formal: true });
+///
+/// assert!(parse::renminbi("十二美元").is_none());
+/// ```
+#[cfg(feature = "currency")]
+pub fn renminbi(text: &str) -> Option<RenminbiCurrency> {
+    let cents = parse_renminbi_cents(text)?;
+
+    Some(crate::renminbi_from_cents(cents, currency_style(text)))
+}
+
+#[cfg(feature = "currency")]
+fn currency_style(text: &str) -> CurrencyStyle {
+    if text.contains([
+        '壹', '贰', '貳', '叁', '參', '肆', '伍', '陆', '陸', '柒', '捌', '玖', '拾', '佰', '仟',
+    ]) || text.trim_end().ends_with('整')
+    {
+        CurrencyStyle::Financial
+    } else if text.contains(['块', '塊', '毛']) {
+        CurrencyStyle::Everyday { formal: false }
+    } else {
+        CurrencyStyle::Everyday { formal: true }
+    }
+}
@@ -0,0 +1,389 @@
+//! Lenient readers of numbers written in Arabic numerals, Chinese
+//! numerals - including the financial ones - or a mix of both.
+
+const SMALL_UNITS: [(char, u128); 6] = [
+    ('十', 10),
+    ('拾', 10),
+    ('百', 100),
+    ('佰', 100),
+    ('千', 1000),
+    ('仟', 1000),
+];
+
+/// Units grouping 4 digits each - as rendered by [chinese_format] -
+/// with their exponent.
+const LARGE_UNITS: [(char, u32); 15] = [
+    ('万', 4),
+    ('萬', 4),
+    ('亿', 8),
+    ('億', 8),
+    ('兆', 12),
+    ('京', 16),
+    ('垓', 20),
+    ('秭', 24),
+    ('穰', 28),
+    ('沟', 32),
+    ('溝', 32),
+    ('涧', 36),
+    ('澗', 36),
+    ('载', 44),
+    ('載', 44),
+];
+
+const NEGATIVE_SIGNS: [&str; 4] = ["负", "負", "-", "−"];
+
+/// The value of a single digit - Arabic, Chinese or financial.
+pub(crate) fn digit_value(character: char) -> Option<u8> {
+    Some(match character {
+        '0' | '零' | '〇' => 0,
+        '1' | '一' | '壹' | '幺' => 1,
+        '2' | '二' | '两' | '兩' | '贰' | '貳' => 2,
+        '3' | '三' | '叁' | '參' => 3,
+        '4' | '四' | '肆' => 4,
+        '5' | '五' | '伍' => 5,
+        '6' | '六' | '陆' | '陸' => 6,
+        '7' | '七' | '柒' => 7,
+        '8' | '八' | '捌' => 8,
+        '9' | '九' | '玖' => 9,
+        _ => return None,
+    })
+}
+
+/// Trims the text, removing inner whitespace and thousands separators,
+/// converting full-width ASCII characters and lowercasing.
+pub(crate) fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|character| !character.is_whitespace() && !matches!(character, ',' | '，'))
+        .map(|character| match character {
+            '！'..='～' => char::from_u32(character as u32 - 0xFEE0).unwrap_or(character),
+            _ => character,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+enum Token {
+    Digits(u128),
+    Unit(u128),
+    LargeUnit(u32),
+}
+
+/// Reads a non-negative integer - like `320`, `三百二十`, `叁佰贰拾`,
+/// `3百2十`, `一万亿` or, digit by digit, `二零二五`.
+pub(crate) fn parse_magnitude(text: &str) -> Option<u128> {
+    let mut tokens: Vec<Token> = Vec::new();
+
+    for character in text.chars() {
+        if let Some(digit) = digit_value(character) {
+            match tokens.last_mut() {
+                Some(Token::Digits(value)) => {
+                    *value = value.checked_mul(10)?.checked_add(digit as u128)?;
+                }
+
+                _ => tokens.push(Token::Digits(digit as u128)),
+            }
+        } else if let Some((_, unit)) = SMALL_UNITS.iter().find(|(unit, _)| *unit == character) {
+            tokens.push(Token::Unit(*unit));
+        } else if let Some((_, exponent)) = LARGE_UNITS.iter().find(|(unit, _)| *unit == character)
+        {
+            tokens.push(Token::LargeUnit(*exponent));
+        } else {
+            return None;
+        }
+    }
+
+    let mut total: u128 = 0;
+    let mut section: u128 = 0;
+    let mut number: Option<u128> = None;
+    let mut last_exponent: Option<u32> = None;
+
+    for token in tokens {
+        match token {
+            Token::Digits(value) => number = Some(value),
+
+            Token::Unit(unit) => {
+                section = section.checked_add(number.take().unwrap_or(1).checked_mul(unit)?)?;
+            }
+
+            Token::LargeUnit(exponent) => {
+                let group = section.checked_add(number.take().unwrap_or(0))?;
+                let multiplier = 10u128.checked_pow(exponent)?;
+
+                // A larger unit after a smaller one - like in 一万亿 -
+                // multiplies everything read so far
+                total = if last_exponent.is_some_and(|last| last < exponent) {
+                    total.checked_add(group)?.checked_mul(multiplier)?
+                } else {
+                    total.checked_add(group.max(1).checked_mul(multiplier)?)?
+                };
+
+                section = 0;
+                last_exponent = Some(exponent);
+            }
+        }
+    }
+
+    total.checked_add(section)?.checked_add(number.unwrap_or(0))
+}
+
+fn strip_negative_sign(text: &str) -> (bool, &str) {
+    NEGATIVE_SIGNS
+        .iter()
+        .find_map(|sign| text.strip_prefix(sign))
+        .map_or((false, text), |body| (true, body))
+}
+
+/// Reads an integer - like [parse_magnitude], but supporting negative signs.
+pub(crate) fn parse_integer(text: &str) -> Option<i128> {
+    let text = normalize(text);
+
+    let (negative, body) = strip_negative_sign(&text);
+
+    if body.is_empty() {
+        return None;
+    }
+
+    let magnitude: i128 = parse_magnitude(body)?.try_into().ok()?;
+
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+/// Reads a fraction as `(denominator, numerator)` - like `三分之二`,
+/// `负三分之十一` or `-11/3`.
+#[cfg(feature = "parse")]
+pub(crate) fn parse_fraction(text: &str) -> Option<(u128, i128)> {
+    let text = normalize(text);
+
+    let (negative, body) = strip_negative_sign(&text);
+
+    let (denominator, numerator) = match body.split_once("分之") {
+        Some((denominator, numerator)) => (denominator, numerator),
+        None => body
+            .split_once('/')
+            .map(|(numerator, denominator)| (denominator, numerator))?,
+    };
+
+    let denominator = parse_magnitude(denominator)?;
+    let numerator: i128 = parse_magnitude(numerator)?.try_into().ok()?;
+
+    (denominator > 0).then_some((denominator, if negative { -numerator } else { numerator }))
+}
+
+/// Reads a date as `(year, month, day)` - like `2025-08-04`, `2025/8/4`,
+/// `2025.8.4`, `2025年8月4日` or `二零二五年八月四号`.
+#[cfg(feature = "gregorian")]
+pub(crate) fn parse_date(text: &str) -> Option<(u16, u8, u8)> {
+    let text = normalize(text);
+
+    let components: Vec<&str> = if text.contains('年') {
+        text.trim_end_matches(['日', '号', '號'])
+            .split(['年', '月'])
+            .collect()
+    } else {
+        text.split(['-', '/', '.']).collect()
+    };
+
+    let [year, month, day] = components.as_slice() else {
+        return None;
+    };
+
+    Some((
+        parse_magnitude(year)?.try_into().ok()?,
+        parse_magnitude(month)?.try_into().ok()?,
+        parse_magnitude(day)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(feature = "gregorian")]
+const DAY_PARTS: [(&str, DayPartShift); 13] = [
+    ("午夜", DayPartShift::Midnight),
+    ("半夜", DayPartShift::Midnight),
+    ("深夜", DayPartShift::Night),
+    ("凌晨", DayPartShift::Night),
+    ("早上", DayPartShift::Morning),
+    ("早晨", DayPartShift::Morning),
+    ("上午", DayPartShift::Morning),
+    ("中午", DayPartShift::Noon),
+    ("下午", DayPartShift::Afternoon),
+    ("傍晚", DayPartShift::Afternoon),
+    ("晚上", DayPartShift::Afternoon),
+    ("夜里", DayPartShift::Afternoon),
+    ("夜裡", DayPartShift::Afternoon),
+];
+
+#[cfg(feature = "gregorian")]
+#[derive(Clone, Copy)]
+enum DayPartShift {
+    Morning,
+    Midnight,
+    Night,
+    Noon,
+    Afternoon,
+}
+
+/// Time of the day read from text.
+#[cfg(feature = "gregorian")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ClockReading {
+    /// The hour, in 24-hour format.
+    pub hour: u8,
+    pub minute: u8,
+    pub second: Option<u8>,
+
+    /// Whether the text mentioned the day part - like `下午` or `PM`.
+    pub day_part: bool,
+}
+
+/// Reads a time of the day - like `16:20`, `4:20:05 PM`, `下午四点二十分`,
+/// `十六点二十分五秒`, `四点半` or `四点一刻`.
+#[cfg(feature = "gregorian")]
+pub(crate) fn parse_time(text: &str) -> Option<ClockReading> {
+    let text = normalize(text);
+
+    let (hour, minute, second, shift) = if let Some((hour, rest)) = text.split_once(':') {
+        let (rest, shift) = if let Some(rest) = rest.strip_suffix("pm") {
+            (rest, Some(DayPartShift::Afternoon))
+        } else if let Some(rest) = rest.strip_suffix("am") {
+            (rest, Some(DayPartShift::Night))
+        } else {
+            (rest, None)
+        };
+
+        let (minute, second) = match rest.split_once(':') {
+            Some((minute, second)) => (minute, Some(parse_magnitude(second)?)),
+            None => (rest, None),
+        };
+
+        (
+            parse_magnitude(hour)?,
+            parse_magnitude(minute)?,
+            second,
+            shift,
+        )
+    } else {
+        let (text, shift) = DAY_PARTS
+            .iter()
+            .find_map(|(prefix, shift)| text.strip_prefix(prefix).map(|text| (text, Some(*shift))))
+            .unwrap_or((text.as_str(), None));
+
+        let (hour, rest) = text.split_once(['点', '點', '时', '時'])?;
+
+        let rest = rest.trim_end_matches(['钟', '鐘']);
+
+        let (rest, second) = match rest.strip_suffix('秒') {
+            Some(rest) => {
+                let (minute, second) = rest.split_once('分').unwrap_or(("", rest));
+                (minute, Some(parse_magnitude(second)?))
+            }
+
+            None => (rest.strip_suffix('分').unwrap_or(rest), None),
+        };
+
+        let minute = match rest {
+            "" | "整" => 0,
+            "半" => 30,
+            "一刻" => 15,
+            "三刻" => 45,
+            _ => parse_magnitude(rest)?,
+        };
+
+        (parse_magnitude(hour)?, minute, second, shift)
+    };
+
+    let hour: u8 = hour.try_into().ok()?;
+    let minute: u8 = minute.try_into().ok()?;
+    let second: Option<u8> = second.map(u8::try_from).transpose().ok()?;
+
+    let hour = shift.map_or(hour, |shift| shift_hour(hour, shift));
+
+    (hour < 24 && minute < 60 && second.is_none_or(|second| second < 60)).then_some(ClockReading {
+        hour,
+        minute,
+        second,
+        day_part: shift.is_some(),
+    })
+}
+
+#[cfg(feature = "gregorian")]
+fn shift_hour(hour: u8, shift: DayPartShift) -> u8 {
+    match shift {
+        DayPartShift::Morning => hour,
+        DayPartShift::Midnight if hour == 11 => 23,
+        DayPartShift::Midnight | DayPartShift::Night if hour == 12 => 0,
+        DayPartShift::Midnight => hour,
+        DayPartShift::Night => hour,
+        DayPartShift::Noon if hour < 11 => hour + 12,
+        DayPartShift::Noon => hour,
+        DayPartShift::Afternoon if hour < 12 => hour + 12,
+        DayPartShift::Afternoon => hour,
+    }
+}
+
+#[cfg(feature = "currency")]
+const YUAN_MARKERS: [char; 5] = ['元', '圆', '圓', '块', '塊'];
+
+/// Reads an amount of Renminbi, in `分` - like `12.50`, `¥12.5`,
+/// `十二元五角`, `十二块五` or `壹拾贰元伍角整`.
+#[cfg(feature = "currency")]
+pub(crate) fn parse_renminbi_cents(text: &str) -> Option<u64> {
+    let text = normalize(text);
+
+    let text = text.trim_start_matches(['¥', '￥']);
+    let text = text.strip_prefix("人民币").unwrap_or(text);
+    let text = text.trim_end_matches(['整', '正']);
+
+    let arabic = text.trim_end_matches(YUAN_MARKERS);
+
+    if !arabic.is_empty() && arabic.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        let (yuan, fractional) = arabic.split_once('.').unwrap_or((arabic, ""));
+
+        if fractional.len() > 2 {
+            return None;
+        }
+
+        let yuan: u64 = if yuan.is_empty() {
+            0
+        } else {
+            yuan.parse().ok()?
+        };
+        let fractional: u64 = format!("{:0<2}", fractional).parse().ok()?;
+
+        return yuan.checked_mul(100)?.checked_add(fractional);
+    }
+
+    let (yuan, rest, has_yuan) = match text.split_once(YUAN_MARKERS) {
+        Some((yuan, rest)) => (yuan, rest, true),
+        None if text.contains(['角', '毛', '分']) => ("", text, false),
+        None => (text, "", true),
+    };
+
+    let yuan: u64 = if yuan.is_empty() {
+        0
+    } else {
+        parse_magnitude(yuan)?.try_into().ok()?
+    };
+
+    let rest = rest.trim_start_matches(['零', '〇']);
+
+    let (dimes, rest) = match rest.split_once(['角', '毛']) {
+        Some((dimes, rest)) => (Some(parse_magnitude(dimes)?), rest),
+        None => (None, rest),
+    };
+
+    let rest = rest.trim_start_matches(['零', '〇']);
+
+    let (dimes, cents) = match (dimes, rest.strip_suffix('分')) {
+        (dimes, Some(cents)) => (dimes.unwrap_or(0), parse_magnitude(cents)?),
+        (None, None) if !rest.is_empty() && has_yuan => (parse_magnitude(rest)?, 0),
+        (Some(dimes), None) if !rest.is_empty() => (dimes, parse_magnitude(rest)?),
+        (dimes, None) if rest.is_empty() => (dimes.unwrap_or(0), 0),
+        _ => return None,
+    };
+
+    if dimes > 9 || cents > 9 {
+        return None;
+    }
+
+    yuan.checked_mul(100)?
+        .checked_add(dimes as u64 * 10 + cents as u64)
+}
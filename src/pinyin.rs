@@ -0,0 +1,314 @@
+//! Pinyin transcription of the logograms produced by the generators.
+//!
+//! Rather than depending on a general-purpose dictionary, this module
+//! embeds the readings of every logogram the crate can generate - choosing,
+//! for polyphonic ones, the reading they have in context: for example,
+//! `正` is read `zhēng` because it only appears in `正月`.
+//!
+//! Logograms outside such vocabulary are copied unchanged.
+//!
+//! **Required feature**: `pinyin`.
+use crate::BilingualRendering;
+
+/// How tones are written in pinyin.
+///
+/// **Required feature**: `pinyin`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ToneStyle {
+    /// Diacritics on the vowels - as in `sān diǎn`.
+    #[default]
+    Marks,
+
+    /// Trailing digits, `5` being the neutral tone - as in `san1 dian3`.
+    Numbers,
+}
+
+/// Transcribes the given logograms into pinyin, one syllable per logogram,
+/// separated by spaces.
+///
+/// ```
+/// use chinese_rand::pinyin::*;
+///
+/// assert_eq!(to_pinyin("三点二十分", ToneStyle::Marks), "sān diǎn èr shí fēn");
+/// assert_eq!(to_pinyin("三點二十分", ToneStyle::Numbers), "san1 dian3 er4 shi2 fen1");
+/// assert_eq!(to_pinyin("正月初一", ToneStyle::Marks), "zhēng yuè chū yī");
+/// assert_eq!(to_pinyin("两万", ToneStyle::Marks), "liǎng wàn");
+/// assert_eq!(to_pinyin("A4", ToneStyle::Marks), "A 4");
+/// ```
+///
+/// **Required feature**: `pinyin`.
+pub fn to_pinyin(logograms: &str, style: ToneStyle) -> String {
+    logograms
+        .chars()
+        .map(|logogram| match reading(logogram) {
+            Some(numbered) => match style {
+                ToneStyle::Marks => with_tone_mark(numbered),
+                ToneStyle::Numbers => numbered.to_string(),
+            },
+
+            None => logogram.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl<T> BilingualRendering<T> {
+    /// The pinyin of the Simplified rendering - see [to_pinyin].
+    ///
+    /// ```
+    /// use chinese_rand::{*, pinyin::*};
+    ///
+    /// let rendering = BilingualRendering::new(("两个", "兩個"));
+    ///
+    /// assert_eq!(rendering.pinyin(ToneStyle::Marks), "liǎng gè");
+    /// ```
+    ///
+    /// **Required feature**: `pinyin`.
+    pub fn pinyin(&self, style: ToneStyle) -> String {
+        to_pinyin(&self.simplified, style)
+    }
+}
+
+fn with_tone_mark(numbered: &str) -> String {
+    let (syllable, tone) = numbered.split_at(numbered.len() - 1);
+    let tone: usize = tone.parse().expect("Tone digit by construction");
+    let syllable = syllable.replace('v', "ü");
+
+    if tone == 5 {
+        return syllable;
+    }
+
+    let marked_index = syllable
+        .find('a')
+        .or_else(|| syllable.find('e'))
+        .or_else(|| syllable.find("ou"))
+        .or_else(|| syllable.rfind(['i', 'o', 'u', 'ü']))
+        .expect("Vowel in every syllable");
+
+    syllable
+        .char_indices()
+        .map(|(index, letter)| {
+            if index == marked_index {
+                mark(letter, tone)
+            } else {
+                letter
+            }
+        })
+        .collect()
+}
+
+fn mark(vowel: char, tone: usize) -> char {
+    let marked = match vowel {
+        'a' => ['ā', 'á', 'ǎ', 'à'],
+        'e' => ['ē', 'é', 'ě', 'è'],
+        'i' => ['ī', 'í', 'ǐ', 'ì'],
+        'o' => ['ō', 'ó', 'ǒ', 'ò'],
+        'u' => ['ū', 'ú', 'ǔ', 'ù'],
+        'ü' => ['ǖ', 'ǘ', 'ǚ', 'ǜ'],
+        _ => return vowel,
+    };
+
+    marked[tone - 1]
+}
+
+fn reading(logogram: char) -> Option<&'static str> {
+    Some(match logogram {
+        '八' | '捌' => "ba1",
+        '白' => "bai2",
+        '佰' | '百' => "bai3",
+        '拜' => "bai4",
+        '半' => "ban4",
+        '傍' => "bang4",
+        '北' => "bei3",
+        '比' => "bi3",
+        '币' => "bi4",
+        '丙' => "bing3",
+        '差' => "cha4",
+        '晨' | '辰' => "chen2",
+        '乘' => "cheng2",
+        '重' => "chong2",
+        '丑' => "chou3",
+        '出' | '初' => "chu1",
+        '除' => "chu2",
+        '处' | '處' => "chu3",
+        '春' => "chun1",
+        '次' => "ci4",
+        '存' => "cun2",
+        '达' | '達' => "da2",
+        '大' => "da4",
+        '代' => "dai4",
+        '单' | '單' => "dan1",
+        '旦' => "dan4",
+        '当' | '當' => "dang4",
+        '到' => "dao4",
+        '登' => "deng1",
+        '等' => "deng3",
+        '低' => "di1",
+        '第' => "di4",
+        '点' | '點' => "dian3",
+        '电' | '電' => "dian4",
+        '丁' => "ding1",
+        '冬' => "dong1",
+        '动' | '動' => "dong4",
+        '度' => "du4",
+        '端' => "duan1",
+        '敦' => "dun1",
+        '多' => "duo1",
+        '額' | '额' => "e2",
+        '二' | '貮' | '貳' | '贰' => "er4",
+        '发' | '發' => "fa1",
+        '房' => "fang2",
+        '分' => "fen1",
+        '負' | '负' => "fu4",
+        '垓' => "gai1",
+        '概' => "gai4",
+        '高' => "gao1",
+        '个' | '個' => "ge4",
+        '庚' => "geng1",
+        '公' => "gong1",
+        '沟' | '溝' => "gou1",
+        '狗' => "gou3",
+        '穀' | '谷' => "gu3",
+        '癸' => "gui3",
+        '国' | '國' => "guo2",
+        '过' | '過' => "guo4",
+        '亥' => "hai4",
+        '寒' => "han2",
+        '毫' => "hao2",
+        '号' | '號' => "hao4",
+        '猴' => "hou2",
+        '后' | '後' => "hou4",
+        '虎' => "hu3",
+        '华' | '華' => "hua2",
+        '話' | '话' => "hua4",
+        '机' | '機' | '雞' | '鸡' => "ji1",
+        '几' | '己' | '幾' => "ji3",
+        '紀' | '纪' | '計' | '计' => "ji4",
+        '加' => "jia1",
+        '甲' => "jia3",
+        '价' | '價' => "jia4",
+        '間' | '间' => "jian1",
+        '减' | '減' => "jian3",
+        '件' | '涧' | '澗' => "jian4",
+        '降' => "jiang4",
+        '角' => "jiao3",
+        '節' | '节' => "jie2",
+        '斤' => "jin1",
+        '京' | '惊' | '驚' => "jing1",
+        '九' | '玖' => "jiu3",
+        '克' | '刻' => "ke4",
+        '口' => "kou3",
+        '块' | '塊' => "kuai4",
+        '腊' | '臘' => "la4",
+        '劳' | '勞' => "lao2",
+        '了' => "le5",
+        '厘' | '釐' => "li2",
+        '礼' | '禮' | '裡' | '里' => "li3",
+        '历' | '曆' | '立' => "li4",
+        '两' | '兩' => "liang3",
+        '列' => "lie4",
+        '凌' | '零' => "ling2",
+        '流' => "liu2",
+        '六' => "liu4",
+        '龍' | '龙' => "long2",
+        '楼' | '樓' => "lou2",
+        '路' | '陆' | '陸' | '露' => "lu4",
+        '伦' | '倫' => "lun2",
+        '率' => "lv4",
+        '码' | '碼' | '馬' | '马' => "ma3",
+        '满' | '滿' => "man3",
+        '芒' => "mang2",
+        '毛' => "mao2",
+        '卯' => "mao3",
+        '美' => "mei3",
+        '米' => "mi3",
+        '秒' => "miao3",
+        '民' => "min2",
+        '名' | '明' => "ming2",
+        '哪' => "na3",
+        '年' => "nian2",
+        '廿' => "nian4",
+        '牛' => "niu2",
+        '农' | '農' => "nong2",
+        '票' => "piao4",
+        '瓶' => "ping2",
+        '七' | '期' | '柒' => "qi1",
+        '气' | '氣' => "qi4",
+        '仟' | '千' => "qian1",
+        '前' | '錢' | '钱' => "qian2",
+        '清' => "qing1",
+        '庆' | '慶' => "qing4",
+        '秋' => "qiu1",
+        '穰' => "rang2",
+        '人' | '壬' => "ren2",
+        '日' => "ri4",
+        '入' => "ru4",
+        '閏' | '闰' => "run4",
+        '三' | '叁' | '参' | '參' | '叄' => "san1",
+        '上' => "shang4",
+        '少' => "shao3",
+        '蛇' => "she2",
+        '摄' | '攝' => "she4",
+        '深' | '申' | '身' => "shen1",
+        '升' | '生' => "sheng1",
+        '十' | '拾' | '时' | '時' => "shi2",
+        '世' | '氏' => "shi4",
+        '属' | '屬' | '暑' | '鼠' => "shu3",
+        '霜' => "shuang1",
+        '水' => "shui3",
+        '四' | '巳' | '肆' => "si4",
+        '岁' | '歲' => "sui4",
+        '台' => "tai2",
+        '体' | '體' => "ti3",
+        '天' => "tian1",
+        '兔' => "tu4",
+        '退' => "tui4",
+        '晚' => "wan3",
+        '万' | '萬' => "wan4",
+        '未' => "wei4",
+        '温' | '溫' => "wen1",
+        '五' | '伍' | '午' => "wu3",
+        '戊' => "wu4",
+        '夕' => "xi1",
+        '下' | '夏' => "xia4",
+        '宵' => "xiao1",
+        '小' => "xiao3",
+        '辛' => "xin1",
+        '星' => "xing1",
+        '戌' => "xu1",
+        '雪' => "xue3",
+        '羊' | '阳' | '陽' => "yang2",
+        '幺' => "yao1",
+        '夜' => "ye4",
+        '一' | '壹' => "yi1",
+        '乙' | '以' => "yi3",
+        '亿' | '億' => "yi4",
+        '寅' => "yin2",
+        '酉' => "you3",
+        '又' | '右' => "you4",
+        '于' | '余' | '於' | '餘' => "yu2",
+        '雨' => "yu3",
+        '元' | '圆' | '圓' => "yuan2",
+        '約' | '约' => "yue1",
+        '月' => "yue4",
+        '載' | '载' => "zai4",
+        '早' => "zao3",
+        '占' | '站' => "zhan4",
+        '兆' => "zhao4",
+        '蛰' | '蟄' => "zhe2",
+        '正' => "zheng1",
+        '整' => "zheng3",
+        '之' | '支' => "zhi1",
+        '至' => "zhi4",
+        '中' | '鐘' | '钟' => "zhong1",
+        '种' | '種' => "zhong4",
+        '周' => "zhou1",
+        '猪' | '豬' => "zhu1",
+        '子' | '秭' => "zi3",
+        '总' | '總' => "zong3",
+        '最' => "zui4",
+        '左' => "zuo3",
+        _ => return None,
+    })
+}
@@ -0,0 +1,382 @@
+use crate::{
+    AgeProfile, ApproxStyle, ArithmeticParams, ChineseFormatGenerator, ChineseRandResult,
+    CountHedge, MixedNumberParams, OrdinalSuffix, RangeExpressionParams, RawGenerator,
+};
+use chinese_format::{ChineseFormat, Variant};
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::{DateParams, DurationParams, LinearTimeParams};
+#[cfg(feature = "digit-sequence")]
+use crate::MeasurementParams;
+#[cfg(feature = "currency")]
+use crate::RenminbiParams;
+
+/// The kind of value to be generated by a [PlanEntry],
+/// together with its generation parameters.
+///
+/// When the `serde` feature is enabled, the kind is
+/// the key, in snake case, of a single-entry object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PlanItem {
+    /// See [ChineseFormatGenerator::integer].
+    Integer { range: RangeInclusive<i128> },
+
+    /// See [ChineseFormatGenerator::count].
+    Count { range: RangeInclusive<u128> },
+
+    /// See [ChineseFormatGenerator::count_rounded].
+    CountRounded {
+        range: RangeInclusive<u128>,
+        granularity: u128,
+        hedge: Option<CountHedge>,
+    },
+
+    /// See [ChineseFormatGenerator::fraction].
+    Fraction {
+        denominator_range: RangeInclusive<u128>,
+        numerator_range: RangeInclusive<i128>,
+    },
+
+    /// See [ChineseFormatGenerator::mixed_number].
+    MixedNumber(MixedNumberParams),
+
+    /// See [ChineseFormatGenerator::ordinal].
+    Ordinal {
+        range: RangeInclusive<u128>,
+        suffix: OrdinalSuffix,
+    },
+
+    /// See [ChineseFormatGenerator::range_expression].
+    RangeExpression(RangeExpressionParams),
+
+    /// See [ChineseFormatGenerator::age].
+    Age { profile: AgeProfile },
+
+    /// See [ChineseFormatGenerator::temperature].
+    Temperature {
+        celsius_range: RangeInclusive<i16>,
+        explicit_scale: bool,
+    },
+
+    /// See [ChineseFormatGenerator::approximate].
+    Approximate {
+        range: RangeInclusive<u128>,
+        style: ApproxStyle,
+    },
+
+    /// See [ChineseFormatGenerator::arithmetic].
+    Arithmetic(ArithmeticParams),
+
+    /// See [ChineseFormatGenerator::digit_sequence].
+    ///
+    /// **Required feature**: `digit-sequence`.
+    #[cfg(feature = "digit-sequence")]
+    DigitSequence { length_range: RangeInclusive<u8> },
+
+    /// See [ChineseFormatGenerator::decimal].
+    ///
+    /// **Required feature**: `digit-sequence`.
+    #[cfg(feature = "digit-sequence")]
+    Decimal {
+        integer_range: RangeInclusive<i128>,
+        fractional_length_range: RangeInclusive<u8>,
+    },
+
+    /// See [ChineseFormatGenerator::measurement].
+    ///
+    /// **Required feature**: `digit-sequence`.
+    #[cfg(feature = "digit-sequence")]
+    Measurement(MeasurementParams),
+
+    /// See [ChineseFormatGenerator::renminbi].
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    Renminbi(RenminbiParams),
+
+    /// See [GregorianGenerator::date](crate::gregorian::GregorianGenerator::date).
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Date(DateParams),
+
+    /// See [GregorianGenerator::linear_time](crate::gregorian::GregorianGenerator::linear_time).
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    LinearTime(LinearTimeParams),
+
+    /// See [GregorianGenerator::delta_time](crate::gregorian::GregorianGenerator::delta_time).
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    DeltaTime,
+
+    /// See [GregorianGenerator::duration](crate::gregorian::GregorianGenerator::duration).
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    Duration(DurationParams),
+}
+
+impl PlanItem {
+    /// The name of the kind - the same key used by `serde`.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// assert_eq!(PlanItem::Integer { range: 0..=9 }.kind(), "integer");
+    /// assert_eq!(PlanItem::Age { profile: AgeProfile::Any }.kind(), "age");
+    /// ```
+    pub fn kind(&self) -> &'static str {
+        match self {
+            PlanItem::Integer { .. } => "integer",
+
+            PlanItem::Count { .. } => "count",
+
+            PlanItem::CountRounded { .. } => "count_rounded",
+
+            PlanItem::Fraction { .. } => "fraction",
+
+            PlanItem::MixedNumber(_) => "mixed_number",
+
+            PlanItem::Ordinal { .. } => "ordinal",
+
+            PlanItem::RangeExpression(_) => "range_expression",
+
+            PlanItem::Age { .. } => "age",
+
+            PlanItem::Temperature { .. } => "temperature",
+
+            PlanItem::Approximate { .. } => "approximate",
+
+            PlanItem::Arithmetic(_) => "arithmetic",
+
+            #[cfg(feature = "digit-sequence")]
+            PlanItem::DigitSequence { .. } => "digit_sequence",
+
+            #[cfg(feature = "digit-sequence")]
+            PlanItem::Decimal { .. } => "decimal",
+
+            #[cfg(feature = "digit-sequence")]
+            PlanItem::Measurement(_) => "measurement",
+
+            #[cfg(feature = "currency")]
+            PlanItem::Renminbi(_) => "renminbi",
+
+            #[cfg(feature = "gregorian")]
+            PlanItem::Date(_) => "date",
+
+            #[cfg(feature = "gregorian")]
+            PlanItem::LinearTime(_) => "linear_time",
+
+            #[cfg(feature = "gregorian")]
+            PlanItem::DeltaTime => "delta_time",
+
+            #[cfg(feature = "gregorian")]
+            PlanItem::Duration(_) => "duration",
+        }
+    }
+
+    /// Creates the item of the given [kind](Self::kind),
+    /// with default parameters - or [None] if the kind is unknown.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// assert_eq!(
+    ///     PlanItem::from_kind("ordinal"),
+    ///     Some(PlanItem::Ordinal { range: 1..=100, suffix: OrdinalSuffix::Bare })
+    /// );
+    /// assert_eq!(PlanItem::from_kind("unknown"), None);
+    /// ```
+    pub fn from_kind(kind: &str) -> Option<Self> {
+        Some(match kind {
+            "integer" => PlanItem::Integer { range: 0..=1000 },
+            "count" => PlanItem::Count { range: 0..=1000 },
+            "count_rounded" => PlanItem::CountRounded {
+                range: 0..=100_000,
+                granularity: 1000,
+                hedge: None,
+            },
+            "fraction" => PlanItem::Fraction {
+                denominator_range: 1..=10,
+                numerator_range: 1..=10,
+            },
+            "mixed_number" => PlanItem::MixedNumber(MixedNumberParams::default()),
+            "ordinal" => PlanItem::Ordinal {
+                range: 1..=100,
+                suffix: OrdinalSuffix::Bare,
+            },
+            "range_expression" => PlanItem::RangeExpression(RangeExpressionParams::default()),
+            "age" => PlanItem::Age {
+                profile: AgeProfile::Any,
+            },
+            "temperature" => PlanItem::Temperature {
+                celsius_range: -20..=40,
+                explicit_scale: false,
+            },
+            "approximate" => PlanItem::Approximate {
+                range: 1..=1000,
+                style: ApproxStyle::Ji,
+            },
+            "arithmetic" => PlanItem::Arithmetic(ArithmeticParams::default()),
+            #[cfg(feature = "digit-sequence")]
+            "digit_sequence" => PlanItem::DigitSequence {
+                length_range: 1..=10,
+            },
+            #[cfg(feature = "digit-sequence")]
+            "decimal" => PlanItem::Decimal {
+                integer_range: 0..=1000,
+                fractional_length_range: 1..=2,
+            },
+            #[cfg(feature = "digit-sequence")]
+            "measurement" => PlanItem::Measurement(MeasurementParams::default()),
+            #[cfg(feature = "currency")]
+            "renminbi" => PlanItem::Renminbi(RenminbiParams::default()),
+            #[cfg(feature = "gregorian")]
+            "date" => PlanItem::Date(DateParams::default()),
+            #[cfg(feature = "gregorian")]
+            "linear_time" => PlanItem::LinearTime(LinearTimeParams::default()),
+            #[cfg(feature = "gregorian")]
+            "delta_time" => PlanItem::DeltaTime,
+            #[cfg(feature = "gregorian")]
+            "duration" => PlanItem::Duration(DurationParams::default()),
+            _ => return None,
+        })
+    }
+}
+
+/// Line of a [GenerationPlan]: how many values of which kind.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlanEntry {
+    /// The kind of value, with its parameters.
+    pub item: PlanItem,
+
+    /// How many values should be generated.
+    pub count: usize,
+}
+
+/// Declarative description of a sequence of values - for example,
+/// an entire exercise sheet - to be produced by [ChineseFormatGenerator::run_plan].
+///
+/// With the `serde` feature, a plan can be loaded from any
+/// serde-supported format:
+///
+/// ```
+/// # #[cfg(all(feature = "serde", feature = "gregorian"))]
+/// # {
+/// use chinese_rand::*;
+///
+/// let plan: GenerationPlan = serde_json::from_str(r#"{
+///     "variant": "Simplified",
+///     "entries": [
+///         {
+///             "item": { "integer": { "range": { "start": 0, "end": 99 } } },
+///             "count": 2
+///         },
+///         {
+///             "item": "delta_time",
+///             "count": 1
+///         }
+///     ]
+/// }"#).unwrap();
+///
+/// assert_eq!(plan.entries[0].item, PlanItem::Integer { range: 0..=99 });
+/// assert_eq!(plan.entries[1].count, 1);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenerationPlan {
+    /// The variant used to render every generated value.
+    #[cfg_attr(feature = "serde", serde(with = "crate::remote::VariantDef"))]
+    pub variant: Variant,
+
+    /// The entries, processed in order.
+    pub entries: Vec<PlanEntry>,
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates all the values described by the given [GenerationPlan],
+    /// returning their Chinese renderings - in the same order as the entries.
+    ///
+    /// Fails as soon as any value cannot be generated - for example,
+    /// because of a [ChineseRandError](crate::ChineseRandError).
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::Variant;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// let plan = GenerationPlan {
+    ///     variant: Variant::Simplified,
+    ///     entries: vec![
+    ///         PlanEntry {
+    ///             item: PlanItem::Integer { range: 0..=99 },
+    ///             count: 2,
+    ///         },
+    ///         PlanEntry {
+    ///             item: PlanItem::Fraction {
+    ///                 denominator_range: 5..=5,
+    ///                 numerator_range: 4..=4,
+    ///             },
+    ///             count: 1,
+    ///         },
+    ///     ],
+    /// };
+    ///
+    /// let rendered = generator.run_plan(&plan)?;
+    /// assert_eq!(rendered, vec!["五十八", "六十五", "五分之四"]);
+    ///
+    /// let failing_plan = GenerationPlan {
+    ///     variant: Variant::Simplified,
+    ///     entries: vec![PlanEntry {
+    ///         item: PlanItem::Fraction {
+    ///             denominator_range: 0..=5,
+    ///             numerator_range: 4..=4,
+    ///         },
+    ///         count: 1,
+    ///     }],
+    /// };
+    /// assert!(generator.run_plan(&failing_plan).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn run_plan(&self, plan: &GenerationPlan) -> ChineseRandResult<Vec<String>> {
+        let mut rendered = Vec::new();
+
+        for entry in &plan.entries {
+            for _ in 0..entry.count {
+                let value = self.generate_plan_item(&entry.item)?;
+
+                rendered.push(value.to_chinese(plan.variant).to_string());
+            }
+        }
+
+        Ok(rendered)
+    }
+
+    pub(crate) fn generate_plan_item(
+        &self,
+        item: &PlanItem,
+    ) -> ChineseRandResult<Box<dyn PlanValue>> {
+        Ok(Box::new(self.generate_value(item)?))
+    }
+}
+
+/// Value generated from a [PlanItem] - also providing its debug representation.
+pub trait PlanValue: ChineseFormat + Debug {}
+
+impl<T: ChineseFormat + Debug> PlanValue for T {}
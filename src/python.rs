@@ -0,0 +1,216 @@
+//! Python bindings via [pyo3](https://crates.io/crates/pyo3) - so that the same
+//! seeded generators can be called from Python, for example when building
+//! training corpora for Chinese OCR or speech recognition.
+//!
+//! The `chinese_rand` Python module provides the `Generator` class,
+//! generating any type of the [standard](crate::TypeRegistry::standard)
+//! [TypeRegistry](crate::TypeRegistry) by name - with its parameters passed
+//! as plain Python values, in the same structure as their JSON serialization:
+//!
+//! ```python
+//! from chinese_rand import Generator
+//!
+//! generator = Generator(seed=90)
+//!
+//! generator.generate("count", {"start": 1, "end": 99})  # '五十九'
+//! generator.generate("count", {"start": 1, "end": 99}, variant="traditional")
+//! generator.integer(-9, 9)
+//! Generator.types()  # ['appointment', 'arithmetic', ...]
+//! ```
+//!
+//! The available types depend on the enabled features - for example, `"date"`
+//! requires `gregorian`.
+//!
+//! The extension module can be built via [maturin](https://www.maturin.rs/),
+//! using the `pyproject.toml` file in the root of the repository - which also
+//! enables `currency`, `gregorian` and `lunar`.
+//!
+//! Errors raised by the generation are mapped to `ValueError`,
+//! while unsupported parameter values are mapped to `TypeError`.
+//!
+//! **Required feature**: `python`.
+
+use crate::{ChineseFormatGenerator, ChineseRandError, FastRandGenerator, TypeRegistry};
+use chinese_format::Variant;
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::{PyBool, PyDict, PyFloat, PyList, PyString, PyTuple},
+};
+use serde_json::{Map, Number, Value};
+
+/// The `Generator` class of the Python module, wrapping
+/// a [ChineseFormatGenerator] based on [FastRandGenerator].
+///
+/// ```
+/// use chinese_rand::python::PythonGenerator;
+/// use pyo3::{prelude::*, py_run};
+///
+/// pyo3::prepare_freethreaded_python();
+///
+/// Python::with_gil(|py| {
+///     let generator = Bound::new(py, PythonGenerator::new(Some(90))).unwrap();
+///
+///     py_run!(py, generator, r#"
+/// assert generator.generate("count", {"start": 1, "end": 99}) == "五十九"
+///
+/// generator.seed(90)
+/// assert generator.generate("count", {"start": 1, "end": 99}) == "五十九"
+///
+/// assert generator.integer(-9, 9) == 3
+///
+/// try:
+///     generator.generate("poem")
+///     assert False
+/// except ValueError as error:
+///     assert str(error) == "Unsupported: Unknown type: poem"
+///
+/// assert "fraction" in generator.types()
+/// "#);
+/// });
+/// ```
+///
+/// **Required feature**: `python`.
+#[pyclass(name = "Generator", module = "chinese_rand", unsendable)]
+pub struct PythonGenerator {
+    generator: ChineseFormatGenerator,
+}
+
+#[pymethods]
+impl PythonGenerator {
+    /// Creates a generator - seeded if a seed is passed.
+    #[new]
+    #[pyo3(signature = (seed = None))]
+    pub fn new(seed: Option<u64>) -> Self {
+        let raw_generator = match seed {
+            Some(seed) => FastRandGenerator::with_seed(seed),
+            None => FastRandGenerator::new(),
+        };
+
+        Self {
+            generator: ChineseFormatGenerator::new(raw_generator),
+        }
+    }
+
+    /// Sets the seed of the underlying random generator.
+    fn seed(&self, seed: u64) -> PyResult<()> {
+        self.generator.seed(seed).map_err(to_value_error)
+    }
+
+    /// Generates a value of the type having the given name,
+    /// rendered in the given variant - `"simplified"` or `"traditional"`.
+    #[pyo3(signature = (name, params = None, variant = "simplified"))]
+    fn generate(
+        &self,
+        name: &str,
+        params: Option<&Bound<'_, PyAny>>,
+        variant: &str,
+    ) -> PyResult<String> {
+        let variant = parse_variant(variant)?;
+
+        let params = match params {
+            Some(params) => to_json(params)?,
+            None => Value::Null,
+        };
+
+        let value = self
+            .generator
+            .generate_by_name(name, params)
+            .map_err(to_value_error)?;
+
+        Ok(value.to_chinese(variant).logograms)
+    }
+
+    /// Generates an integer in the given inclusive range.
+    fn integer(&self, start: i128, end: i128) -> PyResult<i128> {
+        self.generator.integer(start..=end).map_err(to_value_error)
+    }
+
+    /// The names of the types that can be generated, in alphabetical order.
+    #[staticmethod]
+    fn types() -> Vec<String> {
+        TypeRegistry::standard()
+            .names()
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// Initializes the `chinese_rand` Python module.
+///
+/// **Required feature**: `python`.
+#[pymodule]
+pub fn chinese_rand(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PythonGenerator>()
+}
+
+fn to_value_error(err: ChineseRandError) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+fn parse_variant(variant: &str) -> PyResult<Variant> {
+    match variant.to_lowercase().as_str() {
+        "simplified" => Ok(Variant::Simplified),
+        "traditional" => Ok(Variant::Traditional),
+        other => Err(PyValueError::new_err(format!("Unknown variant: {}", other))),
+    }
+}
+
+fn to_json(value: &Bound<'_, PyAny>) -> PyResult<Value> {
+    if value.is_none() {
+        return Ok(Value::Null);
+    }
+
+    if let Ok(boolean) = value.downcast::<PyBool>() {
+        return Ok(Value::Bool(boolean.is_true()));
+    }
+
+    if let Ok(integer) = value.extract::<i64>() {
+        return Ok(Value::from(integer));
+    }
+
+    if let Ok(integer) = value.extract::<u64>() {
+        return Ok(Value::from(integer));
+    }
+
+    if let Ok(float) = value.downcast::<PyFloat>() {
+        return Number::from_f64(float.value())
+            .map(Value::Number)
+            .ok_or_else(|| PyValueError::new_err("Non-finite floats are not supported"));
+    }
+
+    if let Ok(string) = value.downcast::<PyString>() {
+        return Ok(Value::String(string.to_str()?.to_string()));
+    }
+
+    if let Ok(list) = value.downcast::<PyList>() {
+        return list
+            .iter()
+            .map(|item| to_json(&item))
+            .collect::<PyResult<_>>()
+            .map(Value::Array);
+    }
+
+    if let Ok(tuple) = value.downcast::<PyTuple>() {
+        return tuple
+            .iter()
+            .map(|item| to_json(&item))
+            .collect::<PyResult<_>>()
+            .map(Value::Array);
+    }
+
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        let mut object = Map::new();
+
+        for (key, item) in dict.iter() {
+            object.insert(key.extract::<String>()?, to_json(&item)?);
+        }
+
+        return Ok(Value::Object(object));
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "Unsupported parameter type: {}",
+        value.get_type().name()?
+    )))
+}
@@ -0,0 +1,214 @@
+use crate::{
+    check_range, ChineseFormatGenerator, ChineseRandError, ChineseRandResult, RawGenerator,
+};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Variant};
+use std::ops::RangeInclusive;
+
+/// The word joining the bounds of a [RangeExpression].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum RangeConnector {
+    /// `到` - as in `三到五天`.
+    Dao,
+
+    /// `至` - more formal, as in `二十至三十度`.
+    Zhi,
+}
+
+impl ChineseFormat for RangeConnector {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            RangeConnector::Dao => "到".to_chinese(variant),
+            RangeConnector::Zhi => "至".to_chinese(variant),
+        }
+    }
+}
+
+/// Two ordered values joined by a [RangeConnector] and
+/// followed by an optional unit - such as `三到五天`.
+///
+/// The unit is a (Simplified, Traditional) pair, just like
+/// the tuples supported by [chinese_format].
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let expression = RangeExpression {
+///     start: 3,
+///     end: 5,
+///     connector: RangeConnector::Dao,
+///     unit: Some(("天".to_string(), "天".to_string())),
+/// };
+/// assert_eq!(expression.to_chinese(Variant::Simplified), "三到五天");
+///
+/// let expression = RangeExpression {
+///     start: -5,
+///     end: 30,
+///     connector: RangeConnector::Zhi,
+///     unit: Some(("度".to_string(), "度".to_string())),
+/// };
+/// assert_eq!(expression.to_chinese(Variant::Simplified), "负五至三十度");
+///
+/// let expression = RangeExpression {
+///     start: 8,
+///     end: 10,
+///     connector: RangeConnector::Dao,
+///     unit: None,
+/// };
+/// assert_eq!(expression.to_chinese(Variant::Traditional), "八到十");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RangeExpression {
+    /// The lower value.
+    pub start: i128,
+
+    /// The upper value.
+    pub end: i128,
+
+    /// The word joining the values.
+    pub connector: RangeConnector,
+
+    /// The optional unit, as a (Simplified, Traditional) pair.
+    pub unit: Option<(String, String)>,
+}
+
+impl ChineseFormat for RangeExpression {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.start, self.connector, self.end, self.unit]).collect()
+    }
+}
+
+/// Parameters for the random creation of [RangeExpression].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RangeExpressionParams {
+    /// The range both values are drawn from - containing at least 2 values.
+    pub range: RangeInclusive<i128>,
+
+    /// The word joining the values.
+    pub connector: RangeConnector,
+
+    /// The optional unit, as a (Simplified, Traditional) pair.
+    pub unit: Option<(String, String)>,
+}
+
+/// By default, [RangeExpressionParams] describes values
+/// between 1 and 10, joined by `到` and without unit.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// assert_eq!(RangeExpressionParams::default(), RangeExpressionParams {
+///     range: 1..=10,
+///     connector: RangeConnector::Dao,
+///     unit: None
+/// });
+///
+/// let customized = RangeExpressionParams::default()
+///     .with_range(-10..=40)
+///     .with_connector(RangeConnector::Zhi)
+///     .with_unit("度", "度");
+///
+/// assert_eq!(customized, RangeExpressionParams {
+///     range: -10..=40,
+///     connector: RangeConnector::Zhi,
+///     unit: Some(("度".to_string(), "度".to_string()))
+/// });
+/// ```
+impl Default for RangeExpressionParams {
+    fn default() -> Self {
+        Self {
+            range: 1..=10,
+            connector: RangeConnector::Dao,
+            unit: None,
+        }
+    }
+}
+
+impl RangeExpressionParams {
+    /// Sets the range both values are drawn from.
+    pub fn with_range(mut self, range: RangeInclusive<i128>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets the word joining the values.
+    pub fn with_connector(mut self, connector: RangeConnector) -> Self {
+        self.connector = connector;
+        self
+    }
+
+    /// Sets the unit, in both its Simplified and Traditional form.
+    pub fn with_unit(mut self, simplified: &str, traditional: &str) -> Self {
+        self.unit = Some((simplified.to_string(), traditional.to_string()));
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [RangeExpression], whose values
+    /// are always distinct and in ascending order.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if the range is empty,
+    /// and with [ChineseRandError::InvalidRange] if it contains just one value.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// let expression = generator.range_expression(
+    ///     RangeExpressionParams::default().with_unit("天", "天")
+    /// )?;
+    /// assert_eq!(expression.to_chinese(Variant::Simplified), "六到七天");
+    ///
+    /// let expression = generator.range_expression(
+    ///     RangeExpressionParams::default()
+    ///         .with_range(-10..=40)
+    ///         .with_connector(RangeConnector::Zhi)
+    ///         .with_unit("度", "度")
+    /// )?;
+    /// assert_eq!(expression.to_chinese(Variant::Simplified), "七至十三度");
+    ///
+    /// assert!(generator.range_expression(
+    ///     RangeExpressionParams::default().with_range(5..=5)
+    /// ).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn range_expression(
+        &self,
+        params: RangeExpressionParams,
+    ) -> ChineseRandResult<RangeExpression> {
+        check_range(&params.range)?;
+
+        let (start, end) = params.range.into_inner();
+
+        if start == end {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "At least 2 values required in {}..={}",
+                start, end
+            )));
+        }
+
+        let first = self.raw_generator.i128(start..=end);
+
+        let mut second = self.raw_generator.i128(start..=end - 1);
+        if second >= first {
+            second += 1;
+        }
+
+        Ok(RangeExpression {
+            start: first.min(second),
+            end: first.max(second),
+            connector: params.connector,
+            unit: params.unit,
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use crate::RawGenerator;
+use std::{cell::Cell, ops::RangeInclusive};
+
+/// [RawGenerator] deterministically deriving all its values from a byte slice -
+/// like `arbitrary::Unstructured` - so that fuzz targets can drive
+/// the generators of this crate directly from the fuzzer input.
+///
+/// Each value consumes only the bytes needed to cover its range; once the bytes
+/// are over, every value is the lower bound of its range - and booleans are `false`.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let data = [7, 1, 44, 200];
+///
+/// let generator = ChineseFormatGenerator::from_raw_generator(ByteSliceGenerator::new(&data));
+///
+/// assert_eq!(generator.integer(0..=9)?, 7);
+/// assert_eq!(generator.integer(0..=999)?, 300);
+/// assert_eq!(generator.integer(0..=999)?, 200);
+/// assert_eq!(generator.integer(0..=999)?, 0);
+///
+/// let raw_generator = ByteSliceGenerator::new(&data);
+/// assert_eq!(raw_generator.u8(10..=20), 17);
+/// assert_eq!(raw_generator.remaining(), 3);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ByteSliceGenerator<'a> {
+    bytes: &'a [u8],
+    position: Cell<usize>,
+}
+
+impl<'a> ByteSliceGenerator<'a> {
+    /// Creates a generator consuming the given bytes.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            position: Cell::new(0),
+        }
+    }
+
+    /// How many bytes have not been consumed yet.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.position.get()
+    }
+
+    fn next_byte(&self) -> Option<u8> {
+        let byte = self.bytes.get(self.position.get())?;
+        self.position.set(self.position.get() + 1);
+        Some(*byte)
+    }
+
+    /// Reads an offset in `0..=span`, consuming one byte
+    /// for every 8 bits of the span.
+    fn offset(&self, span: u128) -> u128 {
+        let mut offset: u128 = 0;
+        let mut covered_bits = 0;
+
+        while covered_bits < u128::BITS && span >> covered_bits > 0 {
+            let Some(byte) = self.next_byte() else {
+                break;
+            };
+
+            offset = (offset << 8) | byte as u128;
+            covered_bits += 8;
+        }
+
+        match span.checked_add(1) {
+            Some(values) => offset % values,
+            None => offset,
+        }
+    }
+
+    fn unsigned(&self, range: RangeInclusive<u128>) -> u128 {
+        let (start, end) = range.into_inner();
+
+        start + self.offset(end - start)
+    }
+
+    fn signed(&self, range: RangeInclusive<i128>) -> i128 {
+        let (start, end) = range.into_inner();
+
+        start.wrapping_add(self.offset(end.wrapping_sub(start) as u128) as i128)
+    }
+}
+
+impl RawGenerator for ByteSliceGenerator<'_> {
+    draw_in_range!(
+        u8: u8 => unsigned as u128,
+        u16: u16 => unsigned as u128,
+        u32: u32 => unsigned as u128,
+        u64: u64 => unsigned as u128,
+        u128: u128 => unsigned as u128,
+        i8: i8 => signed as i128,
+        i16: i16 => signed as i128,
+        i32: i32 => signed as i128,
+        i64: i64 => signed as i128,
+        i128: i128 => signed as i128
+    );
+
+    fn bool(&self) -> bool {
+        self.next_byte().is_some_and(|byte| byte & 1 == 1)
+    }
+}
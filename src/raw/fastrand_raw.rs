@@ -1,8 +1,14 @@
-use crate::RawGenerator;
-use std::ops::RangeInclusive;
+use crate::{ChineseRandResult, RawGenerator, RawGeneratorMut};
+use std::{cell::RefCell, ops::RangeInclusive};
 
 /// Implementation of [RawGenerator] based on [fastrand].
-pub struct FastRandGenerator;
+///
+/// By default, it relies on the thread-local generator of [fastrand] -
+/// so that [fastrand::seed] affects it; on the other hand, a generator
+/// created via [with_seed](Self::with_seed) - or forked - owns its state.
+pub struct FastRandGenerator {
+    rng: Option<RefCell<fastrand::Rng>>,
+}
 
 impl FastRandGenerator {
     /// Creates a new instance of the generator.
@@ -20,7 +26,38 @@ impl FastRandGenerator {
     /// assert_eq!(number, 29466);
     /// ```
     pub fn new() -> Self {
-        Self
+        Self { rng: None }
+    }
+
+    /// Creates a generator owning its own state - independent
+    /// of the thread-local generator of [fastrand].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// let generator = FastRandGenerator::with_seed(90);
+    /// let number = generator.u128(0..=50000);
+    ///
+    /// fastrand::seed(90);
+    /// assert_eq!(number, fastrand::u128(0..=50000));
+    /// ```
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            rng: Some(RefCell::new(fastrand::Rng::with_seed(seed))),
+        }
+    }
+
+    fn draw<T>(&self, draw: impl FnOnce(&mut fastrand::Rng) -> T) -> T {
+        match &self.rng {
+            Some(rng) => draw(&mut rng.borrow_mut()),
+
+            None => {
+                let mut global = fastrand::Rng::with_seed(fastrand::get_seed());
+                let result = draw(&mut global);
+                fastrand::seed(global.get_seed());
+                result
+            }
+        }
     }
 }
 
@@ -54,7 +91,7 @@ impl RawGenerator for FastRandGenerator {
     /// assert_eq!(number, 177);
     /// ```
     fn u8(&self, range: RangeInclusive<u8>) -> u8 {
-        fastrand::u8(range)
+        self.draw(|rng| rng.u8(range))
     }
 
     /// ```
@@ -68,7 +105,7 @@ impl RawGenerator for FastRandGenerator {
     /// assert_eq!(number, 51377);
     /// ```
     fn u16(&self, range: RangeInclusive<u16>) -> u16 {
-        fastrand::u16(range)
+        self.draw(|rng| rng.u16(range))
     }
 
     /// ```
@@ -82,7 +119,7 @@ impl RawGenerator for FastRandGenerator {
     /// assert_eq!(number, 2982070449);
     /// ```
     fn u32(&self, range: RangeInclusive<u32>) -> u32 {
-        fastrand::u32(range)
+        self.draw(|rng| rng.u32(range))
     }
 
     /// ```
@@ -96,7 +133,7 @@ impl RawGenerator for FastRandGenerator {
     /// assert_eq!(number, 10871161991276185777);
     /// ```
     fn u64(&self, range: RangeInclusive<u64>) -> u64 {
-        fastrand::u64(range)
+        self.draw(|rng| rng.u64(range))
     }
 
     /// ```
@@ -110,7 +147,63 @@ impl RawGenerator for FastRandGenerator {
     /// assert_eq!(number, 200537543036910508246661533454648102841);
     /// ```
     fn u128(&self, range: RangeInclusive<u128>) -> u128 {
-        fastrand::u128(range)
+        self.draw(|rng| rng.u128(range))
+    }
+
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// fastrand::seed(90);
+    ///
+    /// let generator = FastRandGenerator::new();
+    ///
+    /// let number = generator.i8(i8::MIN..=i8::MAX);
+    /// assert_eq!(number, -79);
+    /// ```
+    fn i8(&self, range: RangeInclusive<i8>) -> i8 {
+        self.draw(|rng| rng.i8(range))
+    }
+
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// fastrand::seed(90);
+    ///
+    /// let generator = FastRandGenerator::new();
+    ///
+    /// let number = generator.i16(i16::MIN..=i16::MAX);
+    /// assert_eq!(number, -14159);
+    /// ```
+    fn i16(&self, range: RangeInclusive<i16>) -> i16 {
+        self.draw(|rng| rng.i16(range))
+    }
+
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// fastrand::seed(90);
+    ///
+    /// let generator = FastRandGenerator::new();
+    ///
+    /// let number = generator.i32(i32::MIN..=i32::MAX);
+    /// assert_eq!(number, -1312896847);
+    /// ```
+    fn i32(&self, range: RangeInclusive<i32>) -> i32 {
+        self.draw(|rng| rng.i32(range))
+    }
+
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// fastrand::seed(90);
+    ///
+    /// let generator = FastRandGenerator::new();
+    ///
+    /// let number = generator.i64(i64::MIN..=i64::MAX);
+    /// assert_eq!(number, -7575582082433365839);
+    /// ```
+    fn i64(&self, range: RangeInclusive<i64>) -> i64 {
+        self.draw(|rng| rng.i64(range))
     }
 
     /// ```
@@ -124,7 +217,7 @@ impl RawGenerator for FastRandGenerator {
     /// assert_eq!(number, -139744823884027955216713073977120108615);
     /// ```
     fn i128(&self, range: RangeInclusive<i128>) -> i128 {
-        fastrand::i128(range)
+        self.draw(|rng| rng.i128(range))
     }
 
     /// ```
@@ -138,6 +231,40 @@ impl RawGenerator for FastRandGenerator {
     /// assert_eq!(random_bool, false);
     /// ```
     fn bool(&self) -> bool {
-        fastrand::bool()
+        self.draw(|rng| rng.bool())
+    }
+
+    fn seed(&self, seed: u64) -> ChineseRandResult<()> {
+        match &self.rng {
+            Some(rng) => rng.borrow_mut().seed(seed),
+            None => fastrand::seed(seed),
+        }
+
+        Ok(())
+    }
+
+    fn get_seed(&self) -> ChineseRandResult<u64> {
+        Ok(match &self.rng {
+            Some(rng) => rng.borrow().get_seed(),
+            None => fastrand::get_seed(),
+        })
+    }
+
+    fn fork(&self) -> ChineseRandResult<Box<dyn RawGenerator>> {
+        Ok(Box::new(FastRandGenerator::with_seed(
+            self.draw(|rng| rng.u64(..)),
+        )))
+    }
+}
+
+/// [fastrand::Rng] is a [RawGeneratorMut] - usable via [MutRawGenerator](crate::MutRawGenerator).
+impl RawGeneratorMut for fastrand::Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.u64(..)
+    }
+
+    fn seed(&mut self, seed: u64) -> ChineseRandResult<()> {
+        fastrand::Rng::seed(self, seed);
+        Ok(())
     }
 }
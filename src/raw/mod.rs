@@ -1,12 +1,59 @@
+// Implements the integer methods of RawGenerator by widening
+// to the given method, which draws in a 128-bit range.
+macro_rules! draw_in_range {
+    ($($method: ident: $type: ty => $wide: ident as $wide_type: ty),+) => {
+        $(
+            fn $method(&self, range: RangeInclusive<$type>) -> $type {
+                let (start, end) = range.into_inner();
+
+                self.$wide(start as $wide_type..=end as $wide_type) as $type
+            }
+        )+
+    };
+}
+
+mod byte_slice;
 #[cfg(feature = "fastrand")]
 mod fastrand_raw;
+mod mutable;
+#[cfg(feature = "secure")]
+mod secure;
 
+use crate::{ChineseRandError, ChineseRandResult};
 use std::ops::RangeInclusive;
 
+pub use byte_slice::*;
 #[cfg(feature = "fastrand")]
 pub use fastrand_raw::*;
+pub use mutable::*;
+#[cfg(feature = "secure")]
+pub use secure::*;
 
 /// Generator of primitive values required by [ChineseFormatGenerator](crate::ChineseFormatGenerator).
+///
+/// Only the unsigned methods, [i128](Self::i128) and [bool](Self::bool) are required:
+/// the narrower signed integers and [f64](Self::f64) have default implementations.
+///
+/// ```
+/// use chinese_rand::*;
+/// use std::ops::RangeInclusive;
+///
+/// struct AlwaysStart;
+///
+/// impl RawGenerator for AlwaysStart {
+///     fn u8(&self, range: RangeInclusive<u8>) -> u8 { *range.start() }
+///     fn u16(&self, range: RangeInclusive<u16>) -> u16 { *range.start() }
+///     fn u32(&self, range: RangeInclusive<u32>) -> u32 { *range.start() }
+///     fn u64(&self, range: RangeInclusive<u64>) -> u64 { *range.start() }
+///     fn u128(&self, range: RangeInclusive<u128>) -> u128 { *range.start() }
+///     fn i128(&self, range: RangeInclusive<i128>) -> i128 { *range.start() }
+///     fn bool(&self) -> bool { false }
+/// }
+///
+/// assert_eq!(AlwaysStart.i8(-5..=5), -5);
+/// assert_eq!(AlwaysStart.i64(-90..=90), -90);
+/// assert_eq!(AlwaysStart.f64(-1.5..=1.5), -1.5);
+/// ```
 pub trait RawGenerator {
     /// Generates a random [u8] in the given range.
     fn u8(&self, range: RangeInclusive<u8>) -> u8;
@@ -23,9 +70,177 @@ pub trait RawGenerator {
     /// Generates a random [u128] in the given range.
     fn u128(&self, range: RangeInclusive<u128>) -> u128;
 
+    /// Generates a random [i8] in the given range.
+    ///
+    /// By default, draws via [RawGenerator::i128].
+    fn i8(&self, range: RangeInclusive<i8>) -> i8 {
+        let (start, end) = range.into_inner();
+
+        self.i128(start as i128..=end as i128) as i8
+    }
+
+    /// Generates a random [i16] in the given range.
+    ///
+    /// By default, draws via [RawGenerator::i128].
+    fn i16(&self, range: RangeInclusive<i16>) -> i16 {
+        let (start, end) = range.into_inner();
+
+        self.i128(start as i128..=end as i128) as i16
+    }
+
+    /// Generates a random [i32] in the given range.
+    ///
+    /// By default, draws via [RawGenerator::i128].
+    fn i32(&self, range: RangeInclusive<i32>) -> i32 {
+        let (start, end) = range.into_inner();
+
+        self.i128(start as i128..=end as i128) as i32
+    }
+
+    /// Generates a random [i64] in the given range.
+    ///
+    /// By default, draws via [RawGenerator::i128].
+    fn i64(&self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+
+        self.i128(start as i128..=end as i128) as i64
+    }
+
     /// Generates a random [i128] in the given range.
     fn i128(&self, range: RangeInclusive<i128>) -> i128;
 
     /// Generates a random [bool] in the given range.
     fn bool(&self) -> bool;
+
+    /// Generates a random [f64] in the given range.
+    ///
+    /// The default implementation draws 53 random bits via [RawGenerator::u64],
+    /// so that every result is an exact point on a uniform grid
+    /// spanning the range - both bounds included; the bounds are interpolated
+    /// without computing their difference, which could overflow to infinity.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// fastrand::seed(90);
+    ///
+    /// let generator = FastRandGenerator::new();
+    ///
+    /// let number = generator.f64(-10.0..=10.0);
+    /// assert_eq!(number, 1.7865374483834824);
+    ///
+    /// let fixed = generator.f64(3.5..=3.5);
+    /// assert_eq!(fixed, 3.5);
+    ///
+    /// let huge = generator.f64(f64::MIN..=f64::MAX);
+    /// assert!(huge.is_finite());
+    /// ```
+    fn f64(&self, range: RangeInclusive<f64>) -> f64 {
+        const GRID_STEPS: u64 = 1 << 53;
+
+        let (start, end) = range.into_inner();
+
+        if start == end {
+            return start;
+        }
+
+        let ratio = self.u64(0..=GRID_STEPS) as f64 / GRID_STEPS as f64;
+
+        (start * (1.0 - ratio) + end * ratio).min(end).max(start)
+    }
+
+    /// Resets the state of the generator from the given seed.
+    ///
+    /// By default, fails with [ChineseRandError::Unsupported].
+    fn seed(&self, _seed: u64) -> ChineseRandResult<()> {
+        Err(ChineseRandError::Unsupported("Seeding".to_string()))
+    }
+
+    /// Returns the current state of the generator - that can be
+    /// passed to [seed](Self::seed) to resume the very same sequence.
+    ///
+    /// By default, fails with [ChineseRandError::Unsupported].
+    fn get_seed(&self) -> ChineseRandResult<u64> {
+        Err(ChineseRandError::Unsupported("Seed retrieval".to_string()))
+    }
+
+    /// Creates an independent child generator, whose seed is
+    /// deterministically derived from this generator.
+    ///
+    /// By default, fails with [ChineseRandError::Unsupported].
+    fn fork(&self) -> ChineseRandResult<Box<dyn RawGenerator>> {
+        Err(ChineseRandError::Unsupported("Forking".to_string()))
+    }
+}
+
+macro_rules! impl_forwarding_raw_generator {
+    ($($generator: ty),+) => {
+        $(
+            impl<T: RawGenerator + ?Sized> RawGenerator for $generator {
+                fn u8(&self, range: RangeInclusive<u8>) -> u8 {
+                    (**self).u8(range)
+                }
+
+                fn u16(&self, range: RangeInclusive<u16>) -> u16 {
+                    (**self).u16(range)
+                }
+
+                fn u32(&self, range: RangeInclusive<u32>) -> u32 {
+                    (**self).u32(range)
+                }
+
+                fn u64(&self, range: RangeInclusive<u64>) -> u64 {
+                    (**self).u64(range)
+                }
+
+                fn u128(&self, range: RangeInclusive<u128>) -> u128 {
+                    (**self).u128(range)
+                }
+
+                fn i8(&self, range: RangeInclusive<i8>) -> i8 {
+                    (**self).i8(range)
+                }
+
+                fn i16(&self, range: RangeInclusive<i16>) -> i16 {
+                    (**self).i16(range)
+                }
+
+                fn i32(&self, range: RangeInclusive<i32>) -> i32 {
+                    (**self).i32(range)
+                }
+
+                fn i64(&self, range: RangeInclusive<i64>) -> i64 {
+                    (**self).i64(range)
+                }
+
+                fn i128(&self, range: RangeInclusive<i128>) -> i128 {
+                    (**self).i128(range)
+                }
+
+                fn bool(&self) -> bool {
+                    (**self).bool()
+                }
+
+                fn f64(&self, range: RangeInclusive<f64>) -> f64 {
+                    (**self).f64(range)
+                }
+
+                fn seed(&self, seed: u64) -> ChineseRandResult<()> {
+                    (**self).seed(seed)
+                }
+
+                fn get_seed(&self) -> ChineseRandResult<u64> {
+                    (**self).get_seed()
+                }
+
+                fn fork(&self) -> ChineseRandResult<Box<dyn RawGenerator>> {
+                    (**self).fork()
+                }
+            }
+        )+
+    };
 }
+
+// Boxed and borrowed generators can back a ChineseFormatGenerator
+// just like the generators they point to.
+impl_forwarding_raw_generator!(Box<T>, &T);
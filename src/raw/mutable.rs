@@ -0,0 +1,174 @@
+use crate::{ChineseRandError, ChineseRandResult, RawGenerator};
+use std::{cell::RefCell, ops::RangeInclusive};
+
+/// Stateful source of random bits, taking `&mut self` - so that
+/// standard random number generators can implement it directly,
+/// without interior mutability.
+///
+/// It is turned into a [RawGenerator] via [MutRawGenerator], which derives
+/// uniformly distributed values in any range from [next_u64](Self::next_u64).
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// struct XorShift(u64);
+///
+/// impl RawGeneratorMut for XorShift {
+///     fn next_u64(&mut self) -> u64 {
+///         self.0 ^= self.0 << 13;
+///         self.0 ^= self.0 >> 7;
+///         self.0 ^= self.0 << 17;
+///         self.0
+///     }
+/// }
+///
+/// let generator = ChineseFormatGenerator::new(MutRawGenerator::new(XorShift(90)));
+/// assert_eq!(generator.integer(0..=99)?, 90);
+/// assert_eq!(generator.integer(0..=99)?, 11);
+/// # Ok(())
+/// # }
+/// ```
+pub trait RawGeneratorMut {
+    /// Generates 64 uniformly distributed random bits.
+    fn next_u64(&mut self) -> u64;
+
+    /// Resets the state of the generator from the given seed.
+    ///
+    /// By default, fails with [ChineseRandError::Unsupported].
+    fn seed(&mut self, _seed: u64) -> ChineseRandResult<()> {
+        Err(ChineseRandError::Unsupported("Seeding".to_string()))
+    }
+}
+
+impl<T: RawGeneratorMut + ?Sized> RawGeneratorMut for &mut T {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+
+    fn seed(&mut self, seed: u64) -> ChineseRandResult<()> {
+        (**self).seed(seed)
+    }
+}
+
+impl<T: RawGeneratorMut + ?Sized> RawGeneratorMut for Box<T> {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+
+    fn seed(&mut self, seed: u64) -> ChineseRandResult<()> {
+        (**self).seed(seed)
+    }
+}
+
+/// Adapter implementing [RawGenerator] for any [RawGeneratorMut] -
+/// which can also be a mutable reference, to lend a generator
+/// just for a while.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let mut rng = fastrand::Rng::with_seed(90);
+///
+/// let generator = ChineseFormatGenerator::from_raw_generator(MutRawGenerator::new(&mut rng));
+/// assert_eq!(generator.integer(0..=99)?, 77);
+/// drop(generator);
+///
+/// assert_eq!(rng.u8(0..=9), 3);
+/// # Ok(())
+/// # }
+/// ```
+pub struct MutRawGenerator<G: RawGeneratorMut> {
+    inner: RefCell<G>,
+}
+
+impl<G: RawGeneratorMut> MutRawGenerator<G> {
+    /// Wraps the given [RawGeneratorMut].
+    pub fn new(inner: G) -> Self {
+        Self {
+            inner: RefCell::new(inner),
+        }
+    }
+
+    /// Returns the wrapped [RawGeneratorMut].
+    pub fn into_inner(self) -> G {
+        self.inner.into_inner()
+    }
+
+    fn next_u64(&self) -> u64 {
+        self.inner.borrow_mut().next_u64()
+    }
+
+    fn next_u128(&self) -> u128 {
+        ((self.next_u64() as u128) << 64) | self.next_u64() as u128
+    }
+
+    /// Uniformly draws an offset in `0..=span`, rejecting the draws
+    /// that would bias the remainder.
+    fn offset(&self, span: u128) -> u128 {
+        if span == u128::MAX {
+            return self.next_u128();
+        }
+
+        let values = span + 1;
+
+        if values <= u64::MAX as u128 {
+            let values = values as u64;
+            let threshold = values.wrapping_neg() % values;
+
+            loop {
+                let draw = self.next_u64();
+
+                if draw >= threshold {
+                    return (draw % values) as u128;
+                }
+            }
+        }
+
+        let threshold = values.wrapping_neg() % values;
+
+        loop {
+            let draw = self.next_u128();
+
+            if draw >= threshold {
+                return draw % values;
+            }
+        }
+    }
+
+    fn unsigned(&self, range: RangeInclusive<u128>) -> u128 {
+        let (start, end) = range.into_inner();
+
+        start + self.offset(end - start)
+    }
+
+    fn signed(&self, range: RangeInclusive<i128>) -> i128 {
+        let (start, end) = range.into_inner();
+
+        start.wrapping_add(self.offset(end.wrapping_sub(start) as u128) as i128)
+    }
+}
+
+impl<G: RawGeneratorMut> RawGenerator for MutRawGenerator<G> {
+    draw_in_range!(
+        u8: u8 => unsigned as u128,
+        u16: u16 => unsigned as u128,
+        u32: u32 => unsigned as u128,
+        u64: u64 => unsigned as u128,
+        u128: u128 => unsigned as u128,
+        i8: i8 => signed as i128,
+        i16: i16 => signed as i128,
+        i32: i32 => signed as i128,
+        i64: i64 => signed as i128,
+        i128: i128 => signed as i128
+    );
+
+    fn bool(&self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    fn seed(&self, seed: u64) -> ChineseRandResult<()> {
+        self.inner.borrow_mut().seed(seed)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{ChineseRandError, ChineseRandResult, MutRawGenerator, RawGeneratorMut};
+use rand_chacha::{
+    rand_core::{RngCore, SeedableRng},
+    ChaCha20Rng,
+};
+
+/// Cryptographically secure [RawGeneratorMut], based on the ChaCha20 stream cipher
+/// provided by [rand_chacha].
+///
+/// The output is the keystream of ChaCha20 with a zero nonce:
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// let mut generator = ChaChaGenerator::from_key([0; 32]);
+///
+/// assert_eq!(generator.next_u64(), 0x903df1a0ade0b876);
+/// assert_eq!(generator.next_u64(), 0x28bd8653e56a5d40);
+///
+/// // Skipping to the next 64-byte block
+/// for _ in 2..8 {
+///     generator.next_u64();
+/// }
+/// assert_eq!(generator.next_u64(), 0x7a385155bee7079f);
+/// ```
+///
+/// **Required feature**: `secure`.
+pub struct ChaChaGenerator(ChaCha20Rng);
+
+impl ChaChaGenerator {
+    /// Creates a generator from the given 256-bit key - which must be
+    /// secret and random for the output to be unpredictable.
+    pub fn from_key(key: [u8; 32]) -> Self {
+        Self(ChaCha20Rng::from_seed(key))
+    }
+
+    /// Creates a generator whose key is obtained via [getrandom] from the entropy
+    /// source of the operating system.
+    ///
+    /// Fails with [ChineseRandError::Io] if the source is unavailable.
+    pub fn from_entropy() -> ChineseRandResult<Self> {
+        let mut key = [0; 32];
+
+        getrandom::getrandom(&mut key).map_err(|err| ChineseRandError::Io(err.to_string()))?;
+
+        Ok(Self::from_key(key))
+    }
+}
+
+impl RawGeneratorMut for ChaChaGenerator {
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+}
+
+/// [RawGenerator](crate::RawGenerator) based on [ChaChaGenerator] - for generating
+/// masking data, like fake IDs or amounts, that must not be predictable.
+///
+/// Seeding is deliberately unsupported, as a 64-bit seed would make
+/// the output predictable.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let generator = ChineseFormatGenerator::new(SecureGenerator::from_entropy()?);
+///
+/// let integer = generator.integer(0..=99)?;
+/// assert!((0..=99).contains(&integer));
+///
+/// assert_eq!(
+///     generator.seed(90),
+///     Err(ChineseRandError::Unsupported("Seeding".to_string()))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **Required feature**: `secure`.
+pub type SecureGenerator = MutRawGenerator<ChaChaGenerator>;
+
+impl SecureGenerator {
+    /// Creates a [SecureGenerator] via [ChaChaGenerator::from_entropy].
+    pub fn from_entropy() -> ChineseRandResult<Self> {
+        Ok(Self::new(ChaChaGenerator::from_entropy()?))
+    }
+}
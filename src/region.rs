@@ -0,0 +1,106 @@
+use chinese_format::Variant;
+
+#[cfg(feature = "currency")]
+use chinese_format::currency::CurrencyStyle;
+#[cfg(feature = "gregorian")]
+use chinese_format::gregorian::WeekFormat;
+
+/// Regional usage to which a [ChineseFormatGenerator](crate::ChineseFormatGenerator)
+/// can be tuned via [with_region](crate::ChineseFormatGenerator::with_region).
+///
+/// Beyond the glyphs of its [variant](Self::variant), a region steers
+/// generation choices: the week format, when not set in the parameters;
+/// the register of the everyday currency style, after the [Level](crate::Level)
+/// is applied; the day part of times, which is always rendered
+/// by regions preferring the 12-hour clock.
+///
+/// On the other hand, the choice between `两` and `二` is not affected -
+/// see [TwoPreference](crate::TwoPreference) instead.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::Variant;
+///
+/// assert_eq!(RegionProfile::Mainland.variant(), Variant::Simplified);
+/// assert_eq!(RegionProfile::Taiwan.variant(), Variant::Traditional);
+/// assert!(RegionProfile::HongKong.twelve_hour_clock());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum RegionProfile {
+    /// Simplified glyphs, `周`, colloquial `块` and `毛`, 24-hour clock.
+    Mainland,
+
+    /// Traditional glyphs, `星期`, formal `元` and `角`, 12-hour clock.
+    Taiwan,
+
+    /// Traditional glyphs, `禮拜`, formal `元` and `角`, 12-hour clock.
+    HongKong,
+}
+
+impl RegionProfile {
+    /// The variant in which text is written.
+    pub fn variant(&self) -> Variant {
+        match self {
+            RegionProfile::Mainland => Variant::Simplified,
+            RegionProfile::Taiwan | RegionProfile::HongKong => Variant::Traditional,
+        }
+    }
+
+    /// The preferred way of naming the week days.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::gregorian::WeekFormat;
+    ///
+    /// assert_eq!(RegionProfile::Mainland.week_format(), WeekFormat::Zhou);
+    /// assert_eq!(RegionProfile::HongKong.week_format(), WeekFormat::LiBai);
+    /// ```
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    pub fn week_format(&self) -> WeekFormat {
+        match self {
+            RegionProfile::Mainland => WeekFormat::Zhou,
+            RegionProfile::Taiwan => WeekFormat::XingQi,
+            RegionProfile::HongKong => WeekFormat::LiBai,
+        }
+    }
+
+    /// Adapts the requested [CurrencyStyle] to the regional wording -
+    /// that is, outside the Mainland, the everyday style is always formal.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::currency::CurrencyStyle;
+    ///
+    /// let informal = CurrencyStyle::Everyday { formal: false };
+    ///
+    /// assert_eq!(RegionProfile::Mainland.currency_style(informal), informal);
+    /// assert_eq!(
+    ///     RegionProfile::Taiwan.currency_style(informal),
+    ///     CurrencyStyle::Everyday { formal: true }
+    /// );
+    /// assert_eq!(
+    ///     RegionProfile::Taiwan.currency_style(CurrencyStyle::Financial),
+    ///     CurrencyStyle::Financial
+    /// );
+    /// ```
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    pub fn currency_style(&self, requested: CurrencyStyle) -> CurrencyStyle {
+        match (self, requested) {
+            (RegionProfile::Mainland, _) => requested,
+            (_, CurrencyStyle::Everyday { .. }) => CurrencyStyle::Everyday { formal: true },
+            (_, CurrencyStyle::Financial) => CurrencyStyle::Financial,
+        }
+    }
+
+    /// Whether times tend to be expressed via the 12-hour clock,
+    /// with the day part.
+    pub fn twelve_hour_clock(&self) -> bool {
+        *self != RegionProfile::Mainland
+    }
+}
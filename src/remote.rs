@@ -0,0 +1,238 @@
+//! Serde definitions mirroring the [chinese_format] types
+//! embedded in the parameter structs.
+//!
+//! **Required feature**: `serde`.
+#[cfg(feature = "currency")]
+use chinese_format::currency::CurrencyStyle;
+#[cfg(feature = "gregorian")]
+use chinese_format::gregorian::{DatePattern, DayPart, WeekDay, WeekFormat};
+use chinese_format::Variant;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Variant")]
+pub(crate) enum VariantDef {
+    Simplified,
+    Traditional,
+}
+
+#[cfg(feature = "currency")]
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "CurrencyStyle")]
+pub(crate) enum CurrencyStyleDef {
+    Everyday { formal: bool },
+    Financial,
+}
+
+#[cfg(feature = "gregorian")]
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "DatePattern")]
+pub(crate) enum DatePatternDef {
+    Year,
+    Month,
+    Day,
+    WeekDay,
+    YearMonth,
+    YearMonthDay,
+    MonthDay,
+    MonthDayWeekDay,
+    DayWeekDay,
+    YearMonthDayWeekDay,
+}
+
+#[cfg(feature = "gregorian")]
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "WeekFormat")]
+pub(crate) enum WeekFormatDef {
+    XingQi,
+    Zhou,
+    LiBai,
+}
+
+#[cfg(feature = "gregorian")]
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "WeekDay")]
+pub(crate) enum WeekDayDef {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+#[cfg(feature = "gregorian")]
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "DayPart")]
+pub(crate) enum DayPartDef {
+    EarlyMorning,
+    Morning,
+    Midday,
+    Afternoon,
+    EarlyEvening,
+    Evening,
+    Midnight,
+    LateNight,
+}
+
+/// Adapter for optional [Variant] fields.
+pub(crate) mod option_variant {
+    use super::VariantDef;
+    use chinese_format::Variant;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "VariantDef")] Variant);
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<Variant>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.map(Wrapper).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Variant>, D::Error> {
+        Option::<Wrapper>::deserialize(deserializer)
+            .map(|wrapper| wrapper.map(|Wrapper(variant)| variant))
+    }
+}
+
+/// Adapter for optional [CurrencyStyle] fields.
+#[cfg(feature = "currency")]
+pub(crate) mod option_currency_style {
+    use super::CurrencyStyleDef;
+    use chinese_format::currency::CurrencyStyle;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "CurrencyStyleDef")] CurrencyStyle);
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<CurrencyStyle>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.map(Wrapper).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<CurrencyStyle>, D::Error> {
+        Option::<Wrapper>::deserialize(deserializer)
+            .map(|wrapper| wrapper.map(|Wrapper(style)| style))
+    }
+}
+
+/// Adapter for optional [WeekFormat] fields.
+#[cfg(feature = "gregorian")]
+pub(crate) mod option_week_format {
+    use super::WeekFormatDef;
+    use chinese_format::gregorian::WeekFormat;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "WeekFormatDef")] WeekFormat);
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<WeekFormat>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.map(Wrapper).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<WeekFormat>, D::Error> {
+        Option::<Wrapper>::deserialize(deserializer)
+            .map(|wrapper| wrapper.map(|Wrapper(week_format)| week_format))
+    }
+}
+
+/// Adapter for [DatePattern] vectors.
+#[cfg(feature = "gregorian")]
+pub(crate) mod vec_date_pattern {
+    use super::DatePatternDef;
+    use chinese_format::gregorian::DatePattern;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "DatePatternDef")] DatePattern);
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &[DatePattern],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value.iter().map(|pattern| Wrapper(*pattern)))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<DatePattern>, D::Error> {
+        Vec::<Wrapper>::deserialize(deserializer).map(|wrappers| {
+            wrappers
+                .into_iter()
+                .map(|Wrapper(pattern)| pattern)
+                .collect()
+        })
+    }
+}
+
+/// Adapter for optional [DayPart] fields.
+#[cfg(feature = "gregorian")]
+pub(crate) mod option_day_part {
+    use super::DayPartDef;
+    use chinese_format::gregorian::DayPart;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "DayPartDef")] DayPart);
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<DayPart>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.map(Wrapper).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<DayPart>, D::Error> {
+        Option::<Wrapper>::deserialize(deserializer)
+            .map(|wrapper| wrapper.map(|Wrapper(day_part)| day_part))
+    }
+}
+
+/// Adapter for [WeekDay] lists.
+#[cfg(feature = "gregorian")]
+pub(crate) mod vec_week_day {
+    use super::WeekDayDef;
+    use chinese_format::gregorian::WeekDay;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "WeekDayDef")] WeekDay);
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &[WeekDay],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value
+            .iter()
+            .map(|week_day| Wrapper(*week_day))
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<WeekDay>, D::Error> {
+        Vec::<Wrapper>::deserialize(deserializer).map(|wrappers| {
+            wrappers
+                .into_iter()
+                .map(|Wrapper(week_day)| week_day)
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{ChineseFormatGenerator, ChineseRandError, ChineseRandResult, RawGenerator};
+use std::rc::Rc;
+
+/// How many times, by default, a generator method draws a new candidate
+/// after the previous one turned out to be invalid -
+/// see [ChineseFormatGenerator::with_max_retries].
+pub const DEFAULT_MAX_RETRIES: usize = 1000;
+
+/// Counters describing the candidate-based generations performed
+/// by a [ChineseFormatGenerator] - such as dates and constrained fractions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct GenerationStats {
+    /// The candidates drawn, whether valid or not.
+    pub attempts: u64,
+
+    /// The candidates rejected because invalid.
+    pub retries: u64,
+
+    /// The generations that failed with
+    /// [ChineseRandError::GenerationExhausted].
+    pub exhausted: u64,
+}
+
+/// Internal event of a candidate-based generation - reported to the
+/// observer set via [ChineseFormatGenerator::on_event].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum GenerationEvent<'a> {
+    /// A candidate was rejected because invalid - for example,
+    /// a date not matching the filters - and a new one will be drawn,
+    /// if retries are left.
+    Rejected {
+        /// What was being generated - like `"Date"`.
+        target: &'a str,
+
+        /// The 1-based number of the rejected attempt.
+        attempt: usize,
+    },
+
+    /// The generation failed with [ChineseRandError::GenerationExhausted].
+    Exhausted {
+        /// What was being generated - like `"Date"`.
+        target: &'a str,
+
+        /// The attempts performed.
+        attempts: usize,
+    },
+}
+
+pub(crate) type EventObserver = dyn Fn(&GenerationEvent<'_>);
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Sets the observer of the [GenerationEvent]s - to detect the parameters
+    /// causing heavy rejection sampling; any previous observer is replaced.
+    ///
+    /// ```
+    /// # #[cfg(feature = "gregorian")]
+    /// # {
+    /// use chinese_rand::{*, gregorian::*};
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let rejections = Rc::new(RefCell::new(Vec::new()));
+    /// let observed = rejections.clone();
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    ///     .on_event(move |event| {
+    ///         if let GenerationEvent::Rejected { target, attempt } = event {
+    ///             observed.borrow_mut().push(format!("{} #{}", target, attempt));
+    ///         }
+    ///     });
+    ///
+    /// generator.gregorian().date(
+    ///     DateParams::default().with_week_day_filter(WeekDayFilter::WeekendsOnly)
+    /// )?;
+    /// assert_eq!(*rejections.borrow(), ["Date #1", "Date #2"]);
+    /// # Ok(())
+    /// # }
+    /// # main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// Exhausted generations are reported as well:
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// let exhausted = Rc::new(Cell::new(0));
+    /// let observed = exhausted.clone();
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    ///     .with_max_retries(2)
+    ///     .on_event(move |event| {
+    ///         if let GenerationEvent::Exhausted { attempts, .. } = event {
+    ///             observed.set(*attempts);
+    ///         }
+    ///     });
+    ///
+    /// let impossible = generator.fraction_with(
+    ///     FractionParams::default()
+    ///         .with_denominator_range(4..=4)
+    ///         .with_numerator_range(2..=2)
+    ///         .with_reduced_only(true)
+    /// );
+    /// assert!(impossible.is_err());
+    /// assert_eq!(exhausted.get(), 3);
+    /// ```
+    pub fn on_event(mut self, observer: impl Fn(&GenerationEvent<'_>) + 'static) -> Self {
+        self.observer = Some(Rc::new(observer));
+        self
+    }
+
+    /// Sets how many invalid candidates can be discarded before a generation
+    /// fails with [ChineseRandError::GenerationExhausted] - instead of spinning
+    /// forever on pathological parameters.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    /// assert_eq!(generator.max_retries(), DEFAULT_MAX_RETRIES);
+    ///
+    /// let generator = generator.with_max_retries(5);
+    /// assert_eq!(generator.max_retries(), 5);
+    ///
+    /// let impossible = generator.fraction_with(
+    ///     FractionParams::default()
+    ///         .with_denominator_range(4..=4)
+    ///         .with_numerator_range(2..=2)
+    ///         .with_reduced_only(true)
+    /// );
+    /// assert_eq!(
+    ///     impossible,
+    ///     Err(ChineseRandError::GenerationExhausted("Fraction after 6 attempts".to_string()))
+    /// );
+    /// ```
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// The maximum number of retries currently set.
+    pub fn max_retries(&self) -> usize {
+        self.max_retries
+    }
+
+    /// The counters accumulated since the creation of the generator -
+    /// or since the latest call to [reset_stats](Self::reset_stats).
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    ///     .with_max_retries(3);
+    ///
+    /// let params = FractionParams::default().with_reduced_only(true);
+    /// for _ in 0..10 {
+    ///     generator.fraction_with(params.clone())?;
+    /// }
+    ///
+    /// let stats = generator.stats();
+    /// assert_eq!(stats.attempts, 10 + stats.retries);
+    /// assert_eq!(stats.exhausted, 0);
+    ///
+    /// generator.reset_stats();
+    /// assert_eq!(generator.stats(), GenerationStats::default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stats(&self) -> GenerationStats {
+        self.stats.get()
+    }
+
+    /// Sets all the counters back to 0.
+    pub fn reset_stats(&self) {
+        self.stats.set(GenerationStats::default())
+    }
+
+    /// Calls `attempt` until it returns a value, for at most `max_retries + 1`
+    /// times - then fails with [ChineseRandError::GenerationExhausted].
+    pub(crate) fn retrying<T>(
+        &self,
+        target: &str,
+        mut attempt: impl FnMut() -> Option<T>,
+    ) -> ChineseRandResult<T> {
+        self.try_retrying(target, || Ok(attempt()))
+    }
+
+    /// Like [retrying](Self::retrying), but stopping at the first error.
+    pub(crate) fn try_retrying<T>(
+        &self,
+        target: &str,
+        mut attempt: impl FnMut() -> ChineseRandResult<Option<T>>,
+    ) -> ChineseRandResult<T> {
+        let max_attempts = self.max_retries.saturating_add(1);
+
+        for attempt_number in 1..=max_attempts {
+            self.update_stats(|stats| stats.attempts += 1);
+
+            if let Some(value) = attempt()? {
+                return Ok(value);
+            }
+
+            self.update_stats(|stats| stats.retries += 1);
+            self.notify(GenerationEvent::Rejected {
+                target,
+                attempt: attempt_number,
+            });
+        }
+
+        self.update_stats(|stats| stats.exhausted += 1);
+        self.notify(GenerationEvent::Exhausted {
+            target,
+            attempts: max_attempts,
+        });
+
+        Err(ChineseRandError::GenerationExhausted(format!(
+            "{} after {} attempts",
+            target, max_attempts
+        )))
+    }
+
+    fn notify(&self, event: GenerationEvent<'_>) {
+        if let Some(observer) = &self.observer {
+            observer(&event);
+        }
+    }
+
+    fn update_stats(&self, update: impl FnOnce(&mut GenerationStats)) {
+        let mut stats = self.stats.get();
+        update(&mut stats);
+        self.stats.set(stats);
+    }
+}
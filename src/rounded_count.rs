@@ -0,0 +1,133 @@
+use crate::{
+    check_range, ChineseFormatGenerator, ChineseRandError, ChineseRandResult, RawGenerator,
+};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Count, CountBase, Variant};
+use std::ops::RangeInclusive;
+
+/// Hedging word following a [RoundedCount] - making it approximate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CountHedge {
+    /// `多` - as in `三千多`.
+    Duo,
+
+    /// `几` - as in `三千几`.
+    Ji,
+}
+
+impl ChineseFormat for CountHedge {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            CountHedge::Duo => "多".to_chinese(variant),
+            CountHedge::Ji => ("几", "幾").to_chinese(variant),
+        }
+    }
+}
+
+/// [Count] rounded to some granularity, optionally hedged -
+/// such as `五万` or `三千多`.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Count, Variant};
+///
+/// let rounded = RoundedCount { count: Count(50000), hedge: None };
+/// assert_eq!(rounded.to_chinese(Variant::Simplified), "五万");
+///
+/// let rounded = RoundedCount { count: Count(3000), hedge: Some(CountHedge::Duo) };
+/// assert_eq!(rounded.to_chinese(Variant::Simplified), "三千多");
+///
+/// let rounded = RoundedCount { count: Count(200), hedge: Some(CountHedge::Ji) };
+/// assert_eq!(rounded.to_chinese(Variant::Traditional), "二百幾");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RoundedCount {
+    /// The rounded value.
+    pub count: Count,
+
+    /// The optional hedging word.
+    pub hedge: Option<CountHedge>,
+}
+
+impl ChineseFormat for RoundedCount {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let hedge: &dyn ChineseFormat = match &self.hedge {
+            Some(hedge) => hedge,
+            None => &"",
+        };
+
+        chinese_vec!(variant, [self.count, hedge]).collect()
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [RoundedCount] - a multiple of `granularity`
+    /// within the given range, such as `三千` - optionally hedged.
+    ///
+    /// When hedged, the value is never 0 - because `零多` makes no sense.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if the range is empty,
+    /// and with [ChineseRandError::InvalidRange] if the granularity is 0
+    /// or the range contains no suitable multiple.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// let rounded = generator.count_rounded(1000..=9999, 1000, None)?;
+    /// assert_eq!(rounded.to_chinese(Variant::Simplified), "六千");
+    ///
+    /// let rounded = generator.count_rounded(0..=100000, 10000, Some(CountHedge::Duo))?;
+    /// assert_eq!(rounded.to_chinese(Variant::Simplified), "七万多");
+    ///
+    /// let rounded = generator.count_rounded(10..=99, 10, Some(CountHedge::Ji))?;
+    /// assert_eq!(rounded.to_chinese(Variant::Simplified), "四十几");
+    ///
+    /// assert!(generator.count_rounded(1..=99, 100, None).is_err());
+    /// assert!(generator.count_rounded(1..=99, 0, None).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count_rounded(
+        &self,
+        range: RangeInclusive<CountBase>,
+        granularity: CountBase,
+        hedge: Option<CountHedge>,
+    ) -> ChineseRandResult<RoundedCount> {
+        check_range(&range)?;
+
+        if granularity == 0 {
+            return Err(ChineseRandError::InvalidRange(
+                "Granularity cannot be 0".to_string(),
+            ));
+        }
+
+        let mut lowest_multiplier = range.start().div_ceil(granularity);
+
+        if hedge.is_some() {
+            lowest_multiplier = lowest_multiplier.max(1);
+        }
+
+        let highest_multiplier = range.end() / granularity;
+
+        if lowest_multiplier > highest_multiplier {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "No multiple of {} in {:?}",
+                granularity, range
+            )));
+        }
+
+        let multiplier = self
+            .raw_generator
+            .u128(lowest_multiplier..=highest_multiplier);
+
+        Ok(RoundedCount {
+            count: Count(multiplier * granularity),
+            hedge,
+        })
+    }
+}
@@ -0,0 +1,16 @@
+//! Higher-level generators combining several values into one
+//! consistent bundle - like a receipt whose total matches its lines -
+//! for dialogue and listening exercises.
+//!
+//! **Required feature**: `scenario`.
+mod profile;
+mod receipt;
+mod schedule;
+mod transaction;
+mod weather;
+
+pub use profile::*;
+pub use receipt::*;
+pub use schedule::*;
+pub use transaction::*;
+pub use weather::*;
@@ -0,0 +1,288 @@
+use crate::gregorian::{beyond_calendar, CalendarDate, Clock, SystemClock};
+use crate::{
+    Age, AgeProfile, ChineseFormatGenerator, ChineseRandResult, MeasureUnit, Measurement,
+    RawGenerator,
+};
+use chinese_format::gregorian::Date;
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Decimal, Variant};
+use digit_sequence::DigitSequence;
+use std::iter::repeat_with;
+
+const PHONE_NUMBER_LENGTH: usize = 11;
+
+/// Card introducing a person - whose age matches the birth date
+/// at the reference date, and whose weight is plausible for the height.
+///
+/// Rendered like `三十五岁，一九九零年五月三日出生，电话号码幺三八…，身高一点七五米，体重六十五公斤`;
+/// the phone number is read digit by digit, with 1 read `幺`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PersonProfile {
+    /// The age at the reference date.
+    pub age: Age,
+
+    /// The birth date, as calendar value.
+    pub birth_date: CalendarDate,
+
+    /// The birth date, ready for rendering.
+    pub birthday: Date,
+
+    /// The day at which the age is computed - not rendered.
+    pub reference_date: CalendarDate,
+
+    /// The mobile phone number - 11 digits starting with 1.
+    pub phone_number: DigitSequence,
+
+    /// The height, in meters.
+    pub height: Option<Measurement>,
+
+    /// The weight, in kilograms.
+    pub weight: Option<Measurement>,
+}
+
+impl ChineseFormat for PersonProfile {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let phone_number = self
+            .phone_number
+            .to_chinese(variant)
+            .logograms
+            .replace('一', "幺");
+
+        let mut logograms = chinese_vec!(
+            variant,
+            [
+                self.age,
+                "，",
+                self.birthday,
+                "出生，",
+                ("电话号码", "電話號碼"),
+                phone_number
+            ]
+        )
+        .collect()
+        .logograms;
+
+        if let Some(height) = &self.height {
+            logograms.push_str(
+                &chinese_vec!(variant, ["，身高", height])
+                    .collect()
+                    .logograms,
+            );
+        }
+
+        if let Some(weight) = &self.weight {
+            logograms.push_str(
+                &chinese_vec!(variant, [("，体重", "，體重"), weight])
+                    .collect()
+                    .logograms,
+            );
+        }
+
+        logograms.to_chinese(variant)
+    }
+}
+
+/// Parameters for the random creation of [PersonProfile].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PersonParams {
+    /// The profile of the generated age.
+    pub age_profile: AgeProfile,
+
+    /// The day at which the age is computed.
+    /// If set to [None], today - according to [SystemClock] - is used.
+    pub reference_date: Option<CalendarDate>,
+
+    /// Whether height and weight are generated.
+    pub body: bool,
+}
+
+/// By default, [PersonParams] describes adults as of today,
+/// with height and weight.
+///
+/// ```
+/// use chinese_rand::{*, gregorian::*, scenario::*};
+///
+/// assert_eq!(PersonParams::default(), PersonParams {
+///     age_profile: AgeProfile::Adult,
+///     reference_date: None,
+///     body: true
+/// });
+///
+/// let reference_date = CalendarDate::try_new(2024, 2, 29).unwrap();
+///
+/// let customized = PersonParams::default()
+///     .with_age_profile(AgeProfile::Child)
+///     .with_reference_date(reference_date)
+///     .with_body(false);
+///
+/// assert_eq!(customized, PersonParams {
+///     age_profile: AgeProfile::Child,
+///     reference_date: Some(reference_date),
+///     body: false
+/// });
+/// ```
+impl Default for PersonParams {
+    fn default() -> Self {
+        Self {
+            age_profile: AgeProfile::Adult,
+            reference_date: None,
+            body: true,
+        }
+    }
+}
+
+impl PersonParams {
+    /// Sets the profile of the generated age.
+    pub fn with_age_profile(mut self, age_profile: AgeProfile) -> Self {
+        self.age_profile = age_profile;
+        self
+    }
+
+    /// Sets the day at which the age is computed.
+    pub fn with_reference_date(mut self, reference_date: CalendarDate) -> Self {
+        self.reference_date = Some(reference_date);
+        self
+    }
+
+    /// Sets whether height and weight are generated.
+    pub fn with_body(mut self, body: bool) -> Self {
+        self.body = body;
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [PersonProfile] - whose birth date is exactly
+    /// [age](PersonProfile::age) years before the reference date,
+    /// possibly plus some days.
+    ///
+    /// Fails with [ChineseRandError::InvalidRange](crate::ChineseRandError::InvalidRange)
+    /// if the birth date would precede the year 0.
+    ///
+    /// **Required feature**: `scenario`.
+    ///
+    /// ```
+    /// use chinese_rand::{*, gregorian::*, scenario::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let reference_date = CalendarDate::try_new(2024, 5, 20).unwrap();
+    ///
+    /// let profile = generator.person_profile(
+    ///     PersonParams::default().with_reference_date(reference_date)
+    /// )?;
+    /// assert_eq!(
+    ///     profile.to_chinese(Variant::Simplified),
+    ///     "四十二岁，一九八二年一月十六日出生，电话号码幺四四二六零幺八五二四，身高一点五五米，体重四十六公斤"
+    /// );
+    ///
+    /// let child = generator.person_profile(
+    ///     PersonParams::default()
+    ///         .with_age_profile(AgeProfile::Child)
+    ///         .with_reference_date(reference_date)
+    ///         .with_body(false)
+    /// )?;
+    /// assert_eq!(
+    ///     child.to_chinese(Variant::Traditional),
+    ///     "十一歲，二零一三年一月十七日出生，電話號碼幺五幺六七二三四六七三"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn person_profile(&self, params: PersonParams) -> ChineseRandResult<PersonProfile> {
+        let reference_date = params.reference_date.unwrap_or_else(|| SystemClock.today());
+
+        let age = self.age(params.age_profile);
+        let years = age.0 as i64;
+
+        let earliest_offset = -12 * (years + 1);
+        let earliest_birth = reference_date
+            .add_months(earliest_offset)
+            .and_then(|date| date.add_days(1))
+            .ok_or_else(|| beyond_calendar(&reference_date, earliest_offset, "months"))?;
+        let latest_birth = reference_date
+            .add_months(-12 * years)
+            .expect("Date valid by construction");
+
+        let birth_date = earliest_birth
+            .add_days(
+                self.raw_generator
+                    .i64(0..=earliest_birth.days_until(&latest_birth)),
+            )
+            .expect("Date valid by construction");
+
+        let mut phone_digits = vec![1, self.raw_generator.u8(3..=9)];
+        phone_digits
+            .extend(repeat_with(|| self.raw_generator.u8(0..=9)).take(PHONE_NUMBER_LENGTH - 2));
+
+        let (height, weight) = if params.body {
+            let (height, weight) = self.body_measures(age);
+            (Some(height), Some(weight))
+        } else {
+            (None, None)
+        };
+
+        Ok(PersonProfile {
+            age,
+            birth_date,
+            birthday: birth_date.to_date(false, None),
+            reference_date,
+            phone_number: phone_digits
+                .try_into()
+                .expect("Digits valid by construction"),
+            height,
+            weight,
+        })
+    }
+
+    fn body_measures(&self, age: Age) -> (Measurement, Measurement) {
+        let (height_centimeters, body_mass_index) = if age.0 < 18 {
+            let typical = 75 + 6 * age.0.saturating_sub(1) as u16;
+
+            (
+                self.raw_generator.u16((typical - 5)..=(typical + 5)),
+                self.raw_generator.u16(14..=20),
+            )
+        } else {
+            (
+                self.raw_generator.u16(150..=190),
+                self.raw_generator.u16(18..=26),
+            )
+        };
+
+        let weight_kilograms =
+            (body_mass_index as u32 * height_centimeters as u32 * height_centimeters as u32
+                + 5_000)
+                / 10_000;
+
+        let mut centimeter_digits = vec![
+            (height_centimeters % 100 / 10) as u8,
+            (height_centimeters % 10) as u8,
+        ];
+        while centimeter_digits.last() == Some(&0) {
+            centimeter_digits.pop();
+        }
+
+        let height = Measurement {
+            value: Decimal {
+                integer: (height_centimeters / 100) as i128,
+                fractional: centimeter_digits
+                    .try_into()
+                    .expect("Digits valid by construction"),
+            },
+            unit: MeasureUnit::Meter,
+        };
+
+        let weight = Measurement {
+            value: Decimal {
+                integer: weight_kilograms as i128,
+                fractional: DigitSequence::new(),
+            },
+            unit: MeasureUnit::Kilogram,
+        };
+
+        (height, weight)
+    }
+}
@@ -0,0 +1,318 @@
+use crate::{
+    check_range, renminbi_from_cents, ChineseFormatGenerator, ChineseRandError, ChineseRandResult,
+    RawGenerator,
+};
+use chinese_format::currency::{CurrencyStyle, RenminbiCurrency};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Count, Variant};
+use std::ops::RangeInclusive;
+
+/// Line of a [Receipt] - like `两瓶，单价三元五角，小计七元`.
+///
+/// The item itself is just an index, which callers can map to
+/// their own vocabulary.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReceiptLine {
+    /// The index of the item - within `0..item_count`.
+    pub item: usize,
+
+    /// How many units were bought.
+    pub quantity: Count,
+
+    /// The measure word following the quantity - simplified and traditional.
+    pub measure_word: (String, String),
+
+    /// The price of one unit.
+    pub unit_price: RenminbiCurrency,
+
+    /// The price of the whole line - quantity times unit price.
+    pub line_total: RenminbiCurrency,
+}
+
+impl ChineseFormat for ReceiptLine {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                self.quantity,
+                (self.measure_word.0.as_str(), self.measure_word.1.as_str()),
+                ("，单价", "，單價"),
+                self.unit_price,
+                ("，小计", "，小計"),
+                self.line_total
+            ]
+        )
+        .collect()
+    }
+}
+
+/// Shopping receipt, whose grand total is the sum of its lines.
+///
+/// Rendered as its lines, separated by `；`, followed by the grand total.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Receipt {
+    /// The lines - each referring to a different item.
+    pub lines: Vec<ReceiptLine>,
+
+    /// The sum of all the line totals.
+    pub total: RenminbiCurrency,
+}
+
+impl ChineseFormat for Receipt {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let mut logograms: Vec<String> = self
+            .lines
+            .iter()
+            .map(|line| line.to_chinese(variant).logograms)
+            .collect();
+
+        logograms.push(
+            chinese_vec!(variant, [("总计", "總計"), self.total])
+                .collect()
+                .logograms,
+        );
+
+        logograms.join("；").to_chinese(variant)
+    }
+}
+
+/// The maximum [item_count](ReceiptParams::item_count) of a [Receipt].
+///
+/// **Required feature**: `scenario`.
+pub const MAX_RECEIPT_ITEMS: usize = 10_000;
+
+/// Parameters for the random creation of [Receipt].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReceiptParams {
+    /// The range of the number of lines.
+    pub line_range: RangeInclusive<u8>,
+
+    /// How many items can appear - at least as many as the lines,
+    /// and at most [MAX_RECEIPT_ITEMS].
+    pub item_count: usize,
+
+    /// The range of the quantity in each line.
+    pub quantity_range: RangeInclusive<u32>,
+
+    /// The range of the unit prices, in `分`.
+    pub unit_price_range: RangeInclusive<u64>,
+
+    /// The measure words to choose from - simplified and traditional.
+    pub measure_words: Vec<(String, String)>,
+
+    /// The style of all the amounts.
+    #[cfg_attr(feature = "serde", serde(with = "crate::remote::CurrencyStyleDef"))]
+    pub style: CurrencyStyle,
+}
+
+/// By default, [ReceiptParams] describes 1 to 5 lines - out of 10 items -
+/// each with 1 to 5 units costing from 1 to 50 yuan, in formal everyday style.
+///
+/// ```
+/// use chinese_rand::scenario::*;
+/// use chinese_format::currency::CurrencyStyle;
+///
+/// let default = ReceiptParams::default();
+/// assert_eq!(default.line_range, 1..=5);
+/// assert_eq!(default.item_count, 10);
+/// assert_eq!(default.quantity_range, 1..=5);
+/// assert_eq!(default.unit_price_range, 100..=5000);
+/// assert_eq!(default.measure_words.len(), 4);
+/// assert_eq!(default.style, CurrencyStyle::Everyday { formal: true });
+///
+/// let customized = ReceiptParams::default()
+///     .with_line_range(2..=2)
+///     .with_item_count(3)
+///     .with_quantity_range(1..=1)
+///     .with_unit_price_range(50..=90)
+///     .with_measure_words(vec![("杯".to_string(), "杯".to_string())])
+///     .with_style(CurrencyStyle::Everyday { formal: false });
+///
+/// assert_eq!(customized, ReceiptParams {
+///     line_range: 2..=2,
+///     item_count: 3,
+///     quantity_range: 1..=1,
+///     unit_price_range: 50..=90,
+///     measure_words: vec![("杯".to_string(), "杯".to_string())],
+///     style: CurrencyStyle::Everyday { formal: false }
+/// });
+/// ```
+impl Default for ReceiptParams {
+    fn default() -> Self {
+        Self {
+            line_range: 1..=5,
+            item_count: 10,
+            quantity_range: 1..=5,
+            unit_price_range: 100..=5000,
+            measure_words: [("个", "個"), ("件", "件"), ("瓶", "瓶"), ("斤", "斤")]
+                .into_iter()
+                .map(|(simplified, traditional)| (simplified.to_string(), traditional.to_string()))
+                .collect(),
+            style: CurrencyStyle::Everyday { formal: true },
+        }
+    }
+}
+
+impl ReceiptParams {
+    /// Sets the range of the number of lines.
+    pub fn with_line_range(mut self, line_range: RangeInclusive<u8>) -> Self {
+        self.line_range = line_range;
+        self
+    }
+
+    /// Sets how many items can appear.
+    pub fn with_item_count(mut self, item_count: usize) -> Self {
+        self.item_count = item_count;
+        self
+    }
+
+    /// Sets the range of the quantity in each line.
+    pub fn with_quantity_range(mut self, quantity_range: RangeInclusive<u32>) -> Self {
+        self.quantity_range = quantity_range;
+        self
+    }
+
+    /// Sets the range of the unit prices, in `分`.
+    pub fn with_unit_price_range(mut self, unit_price_range: RangeInclusive<u64>) -> Self {
+        self.unit_price_range = unit_price_range;
+        self
+    }
+
+    /// Sets the measure words to choose from.
+    pub fn with_measure_words(mut self, measure_words: Vec<(String, String)>) -> Self {
+        self.measure_words = measure_words;
+        self
+    }
+
+    /// Sets the style of all the amounts.
+    pub fn with_style(mut self, style: CurrencyStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [Receipt] - with distinct items and consistent totals.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if a range is empty, and with
+    /// [ChineseRandError::InvalidRange] if there are fewer items than the
+    /// maximum number of lines, more than [MAX_RECEIPT_ITEMS] items, no measure words -
+    /// or if an amount exceeds [u64] cents.
+    ///
+    /// **Required feature**: `scenario`.
+    ///
+    /// ```
+    /// use chinese_rand::{*, scenario::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let receipt = generator.receipt(ReceiptParams::default().with_line_range(2..=2))?;
+    /// assert_eq!(receipt.lines.len(), 2);
+    /// assert_ne!(receipt.lines[0].item, receipt.lines[1].item);
+    /// assert_eq!(
+    ///     receipt.to_chinese(Variant::Simplified),
+    ///     concat!(
+    ///         "三件，单价八元一角七分，小计二十四元五角一分；",
+    ///         "一斤，单价六元两角八分，小计六元两角八分；",
+    ///         "总计三十元七角九分"
+    ///     )
+    /// );
+    ///
+    /// assert!(generator.receipt(ReceiptParams::default().with_item_count(2)).is_err());
+    ///
+    /// assert_eq!(
+    ///     generator.receipt(ReceiptParams::default().with_item_count(MAX_RECEIPT_ITEMS + 1)),
+    ///     Err(ChineseRandError::InvalidRange("Item count beyond 10000: 10001".to_string()))
+    /// );
+    ///
+    /// let overflowing = ReceiptParams::default()
+    ///     .with_quantity_range(3..=3)
+    ///     .with_unit_price_range(u64::MAX / 2..=u64::MAX / 2);
+    /// assert!(matches!(
+    ///     generator.receipt(overflowing),
+    ///     Err(ChineseRandError::InvalidRange(_))
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receipt(&self, params: ReceiptParams) -> ChineseRandResult<Receipt> {
+        check_range(&params.line_range)?;
+        check_range(&params.quantity_range)?;
+        check_range(&params.unit_price_range)?;
+
+        if params.item_count < *params.line_range.end() as usize {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Fewer items ({}) than lines: {:?}",
+                params.item_count, params.line_range
+            )));
+        }
+
+        if params.item_count > MAX_RECEIPT_ITEMS {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Item count beyond {}: {}",
+                MAX_RECEIPT_ITEMS, params.item_count
+            )));
+        }
+
+        if params.measure_words.is_empty() {
+            return Err(ChineseRandError::InvalidRange(
+                "No measure words".to_string(),
+            ));
+        }
+
+        let line_count = self.raw_generator.u8(params.line_range.clone()) as usize;
+
+        let items: Vec<usize> = self
+            .shuffled_cycle(0..params.item_count)
+            .take(line_count)
+            .collect();
+
+        let mut total_cents: u64 = 0;
+
+        let lines = items
+            .into_iter()
+            .map(|item| {
+                let quantity = self.raw_generator.u32(params.quantity_range.clone());
+
+                let measure_index = self
+                    .raw_generator
+                    .u64(0..=(params.measure_words.len() as u64 - 1))
+                    as usize;
+
+                let unit_price_cents = self.raw_generator.u64(params.unit_price_range.clone());
+
+                let line_cents =
+                    unit_price_cents
+                        .checked_mul(quantity as u64)
+                        .ok_or_else(|| {
+                            ChineseRandError::InvalidRange(format!(
+                                "Line total beyond u64 cents: {} x {}",
+                                unit_price_cents, quantity
+                            ))
+                        })?;
+
+                total_cents = total_cents.checked_add(line_cents).ok_or_else(|| {
+                    ChineseRandError::InvalidRange(format!(
+                        "Receipt total beyond u64 cents: {} + {}",
+                        total_cents, line_cents
+                    ))
+                })?;
+
+                Ok(ReceiptLine {
+                    item,
+                    quantity: Count(quantity as u128),
+                    measure_word: params.measure_words[measure_index].clone(),
+                    unit_price: renminbi_from_cents(unit_price_cents, params.style),
+                    line_total: renminbi_from_cents(line_cents, params.style),
+                })
+            })
+            .collect::<ChineseRandResult<_>>()?;
+
+        Ok(Receipt {
+            lines,
+            total: renminbi_from_cents(total_cents, params.style),
+        })
+    }
+}
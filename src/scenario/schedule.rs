@@ -0,0 +1,332 @@
+use crate::gregorian::{clock_time, CalendarDate};
+use crate::{
+    check_range, renminbi_from_cents, ChineseFormatGenerator, ChineseRandError, ChineseRandResult,
+    RawGenerator,
+};
+use chinese_format::currency::{CurrencyStyle, RenminbiCurrency};
+use chinese_format::gregorian::{Date, LinearTime, WeekFormat};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Variant};
+use std::ops::RangeInclusive;
+
+const MINUTES_PER_DAY: u16 = 24 * 60;
+
+/// Where passengers board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Terminal {
+    /// Train platform - like `三号站台`.
+    Platform,
+
+    /// Boarding gate - like `十二号登机口`.
+    Gate,
+}
+
+impl ChineseFormat for Terminal {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            Terminal::Platform => "站台".to_chinese(variant),
+            Terminal::Gate => ("登机口", "登機口").to_chinese(variant),
+        }
+    }
+}
+
+/// Entry of a train or flight schedule, whose arrival follows
+/// the departure on the same day.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScheduleEntry {
+    /// The day of the journey, as calendar value.
+    pub calendar_date: CalendarDate,
+
+    /// The day of the journey, ready for rendering.
+    pub date: Date,
+
+    /// The departure time.
+    pub departure: LinearTime,
+
+    /// The arrival time.
+    pub arrival: LinearTime,
+
+    /// The duration of the journey, in minutes.
+    pub duration_minutes: u16,
+
+    /// The kind of boarding point.
+    pub terminal: Terminal,
+
+    /// The number of the boarding point.
+    pub terminal_number: u16,
+
+    /// The ticket price.
+    pub price: RenminbiCurrency,
+}
+
+impl ChineseFormat for ScheduleEntry {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                self.date,
+                self.departure,
+                ("出发，", "出發，"),
+                self.arrival,
+                ("到达，", "到達，"),
+                self.terminal_number,
+                ("号", "號"),
+                self.terminal,
+                ("，票价", "，票價"),
+                self.price
+            ]
+        )
+        .collect()
+    }
+}
+
+/// Parameters for the random creation of [ScheduleEntry].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleParams {
+    /// The year range of the date.
+    /// If set to [None], then the year range of the [GeneratorConfig](crate::GeneratorConfig)
+    /// is used - by default, [DEFAULT_YEAR_RANGE](crate::gregorian::DEFAULT_YEAR_RANGE).
+    pub year_range: Option<RangeInclusive<u16>>,
+
+    /// The range of the journey duration, in minutes - within a day.
+    pub duration_range: RangeInclusive<u16>,
+
+    /// The granularity of departure and arrival minutes.
+    pub minute_step: u8,
+
+    /// The kind of boarding point.
+    pub terminal: Terminal,
+
+    /// The range of the boarding point numbers.
+    pub terminal_range: RangeInclusive<u16>,
+
+    /// The range of the ticket prices, in whole yuan.
+    pub yuan_range: RangeInclusive<u64>,
+
+    /// If set to `true`, the times include the day part,
+    /// plus a 12-hour format; otherwise, the 24-hour format applies.
+    pub day_part: bool,
+
+    /// If set, the rendered date includes its week day, in the given format.
+    #[cfg_attr(feature = "serde", serde(with = "crate::remote::option_week_format"))]
+    pub week_format: Option<WeekFormat>,
+}
+
+/// By default, [ScheduleParams] describes train journeys lasting from
+/// 30 minutes to 10 hours, on 5-minute boundaries, from platforms 1 to 20,
+/// costing from 50 to 1000 yuan - with day part and without week day.
+///
+/// ```
+/// use chinese_rand::scenario::*;
+/// use chinese_format::gregorian::WeekFormat;
+///
+/// assert_eq!(ScheduleParams::default(), ScheduleParams {
+///     year_range: None,
+///     duration_range: 30..=600,
+///     minute_step: 5,
+///     terminal: Terminal::Platform,
+///     terminal_range: 1..=20,
+///     yuan_range: 50..=1000,
+///     day_part: true,
+///     week_format: None
+/// });
+///
+/// let customized = ScheduleParams::default()
+///     .with_year_range(2025..=2025)
+///     .with_duration_range(60..=180)
+///     .with_minute_step(10)
+///     .with_terminal(Terminal::Gate)
+///     .with_terminal_range(1..=50)
+///     .with_yuan_range(300..=3000)
+///     .with_week_format(WeekFormat::XingQi);
+///
+/// assert_eq!(customized, ScheduleParams {
+///     year_range: Some(2025..=2025),
+///     duration_range: 60..=180,
+///     minute_step: 10,
+///     terminal: Terminal::Gate,
+///     terminal_range: 1..=50,
+///     yuan_range: 300..=3000,
+///     day_part: true,
+///     week_format: Some(WeekFormat::XingQi)
+/// });
+/// ```
+impl Default for ScheduleParams {
+    fn default() -> Self {
+        Self {
+            year_range: None,
+            duration_range: 30..=600,
+            minute_step: 5,
+            terminal: Terminal::Platform,
+            terminal_range: 1..=20,
+            yuan_range: 50..=1000,
+            day_part: true,
+            week_format: None,
+        }
+    }
+}
+
+impl ScheduleParams {
+    /// Sets the year range.
+    pub fn with_year_range(mut self, year_range: RangeInclusive<u16>) -> Self {
+        self.year_range = Some(year_range);
+        self
+    }
+
+    /// Sets the range of the journey duration, in minutes.
+    pub fn with_duration_range(mut self, duration_range: RangeInclusive<u16>) -> Self {
+        self.duration_range = duration_range;
+        self
+    }
+
+    /// Sets the granularity of the minutes.
+    pub fn with_minute_step(mut self, minute_step: u8) -> Self {
+        self.minute_step = minute_step;
+        self
+    }
+
+    /// Sets the kind of boarding point.
+    pub fn with_terminal(mut self, terminal: Terminal) -> Self {
+        self.terminal = terminal;
+        self
+    }
+
+    /// Sets the range of the boarding point numbers.
+    pub fn with_terminal_range(mut self, terminal_range: RangeInclusive<u16>) -> Self {
+        self.terminal_range = terminal_range;
+        self
+    }
+
+    /// Sets the range of the ticket prices, in whole yuan.
+    pub fn with_yuan_range(mut self, yuan_range: RangeInclusive<u64>) -> Self {
+        self.yuan_range = yuan_range;
+        self
+    }
+
+    /// Sets the week format of the rendered date.
+    pub fn with_week_format(mut self, week_format: WeekFormat) -> Self {
+        self.week_format = Some(week_format);
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [ScheduleEntry] - whose arrival is always
+    /// later than the departure, on the same day.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if a range is empty, and
+    /// with [ChineseRandError::InvalidRange] if the durations are not
+    /// within a day, the minute step is not in the 1..=60 range,
+    /// no duration is a multiple of the minute step
+    /// or the prices exceed [u64] cents.
+    ///
+    /// **Required feature**: `scenario`.
+    ///
+    /// ```
+    /// use chinese_rand::{*, scenario::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let entry = generator.schedule_entry(ScheduleParams::default().with_year_range(2025..=2025))?;
+    /// assert_eq!(
+    ///     entry.to_chinese(Variant::Simplified),
+    ///     concat!(
+    ///         "二零二五年八月四日下午两点四十五分出发，",
+    ///         "傍晚六点三十分到达，六号站台，票价三百七十九元"
+    ///     )
+    /// );
+    ///
+    /// assert!(generator
+    ///     .schedule_entry(ScheduleParams::default().with_duration_range(0..=1440))
+    ///     .is_err());
+    ///
+    /// assert_eq!(
+    ///     generator.schedule_entry(
+    ///         ScheduleParams::default()
+    ///             .with_duration_range(45..=50)
+    ///             .with_minute_step(30)
+    ///     ),
+    ///     Err(ChineseRandError::InvalidRange(
+    ///         "No duration multiple of 30 in 45..=50".to_string()
+    ///     ))
+    /// );
+    ///
+    /// assert_eq!(
+    ///     generator.schedule_entry(ScheduleParams::default().with_yuan_range(1..=u64::MAX)),
+    ///     Err(ChineseRandError::InvalidRange(format!(
+    ///         "Prices beyond u64 cents: 1..={}",
+    ///         u64::MAX
+    ///     )))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn schedule_entry(&self, params: ScheduleParams) -> ChineseRandResult<ScheduleEntry> {
+        let year_range = params
+            .year_range
+            .clone()
+            .unwrap_or_else(|| self.config.year_range.clone());
+
+        check_range(&year_range)?;
+        check_range(&params.duration_range)?;
+        check_range(&params.terminal_range)?;
+        check_range(&params.yuan_range)?;
+
+        if *params.duration_range.start() == 0 || *params.duration_range.end() >= MINUTES_PER_DAY {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Durations not in 1..{}: {:?}",
+                MINUTES_PER_DAY, params.duration_range
+            )));
+        }
+
+        if !(1..=60).contains(&params.minute_step) {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Minute step not in 1..=60: {}",
+                params.minute_step
+            )));
+        }
+
+        if params.yuan_range.end().checked_mul(100).is_none() {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Prices beyond u64 cents: {:?}",
+                params.yuan_range
+            )));
+        }
+
+        let step = params.minute_step as u16;
+
+        let duration_multiples =
+            params.duration_range.start().div_ceil(step)..=params.duration_range.end() / step;
+
+        if duration_multiples.is_empty() {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "No duration multiple of {} in {:?}",
+                step, params.duration_range
+            )));
+        }
+
+        let calendar_date = self.gregorian().calendar_date(year_range)?;
+
+        let duration_minutes = self.raw_generator.u16(duration_multiples) * step;
+
+        let latest_departure = (MINUTES_PER_DAY - 1).saturating_sub(duration_minutes) / step;
+        let departure_minutes = self.raw_generator.u16(0..=latest_departure) * step;
+
+        let terminal_number = self.raw_generator.u16(params.terminal_range.clone());
+        let yuan = self.raw_generator.u64(params.yuan_range.clone());
+
+        Ok(ScheduleEntry {
+            calendar_date,
+            date: calendar_date.to_date(false, params.week_format),
+            departure: clock_time(departure_minutes, params.day_part),
+            arrival: clock_time(departure_minutes + duration_minutes, params.day_part),
+            duration_minutes,
+            terminal: params.terminal,
+            terminal_number,
+            price: renminbi_from_cents(yuan * 100, CurrencyStyle::Everyday { formal: true }),
+        })
+    }
+}
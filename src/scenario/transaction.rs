@@ -0,0 +1,287 @@
+use crate::gregorian::{clock_time, CalendarDate};
+use crate::{
+    check_range, renminbi_from_cents, ChineseFormatGenerator, ChineseRandResult, RawGenerator,
+};
+use chinese_format::currency::{CurrencyStyle, RenminbiCurrency};
+use chinese_format::gregorian::{Date, LinearTime};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Variant};
+use digit_sequence::DigitSequence;
+use std::iter::repeat_with;
+use std::ops::RangeInclusive;
+
+const MINUTES_PER_DAY: u16 = 24 * 60;
+
+/// Direction of a [Transaction].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransactionKind {
+    /// Money paid into the account - `存入`.
+    Deposit,
+
+    /// Money taken from the account - `支出`.
+    Withdrawal,
+}
+
+impl ChineseFormat for TransactionKind {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            TransactionKind::Deposit => "存入".to_chinese(variant),
+            TransactionKind::Withdrawal => "支出".to_chinese(variant),
+        }
+    }
+}
+
+/// Bank transaction, whose resulting balance is the previous one
+/// plus or minus the amount.
+///
+/// Rendered like `二零二五年八月四日下午两点四十五分，存入贰佰元整，余额…，流水号…` -
+/// the previous balance being available for questions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Transaction {
+    /// The day of the transaction, as calendar value.
+    pub calendar_date: CalendarDate,
+
+    /// The day of the transaction, ready for rendering.
+    pub date: Date,
+
+    /// The time of the transaction.
+    pub time: LinearTime,
+
+    /// Whether the money was deposited or withdrawn.
+    pub kind: TransactionKind,
+
+    /// The amount of the transaction.
+    pub amount: RenminbiCurrency,
+
+    /// The balance before the transaction - not rendered.
+    pub previous_balance: RenminbiCurrency,
+
+    /// The balance after the transaction.
+    pub balance: RenminbiCurrency,
+
+    /// The reference number of the transaction.
+    pub reference: DigitSequence,
+}
+
+impl ChineseFormat for Transaction {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                self.date,
+                self.time,
+                "，",
+                self.kind,
+                self.amount,
+                ("，余额", "，餘額"),
+                self.balance,
+                ("，流水号", "，流水號"),
+                self.reference
+            ]
+        )
+        .collect()
+    }
+}
+
+/// Parameters for the random creation of [Transaction].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransactionParams {
+    /// The year range of the date.
+    /// If set to [None], then the year range of the [GeneratorConfig](crate::GeneratorConfig)
+    /// is used - by default, [DEFAULT_YEAR_RANGE](crate::gregorian::DEFAULT_YEAR_RANGE).
+    pub year_range: Option<RangeInclusive<u16>>,
+
+    /// The range of the amounts, in `分`.
+    pub amount_range: RangeInclusive<u64>,
+
+    /// The range of the balances before the transaction, in `分`.
+    pub balance_range: RangeInclusive<u64>,
+
+    /// The number of digits of the reference.
+    pub reference_length: u8,
+
+    /// The style of all the amounts.
+    #[cfg_attr(feature = "serde", serde(with = "crate::remote::CurrencyStyleDef"))]
+    pub style: CurrencyStyle,
+
+    /// If set to `true`, the time includes the day part,
+    /// plus a 12-hour format; otherwise, the 24-hour format applies.
+    pub day_part: bool,
+}
+
+/// By default, [TransactionParams] describes amounts from 1 to 10000 yuan,
+/// on balances up to 100000 yuan, with 12-digit references -
+/// in financial style, with day part.
+///
+/// ```
+/// use chinese_rand::scenario::*;
+/// use chinese_format::currency::CurrencyStyle;
+///
+/// assert_eq!(TransactionParams::default(), TransactionParams {
+///     year_range: None,
+///     amount_range: 100..=1_000_000,
+///     balance_range: 0..=10_000_000,
+///     reference_length: 12,
+///     style: CurrencyStyle::Financial,
+///     day_part: true
+/// });
+///
+/// let customized = TransactionParams::default()
+///     .with_year_range(2025..=2025)
+///     .with_amount_range(1..=100)
+///     .with_balance_range(100..=1000)
+///     .with_reference_length(8)
+///     .with_style(CurrencyStyle::Everyday { formal: true });
+///
+/// assert_eq!(customized, TransactionParams {
+///     year_range: Some(2025..=2025),
+///     amount_range: 1..=100,
+///     balance_range: 100..=1000,
+///     reference_length: 8,
+///     style: CurrencyStyle::Everyday { formal: true },
+///     day_part: true
+/// });
+/// ```
+impl Default for TransactionParams {
+    fn default() -> Self {
+        Self {
+            year_range: None,
+            amount_range: 100..=1_000_000,
+            balance_range: 0..=10_000_000,
+            reference_length: 12,
+            style: CurrencyStyle::Financial,
+            day_part: true,
+        }
+    }
+}
+
+impl TransactionParams {
+    /// Sets the year range.
+    pub fn with_year_range(mut self, year_range: RangeInclusive<u16>) -> Self {
+        self.year_range = Some(year_range);
+        self
+    }
+
+    /// Sets the range of the amounts, in `分`.
+    pub fn with_amount_range(mut self, amount_range: RangeInclusive<u64>) -> Self {
+        self.amount_range = amount_range;
+        self
+    }
+
+    /// Sets the range of the balances before the transaction, in `分`.
+    pub fn with_balance_range(mut self, balance_range: RangeInclusive<u64>) -> Self {
+        self.balance_range = balance_range;
+        self
+    }
+
+    /// Sets the number of digits of the reference.
+    pub fn with_reference_length(mut self, reference_length: u8) -> Self {
+        self.reference_length = reference_length;
+        self
+    }
+
+    /// Sets the style of all the amounts.
+    pub fn with_style(mut self, style: CurrencyStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [Transaction] - never withdrawing more
+    /// than the previous balance, nor depositing beyond [u64] cents.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if a range is empty, and with
+    /// [ChineseRandError::GenerationExhausted](crate::ChineseRandError::GenerationExhausted)
+    /// if no suitable transaction is found.
+    ///
+    /// **Required feature**: `scenario`.
+    ///
+    /// ```
+    /// use chinese_rand::{*, scenario::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let transaction = generator.transaction(
+    ///     TransactionParams::default()
+    ///         .with_year_range(2025..=2025)
+    ///         .with_amount_range(10_000..=50_000)
+    ///         .with_reference_length(8)
+    /// )?;
+    /// assert_eq!(
+    ///     transaction.to_chinese(Variant::Simplified),
+    ///     concat!(
+    ///         "二零二五年八月二十三日中午十一点四十三分，支出壹佰元陆角玖分整，",
+    ///         "余额陆万伍仟陆佰捌拾贰元捌角贰分整，流水号二六零一八五二四"
+    ///     )
+    /// );
+    ///
+    /// assert!(generator
+    ///     .transaction(TransactionParams::default().with_amount_range(100..=0))
+    ///     .is_err());
+    ///
+    /// let huge = generator.transaction(
+    ///     TransactionParams::default()
+    ///         .with_amount_range(u64::MAX / 2..=u64::MAX)
+    ///         .with_balance_range(u64::MAX / 2..=u64::MAX)
+    /// );
+    /// assert!(matches!(
+    ///     huge,
+    ///     Ok(Transaction { kind: TransactionKind::Withdrawal, .. })
+    ///         | Err(ChineseRandError::GenerationExhausted(_))
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transaction(&self, params: TransactionParams) -> ChineseRandResult<Transaction> {
+        let year_range = params
+            .year_range
+            .clone()
+            .unwrap_or_else(|| self.config.year_range.clone());
+
+        check_range(&year_range)?;
+        check_range(&params.amount_range)?;
+        check_range(&params.balance_range)?;
+
+        let (kind, amount_cents, previous_cents, balance_cents) =
+            self.retrying("Transaction", || {
+                let kind = if self.raw_generator.bool() {
+                    TransactionKind::Deposit
+                } else {
+                    TransactionKind::Withdrawal
+                };
+
+                let amount_cents = self.raw_generator.u64(params.amount_range.clone());
+                let previous_cents = self.raw_generator.u64(params.balance_range.clone());
+
+                let balance_cents = match kind {
+                    TransactionKind::Deposit => previous_cents.checked_add(amount_cents),
+                    TransactionKind::Withdrawal => previous_cents.checked_sub(amount_cents),
+                }?;
+
+                Some((kind, amount_cents, previous_cents, balance_cents))
+            })?;
+
+        let calendar_date = self.gregorian().calendar_date(year_range)?;
+        let minute_of_day = self.raw_generator.u16(0..=(MINUTES_PER_DAY - 1));
+
+        let reference: Vec<u8> = repeat_with(|| self.raw_generator.u8(0..=9))
+            .take(params.reference_length as usize)
+            .collect();
+
+        Ok(Transaction {
+            calendar_date,
+            date: calendar_date.to_date(false, None),
+            time: clock_time(minute_of_day, params.day_part),
+            kind,
+            amount: renminbi_from_cents(amount_cents, params.style),
+            previous_balance: renminbi_from_cents(previous_cents, params.style),
+            balance: renminbi_from_cents(balance_cents, params.style),
+            reference: reference.try_into().expect("Digits valid by construction"),
+        })
+    }
+}
@@ -0,0 +1,223 @@
+use crate::gregorian::CalendarDate;
+use crate::{
+    check_range, ChineseFormatGenerator, ChineseRandError, ChineseRandResult, Percentage,
+    RawGenerator, Temperature,
+};
+use chinese_format::gregorian::{Date, DayPart};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Variant};
+use std::ops::RangeInclusive;
+
+/// Weather forecast - whose lowest temperature never exceeds the highest one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WeatherReport {
+    /// The day of the forecast, as calendar value.
+    pub calendar_date: CalendarDate,
+
+    /// The day of the forecast, ready for rendering.
+    pub date: Date,
+
+    /// The part of the day to which the forecast refers.
+    pub day_part: DayPart,
+
+    /// The lowest temperature.
+    pub low: Temperature,
+
+    /// The highest temperature.
+    pub high: Temperature,
+
+    /// The probability of precipitation.
+    pub precipitation: Percentage,
+}
+
+impl ChineseFormat for WeatherReport {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                self.date,
+                self.day_part,
+                ("，最低气温", "，最低氣溫"),
+                self.low,
+                ("，最高气温", "，最高氣溫"),
+                self.high,
+                ("，降水概率", "，降水機率"),
+                self.precipitation
+            ]
+        )
+        .collect()
+    }
+}
+
+/// Parameters for the random creation of [WeatherReport].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeatherParams {
+    /// The year range of the date.
+    /// If set to [None], then the year range of the [GeneratorConfig](crate::GeneratorConfig)
+    /// is used - by default, [DEFAULT_YEAR_RANGE](crate::gregorian::DEFAULT_YEAR_RANGE).
+    pub year_range: Option<RangeInclusive<u16>>,
+
+    /// The range of both temperatures, in degrees Celsius.
+    pub celsius_range: RangeInclusive<i16>,
+
+    /// The maximum difference between the highest and the lowest temperature.
+    pub max_spread: u8,
+
+    /// The granularity of the precipitation probability - within 1..=100.
+    pub precipitation_step: u8,
+
+    /// Whether the scale (`摄氏`) is rendered before the unit.
+    pub explicit_scale: bool,
+}
+
+/// By default, [WeatherParams] describes temperatures from -20 to 40 degrees,
+/// at most 15 degrees apart, with precipitation probabilities multiple of 10%.
+///
+/// ```
+/// use chinese_rand::scenario::*;
+///
+/// assert_eq!(WeatherParams::default(), WeatherParams {
+///     year_range: None,
+///     celsius_range: -20..=40,
+///     max_spread: 15,
+///     precipitation_step: 10,
+///     explicit_scale: false
+/// });
+///
+/// let customized = WeatherParams::default()
+///     .with_year_range(2025..=2025)
+///     .with_celsius_range(10..=30)
+///     .with_max_spread(5)
+///     .with_precipitation_step(5)
+///     .with_explicit_scale(true);
+///
+/// assert_eq!(customized, WeatherParams {
+///     year_range: Some(2025..=2025),
+///     celsius_range: 10..=30,
+///     max_spread: 5,
+///     precipitation_step: 5,
+///     explicit_scale: true
+/// });
+/// ```
+impl Default for WeatherParams {
+    fn default() -> Self {
+        Self {
+            year_range: None,
+            celsius_range: -20..=40,
+            max_spread: 15,
+            precipitation_step: 10,
+            explicit_scale: false,
+        }
+    }
+}
+
+impl WeatherParams {
+    /// Sets the year range.
+    pub fn with_year_range(mut self, year_range: RangeInclusive<u16>) -> Self {
+        self.year_range = Some(year_range);
+        self
+    }
+
+    /// Sets the range of both temperatures.
+    pub fn with_celsius_range(mut self, celsius_range: RangeInclusive<i16>) -> Self {
+        self.celsius_range = celsius_range;
+        self
+    }
+
+    /// Sets the maximum difference between the temperatures.
+    pub fn with_max_spread(mut self, max_spread: u8) -> Self {
+        self.max_spread = max_spread;
+        self
+    }
+
+    /// Sets the granularity of the precipitation probability.
+    pub fn with_precipitation_step(mut self, precipitation_step: u8) -> Self {
+        self.precipitation_step = precipitation_step;
+        self
+    }
+
+    /// Sets whether the scale is rendered before the unit.
+    pub fn with_explicit_scale(mut self, explicit_scale: bool) -> Self {
+        self.explicit_scale = explicit_scale;
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [WeatherReport].
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if a range is empty, and
+    /// with [ChineseRandError::InvalidRange] if the precipitation step
+    /// is not in the 1..=100 range.
+    ///
+    /// **Required feature**: `scenario`.
+    ///
+    /// ```
+    /// use chinese_rand::{*, scenario::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let report = generator.weather_report(WeatherParams::default().with_year_range(2025..=2025))?;
+    /// assert!(report.low.celsius <= report.high.celsius);
+    /// assert_eq!(
+    ///     report.to_chinese(Variant::Simplified),
+    ///     "二零二五年八月四日上午，最低气温二十四度，最高气温二十八度，降水概率百分之五十"
+    /// );
+    ///
+    /// assert!(generator
+    ///     .weather_report(WeatherParams::default().with_precipitation_step(0))
+    ///     .is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn weather_report(&self, params: WeatherParams) -> ChineseRandResult<WeatherReport> {
+        let year_range = params
+            .year_range
+            .clone()
+            .unwrap_or_else(|| self.config.year_range.clone());
+
+        check_range(&year_range)?;
+        check_range(&params.celsius_range)?;
+
+        if !(1..=100).contains(&params.precipitation_step) {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Precipitation step not in 1..=100: {}",
+                params.precipitation_step
+            )));
+        }
+
+        let gregorian = self.gregorian();
+
+        let calendar_date = gregorian.calendar_date(year_range)?;
+        let day_part = gregorian.day_part();
+
+        let (min_celsius, max_celsius) = params.celsius_range.into_inner();
+
+        let low = self.raw_generator.i16(min_celsius..=max_celsius);
+        let high = self.raw_generator.i16(
+            low..=low
+                .saturating_add(params.max_spread as i16)
+                .min(max_celsius),
+        );
+
+        let precipitation = self.raw_generator.u8(0..=(100 / params.precipitation_step))
+            * params.precipitation_step;
+
+        Ok(WeatherReport {
+            calendar_date,
+            date: calendar_date.to_date(false, None),
+            day_part,
+            low: Temperature {
+                celsius: low,
+                explicit_scale: params.explicit_scale,
+            },
+            high: Temperature {
+                celsius: high,
+                explicit_scale: params.explicit_scale,
+            },
+            precipitation: Percentage(precipitation),
+        })
+    }
+}
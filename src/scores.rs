@@ -0,0 +1,211 @@
+use crate::{check_range, ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Variant};
+use std::ops::RangeInclusive;
+
+/// Result of a match - like `三比二`.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let result = ScorePair { home: 3, away: 2 };
+/// assert_eq!(result.to_chinese(Variant::Simplified), "三比二");
+///
+/// let result = ScorePair { home: 0, away: 10 };
+/// assert_eq!(result.to_chinese(Variant::Simplified), "零比十");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScorePair {
+    /// The score of the first side.
+    pub home: u16,
+
+    /// The score of the second side.
+    pub away: u16,
+}
+
+impl ChineseFormat for ScorePair {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(variant, [self.home, "比", self.away]).collect()
+    }
+}
+
+/// Exam result - like `八十五分` or `八十五点五分`.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let score = ExamScore { points: 85, half: false };
+/// assert_eq!(score.to_chinese(Variant::Simplified), "八十五分");
+///
+/// let score = ExamScore { points: 85, half: true };
+/// assert_eq!(score.to_chinese(Variant::Simplified), "八十五点五分");
+/// assert_eq!(score.to_chinese(Variant::Traditional), "八十五點五分");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExamScore {
+    /// The whole points.
+    pub points: u16,
+
+    /// Whether half a point is added.
+    pub half: bool,
+}
+
+impl ChineseFormat for ExamScore {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        if self.half {
+            chinese_vec!(variant, [self.points, ("点", "點"), "五", "分"]).collect()
+        } else {
+            chinese_vec!(variant, [self.points, "分"]).collect()
+        }
+    }
+}
+
+/// Parameters for the random creation of [ExamScore].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ExamScoreParams {
+    /// The range of the points.
+    pub range: RangeInclusive<u16>,
+
+    /// Whether scores ending in half a point can be generated.
+    pub half_points: bool,
+}
+
+/// By default, [ExamScoreParams] describes whole points from 0 to 100.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// assert_eq!(ExamScoreParams::default(), ExamScoreParams {
+///     range: 0..=100,
+///     half_points: false
+/// });
+///
+/// let customized = ExamScoreParams::default()
+///     .with_range(60..=150)
+///     .with_half_points(true);
+///
+/// assert_eq!(customized, ExamScoreParams {
+///     range: 60..=150,
+///     half_points: true
+/// });
+/// ```
+impl Default for ExamScoreParams {
+    fn default() -> Self {
+        Self {
+            range: 0..=100,
+            half_points: false,
+        }
+    }
+}
+
+impl ExamScoreParams {
+    /// Sets the range of the points.
+    pub fn with_range(mut self, range: RangeInclusive<u16>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets whether scores ending in half a point can be generated.
+    pub fn with_half_points(mut self, half_points: bool) -> Self {
+        self.half_points = half_points;
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [ScorePair], each side within the given range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let result = generator.score_pair(0..=5)?;
+    /// assert_eq!(result.to_chinese(Variant::Simplified), "四比二");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn score_pair(&self, range: RangeInclusive<u16>) -> ChineseRandResult<ScorePair> {
+        check_range(&range)?;
+
+        Ok(ScorePair {
+            home: self.raw_generator.u16(range.clone()),
+            away: self.raw_generator.u16(range),
+        })
+    }
+
+    /// Generates a random [ExamScore], with whole points in the given range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let score = generator.exam_score(60..=100)?;
+    /// assert_eq!(score.to_chinese(Variant::Simplified), "八十八分");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn exam_score(&self, range: RangeInclusive<u16>) -> ChineseRandResult<ExamScore> {
+        self.exam_score_with(ExamScoreParams::default().with_range(range))
+    }
+
+    /// Generates a random [ExamScore] - never exceeding the upper bound
+    /// of the range, even with half points.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let score = generator.exam_score_with(
+    ///     ExamScoreParams::default().with_half_points(true)
+    /// )?;
+    /// assert_eq!(score.to_chinese(Variant::Simplified), "六十九点五分");
+    ///
+    /// let full_marks = generator.exam_score_with(
+    ///     ExamScoreParams::default().with_range(100..=100).with_half_points(true)
+    /// )?;
+    /// assert_eq!(full_marks, ExamScore { points: 100, half: false });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn exam_score_with(&self, params: ExamScoreParams) -> ChineseRandResult<ExamScore> {
+        check_range(&params.range)?;
+
+        if !params.half_points {
+            return Ok(ExamScore {
+                points: self.raw_generator.u16(params.range),
+                half: false,
+            });
+        }
+
+        let (start, end) = params.range.into_inner();
+        let half_points = self
+            .raw_generator
+            .u32((start as u32 * 2)..=(end as u32 * 2));
+
+        Ok(ExamScore {
+            points: (half_points / 2) as u16,
+            half: half_points % 2 == 1,
+        })
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{ChineseFormatGenerator, ChineseRandResult, GenerationStats, RawGenerator};
+use std::cell::Cell;
+
+/// Snapshot of the state of a [ChineseFormatGenerator],
+/// returned by [ChineseFormatGenerator::state].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeneratorState(pub u64);
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Resets the underlying [RawGenerator](crate::RawGenerator) from the given seed -
+    /// failing with [ChineseRandError::Unsupported](crate::ChineseRandError::Unsupported)
+    /// if it does not support seeding.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// generator.seed(90)?;
+    /// let first = generator.integer(0..=1000)?;
+    ///
+    /// generator.seed(90)?;
+    /// assert_eq!(generator.integer(0..=1000)?, first);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn seed(&self, seed: u64) -> ChineseRandResult<()> {
+        self.raw_generator.seed(seed)
+    }
+
+    /// Takes a snapshot of the current state, that can be passed
+    /// to [restore](Self::restore) to replay the subsequent values.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let state = generator.state()?;
+    /// let values = (generator.integer(0..=1000)?, generator.integer(0..=1000)?);
+    ///
+    /// generator.restore(state)?;
+    /// assert_eq!((generator.integer(0..=1000)?, generator.integer(0..=1000)?), values);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn state(&self) -> ChineseRandResult<GeneratorState> {
+        self.raw_generator.get_seed().map(GeneratorState)
+    }
+
+    /// Restores a state previously returned by [state](Self::state).
+    pub fn restore(&self, state: GeneratorState) -> ChineseRandResult<()> {
+        self.raw_generator.seed(state.0)
+    }
+
+    /// Creates a child generator - with the same [Level](crate::Level), [RegionProfile](crate::RegionProfile),
+    /// [GeneratorConfig](crate::GeneratorConfig) and [event observer](ChineseFormatGenerator::on_event) -
+    /// whose seed is deterministically derived from this generator, which advances.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    ///     .with_level(Level::Beginner);
+    /// let child = generator.fork()?;
+    /// assert_eq!(child.level(), Some(Level::Beginner));
+    ///
+    /// let same_parent = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    ///     .with_level(Level::Beginner);
+    /// let same_child = same_parent.fork()?;
+    ///
+    /// assert_eq!(child.count(0..=1000)?, same_child.count(0..=1000)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fork(&self) -> ChineseRandResult<ChineseFormatGenerator> {
+        Ok(ChineseFormatGenerator {
+            raw_generator: self.raw_generator.fork()?,
+            level: self.level,
+            region: self.region,
+            max_retries: self.max_retries,
+            stats: Cell::new(GenerationStats::default()),
+            observer: self.observer.clone(),
+            config: self.config.clone(),
+            #[cfg(feature = "stats")]
+            statistics: Default::default(),
+        })
+    }
+
+    /// Borrows this generator's settings - its [Level](crate::Level), [RegionProfile](crate::RegionProfile),
+    /// [GeneratorConfig](crate::GeneratorConfig) and [event observer](ChineseFormatGenerator::on_event) -
+    /// to draw from the given [RawGenerator] instead; thus, any generator method can be called
+    /// on an explicit random stream - like a different one for each parallel test shard.
+    ///
+    /// ```
+    /// # #[cfg(feature = "gregorian")]
+    /// # {
+    /// use chinese_rand::{*, gregorian::*};
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    ///     .with_level(Level::Beginner);
+    ///
+    /// let shard_stream = FastRandGenerator::with_seed(7);
+    /// let shard = generator.using(&shard_stream);
+    /// assert_eq!(shard.level(), Some(Level::Beginner));
+    ///
+    /// let date = shard.gregorian().date(DateParams::default())?;
+    /// assert_eq!(date.to_chinese(Variant::Simplified), "一九四九年四月十三日");
+    ///
+    /// let same_stream: &dyn RawGenerator = &FastRandGenerator::with_seed(7);
+    /// let same_date = generator.using(same_stream).gregorian().date(DateParams::default())?;
+    /// assert_eq!(same_date, date);
+    /// # Ok(())
+    /// # }
+    /// # main().unwrap();
+    /// # }
+    /// ```
+    pub fn using<'a, G: RawGenerator + ?Sized>(
+        &self,
+        raw_generator: &'a G,
+    ) -> ChineseFormatGenerator<&'a G> {
+        ChineseFormatGenerator {
+            raw_generator,
+            level: self.level,
+            region: self.region,
+            max_retries: self.max_retries,
+            stats: Cell::new(GenerationStats::default()),
+            observer: self.observer.clone(),
+            config: self.config.clone(),
+            #[cfg(feature = "stats")]
+            statistics: Default::default(),
+        }
+    }
+
+    /// Runs the given function on a view of this generator whose [RawGenerator]
+    /// is type-erased - as expected by the registries of custom generation
+    /// functions - carrying over the stats and the statistics.
+    pub(crate) fn erased<T>(
+        &self,
+        run: impl FnOnce(&ChineseFormatGenerator<&dyn RawGenerator>) -> T,
+    ) -> T {
+        let view = ChineseFormatGenerator {
+            raw_generator: &self.raw_generator as &dyn RawGenerator,
+            level: self.level,
+            region: self.region,
+            max_retries: self.max_retries,
+            stats: Cell::new(self.stats.get()),
+            observer: self.observer.clone(),
+            config: self.config.clone(),
+            #[cfg(feature = "stats")]
+            statistics: self.statistics.take().into(),
+        };
+
+        let result = run(&view);
+
+        self.stats.set(view.stats.get());
+        #[cfg(feature = "stats")]
+        self.statistics.replace(view.statistics.into_inner());
+
+        result
+    }
+}
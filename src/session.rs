@@ -0,0 +1,192 @@
+use crate::{ChineseFormatGenerator, ChineseRandResult};
+use chinese_format::{Count, CountBase, Fraction};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::DateParams;
+#[cfg(feature = "currency")]
+use crate::RenminbiParams;
+#[cfg(feature = "currency")]
+use chinese_format::currency::RenminbiCurrency;
+#[cfg(feature = "gregorian")]
+use chinese_format::gregorian::Date;
+
+/// How many values per method a [SessionGenerator] remembers by default.
+pub const DEFAULT_SESSION_MEMORY: usize = 100;
+
+/// Wraps a [ChineseFormatGenerator] so as to avoid repeated values within
+/// a session - for example, the same price twice in a 30-item worksheet.
+///
+/// For each method, the most recent values - up to a given memory size -
+/// are remembered, comparing them via their debug representation;
+/// repeats are discarded within the
+/// [retry budget](ChineseFormatGenerator::with_max_retries) of the
+/// wrapped generator, beyond which generation fails with
+/// [ChineseRandError::GenerationExhausted](crate::ChineseRandError::GenerationExhausted).
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// # fn main() -> GenericResult<()> {
+/// let session = SessionGenerator::new(
+///     ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+/// );
+///
+/// let mut integers: Vec<i128> = (0..10)
+///     .map(|_| session.integer(1..=10))
+///     .collect::<Result<_, _>>()?;
+/// integers.sort();
+/// assert_eq!(integers, (1..=10).collect::<Vec<_>>());
+///
+/// assert!(session.integer(1..=10).is_err());
+///
+/// session.clear();
+/// assert!(session.integer(1..=10).is_ok());
+/// # Ok(())
+/// # }
+/// ```
+pub struct SessionGenerator {
+    generator: ChineseFormatGenerator,
+    memory: usize,
+    history: RefCell<HashMap<&'static str, VecDeque<String>>>,
+}
+
+impl SessionGenerator {
+    /// Creates a session remembering [DEFAULT_SESSION_MEMORY] values per method.
+    pub fn new(generator: ChineseFormatGenerator) -> Self {
+        Self {
+            generator,
+            memory: DEFAULT_SESSION_MEMORY,
+            history: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Sets how many values per method are remembered - the oldest
+    /// ones being forgotten first.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let session = SessionGenerator::new(
+    ///     ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    /// ).with_memory(1);
+    /// assert_eq!(session.memory(), 1);
+    ///
+    /// for _ in 0..10 {
+    ///     session.integer(1..=2)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_memory(mut self, memory: usize) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    /// How many values per method are remembered.
+    pub fn memory(&self) -> usize {
+        self.memory
+    }
+
+    /// The wrapped generator - whose values are not remembered.
+    pub fn generator(&self) -> &ChineseFormatGenerator {
+        &self.generator
+    }
+
+    /// Forgets all the values generated so far.
+    pub fn clear(&self) {
+        self.history.borrow_mut().clear();
+    }
+
+    /// Calls `generate` until it returns a value not yet remembered
+    /// for the given method name - which can be any string.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let session = SessionGenerator::new(
+    ///     ChineseFormatGenerator::new(FastRandGenerator::with_seed(90))
+    /// );
+    ///
+    /// let first = session.unique("age", |generator| Ok(generator.age(AgeProfile::Child)))?;
+    /// let second = session.unique("age", |generator| Ok(generator.age(AgeProfile::Child)))?;
+    /// assert_ne!(first, second);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unique<T: Debug>(
+        &self,
+        method: &'static str,
+        mut generate: impl FnMut(&ChineseFormatGenerator) -> ChineseRandResult<T>,
+    ) -> ChineseRandResult<T> {
+        let (value, key) = self.generator.try_retrying(method, || {
+            let value = generate(&self.generator)?;
+            let key = format!("{:?}", value);
+
+            let is_repeated = self
+                .history
+                .borrow()
+                .get(method)
+                .is_some_and(|values| values.contains(&key));
+
+            Ok((!is_repeated).then_some((value, key)))
+        })?;
+
+        if self.memory > 0 {
+            let mut history = self.history.borrow_mut();
+            let values = history.entry(method).or_default();
+
+            if values.len() >= self.memory {
+                values.pop_front();
+            }
+
+            values.push_back(key);
+        }
+
+        Ok(value)
+    }
+
+    /// Non-repeating [ChineseFormatGenerator::integer].
+    pub fn integer(&self, range: RangeInclusive<i128>) -> ChineseRandResult<i128> {
+        self.unique("integer", |generator| generator.integer(range.clone()))
+    }
+
+    /// Non-repeating [ChineseFormatGenerator::count].
+    pub fn count(&self, range: RangeInclusive<CountBase>) -> ChineseRandResult<Count> {
+        self.unique("count", |generator| generator.count(range.clone()))
+    }
+
+    /// Non-repeating [ChineseFormatGenerator::fraction].
+    pub fn fraction(
+        &self,
+        denominator_range: RangeInclusive<u128>,
+        numerator_range: RangeInclusive<i128>,
+    ) -> ChineseRandResult<Fraction> {
+        self.unique("fraction", |generator| {
+            generator.fraction(denominator_range.clone(), numerator_range.clone())
+        })
+    }
+
+    /// Non-repeating [ChineseFormatGenerator::renminbi].
+    ///
+    /// **Required feature**: `currency`.
+    #[cfg(feature = "currency")]
+    pub fn renminbi(&self, params: RenminbiParams) -> ChineseRandResult<RenminbiCurrency> {
+        self.unique("renminbi", |generator| generator.renminbi(params.clone()))
+    }
+
+    /// Non-repeating [GregorianGenerator::date](crate::gregorian::GregorianGenerator::date).
+    ///
+    /// **Required feature**: `gregorian`.
+    #[cfg(feature = "gregorian")]
+    pub fn date(&self, params: DateParams) -> ChineseRandResult<Date> {
+        self.unique("date", |generator| {
+            generator.gregorian().date(params.clone())
+        })
+    }
+}
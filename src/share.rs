@@ -0,0 +1,201 @@
+use crate::{
+    check_range, ChineseFormatGenerator, ChineseRandError, ChineseRandResult, RawGenerator,
+};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Count, CountBase, Variant};
+use std::ops::RangeInclusive;
+
+/// Statistical statement combining a quantity and its share of a total -
+/// like `三百人，占百分之三十` - for chart-description practice.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Count, Variant};
+///
+/// let statement = ShareStatement {
+///     part: Count(300),
+///     total: Count(1000),
+///     percentage: 30,
+///     unit: ("人".to_string(), "人".to_string())
+/// };
+///
+/// assert_eq!(statement.to_chinese(Variant::Simplified), "三百人，占百分之三十");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShareStatement {
+    /// The quantity mentioned in the statement.
+    pub part: Count,
+
+    /// The total of which the quantity is a share - not rendered,
+    /// but available for questions.
+    pub total: Count,
+
+    /// The share, in percentage points.
+    pub percentage: u8,
+
+    /// The unit following the quantity - simplified and traditional.
+    pub unit: (String, String),
+}
+
+/// Percentage - like `百分之三十`; 100% is rendered as `百分之百`.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// assert_eq!(Percentage(30).to_chinese(Variant::Simplified), "百分之三十");
+/// assert_eq!(Percentage(100).to_chinese(Variant::Simplified), "百分之百");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Percentage(pub u8);
+
+impl ChineseFormat for Percentage {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        if self.0 == 100 {
+            return "百分之百".to_chinese(variant);
+        }
+
+        chinese_vec!(variant, ["百分之", self.0]).collect()
+    }
+}
+
+impl ShareStatement {
+    /// The share, as [Percentage].
+    pub fn share(&self) -> Percentage {
+        Percentage(self.percentage)
+    }
+}
+
+impl ChineseFormat for ShareStatement {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        chinese_vec!(
+            variant,
+            [
+                self.part,
+                (self.unit.0.as_str(), self.unit.1.as_str()),
+                "，占",
+                self.share()
+            ]
+        )
+        .collect()
+    }
+}
+
+/// Parameters for the random creation of [ShareStatement].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ShareParams {
+    /// The range of the total.
+    pub total_range: RangeInclusive<CountBase>,
+
+    /// The range of the share, in percentage points - within 0..=100.
+    pub percentage_range: RangeInclusive<u8>,
+
+    /// The unit following the quantity - simplified and traditional.
+    pub unit: (String, String),
+}
+
+/// By default, [ShareParams] describes shares from 1% to 99% of
+/// 100 to 100000 people.
+///
+/// ```
+/// use chinese_rand::*;
+///
+/// assert_eq!(ShareParams::default(), ShareParams {
+///     total_range: 100..=100_000,
+///     percentage_range: 1..=99,
+///     unit: ("人".to_string(), "人".to_string())
+/// });
+///
+/// let customized = ShareParams::default()
+///     .with_total_range(1000..=2000)
+///     .with_percentage_range(10..=90)
+///     .with_unit("辆车", "輛車");
+///
+/// assert_eq!(customized, ShareParams {
+///     total_range: 1000..=2000,
+///     percentage_range: 10..=90,
+///     unit: ("辆车".to_string(), "輛車".to_string())
+/// });
+/// ```
+impl Default for ShareParams {
+    fn default() -> Self {
+        Self {
+            total_range: 100..=100_000,
+            percentage_range: 1..=99,
+            unit: ("人".to_string(), "人".to_string()),
+        }
+    }
+}
+
+impl ShareParams {
+    /// Sets the range of the total.
+    pub fn with_total_range(mut self, total_range: RangeInclusive<CountBase>) -> Self {
+        self.total_range = total_range;
+        self
+    }
+
+    /// Sets the range of the share, in percentage points.
+    pub fn with_percentage_range(mut self, percentage_range: RangeInclusive<u8>) -> Self {
+        self.percentage_range = percentage_range;
+        self
+    }
+
+    /// Sets the unit following the quantity.
+    pub fn with_unit(mut self, simplified: &str, traditional: &str) -> Self {
+        self.unit = (simplified.to_string(), traditional.to_string());
+        self
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [ShareStatement] - whose quantity is exactly
+    /// the given percentage of the total.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange] if a range is empty, with
+    /// [ChineseRandError::InvalidRange] if the percentages exceed 100, and
+    /// with [ChineseRandError::GenerationExhausted] if no total is exactly divisible.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let statement = generator.share_statement(ShareParams::default())?;
+    /// assert_eq!(statement.to_chinese(Variant::Simplified), "三千八百七十九人，占百分之十八");
+    /// assert_eq!(statement.part.0 * 100, statement.total.0 * statement.percentage as u128);
+    ///
+    /// assert!(generator
+    ///     .share_statement(ShareParams::default().with_percentage_range(50..=101))
+    ///     .is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn share_statement(&self, params: ShareParams) -> ChineseRandResult<ShareStatement> {
+        check_range(&params.total_range)?;
+        check_range(&params.percentage_range)?;
+
+        if *params.percentage_range.end() > 100 {
+            return Err(ChineseRandError::InvalidRange(format!(
+                "Percentages beyond 100: {:?}",
+                params.percentage_range
+            )));
+        }
+
+        self.retrying("Share statement", || {
+            let total = self.raw_generator.u128(params.total_range.clone());
+            let percentage = self.raw_generator.u8(params.percentage_range.clone());
+
+            let scaled = total.checked_mul(percentage as u128)?;
+
+            scaled.is_multiple_of(100).then(|| ShareStatement {
+                part: Count(scaled / 100),
+                total: Count(total),
+                percentage,
+                unit: params.unit.clone(),
+            })
+        })
+    }
+}
@@ -0,0 +1,203 @@
+//! Export of generated values as [SSML](https://www.w3.org/TR/speech-synthesis11/),
+//! so that listening-comprehension audio can be synthesized directly.
+//!
+//! **Required feature**: `ssml`.
+use chinese_format::{ChineseFormat, Variant};
+
+/// The pause inserted by default between consecutive fragments, in milliseconds.
+///
+/// **Required feature**: `ssml`.
+pub const DEFAULT_PAUSE_MILLIS: u32 = 500;
+
+/// How a speech synthesizer should interpret a fragment -
+/// rendered as a `<say-as>` element.
+///
+/// **Required feature**: `ssml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SayAs {
+    /// `cardinal` - for integers and counts.
+    Cardinal,
+
+    /// `ordinal` - for ordinals such as `第三`.
+    Ordinal,
+
+    /// `date`.
+    Date,
+
+    /// `time`.
+    Time,
+
+    /// `currency`.
+    Currency,
+
+    /// `telephone` - for digit sequences read one by one.
+    Telephone,
+
+    /// `characters` - spelling each logogram.
+    Characters,
+}
+
+impl SayAs {
+    /// The value of the `interpret-as` attribute.
+    ///
+    /// ```
+    /// use chinese_rand::ssml::*;
+    ///
+    /// assert_eq!(SayAs::Telephone.interpret_as(), "telephone");
+    /// ```
+    pub fn interpret_as(&self) -> &'static str {
+        match self {
+            SayAs::Cardinal => "cardinal",
+            SayAs::Ordinal => "ordinal",
+            SayAs::Date => "date",
+            SayAs::Time => "time",
+            SayAs::Currency => "currency",
+            SayAs::Telephone => "telephone",
+            SayAs::Characters => "characters",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Fragment {
+    Text {
+        logograms: String,
+        say_as: Option<SayAs>,
+    },
+    Pause {
+        millis: u32,
+    },
+}
+
+/// Sequence of generated values, rendered in a given [Variant]
+/// and exported as a single `<speak>` document.
+///
+/// A pause is automatically inserted between consecutive values,
+/// unless an explicit one is added via [pause](Self::pause).
+///
+/// ```
+/// use chinese_rand::ssml::*;
+/// use chinese_format::{Count, Variant};
+///
+/// let ssml = SsmlDocument::new(Variant::Simplified)
+///     .with_pause(300)
+///     .value(&Count(3), Some(SayAs::Cardinal))
+///     .value(&"<个>", None)
+///     .pause(1000)
+///     .value(&("两点", "兩點"), Some(SayAs::Time))
+///     .to_ssml();
+///
+/// assert_eq!(
+///     ssml,
+///     concat!(
+///         r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="zh-CN">"#,
+///         r#"<say-as interpret-as="cardinal">三</say-as>"#,
+///         r#"<break time="300ms"/>"#,
+///         r#"&lt;个&gt;"#,
+///         r#"<break time="1000ms"/>"#,
+///         r#"<say-as interpret-as="time">两点</say-as>"#,
+///         r#"</speak>"#
+///     )
+/// );
+///
+/// let traditional = SsmlDocument::new(Variant::Traditional)
+///     .value(&("两点", "兩點"), None)
+///     .to_ssml();
+///
+/// assert_eq!(
+///     traditional,
+///     r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="zh-TW">兩點</speak>"#
+/// );
+/// ```
+///
+/// **Required feature**: `ssml`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SsmlDocument {
+    variant: Variant,
+    pause_millis: u32,
+    fragments: Vec<Fragment>,
+}
+
+impl SsmlDocument {
+    /// Creates an empty document - whose language depends on the [Variant].
+    pub fn new(variant: Variant) -> Self {
+        Self {
+            variant,
+            pause_millis: DEFAULT_PAUSE_MILLIS,
+            fragments: Vec::new(),
+        }
+    }
+
+    /// Sets the pause automatically inserted between consecutive values.
+    pub fn with_pause(mut self, pause_millis: u32) -> Self {
+        self.pause_millis = pause_millis;
+        self
+    }
+
+    /// Appends a value, rendered in the document's [Variant] and optionally
+    /// wrapped in a `<say-as>` element.
+    pub fn value(mut self, value: &dyn ChineseFormat, say_as: Option<SayAs>) -> Self {
+        if let Some(Fragment::Text { .. }) = self.fragments.last() {
+            self.fragments.push(Fragment::Pause {
+                millis: self.pause_millis,
+            });
+        }
+
+        self.fragments.push(Fragment::Text {
+            logograms: value.to_chinese(self.variant).logograms,
+            say_as,
+        });
+
+        self
+    }
+
+    /// Appends an explicit pause, replacing the automatic one.
+    pub fn pause(mut self, millis: u32) -> Self {
+        self.fragments.push(Fragment::Pause { millis });
+        self
+    }
+
+    /// Renders the whole `<speak>` document.
+    pub fn to_ssml(&self) -> String {
+        let language = match self.variant {
+            Variant::Simplified => "zh-CN",
+            Variant::Traditional => "zh-TW",
+        };
+
+        let body: String = self
+            .fragments
+            .iter()
+            .map(|fragment| match fragment {
+                Fragment::Text {
+                    logograms,
+                    say_as: Some(say_as),
+                } => format!(
+                    r#"<say-as interpret-as="{}">{}</say-as>"#,
+                    say_as.interpret_as(),
+                    escape(logograms)
+                ),
+
+                Fragment::Text {
+                    logograms,
+                    say_as: None,
+                } => escape(logograms),
+
+                Fragment::Pause { millis } => format!(r#"<break time="{}ms"/>"#, millis),
+            })
+            .collect();
+
+        format!(
+            r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="{}">{}</speak>"#,
+            language, body
+        )
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
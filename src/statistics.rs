@@ -0,0 +1,127 @@
+use crate::{ChineseFormatGenerator, RawGenerator};
+
+#[cfg(feature = "stats")]
+use std::collections::BTreeMap;
+
+/// Occurrences of the generated values, by bucket.
+///
+/// **Required feature**: `stats`.
+#[cfg(feature = "stats")]
+pub type Histogram = BTreeMap<String, u64>;
+
+/// The [Histogram] of every generator method called so far,
+/// returned by [ChineseFormatGenerator::statistics].
+///
+/// The buckets depend on the method: integers, counts and yuan amounts are
+/// grouped by order of magnitude - like `10..=99` - fractions by denominator
+/// and dates by month - from `01` to `12`, or `none`.
+///
+/// **Required feature**: `stats`.
+#[cfg(feature = "stats")]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Statistics {
+    histograms: BTreeMap<&'static str, Histogram>,
+}
+
+#[cfg(feature = "stats")]
+impl Statistics {
+    /// The histogram of the given method, if it has ever been called.
+    pub fn histogram(&self, method: &str) -> Option<&Histogram> {
+        self.histograms.get(method)
+    }
+
+    /// The names of the recorded methods, in alphabetical order.
+    pub fn methods(&self) -> impl Iterator<Item = &str> {
+        self.histograms.keys().copied()
+    }
+
+    /// How many values the given method has generated.
+    pub fn total(&self, method: &str) -> u64 {
+        self.histogram(method)
+            .map(|histogram| histogram.values().sum())
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(feature = "stats")]
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Snapshot of the distribution of the values generated so far -
+    /// for example, to verify that some exercise material covers all the months.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// for _ in 0..100 {
+    ///     generator.integer(0..=999)?;
+    /// }
+    /// generator.count(5..=5)?;
+    ///
+    /// let statistics = generator.statistics();
+    /// assert_eq!(statistics.methods().collect::<Vec<_>>(), vec!["count", "integer"]);
+    /// assert_eq!(statistics.total("integer"), 100);
+    ///
+    /// let count_histogram = statistics.histogram("count").unwrap();
+    /// assert_eq!(count_histogram.get("1..=9"), Some(&1));
+    ///
+    /// generator.reset_statistics();
+    /// assert_eq!(generator.statistics().total("integer"), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `stats`.
+    pub fn statistics(&self) -> Statistics {
+        self.statistics.borrow().clone()
+    }
+
+    /// Discards all the recorded histograms.
+    ///
+    /// **Required feature**: `stats`.
+    pub fn reset_statistics(&self) {
+        *self.statistics.borrow_mut() = Statistics::default();
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Adds an occurrence to the given bucket of the method's histogram -
+    /// the bucket being computed only when the `stats` feature is enabled.
+    #[cfg(feature = "stats")]
+    pub(crate) fn record(&self, method: &'static str, bucket: impl FnOnce() -> String) {
+        *self
+            .statistics
+            .borrow_mut()
+            .histograms
+            .entry(method)
+            .or_default()
+            .entry(bucket())
+            .or_default() += 1;
+    }
+
+    #[cfg(not(feature = "stats"))]
+    pub(crate) fn record(&self, _method: &'static str, _bucket: impl FnOnce() -> String) {}
+}
+
+/// The order-of-magnitude bucket of an integer - like `100..=999` or `-9..=-1`.
+pub(crate) fn magnitude_bucket(value: i128) -> String {
+    if value == 0 {
+        return "0".to_string();
+    }
+
+    let magnitude = value.unsigned_abs();
+
+    let mut lower: u128 = 1;
+    while lower <= magnitude / 10 {
+        lower *= 10;
+    }
+
+    let upper = lower.saturating_mul(10) - 1;
+
+    if value > 0 {
+        format!("{}..={}", lower, upper)
+    } else {
+        format!("-{}..=-{}", upper, lower)
+    }
+}
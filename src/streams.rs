@@ -0,0 +1,192 @@
+use crate::PlanItem;
+
+#[cfg(feature = "fastrand")]
+use crate::{
+    ChineseFormatGenerator, ChineseRandResult, FastRandGenerator, GenerationPlan, GenerationStats,
+    RawGenerator,
+};
+#[cfg(feature = "fastrand")]
+use std::{
+    cell::Cell,
+    collections::{hash_map::Entry, HashMap},
+};
+
+/// Family of values drawing from the same deterministic sub-stream -
+/// see [ChineseFormatGenerator::sub_stream].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StreamCategory {
+    /// Integers, counts, fractions and every other numeric value.
+    Numbers,
+
+    /// Currency amounts.
+    Currency,
+
+    /// Dates, times and durations.
+    Dates,
+}
+
+impl StreamCategory {
+    /// All the categories, in declaration order.
+    pub const ALL: [StreamCategory; 3] = [
+        StreamCategory::Numbers,
+        StreamCategory::Currency,
+        StreamCategory::Dates,
+    ];
+
+    /// Derives the seed of this category's sub-stream from a master seed,
+    /// so that different categories get unrelated sequences.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// let numbers = StreamCategory::Numbers.derive_seed(90);
+    /// let dates = StreamCategory::Dates.derive_seed(90);
+    ///
+    /// assert_ne!(numbers, dates);
+    /// assert_eq!(numbers, StreamCategory::Numbers.derive_seed(90));
+    /// ```
+    pub fn derive_seed(&self, master_seed: u64) -> u64 {
+        // SplitMix64 finalizer, applied to a per-category offset
+        let mut z =
+            master_seed.wrapping_add((*self as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl PlanItem {
+    /// The [StreamCategory] of the item.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// assert_eq!(PlanItem::Integer { range: 0..=9 }.category(), StreamCategory::Numbers);
+    /// ```
+    pub fn category(&self) -> StreamCategory {
+        match self {
+            #[cfg(feature = "currency")]
+            PlanItem::Renminbi(_) => StreamCategory::Currency,
+
+            #[cfg(feature = "gregorian")]
+            PlanItem::Date(_)
+            | PlanItem::LinearTime(_)
+            | PlanItem::DeltaTime
+            | PlanItem::Duration(_) => StreamCategory::Dates,
+
+            _ => StreamCategory::Numbers,
+        }
+    }
+}
+
+#[cfg(feature = "fastrand")]
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Creates a child generator - with the same [Level](crate::Level), [RegionProfile](crate::RegionProfile),
+    /// [GeneratorConfig](crate::GeneratorConfig) and [event observer](ChineseFormatGenerator::on_event) -
+    /// drawing from a [FastRandGenerator] seeded by [derive_seed](StreamCategory::derive_seed):
+    /// thus, its sequence only depends on the master seed and on the category,
+    /// while this generator is left untouched - whatever its [RawGenerator].
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(7));
+    ///
+    /// let numbers = generator.sub_stream(90, StreamCategory::Numbers);
+    /// let first = numbers.integer(0..=1000)?;
+    ///
+    /// let same_numbers = generator.sub_stream(90, StreamCategory::Numbers);
+    /// assert_eq!(same_numbers.integer(0..=1000)?, first);
+    ///
+    /// let untouched = ChineseFormatGenerator::new(FastRandGenerator::with_seed(7));
+    /// assert_eq!(generator.integer(0..=1000)?, untouched.integer(0..=1000)?);
+    ///
+    /// let unforkable = ChineseFormatGenerator::new(ByteSliceGenerator::new(&[1, 2, 3]));
+    /// let bytes_numbers = unforkable.sub_stream(90, StreamCategory::Numbers);
+    /// assert_eq!(bytes_numbers.integer(0..=1000)?, first);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `fastrand`.
+    pub fn sub_stream(&self, master_seed: u64, category: StreamCategory) -> ChineseFormatGenerator {
+        ChineseFormatGenerator {
+            raw_generator: Box::new(FastRandGenerator::with_seed(
+                category.derive_seed(master_seed),
+            )),
+            level: self.level,
+            region: self.region,
+            max_retries: self.max_retries,
+            stats: Cell::new(GenerationStats::default()),
+            observer: self.observer.clone(),
+            config: self.config.clone(),
+            #[cfg(feature = "stats")]
+            statistics: Default::default(),
+        }
+    }
+
+    /// Like [run_plan](Self::run_plan), but each [StreamCategory] draws
+    /// from its own [sub_stream](Self::sub_stream): thus, editing the entries
+    /// of one category does not alter the values of the others.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::Variant;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// # #[cfg(feature = "gregorian")]
+    /// # {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+    ///
+    /// let plan = GenerationPlan {
+    ///     variant: Variant::Simplified,
+    ///     entries: vec![
+    ///         PlanEntry { item: PlanItem::Integer { range: 0..=99 }, count: 1 },
+    ///         PlanEntry { item: PlanItem::DeltaTime, count: 1 },
+    ///         PlanEntry { item: PlanItem::Integer { range: 0..=99 }, count: 1 },
+    ///     ],
+    /// };
+    ///
+    /// let mut edited_plan = plan.clone();
+    /// edited_plan.entries[1].count = 3;
+    ///
+    /// let rendered = generator.run_plan_streamed(&plan, 90)?;
+    /// let edited_rendered = generator.run_plan_streamed(&edited_plan, 90)?;
+    ///
+    /// assert_eq!(rendered[0], edited_rendered[0]);
+    /// assert_eq!(rendered[2], edited_rendered[4]);
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `fastrand`.
+    pub fn run_plan_streamed(
+        &self,
+        plan: &GenerationPlan,
+        master_seed: u64,
+    ) -> ChineseRandResult<Vec<String>> {
+        let mut streams: HashMap<StreamCategory, ChineseFormatGenerator> = HashMap::new();
+        let mut rendered = Vec::new();
+
+        for entry in &plan.entries {
+            let category = entry.item.category();
+
+            let stream = match streams.entry(category) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(self.sub_stream(master_seed, category)),
+            };
+
+            for _ in 0..entry.count {
+                let value = stream.generate_plan_item(&entry.item)?;
+
+                rendered.push(value.to_chinese(plan.variant).to_string());
+            }
+        }
+
+        Ok(rendered)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{check_range, ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use chinese_format::{chinese_vec, Chinese, ChineseFormat, Variant};
+use std::ops::RangeInclusive;
+
+/// Temperature in degrees Celsius - such as `二十八度` or `零下五度`.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let temperature = Temperature { celsius: 28, explicit_scale: false };
+/// assert_eq!(temperature.to_chinese(Variant::Simplified), "二十八度");
+///
+/// let temperature = Temperature { celsius: -5, explicit_scale: false };
+/// assert_eq!(temperature.to_chinese(Variant::Simplified), "零下五度");
+///
+/// let temperature = Temperature { celsius: -5, explicit_scale: true };
+/// assert_eq!(temperature.to_chinese(Variant::Simplified), "零下五摄氏度");
+/// assert_eq!(temperature.to_chinese(Variant::Traditional), "零下五攝氏度");
+///
+/// let temperature = Temperature { celsius: 0, explicit_scale: false };
+/// assert_eq!(temperature.to_chinese(Variant::Simplified), "零度");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Temperature {
+    /// The degrees Celsius.
+    pub celsius: i16,
+
+    /// Whether the scale (`摄氏`) is rendered before the unit.
+    pub explicit_scale: bool,
+}
+
+impl ChineseFormat for Temperature {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        let below_zero = if self.celsius < 0 { "零下" } else { "" };
+
+        let scale: &dyn ChineseFormat = if self.explicit_scale {
+            &("摄氏", "攝氏")
+        } else {
+            &""
+        };
+
+        chinese_vec!(
+            variant,
+            [below_zero, self.celsius.unsigned_abs(), scale, "度"]
+        )
+        .collect()
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a random [Temperature], in degrees Celsius within the given range.
+    ///
+    /// Fails with [ChineseRandError::EmptyRange](crate::ChineseRandError::EmptyRange)
+    /// if the range is empty.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// fastrand::seed(90);
+    /// let raw_generator = FastRandGenerator::new();
+    /// let generator = ChineseFormatGenerator::new(raw_generator);
+    ///
+    /// let temperature = generator.temperature(-20..=40, false)?;
+    /// assert_eq!(temperature.to_chinese(Variant::Simplified), "二十二度");
+    ///
+    /// let temperature = generator.temperature(-20..=-1, true)?;
+    /// assert_eq!(temperature.to_chinese(Variant::Simplified), "零下十四摄氏度");
+    ///
+    /// assert_eq!(
+    ///     generator.temperature(40..=-20, false),
+    ///     Err(ChineseRandError::EmptyRange("40..=-20".to_string()))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn temperature(
+        &self,
+        celsius_range: RangeInclusive<i16>,
+        explicit_scale: bool,
+    ) -> ChineseRandResult<Temperature> {
+        check_range(&celsius_range)?;
+
+        Ok(Temperature {
+            celsius: self.raw_generator.i16(celsius_range),
+            explicit_scale,
+        })
+    }
+}
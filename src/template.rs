@@ -0,0 +1,419 @@
+//! Sentences embedding generated values - like `我{date}去北京，票价{price}`.
+//!
+//! Each `{name}` placeholder of a [Template] is replaced by a freshly
+//! generated value, described by the [PlanItem] registered for that name -
+//! or, by default, by [PlanItem::from_kind] applied to the name itself;
+//! literal braces are written as `{{` and `}}`.
+//!
+//! Custom placeholders - like names or cities - can be declared
+//! via a [PlaceholderRegistry].
+//!
+//! ```
+//! # #[cfg(all(feature = "currency", feature = "gregorian"))]
+//! # {
+//! use chinese_rand::{*, gregorian::*, template::*};
+//!
+//! # fn main() -> GenericResult<()> {
+//! let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+//!
+//! let template = Template::parse("我{date}去北京，票价{price}")?
+//!     .with_placeholder("date", PlanItem::Date(DateParams::default().with_year_range(2025..=2025)))
+//!     .with_placeholder("price", PlanItem::Renminbi(RenminbiParams::default()));
+//!
+//! let filled = generator.fill(&template)?;
+//! assert_eq!(filled.text(), "我二零二五年五月二十三日去北京，票价六百四十三元四角两分");
+//! assert_eq!(filled.values().count(), 2);
+//! # Ok(())
+//! # }
+//! # main().unwrap();
+//! # }
+//! ```
+use crate::{
+    BilingualLabel, ChineseFormatGenerator, ChineseRandError, ChineseRandResult, PlanItem,
+    PlanValue, RawGenerator,
+};
+use chinese_format::Variant;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+/// Fragment of a [Template].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TemplatePart {
+    /// Text copied as it is.
+    Text(String),
+
+    /// Name of the placeholder to be replaced by a generated value.
+    Placeholder(String),
+}
+
+/// Sentence with `{name}` placeholders, to be filled
+/// via [ChineseFormatGenerator::fill].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Template {
+    parts: Vec<TemplatePart>,
+    items: BTreeMap<String, PlanItem>,
+    variant: Variant,
+}
+
+impl Template {
+    /// Parses the given source - rendered in [Variant::Simplified] unless
+    /// [with_variant](Self::with_variant) is called.
+    ///
+    /// Fails with [ChineseRandError::InvalidTemplate] if a brace is unbalanced
+    /// or a placeholder has no name.
+    ///
+    /// ```
+    /// use chinese_rand::template::*;
+    ///
+    /// let template = Template::parse("他今年{age}了{{真的}}").unwrap();
+    /// assert_eq!(template.parts(), &[
+    ///     TemplatePart::Text("他今年".to_string()),
+    ///     TemplatePart::Placeholder("age".to_string()),
+    ///     TemplatePart::Text("了{真的}".to_string())
+    /// ]);
+    ///
+    /// assert!(Template::parse("他今年{age了").is_err());
+    /// assert!(Template::parse("他今年age}了").is_err());
+    /// assert!(Template::parse("他今年{}了").is_err());
+    /// ```
+    pub fn parse(source: &str) -> ChineseRandResult<Self> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut chars = source.chars().peekable();
+
+        while let Some(current) = chars.next() {
+            match current {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+
+                '{' => {
+                    let mut name = String::new();
+
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+
+                            Some('{') | None => {
+                                return Err(ChineseRandError::InvalidTemplate(format!(
+                                    "Unclosed placeholder in: {}",
+                                    source
+                                )))
+                            }
+
+                            Some(other) => name.push(other),
+                        }
+                    }
+
+                    if name.is_empty() {
+                        return Err(ChineseRandError::InvalidTemplate(format!(
+                            "Unnamed placeholder in: {}",
+                            source
+                        )));
+                    }
+
+                    if !text.is_empty() {
+                        parts.push(TemplatePart::Text(std::mem::take(&mut text)));
+                    }
+
+                    parts.push(TemplatePart::Placeholder(name));
+                }
+
+                '}' => {
+                    return Err(ChineseRandError::InvalidTemplate(format!(
+                        "Unopened placeholder in: {}",
+                        source
+                    )))
+                }
+
+                other => text.push(other),
+            }
+        }
+
+        if !text.is_empty() {
+            parts.push(TemplatePart::Text(text));
+        }
+
+        Ok(Self {
+            parts,
+            items: BTreeMap::new(),
+            variant: Variant::Simplified,
+        })
+    }
+
+    /// Sets the [PlanItem] generating the values of the given placeholder.
+    pub fn with_placeholder(mut self, name: &str, item: PlanItem) -> Self {
+        self.items.insert(name.to_string(), item);
+        self
+    }
+
+    /// Sets the variant used to render the generated values.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// The fragments of the template, in order.
+    pub fn parts(&self) -> &[TemplatePart] {
+        &self.parts
+    }
+
+    /// The [PlanItem] explicitly set for the given placeholder, if any.
+    pub fn item(&self, name: &str) -> Option<&PlanItem> {
+        self.items.get(name)
+    }
+
+    /// The variant used to render the generated values.
+    pub fn variant(&self) -> Variant {
+        self.variant
+    }
+}
+
+/// Value generated for a placeholder of a [Template].
+#[derive(Debug)]
+pub struct FilledValue {
+    /// The name of the placeholder.
+    pub name: String,
+
+    /// The Chinese rendering of the value, as it appears in the text.
+    pub rendered: String,
+
+    /// The generated value.
+    pub value: Box<dyn PlanValue>,
+}
+
+/// Fragment of a [FilledTemplate].
+#[derive(Debug)]
+pub enum FilledPart {
+    /// Text copied from the template.
+    Text(String),
+
+    /// Value generated for a placeholder.
+    Value(FilledValue),
+}
+
+/// Result of [ChineseFormatGenerator::fill]: the text, together
+/// with the structured values it embeds.
+#[derive(Debug)]
+pub struct FilledTemplate {
+    /// The fragments, in the same order as the template.
+    pub parts: Vec<FilledPart>,
+}
+
+impl FilledTemplate {
+    /// The whole text, with every placeholder replaced by its value.
+    pub fn text(&self) -> String {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                FilledPart::Text(text) => text.as_str(),
+                FilledPart::Value(value) => value.rendered.as_str(),
+            })
+            .collect()
+    }
+
+    /// The generated values, in order of appearance.
+    pub fn values(&self) -> impl Iterator<Item = &FilledValue> {
+        self.parts.iter().filter_map(|part| match part {
+            FilledPart::Value(value) => Some(value),
+            FilledPart::Text(_) => None,
+        })
+    }
+}
+
+/// [BilingualLabel] for a word - like a name or a city, chosen by a [PlaceholderRegistry],
+/// or a currency unit.
+///
+/// ```
+/// use chinese_rand::template::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// let city = Word::new("广州", "廣州");
+///
+/// assert_eq!(city.to_chinese(Variant::Simplified), "广州");
+/// assert_eq!(city.to_chinese(Variant::Traditional), "廣州");
+/// ```
+pub type Word = BilingualLabel;
+
+type PlaceholderGenerator =
+    dyn Fn(&ChineseFormatGenerator<&dyn RawGenerator>) -> ChineseRandResult<Box<dyn PlanValue>>;
+
+/// Custom placeholder kinds - like lists of names or cities - available
+/// to [ChineseFormatGenerator::fill_with] alongside the built-in ones.
+///
+/// When resolving a placeholder, the [PlanItem] set on the [Template]
+/// comes first, then the registry, and finally [PlanItem::from_kind].
+#[derive(Clone, Default)]
+pub struct PlaceholderRegistry {
+    generators: BTreeMap<String, Rc<PlaceholderGenerator>>,
+}
+
+impl Debug for PlaceholderRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PlaceholderRegistry")
+            .field("names", &self.generators.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl PlaceholderRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a placeholder whose values are created by the given function.
+    pub fn with_generator<T: PlanValue + 'static>(
+        mut self,
+        name: &str,
+        generator: impl Fn(&ChineseFormatGenerator<&dyn RawGenerator>) -> ChineseRandResult<T> + 'static,
+    ) -> Self {
+        self.generators.insert(
+            name.to_string(),
+            Rc::new(move |owner| Ok(Box::new(generator(owner)?) as Box<dyn PlanValue>)),
+        );
+        self
+    }
+
+    /// Registers a placeholder whose values are uniformly chosen among
+    /// the given words - failing with [ChineseRandError::InvalidRange]
+    /// on generation if there are none.
+    pub fn with_words(self, name: &str, words: Vec<Word>) -> Self {
+        let target = name.to_string();
+
+        self.with_generator(name, move |owner| {
+            if words.is_empty() {
+                return Err(ChineseRandError::InvalidRange(format!(
+                    "No words for placeholder: {}",
+                    target
+                )));
+            }
+
+            let index = owner.raw_generator.u64(0..=(words.len() as u64 - 1)) as usize;
+
+            Ok(words[index].clone())
+        })
+    }
+
+    /// Whether a placeholder with the given name was registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.generators.contains_key(name)
+    }
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Fills the given [Template], generating a new value for each placeholder.
+    ///
+    /// Fails with [ChineseRandError::InvalidTemplate] if a placeholder has
+    /// no [PlanItem] and its name is not a [kind](PlanItem::kind), or
+    /// with any error arising from the generation itself.
+    ///
+    /// ```
+    /// use chinese_rand::{*, template::*};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let template = Template::parse("他今年{age}，有{count}本书")?
+    ///     .with_placeholder("count", PlanItem::Count { range: 1..=9 });
+    ///
+    /// let filled = generator.fill(&template)?;
+    /// assert_eq!(filled.text(), "他今年四十三岁，有六本书");
+    ///
+    /// let names: Vec<&str> = filled.values().map(|value| value.name.as_str()).collect();
+    /// assert_eq!(names, vec!["age", "count"]);
+    ///
+    /// let same_stream = FastRandGenerator::with_seed(90);
+    /// let same_filled = generator.using(&same_stream).fill(&template)?;
+    /// assert_eq!(same_filled.text(), filled.text());
+    ///
+    /// assert!(generator.fill(&Template::parse("{unknown}")?).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fill(&self, template: &Template) -> ChineseRandResult<FilledTemplate> {
+        self.fill_with(template, &PlaceholderRegistry::default())
+    }
+
+    /// Fills the given [Template] - like [fill](Self::fill) - also
+    /// supporting the custom placeholders of the given [PlaceholderRegistry].
+    ///
+    /// ```
+    /// use chinese_rand::{*, template::*};
+    /// use chinese_format::Variant;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let registry = PlaceholderRegistry::new()
+    ///     .with_words("name", vec![Word::new("小王", "小王"), Word::new("小李", "小李")])
+    ///     .with_words("city", vec![Word::new("广州", "廣州"), Word::new("台北", "臺北")]);
+    ///
+    /// let template = Template::parse("{name}在{city}住了{count}年")?
+    ///     .with_placeholder("count", PlanItem::Count { range: 2..=20 });
+    ///
+    /// let filled = generator.fill_with(&template, &registry)?;
+    /// assert_eq!(filled.text(), "小李在广州住了十四年");
+    ///
+    /// let traditional = template.with_variant(Variant::Traditional);
+    /// let filled = generator.fill_with(&traditional, &registry)?;
+    /// assert_eq!(filled.text(), "小王在廣州住了十年");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fill_with(
+        &self,
+        template: &Template,
+        registry: &PlaceholderRegistry,
+    ) -> ChineseRandResult<FilledTemplate> {
+        let parts = template
+            .parts
+            .iter()
+            .map(|part| {
+                Ok(match part {
+                    TemplatePart::Text(text) => FilledPart::Text(text.clone()),
+
+                    TemplatePart::Placeholder(name) => {
+                        let value = self.resolve_placeholder(template, registry, name)?;
+
+                        FilledPart::Value(FilledValue {
+                            name: name.clone(),
+                            rendered: value.to_chinese(template.variant).to_string(),
+                            value,
+                        })
+                    }
+                })
+            })
+            .collect::<ChineseRandResult<Vec<_>>>()?;
+
+        Ok(FilledTemplate { parts })
+    }
+
+    fn resolve_placeholder(
+        &self,
+        template: &Template,
+        registry: &PlaceholderRegistry,
+        name: &str,
+    ) -> ChineseRandResult<Box<dyn PlanValue>> {
+        if let Some(item) = template.items.get(name) {
+            return self.generate_plan_item(item);
+        }
+
+        if let Some(generator) = registry.generators.get(name) {
+            return self.erased(|view| generator(view));
+        }
+
+        let item = PlanItem::from_kind(name).ok_or_else(|| {
+            ChineseRandError::InvalidTemplate(format!("Unknown placeholder: {}", name))
+        })?;
+
+        self.generate_plan_item(&item)
+    }
+}
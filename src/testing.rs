@@ -0,0 +1,246 @@
+//! Golden-file snapshots of generated values, to lock down
+//! the behavior of the generators across crate upgrades.
+//!
+//! Each golden file is a pretty-printed JSON array of [Snapshot];
+//! it is recorded - instead of compared - when missing or when the
+//! [UPDATE_GOLDEN_VAR] environment variable is set to `1`.
+//!
+//! Statistical assertions - like [assert_uniform] - are available as well,
+//! to verify that the generated values are actually well distributed.
+use crate::{ChineseRandError, ChineseRandResult};
+use chinese_format::{ChineseFormat, Variant};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+
+/// Environment variable requesting to record the golden files again.
+pub const UPDATE_GOLDEN_VAR: &str = "CHINESE_RAND_UPDATE_GOLDEN";
+
+/// A generated value - in its debug representation - with its renderings.
+///
+/// ```
+/// use chinese_rand::testing::*;
+/// use chinese_format::Count;
+///
+/// assert_eq!(Snapshot::of(&Count(2)), Snapshot {
+///     value: "Count(2)".to_string(),
+///     simplified: "两".to_string(),
+///     traditional: "兩".to_string()
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// The debug representation of the value.
+    pub value: String,
+
+    /// The rendering in [Variant::Simplified].
+    pub simplified: String,
+
+    /// The rendering in [Variant::Traditional].
+    pub traditional: String,
+}
+
+impl Snapshot {
+    /// Takes the snapshot of a value.
+    pub fn of<T: ChineseFormat + Debug>(value: &T) -> Self {
+        Self {
+            value: format!("{:?}", value),
+            simplified: value.to_chinese(Variant::Simplified).to_string(),
+            traditional: value.to_chinese(Variant::Traditional).to_string(),
+        }
+    }
+}
+
+/// What happened when checking a golden file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GoldenOutcome {
+    /// The snapshots match the golden file.
+    Matched,
+
+    /// The golden file has been written.
+    Recorded,
+}
+
+/// Compares the snapshots against the golden file at the given path -
+/// recording it when missing, or when requested via [UPDATE_GOLDEN_VAR].
+///
+/// Fails with [ChineseRandError::Io] if the file cannot be accessed,
+/// and with [ChineseRandError::AssertionFailed] if it cannot be parsed
+/// or if its content differs.
+///
+/// ```
+/// use chinese_rand::{*, testing::*};
+/// use chinese_format::Count;
+///
+/// # fn main() -> GenericResult<()> {
+/// let path = std::env::temp_dir().join("chinese-rand-golden-doctest.json");
+/// # let _ = std::fs::remove_file(&path);
+/// # std::env::remove_var(UPDATE_GOLDEN_VAR);
+///
+/// let snapshots = vec![Snapshot::of(&Count(2))];
+///
+/// assert_eq!(check_golden(&path, &snapshots)?, GoldenOutcome::Recorded);
+/// assert_eq!(check_golden(&path, &snapshots)?, GoldenOutcome::Matched);
+///
+/// let changed = vec![Snapshot::of(&Count(3))];
+/// assert!(check_golden(&path, &changed).is_err());
+/// # std::fs::remove_file(&path)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn check_golden(
+    path: impl AsRef<Path>,
+    snapshots: &[Snapshot],
+) -> ChineseRandResult<GoldenOutcome> {
+    let path = path.as_ref();
+
+    let update_requested = std::env::var(UPDATE_GOLDEN_VAR).is_ok_and(|value| value == "1");
+
+    if update_requested || !path.exists() {
+        let json = serde_json::to_string_pretty(snapshots)
+            .map_err(|err| ChineseRandError::AssertionFailed(err.to_string()))?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, json + "\n")?;
+
+        return Ok(GoldenOutcome::Recorded);
+    }
+
+    let golden: Vec<Snapshot> = serde_json::from_str(&fs::read_to_string(path)?)
+        .map_err(|err| ChineseRandError::AssertionFailed(err.to_string()))?;
+
+    if golden.len() != snapshots.len() {
+        return Err(ChineseRandError::AssertionFailed(format!(
+            "{} snapshots expected by {}, {} found",
+            golden.len(),
+            path.display(),
+            snapshots.len()
+        )));
+    }
+
+    match golden
+        .iter()
+        .zip(snapshots)
+        .position(|(expected, actual)| expected != actual)
+    {
+        Some(index) => Err(ChineseRandError::AssertionFailed(format!(
+            "Snapshot {} differs from {}: expected {:?}, found {:?}",
+            index,
+            path.display(),
+            golden[index],
+            snapshots[index]
+        ))),
+
+        None => Ok(GoldenOutcome::Matched),
+    }
+}
+
+/// Like [check_golden], but panics on failure - as expected within tests.
+pub fn assert_golden(path: impl AsRef<Path>, snapshots: &[Snapshot]) {
+    if let Err(err) = check_golden(path, snapshots) {
+        panic!("{}", err);
+    }
+}
+
+/// Checks that the values are uniformly distributed across the given buckets:
+/// the count of each bucket must not deviate from the mean count by more than
+/// `tolerance` - a fraction of the mean, like `0.1` for 10%.
+///
+/// Fails with [ChineseRandError::InvalidParams] if there are no buckets or
+/// the tolerance is negative, and with [ChineseRandError::AssertionFailed] if there
+/// are no values, if a value belongs to no bucket or if a count deviates too much.
+///
+/// ```
+/// use chinese_rand::{*, testing::*};
+///
+/// # fn main() -> GenericResult<()> {
+/// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+///
+/// let digits = (0..10_000)
+///     .map(|_| generator.integer(0..=9))
+///     .collect::<ChineseRandResult<Vec<_>>>()?;
+///
+/// check_uniform(digits.iter().copied(), 0..=9, 0.1)?;
+///
+/// assert_eq!(
+///     check_uniform(digits.iter().copied(), 0..=9, 0.01),
+///     Err(ChineseRandError::AssertionFailed(
+///         "Bucket 0 has 1025 values, 1000.0 expected - tolerance: 0.01".to_string()
+///     ))
+/// );
+///
+/// assert_eq!(
+///     check_uniform(digits.iter().copied(), 0..=8, 0.1),
+///     Err(ChineseRandError::AssertionFailed("Value outside the buckets: 9".to_string()))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn check_uniform<T: PartialEq + Debug>(
+    values: impl IntoIterator<Item = T>,
+    buckets: impl IntoIterator<Item = T>,
+    tolerance: f64,
+) -> ChineseRandResult<()> {
+    let mut counts: Vec<(T, usize)> = buckets.into_iter().map(|bucket| (bucket, 0)).collect();
+
+    if counts.is_empty() {
+        return Err(ChineseRandError::InvalidParams("No buckets".to_string()));
+    }
+
+    if tolerance.is_nan() || tolerance < 0.0 {
+        return Err(ChineseRandError::InvalidParams(format!(
+            "Tolerance: {}",
+            tolerance
+        )));
+    }
+
+    let mut total: usize = 0;
+
+    for value in values {
+        match counts.iter_mut().find(|(bucket, _)| *bucket == value) {
+            Some((_, count)) => *count += 1,
+
+            None => {
+                return Err(ChineseRandError::AssertionFailed(format!(
+                    "Value outside the buckets: {:?}",
+                    value
+                )))
+            }
+        }
+
+        total += 1;
+    }
+
+    if total == 0 {
+        return Err(ChineseRandError::AssertionFailed("No values".to_string()));
+    }
+
+    let expected = total as f64 / counts.len() as f64;
+
+    match counts
+        .iter()
+        .find(|(_, count)| (*count as f64 - expected).abs() > tolerance * expected)
+    {
+        Some((bucket, count)) => Err(ChineseRandError::AssertionFailed(format!(
+            "Bucket {:?} has {} values, {:.1} expected - tolerance: {}",
+            bucket, count, expected, tolerance
+        ))),
+
+        None => Ok(()),
+    }
+}
+
+/// Like [check_uniform], but panics on failure - as expected within tests.
+pub fn assert_uniform<T: PartialEq + Debug>(
+    values: impl IntoIterator<Item = T>,
+    buckets: impl IntoIterator<Item = T>,
+    tolerance: f64,
+) {
+    if let Err(err) = check_uniform(values, buckets, tolerance) {
+        panic!("{}", err);
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{ChineseFormatGenerator, ChineseRandResult, RawGenerator};
+use chinese_format::{ChineseFormat, Variant};
+
+const MAX_DIGITS: u8 = 12;
+
+const MAGNITUDE_EXPONENTS: [u32; 3] = [4, 8, 12];
+
+/// Rendering rule exercised by a [TrickyNumberGenerator].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TrickyKind {
+    /// Zeros within the number, collapsing into `零` - like `一千零五`
+    /// or `三亿零二十万`.
+    InternalZero,
+
+    /// Two significant digits followed by zeros - like `一千五百`,
+    /// colloquially shortened to `一千五`.
+    TrailingZeros,
+
+    /// Numbers at or next to a `万`, `亿` or `兆` boundary - like `九千九百九十九`,
+    /// `一万` or `一亿零一`.
+    MagnitudeBoundary,
+}
+
+const KINDS: [TrickyKind; 3] = [
+    TrickyKind::InternalZero,
+    TrickyKind::TrailingZeros,
+    TrickyKind::MagnitudeBoundary,
+];
+
+/// Random generator of non-negative integers concentrated on the edge cases
+/// of Chinese numerals - for renderer test suites and advanced students.
+///
+/// Just like [GregorianGenerator](crate::gregorian::GregorianGenerator),
+/// it must be created via the [ChineseFormatGenerator::tricky_numbers] method.
+pub struct TrickyNumberGenerator<'a, R: RawGenerator = Box<dyn RawGenerator>> {
+    owner: &'a ChineseFormatGenerator<R>,
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Creates a reusable [TrickyNumberGenerator] instance.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let tricky = generator.tricky_numbers();
+    ///
+    /// let (kind, value) = tricky.any()?;
+    /// assert_eq!(kind, TrickyKind::TrailingZeros);
+    /// assert_eq!(value.to_chinese(Variant::Simplified), "一万六千");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn tricky_numbers(&self) -> TrickyNumberGenerator<'_, R> {
+        TrickyNumberGenerator { owner: self }
+    }
+}
+
+impl<R: RawGenerator> TrickyNumberGenerator<'_, R> {
+    /// Generates an integer - up to 12 digits - exercising the given [TrickyKind].
+    ///
+    /// Fails with [ChineseRandError::GenerationExhausted](crate::ChineseRandError::GenerationExhausted)
+    /// only in the unlikely case of no internal zero being rendered as `零`
+    /// within the [retry budget](ChineseFormatGenerator::with_max_retries).
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    /// let tricky = generator.tricky_numbers();
+    ///
+    /// let internal_zero = tricky.integer(TrickyKind::InternalZero)?;
+    /// assert_eq!(internal_zero.to_chinese(Variant::Simplified), "五亿七千二百四十万六千零一十三");
+    ///
+    /// let trailing_zeros = tricky.integer(TrickyKind::TrailingZeros)?;
+    /// assert_eq!(trailing_zeros.to_chinese(Variant::Simplified), "七千六百");
+    ///
+    /// let boundary = tricky.integer(TrickyKind::MagnitudeBoundary)?;
+    /// assert_eq!(boundary.to_chinese(Variant::Simplified), "九万");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn integer(&self, kind: TrickyKind) -> ChineseRandResult<i128> {
+        match kind {
+            TrickyKind::InternalZero => self.owner.retrying("Internal zero", || {
+                let value = self.internal_zero_candidate();
+
+                value
+                    .to_chinese(Variant::Simplified)
+                    .logograms
+                    .contains('零')
+                    .then_some(value)
+            }),
+
+            TrickyKind::TrailingZeros => Ok(self.trailing_zeros()),
+
+            TrickyKind::MagnitudeBoundary => Ok(self.magnitude_boundary()),
+        }
+    }
+
+    /// Generates an integer exercising a random [TrickyKind] - returned as well.
+    pub fn any(&self) -> ChineseRandResult<(TrickyKind, i128)> {
+        let kind = KINDS[self.index(KINDS.len())];
+
+        Ok((kind, self.integer(kind)?))
+    }
+
+    fn internal_zero_candidate(&self) -> i128 {
+        let raw_generator = &self.owner.raw_generator;
+
+        let length = raw_generator.u8(3..=MAX_DIGITS) as usize;
+
+        let mut digits: Vec<u8> = (0..length).map(|_| raw_generator.u8(0..=9)).collect();
+        digits[0] = raw_generator.u8(1..=9);
+        digits[length - 1] = raw_generator.u8(1..=9);
+
+        let run_start = raw_generator.u8(1..=(length as u8 - 2)) as usize;
+        let run_end = raw_generator.u8(run_start as u8..=(length as u8 - 2)) as usize;
+        digits[run_start..=run_end].fill(0);
+
+        digits
+            .iter()
+            .fold(0, |value, digit| value * 10 + *digit as i128)
+    }
+
+    fn trailing_zeros(&self) -> i128 {
+        let raw_generator = &self.owner.raw_generator;
+
+        let significant = raw_generator.i128(1..=9) * 10 + raw_generator.i128(1..=9);
+        let zeros = raw_generator.u32(1..=(MAX_DIGITS as u32 - 2));
+
+        significant * 10i128.pow(zeros)
+    }
+
+    fn magnitude_boundary(&self) -> i128 {
+        let raw_generator = &self.owner.raw_generator;
+
+        let exponent = MAGNITUDE_EXPONENTS[self.index(MAGNITUDE_EXPONENTS.len())];
+        let multiplier = raw_generator.i128(1..=9);
+        let offset = match raw_generator.u8(0..=3) {
+            0 => -1,
+            1 => 0,
+            2 => raw_generator.i128(1..=9),
+            _ => raw_generator.i128(1..=9) * 10,
+        };
+
+        multiplier * 10i128.pow(exponent) + offset
+    }
+
+    fn index(&self, length: usize) -> usize {
+        self.owner.raw_generator.u64(0..=(length as u64 - 1)) as usize
+    }
+}
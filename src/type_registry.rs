@@ -0,0 +1,230 @@
+use crate::{
+    labels::{BusRoute, RoomNumber, TrainNumber},
+    ArithmeticProblem, ChineseFormatGenerator, ChineseRandError, ChineseRandResult, ExamScore,
+    Generatable, Generate, MixedNumber, PlanValue, RawGenerator, ShareStatement,
+};
+use chinese_format::{Count, Financial, Fraction};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::{collections::BTreeMap, fmt::Debug, rc::Rc};
+
+#[cfg(feature = "gregorian")]
+use crate::gregorian::{Appointment, ColloquialTime, Duration};
+#[cfg(feature = "lunar")]
+use crate::lunar::LunarDate;
+#[cfg(feature = "digit-sequence")]
+use crate::Measurement;
+#[cfg(feature = "currency")]
+use crate::{MonetaryAmount, SignedRenminbi};
+#[cfg(feature = "currency")]
+use chinese_format::currency::RenminbiCurrency;
+#[cfg(feature = "gregorian")]
+use chinese_format::gregorian::{Date, DeltaTime, LinearTime};
+
+type TypeGenerator = dyn Fn(
+    &ChineseFormatGenerator<&dyn RawGenerator>,
+    Value,
+) -> ChineseRandResult<Box<dyn PlanValue>>;
+
+/// Generation functions keyed by type name - like `"date"` or `"fraction"` -
+/// each receiving its parameters as JSON, so that plugin-style systems
+/// and configuration files can request types by name.
+///
+/// [standard](Self::standard) provides the [Generatable] types,
+/// named after their dedicated methods, and can be extended
+/// via [with_type](Self::with_type).
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+/// use serde_json::json;
+///
+/// # fn main() -> GenericResult<()> {
+/// let registry = TypeRegistry::standard().with_type("dice", |generator, faces: u8| {
+///     generator.count(1..=faces as u128)
+/// });
+/// assert!(registry.contains("fraction"));
+///
+/// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+///
+/// let dice = registry.generate(&generator, "dice", json!(6))?;
+/// assert_eq!(dice.to_chinese(Variant::Simplified), "四");
+///
+/// let unknown = registry.generate(&generator, "poem", json!(null));
+/// assert_eq!(
+///     unknown.unwrap_err(),
+///     ChineseRandError::Unsupported("Unknown type: poem".to_string())
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **Required feature**: `registry`.
+#[derive(Clone, Default)]
+pub struct TypeRegistry {
+    generators: BTreeMap<String, Rc<TypeGenerator>>,
+}
+
+impl Debug for TypeRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypeRegistry")
+            .field("names", &self.generators.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl TypeRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry providing every [Generatable] type implementing
+    /// [ChineseFormat](chinese_format::ChineseFormat), named after the dedicated method - like `"integer"`, `"mixed_number"`
+    /// or `"linear_time"`.
+    pub fn standard() -> Self {
+        let registry = Self::new()
+            .with_generatable::<i128>("integer")
+            .with_generatable::<Count>("count")
+            .with_generatable::<Fraction>("fraction")
+            .with_generatable::<MixedNumber>("mixed_number")
+            .with_generatable::<Financial>("financial")
+            .with_generatable::<ArithmeticProblem>("arithmetic")
+            .with_generatable::<ExamScore>("exam_score")
+            .with_generatable::<ShareStatement>("share_statement")
+            .with_generatable::<RoomNumber>("room_number")
+            .with_generatable::<BusRoute>("bus_route")
+            .with_generatable::<TrainNumber>("train_number");
+
+        #[cfg(feature = "digit-sequence")]
+        let registry = registry.with_generatable::<Measurement>("measurement");
+
+        #[cfg(feature = "currency")]
+        let registry = registry
+            .with_generatable::<RenminbiCurrency>("renminbi")
+            .with_generatable::<SignedRenminbi>("signed_renminbi")
+            .with_generatable::<MonetaryAmount>("monetary");
+
+        #[cfg(feature = "gregorian")]
+        let registry = registry
+            .with_generatable::<Date>("date")
+            .with_generatable::<LinearTime>("linear_time")
+            .with_generatable::<DeltaTime>("delta_time")
+            .with_generatable::<ColloquialTime>("colloquial_time")
+            .with_generatable::<Duration>("duration")
+            .with_generatable::<Appointment>("appointment");
+
+        #[cfg(feature = "lunar")]
+        let registry = registry.with_generatable::<LunarDate>("lunar_date");
+
+        registry
+    }
+
+    /// Registers a type whose values are created by the given function,
+    /// from the deserialized parameters.
+    pub fn with_type<P: DeserializeOwned, T: PlanValue + 'static>(
+        mut self,
+        name: &str,
+        generator: impl Fn(&ChineseFormatGenerator<&dyn RawGenerator>, P) -> ChineseRandResult<T>
+            + 'static,
+    ) -> Self {
+        let target = name.to_string();
+
+        self.generators.insert(
+            name.to_string(),
+            Rc::new(move |owner, params| {
+                let params = serde_json::from_value(params).map_err(|err| {
+                    ChineseRandError::InvalidParams(format!("{} - {}", target, err))
+                })?;
+
+                Ok(Box::new(generator(owner, params)?) as Box<dyn PlanValue>)
+            }),
+        );
+        self
+    }
+
+    /// Registers a [Generatable] type under the given name.
+    pub fn with_generatable<T: Generatable + PlanValue + 'static>(self, name: &str) -> Self
+    where
+        T::Params: DeserializeOwned,
+        for<'a> ChineseFormatGenerator<&'a dyn RawGenerator>: Generate<T>,
+    {
+        self.with_type(name, |generator, params| {
+            Generate::<T>::generate(generator, params)
+        })
+    }
+
+    /// Whether a type with the given name was registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.generators.contains_key(name)
+    }
+
+    /// The registered names, in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.generators.keys().map(String::as_str)
+    }
+
+    /// Generates a value of the type registered with the given name.
+    ///
+    /// Fails with [ChineseRandError::Unsupported] if the name is unknown,
+    /// with [ChineseRandError::InvalidParams] if the parameters cannot
+    /// be deserialized, or with any error arising from the generation itself.
+    pub fn generate<R: RawGenerator>(
+        &self,
+        generator: &ChineseFormatGenerator<R>,
+        name: &str,
+        params: Value,
+    ) -> ChineseRandResult<Box<dyn PlanValue>> {
+        let type_generator = self
+            .generators
+            .get(name)
+            .ok_or_else(|| ChineseRandError::Unsupported(format!("Unknown type: {}", name)))?;
+
+        generator.erased(|view| type_generator(view, params))
+    }
+}
+
+thread_local! {
+    static STANDARD_REGISTRY: TypeRegistry = TypeRegistry::standard();
+}
+
+impl<R: RawGenerator> ChineseFormatGenerator<R> {
+    /// Generates a value of the type having the given name in
+    /// the [standard](TypeRegistry::standard) [TypeRegistry],
+    /// from its parameters in JSON.
+    ///
+    /// ```
+    /// use chinese_rand::*;
+    /// use chinese_format::{ChineseFormat, Variant};
+    /// use serde_json::json;
+    ///
+    /// # fn main() -> GenericResult<()> {
+    /// let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+    ///
+    /// let count = generator.generate_by_name("count", json!({ "start": 1, "end": 99 }))?;
+    /// assert_eq!(count.to_chinese(Variant::Simplified), "五十九");
+    ///
+    /// let fraction = generator.generate_by_name("fraction", json!({
+    ///     "denominator_range": { "start": 2, "end": 9 },
+    ///     "numerator_range": { "start": 1, "end": 9 },
+    ///     "forbid_zero_numerator": true,
+    ///     "proper_only": true,
+    ///     "reduced_only": false
+    /// }))?;
+    /// assert_eq!(fraction.to_chinese(Variant::Simplified), "七分之三");
+    ///
+    /// let invalid = generator.generate_by_name("count", json!("many"));
+    /// assert!(matches!(invalid, Err(ChineseRandError::InvalidParams(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Required feature**: `registry`.
+    pub fn generate_by_name(
+        &self,
+        name: &str,
+        params: Value,
+    ) -> ChineseRandResult<Box<dyn PlanValue>> {
+        STANDARD_REGISTRY.with(|registry| registry.generate(self, name, params))
+    }
+}
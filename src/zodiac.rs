@@ -0,0 +1,76 @@
+use chinese_format::{Chinese, ChineseFormat, Variant};
+
+/// The twelve animals of the Chinese zodiac (生肖), in cycle order.
+///
+/// ```
+/// use chinese_rand::*;
+/// use chinese_format::{ChineseFormat, Variant};
+///
+/// assert_eq!(Zodiac::Dragon.to_chinese(Variant::Simplified), "龙");
+/// assert_eq!(Zodiac::Dragon.to_chinese(Variant::Traditional), "龍");
+///
+/// assert_eq!(Zodiac::from_gregorian_year(2024), Zodiac::Dragon);
+/// assert_eq!(Zodiac::from_gregorian_year(1984), Zodiac::Rat);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Zodiac {
+    Rat,
+    Ox,
+    Tiger,
+    Rabbit,
+    Dragon,
+    Snake,
+    Horse,
+    Goat,
+    Monkey,
+    Rooster,
+    Dog,
+    Pig,
+}
+
+impl Zodiac {
+    /// All the animals, in cycle order.
+    pub const ALL: [Zodiac; 12] = [
+        Zodiac::Rat,
+        Zodiac::Ox,
+        Zodiac::Tiger,
+        Zodiac::Rabbit,
+        Zodiac::Dragon,
+        Zodiac::Snake,
+        Zodiac::Horse,
+        Zodiac::Goat,
+        Zodiac::Monkey,
+        Zodiac::Rooster,
+        Zodiac::Dog,
+        Zodiac::Pig,
+    ];
+
+    /// The animal associated with the given Gregorian year.
+    ///
+    /// The lunar year actually starts between late January and
+    /// mid February, so dates before the Spring Festival
+    /// belong to the previous animal.
+    pub fn from_gregorian_year(year: u16) -> Zodiac {
+        Self::ALL[(year as i32 - 4).rem_euclid(12) as usize]
+    }
+}
+
+impl ChineseFormat for Zodiac {
+    fn to_chinese(&self, variant: Variant) -> Chinese {
+        match self {
+            Zodiac::Rat => "鼠".to_chinese(variant),
+            Zodiac::Ox => "牛".to_chinese(variant),
+            Zodiac::Tiger => "虎".to_chinese(variant),
+            Zodiac::Rabbit => "兔".to_chinese(variant),
+            Zodiac::Dragon => ("龙", "龍").to_chinese(variant),
+            Zodiac::Snake => "蛇".to_chinese(variant),
+            Zodiac::Horse => ("马", "馬").to_chinese(variant),
+            Zodiac::Goat => "羊".to_chinese(variant),
+            Zodiac::Monkey => "猴".to_chinese(variant),
+            Zodiac::Rooster => ("鸡", "雞").to_chinese(variant),
+            Zodiac::Dog => "狗".to_chinese(variant),
+            Zodiac::Pig => ("猪", "豬").to_chinese(variant),
+        }
+    }
+}
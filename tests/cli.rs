@@ -0,0 +1,42 @@
+#![cfg(feature = "cli")]
+
+use speculate2::*;
+use std::process::{Command, Output};
+
+fn run_cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_chinese-rand"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+speculate! {
+    describe "The CLI" {
+        it "should print the requested number of values" {
+            let output = run_cli(&["--type", "date", "--count", "3", "--seed", "90"]);
+
+            assert!(output.status.success());
+            assert_eq!(
+                String::from_utf8(output.stdout).unwrap(),
+                "二零三六年五月二十三日\n一八九七年六月七日\n二零二四年一月六日\n"
+            );
+        }
+
+        it "should support the Traditional variant" {
+            let output = run_cli(&["--type", "age", "--count", "3", "--seed", "90", "--variant", "traditional"]);
+
+            assert!(output.status.success());
+            assert_eq!(
+                String::from_utf8(output.stdout).unwrap(),
+                "四十三歲\n十五歲\n十六歲\n"
+            );
+        }
+
+        it "should fail on unknown types" {
+            let output = run_cli(&["--type", "unknown"]);
+
+            assert_eq!(output.status.code(), Some(2));
+            assert!(String::from_utf8(output.stderr).unwrap().starts_with("Unknown type: unknown"));
+        }
+    }
+}
@@ -0,0 +1,68 @@
+#![cfg(all(feature = "testing", feature = "gregorian"))]
+
+use chinese_format::gregorian::WeekDay;
+use chinese_rand::{testing::*, *};
+use speculate2::*;
+use std::collections::BTreeSet;
+
+const SAMPLES: usize = 20_000;
+
+speculate! {
+    describe "Distribution" {
+        before {
+            let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+        }
+
+        it "should spread the digits of integers" {
+            let digits = (0..SAMPLES).map(|_| generator.integer(0..=9).unwrap());
+
+            assert_uniform(digits, 0..=9, 0.1);
+        }
+
+        it "should spread the months of calendar dates" {
+            let months = (0..SAMPLES).map(|_| generator.gregorian().calendar_date(2000..=2030).unwrap().month());
+
+            assert_uniform(months, 1..=12, 0.15);
+        }
+
+        it "should spread the week days of calendar dates" {
+            let week_days = (0..SAMPLES).map(|_| generator.gregorian().calendar_date(2000..=2030).unwrap().week_day());
+
+            assert_uniform(
+                week_days,
+                [
+                    WeekDay::Sunday,
+                    WeekDay::Monday,
+                    WeekDay::Tuesday,
+                    WeekDay::Wednesday,
+                    WeekDay::Thursday,
+                    WeekDay::Friday,
+                    WeekDay::Saturday
+                ],
+                0.1
+            );
+        }
+
+        it "should cover every age from 1 to 100 with the age profiles" {
+            let ages: BTreeSet<u8> = [AgeProfile::Child, AgeProfile::Teen, AgeProfile::Adult, AgeProfile::Elderly]
+                .into_iter()
+                .flat_map(|profile| (0..SAMPLES).map(move |_| profile))
+                .map(|profile| generator.age(profile).0)
+                .collect();
+
+            assert_eq!(ages, (1..=100).collect());
+
+            let any_ages: BTreeSet<u8> = (0..SAMPLES).map(|_| generator.age(AgeProfile::Any).0).collect();
+
+            assert!((13..=17).all(|age| any_ages.contains(&age)));
+        }
+
+        it "should spread the minutes of linear times" {
+            let minutes = (0..SAMPLES).map(|_| {
+                u8::from(generator.gregorian().linear_time(gregorian::LinearTimeParams::default()).unwrap().minute)
+            });
+
+            assert_uniform(minutes, 0..=59, 0.2);
+        }
+    }
+}
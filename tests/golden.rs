@@ -0,0 +1,32 @@
+#![cfg(feature = "testing")]
+
+use chinese_rand::{testing::*, *};
+use speculate2::*;
+use std::path::PathBuf;
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(name)
+}
+
+speculate! {
+    describe "Golden files" {
+        before {
+            let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+        }
+
+        it "should lock down the numeric generators" {
+            let mut snapshots = Vec::new();
+
+            for _ in 0..5 {
+                snapshots.push(Snapshot::of(&generator.integer(-1000..=1000).unwrap()));
+                snapshots.push(Snapshot::of(&generator.count(0..=1000).unwrap()));
+                snapshots.push(Snapshot::of(&generator.fraction(1..=10, 1..=10).unwrap()));
+            }
+
+            assert_golden(golden_path("numeric.json"), &snapshots);
+        }
+    }
+}
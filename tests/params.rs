@@ -0,0 +1,59 @@
+use chinese_rand::*;
+use speculate2::*;
+use std::{fmt::Debug, hash::Hash};
+
+fn assert_params_traits<T: Debug + Clone + PartialEq + Eq + Hash>() {}
+
+speculate! {
+    describe "Parameter types" {
+        it "should all be Debug, Clone, PartialEq, Eq and Hash" {
+            assert_params_traits::<Level>();
+            assert_params_traits::<GenerationPlan>();
+            assert_params_traits::<PlanEntry>();
+            assert_params_traits::<PlanItem>();
+            assert_params_traits::<ArithmeticParams>();
+            assert_params_traits::<FractionParams>();
+            assert_params_traits::<MixedNumberParams>();
+            assert_params_traits::<RangeExpressionParams>();
+            assert_params_traits::<ExamScoreParams>();
+            assert_params_traits::<ShareParams>();
+            assert_params_traits::<corpus::CorpusParams>();
+            assert_params_traits::<labels::RoomNumberParams>();
+            assert_params_traits::<labels::BusRouteParams>();
+            assert_params_traits::<labels::TrainNumberParams>();
+            assert_params_traits::<exercise::ClozeParams>();
+            assert_params_traits::<exercise::DictationEntry>();
+            assert_params_traits::<FinancialParams>();
+
+            #[cfg(feature = "digit-sequence")]
+            assert_params_traits::<MeasurementParams>();
+
+            #[cfg(feature = "currency")]
+            assert_params_traits::<RenminbiParams>();
+
+            #[cfg(feature = "currency")]
+            assert_params_traits::<MonetaryParams>();
+
+            #[cfg(feature = "scenario")]
+            {
+                assert_params_traits::<scenario::PersonParams>();
+                assert_params_traits::<scenario::ReceiptParams>();
+                assert_params_traits::<scenario::ScheduleParams>();
+                assert_params_traits::<scenario::TransactionParams>();
+                assert_params_traits::<scenario::WeatherParams>();
+            }
+
+            #[cfg(feature = "gregorian")]
+            {
+                assert_params_traits::<gregorian::DateParams>();
+                assert_params_traits::<gregorian::LinearTimeParams>();
+                assert_params_traits::<gregorian::DeltaTimeParams>();
+                assert_params_traits::<gregorian::DurationParams>();
+                assert_params_traits::<gregorian::DatePairParams>();
+                assert_params_traits::<gregorian::HolidayTable>();
+                assert_params_traits::<gregorian::AppointmentParams>();
+                assert_params_traits::<gregorian::TimeDifferenceParams>();
+            }
+        }
+    }
+}
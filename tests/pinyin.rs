@@ -0,0 +1,258 @@
+#![cfg(all(feature = "pinyin", feature = "currency", feature = "gregorian"))]
+
+use chinese_format::{currency::CurrencyStyle, ChineseFormat, Variant};
+use chinese_rand::{exercise::*, games::*, gregorian::*, labels::*, pinyin::*, *};
+use speculate2::*;
+
+fn is_logogram(character: char) -> bool {
+    matches!(character, '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}')
+}
+
+fn assert_transcribed(logograms: &str) {
+    let pinyin = to_pinyin(logograms, ToneStyle::Numbers);
+
+    assert!(
+        !pinyin.chars().any(is_logogram),
+        "Missing reading in: {} -> {}",
+        logograms,
+        pinyin
+    );
+}
+
+fn assert_fully_transcribed(value: &dyn ChineseFormat) {
+    for variant in [Variant::Simplified, Variant::Traditional] {
+        assert_transcribed(&value.to_chinese(variant).logograms);
+    }
+}
+
+speculate! {
+    describe "Pinyin transcription" {
+        it "should cover every logogram generated" {
+            fastrand::seed(90);
+            let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+            let gregorian = generator.gregorian();
+
+            for _ in 0..200 {
+                assert_fully_transcribed(&generator.integer(-100_000_000_000..=100_000_000_000).unwrap());
+                assert_fully_transcribed(&generator.count(0..=100_000_000_000).unwrap());
+                assert_fully_transcribed(&generator.fraction(1..=100, -100..=100).unwrap());
+                assert_fully_transcribed(&generator.decimal(-1000..=1000, 0..=3).unwrap());
+                assert_fully_transcribed(&generator.renminbi(
+                    RenminbiParams::default().with_style(CurrencyStyle::Financial)
+                ).unwrap());
+                assert_fully_transcribed(&generator.renminbi(
+                    RenminbiParams::default().with_style(CurrencyStyle::Everyday { formal: false })
+                ).unwrap());
+                assert_fully_transcribed(&gregorian.date(
+                    DateParams::default()
+                        .with_pattern(chinese_format::gregorian::DatePattern::YearMonthDayWeekDay)
+                        .with_week_format(chinese_format::gregorian::WeekFormat::LiBai)
+                ).unwrap());
+                assert_fully_transcribed(&gregorian.linear_time(LinearTimeParams::default().with_day_part(true)).unwrap());
+                assert_fully_transcribed(&gregorian.delta_time().unwrap());
+                assert_fully_transcribed(&gregorian.duration(DurationParams::default()).unwrap());
+                assert_fully_transcribed(&generator.ordinal(1..=100, OrdinalSuffix::Ge).unwrap());
+                assert_fully_transcribed(&generator.age(AgeProfile::Any));
+                assert_fully_transcribed(&generator.temperature(-30..=40, true).unwrap());
+                assert_fully_transcribed(&generator.arithmetic(ArithmeticParams::default().with_exact_division(false)).unwrap());
+                assert_fully_transcribed(&generator.mixed_number(MixedNumberParams::default()).unwrap());
+                assert_fully_transcribed(&generator.approximate(1..=1_000_000, ApproxStyle::Ji).unwrap());
+                assert_fully_transcribed(&generator.approximate(1..=1_000_000, ApproxStyle::Dayue).unwrap());
+                assert_fully_transcribed(&generator.count_rounded(0..=100_000, 100, Some(CountHedge::Ji)).unwrap());
+            }
+
+            for zodiac in Zodiac::ALL {
+                assert_fully_transcribed(&zodiac);
+            }
+
+            #[cfg(feature = "lunar")]
+            {
+                use chinese_rand::lunar::*;
+
+                for _ in 0..200 {
+                    assert_fully_transcribed(&generator.lunar().date(LunarDateParams {
+                        include_year: true,
+                        allow_leap_month: true,
+                    }).unwrap());
+                }
+
+                for term in SolarTerm::ALL {
+                    assert_fully_transcribed(&term);
+                }
+
+                for festival in Festival::ALL {
+                    assert_fully_transcribed(&festival);
+                    assert_fully_transcribed(&festival.date());
+                }
+            }
+        }
+
+        it "should cover the output of every generator" {
+            fastrand::seed(90);
+            let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+            let gregorian = generator.gregorian();
+            let financial = generator.financial();
+            let tricky_numbers = generator.tricky_numbers();
+
+            for _ in 0..200 {
+                assert_fully_transcribed(&generator.digit_sequence(1..=12).unwrap());
+                assert_fully_transcribed(&generator.fraction_with(FractionParams::default()).unwrap());
+                assert_fully_transcribed(&generator.ordinal(1..=100, OrdinalSuffix::Ming).unwrap());
+                assert_fully_transcribed(&generator.range_expression(
+                    RangeExpressionParams::default().with_connector(RangeConnector::Zhi)
+                ).unwrap());
+                assert_fully_transcribed(&generator.range_expression(
+                    RangeExpressionParams::default().with_connector(RangeConnector::Dao)
+                ).unwrap());
+                assert_fully_transcribed(&generator.approximate(1..=1_000_000, ApproxStyle::Zuoyou).unwrap());
+                assert_fully_transcribed(&generator.approximate(1..=1_000_000, ApproxStyle::Adjacent).unwrap());
+                assert_fully_transcribed(&generator.count_rounded(0..=100_000, 100, Some(CountHedge::Duo)).unwrap());
+                assert_fully_transcribed(&generator.phrased_count(0..=100_000, TwoPreference::Random).unwrap());
+                assert_fully_transcribed(&generator.arithmetic(ArithmeticParams::default()).unwrap());
+                assert_fully_transcribed(&generator.score_pair(0..=100).unwrap());
+                assert_fully_transcribed(&generator.exam_score_with(ExamScoreParams::default().with_half_points(true)).unwrap());
+                assert_fully_transcribed(&generator.share_statement(ShareParams::default()).unwrap());
+
+                for unit in [
+                    MeasureUnit::Millimeter,
+                    MeasureUnit::Centimeter,
+                    MeasureUnit::Meter,
+                    MeasureUnit::Kilometer,
+                    MeasureUnit::Gram,
+                    MeasureUnit::HalfKilogram,
+                    MeasureUnit::Kilogram,
+                    MeasureUnit::Milliliter,
+                    MeasureUnit::Liter,
+                ] {
+                    assert_fully_transcribed(&generator.measurement(
+                        MeasurementParams::default().with_unit(unit).with_decimal_places(2)
+                    ).unwrap());
+                }
+
+                for profile in [AgeProfile::Child, AgeProfile::Teen, AgeProfile::Adult, AgeProfile::Elderly] {
+                    assert_fully_transcribed(&generator.age(profile));
+                }
+
+                for kind in [TrickyKind::InternalZero, TrickyKind::TrailingZeros, TrickyKind::MagnitudeBoundary] {
+                    assert_fully_transcribed(&tricky_numbers.integer(kind).unwrap());
+                }
+
+                assert_fully_transcribed(&financial.integer(FinancialParams::default()).unwrap());
+                assert_fully_transcribed(&financial.renminbi(
+                    FinancialParams::default().with_ten_form(TenForm::Explicit)
+                ).unwrap());
+                assert_fully_transcribed(&generator.renminbi_amount(RenminbiParams::default()).unwrap().currency);
+                assert_fully_transcribed(&generator.monetary(MonetaryParams::default()).unwrap());
+
+                for negative_prefix in [NegativePrefix::Fu, NegativePrefix::Tui] {
+                    assert_fully_transcribed(&generator.signed_renminbi(
+                        SignedRenminbiParams::default()
+                            .with_sign(AmountSign::Negative)
+                            .with_negative_prefix(negative_prefix)
+                    ).unwrap());
+                }
+
+                for reading in [NumberReading::Positional, NumberReading::DigitByDigit { yao: true }] {
+                    assert_fully_transcribed(&generator.room_number(
+                        RoomNumberParams::default().with_reading(reading)
+                    ).unwrap());
+                    assert_fully_transcribed(&generator.bus_route(
+                        BusRouteParams::default().with_reading(reading)
+                    ).unwrap());
+                    assert_fully_transcribed(&generator.train_number(
+                        TrainNumberParams::default().with_reading(reading)
+                    ).unwrap());
+                }
+
+                assert_fully_transcribed(&generator.floor(1..=30).unwrap());
+                assert_fully_transcribed(&generator.dice(2, 6).unwrap());
+                assert_fully_transcribed(&generator.lottery_ticket(LotterySpec::default()).unwrap());
+
+                assert_fully_transcribed(&gregorian.year(1900..=2100).unwrap());
+                assert_fully_transcribed(&gregorian.month());
+                assert_fully_transcribed(&gregorian.day(true));
+                assert_fully_transcribed(&gregorian.day(false));
+                assert_fully_transcribed(&gregorian.week_day(chinese_format::gregorian::WeekFormat::XingQi));
+                assert_fully_transcribed(&gregorian.week_day(chinese_format::gregorian::WeekFormat::Zhou));
+                assert_fully_transcribed(&gregorian.hour24());
+                assert_fully_transcribed(&gregorian.hour12());
+                assert_fully_transcribed(&gregorian.day_part());
+                assert_fully_transcribed(&gregorian.minute());
+                assert_fully_transcribed(&gregorian.second());
+                assert_fully_transcribed(&gregorian.linear_time(
+                    LinearTimeParams::default().with_second(true).with_on_the_hour(OnTheHour::Only)
+                ).unwrap());
+
+                for style in [DeltaStyle::Guo, DeltaStyle::Cha] {
+                    assert_fully_transcribed(&gregorian.delta_time_with(
+                        DeltaTimeParams::default().with_style(style).with_on_the_hour(true)
+                    ).unwrap());
+                }
+
+                assert_fully_transcribed(&gregorian.duration(
+                    DurationParams::default().with_minutes(true).with_seconds(true)
+                ).unwrap());
+                assert_fully_transcribed(&gregorian.colloquial_time(
+                    ColloquialTimeParams::default().with_colloquial_percent(100).with_three_quarters(true)
+                ).unwrap());
+                assert_fully_transcribed(&gregorian.styled_linear_time(
+                    LinearTimeParams::default().with_day_part(true),
+                    MinuteStyle::default().with_zheng_on_the_hour(true).with_zero_before_single_digit(true)
+                ).unwrap());
+                assert_fully_transcribed(&gregorian.zoned_time(RegionLabel::beijing(), LinearTimeParams::default()).unwrap());
+
+                let time_difference = gregorian.time_difference(TimeDifferenceParams::default()).unwrap();
+                assert_fully_transcribed(&time_difference.local);
+                assert_fully_transcribed(&time_difference.remote);
+
+                assert_fully_transcribed(&gregorian.stopwatch(
+                    StopwatchParams::default().with_precision(StopwatchPrecision::Milliseconds)
+                ).unwrap());
+                assert_fully_transcribed(&gregorian.appointment(AppointmentParams::default()).unwrap());
+
+                let date_pair = gregorian.date_pair(DatePairParams::default()).unwrap();
+                assert_fully_transcribed(&date_pair.first_date);
+                assert_fully_transcribed(&date_pair.second_date);
+                assert_fully_transcribed(&date_pair.offset);
+
+                assert_fully_transcribed(&gregorian.zodiac_year(1900..=2100).unwrap());
+                assert_fully_transcribed(&gregorian.short_year(1900..=2100).unwrap());
+                assert_fully_transcribed(&gregorian.birth_year(Generation(1980)));
+                assert_fully_transcribed(&Generation(2000));
+                assert_fully_transcribed(&gregorian.decade(1800..=2099, true).unwrap());
+                assert_fully_transcribed(&gregorian.century(1..=21).unwrap());
+
+                assert_fully_transcribed(&gregorian.holiday(2000..=2030, &HolidayTable::default()).unwrap());
+
+                for variant in [Variant::Simplified, Variant::Traditional] {
+                    for category in [QaCategory::Date, QaCategory::Time, QaCategory::Price] {
+                        let qa_pair = generator.qa_pair(category, &QaParams::default(), variant).unwrap();
+                        assert_transcribed(&qa_pair.question);
+                        assert_transcribed(&qa_pair.answer);
+                    }
+
+                    for source in [
+                        QuestionSource::Date { year_range: 1900..=2100, formal: true },
+                        QuestionSource::Time(LinearTimeParams::default().with_day_part(true)),
+                        QuestionSource::Price(RenminbiParams::default()),
+                    ] {
+                        let question_form = generator.question_form(&source, variant).unwrap();
+                        assert_transcribed(&question_form.question);
+                        assert_transcribed(&question_form.answer);
+                    }
+                }
+
+                #[cfg(feature = "scenario")]
+                {
+                    use chinese_rand::scenario::*;
+
+                    assert_fully_transcribed(&generator.person_profile(PersonParams::default().with_body(true)).unwrap());
+                    assert_fully_transcribed(&generator.receipt(ReceiptParams::default()).unwrap());
+                    assert_fully_transcribed(&generator.schedule_entry(ScheduleParams::default()).unwrap());
+                    assert_fully_transcribed(&generator.transaction(TransactionParams::default()).unwrap());
+                    assert_fully_transcribed(&generator.weather_report(WeatherParams::default()).unwrap());
+                }
+            }
+        }
+    }
+}
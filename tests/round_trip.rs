@@ -0,0 +1,116 @@
+#![cfg(all(feature = "parse", feature = "currency", feature = "gregorian"))]
+
+use chinese_format::{currency::CurrencyStyle, gregorian::LinearTime, ChineseFormat, Variant};
+use chinese_rand::{gregorian::*, parse, *};
+use speculate2::*;
+
+const ITERATIONS: usize = 300;
+
+const VARIANTS: [Variant; 2] = [Variant::Simplified, Variant::Traditional];
+
+fn render(value: &dyn ChineseFormat, variant: Variant) -> String {
+    value.to_chinese(variant).logograms
+}
+
+fn without_zero_second(time: LinearTime) -> LinearTime {
+    LinearTime {
+        second: time.second.filter(|second| u8::from(*second) > 0),
+        ..time
+    }
+}
+
+speculate! {
+    describe "Round trip - generating, rendering and parsing" {
+        before {
+            let generator = ChineseFormatGenerator::new(FastRandGenerator::with_seed(90));
+        }
+
+        it "should preserve integers" {
+            for _ in 0..ITERATIONS {
+                let integer = generator.integer(i128::MIN..=i128::MAX).unwrap();
+                let small_integer = generator.integer(-100_000..=100_000).unwrap();
+
+                for variant in VARIANTS {
+                    assert_eq!(parse::integer(&render(&integer, variant)), Some(integer));
+                    assert_eq!(parse::integer(&render(&small_integer, variant)), Some(small_integer));
+                }
+            }
+        }
+
+        it "should preserve fractions" {
+            for _ in 0..ITERATIONS {
+                let fraction = generator.fraction(1..=1000, -1000..=1000).unwrap();
+
+                if fraction.numerator() == 0 {
+                    continue;
+                }
+
+                for variant in VARIANTS {
+                    assert_eq!(parse::fraction(&render(&fraction, variant)), Some(fraction));
+                }
+            }
+        }
+
+        it "should preserve calendar dates" {
+            for _ in 0..ITERATIONS {
+                let date = generator.gregorian().calendar_date(1900..=2100).unwrap();
+
+                for variant in VARIANTS {
+                    let rendered = render(&date.to_date(false, None), variant);
+
+                    assert_eq!(parse::calendar_date(&rendered), Some(date));
+                }
+            }
+        }
+
+        it "should preserve linear times" {
+            for _ in 0..ITERATIONS {
+                for day_part in [false, true] {
+                    let time = generator.gregorian().linear_time(
+                        LinearTimeParams::default()
+                            .with_day_part(day_part)
+                            .with_second(true)
+                    ).unwrap();
+
+                    for variant in VARIANTS {
+                        let parsed = parse::linear_time(&render(&time, variant)).map(without_zero_second);
+
+                        assert_eq!(parsed, Some(without_zero_second(time)));
+                    }
+                }
+            }
+        }
+
+        it "should preserve Renminbi amounts" {
+            for _ in 0..ITERATIONS {
+                for style in [
+                    CurrencyStyle::Everyday { formal: true },
+                    CurrencyStyle::Everyday { formal: false },
+                    CurrencyStyle::Financial,
+                ] {
+                    let amount = generator.renminbi(
+                        RenminbiParams::default()
+                            .with_style(style)
+                            .with_yuan_range(0..=1_000_000)
+                    ).unwrap();
+
+                    for variant in VARIANTS {
+                        let rendered = render(&amount, variant);
+                        let parsed = parse::renminbi(&rendered).unwrap_or_else(|| panic!("Unparsable: {}", rendered));
+
+                        assert_eq!(
+                            (parsed.yuan(), parsed.dimes(), parsed.cents()),
+                            (amount.yuan(), amount.dimes(), amount.cents()),
+                            "Mismatch for: {}",
+                            rendered
+                        );
+
+                        if amount.yuan() > 0 || style == CurrencyStyle::Financial {
+                            assert_eq!(parsed.style(), style, "Style mismatch for: {}", rendered);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
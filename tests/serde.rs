@@ -0,0 +1,209 @@
+#![cfg(all(feature = "serde", feature = "currency", feature = "gregorian"))]
+
+use chinese_format::{
+    currency::CurrencyStyle,
+    gregorian::{DatePattern, DayPart, WeekFormat},
+};
+use chinese_rand::{corpus::*, gregorian::*, *};
+use speculate2::*;
+
+speculate! {
+    describe "Parameter structs" {
+        it "should deserialize DateParams from JSON" {
+            let params: DateParams = serde_json::from_str(r#"{
+                "pattern": "YearMonthDayWeekDay",
+                "year_range": { "start": 2000, "end": 2019 },
+                "formal": true,
+                "week_format": "Zhou"
+            }"#).unwrap();
+
+            assert_eq!(params, DateParams {
+                pattern: PatternChoice::Fixed(DatePattern::YearMonthDayWeekDay),
+                year_range: Some(2000..=2019),
+                formal: true,
+                week_format: Some(WeekFormat::Zhou),
+
+                holiday_filter: HolidayFilter::Any,
+
+                week_day_filter: WeekDayFilter::Any,
+
+                generation: None
+            });
+        }
+
+        it "should deserialize a random PatternChoice in DateParams" {
+            let params: DateParams = serde_json::from_str(r#"{
+                "pattern": ["YearMonthDay", "MonthDay"],
+                "year_range": null,
+                "formal": false,
+                "week_format": null
+            }"#).unwrap();
+
+            assert_eq!(
+                params.pattern,
+                PatternChoice::RandomFrom(vec![DatePattern::YearMonthDay, DatePattern::MonthDay])
+            );
+
+            let json = serde_json::to_string(&params).unwrap();
+
+            assert_eq!(serde_json::from_str::<DateParams>(&json).unwrap(), params);
+        }
+
+        it "should round-trip DateParams without optional values" {
+            let params = DateParams {
+                pattern: PatternChoice::Fixed(DatePattern::Month),
+                year_range: None,
+                formal: false,
+                week_format: None,
+
+                holiday_filter: HolidayFilter::Any,
+
+                week_day_filter: WeekDayFilter::Any,
+
+                generation: None
+            };
+
+            let json = serde_json::to_string(&params).unwrap();
+
+            assert_eq!(serde_json::from_str::<DateParams>(&json).unwrap(), params);
+        }
+
+        it "should deserialize LinearTimeParams without pinned day part" {
+            let params: LinearTimeParams = serde_json::from_str(r#"{
+                "day_part": true,
+                "include_second": true
+            }"#).unwrap();
+
+            assert_eq!(params, LinearTimeParams {
+                day_part: true,
+                include_second: true,
+                pinned_day_part: None,
+                on_the_hour: OnTheHour::Allowed,
+                pinned_hour: None,
+                pinned_minute: None,
+                second_step: 1
+            });
+        }
+
+        it "should round-trip LinearTimeParams" {
+            let params = LinearTimeParams {
+                day_part: true,
+                include_second: false,
+                pinned_day_part: Some(DayPart::Afternoon),
+                on_the_hour: OnTheHour::Excluded,
+                pinned_hour: None,
+                pinned_minute: None,
+                second_step: 1
+            };
+
+            let json = serde_json::to_string(&params).unwrap();
+
+            assert_eq!(serde_json::from_str::<LinearTimeParams>(&json).unwrap(), params);
+        }
+
+        it "should deserialize RenminbiParams from JSON" {
+            let params: RenminbiParams = serde_json::from_str(r#"{
+                "style": { "Everyday": { "formal": false } },
+                "yuan_range": { "start": 0, "end": 500 },
+                "include_dimes": true,
+                "include_cents": false
+            }"#).unwrap();
+
+            assert_eq!(params, RenminbiParams {
+                style: Some(CurrencyStyle::Everyday { formal: false }),
+                yuan_range: 0..=500,
+                yuan_step: 1,
+                include_dimes: true,
+                include_cents: false,
+                nonzero_minor_units: false,
+                forbid_round_amounts: false
+            });
+        }
+
+        it "should deserialize a partial GeneratorConfig from JSON" {
+            let config: GeneratorConfig = serde_json::from_str(r#"{
+                "currency_style": "Financial",
+                "variant": "Traditional"
+            }"#).unwrap();
+
+            assert_eq!(config, GeneratorConfig {
+                year_range: DEFAULT_YEAR_RANGE,
+                currency_style: Some(CurrencyStyle::Financial),
+                week_format: None,
+                variant: Some(chinese_format::Variant::Traditional)
+            });
+        }
+
+        it "should round-trip GeneratorConfig" {
+            let config = GeneratorConfig::default()
+                .with_year_range(2000..=2030)
+                .with_week_format(WeekFormat::LiBai);
+
+            let json = serde_json::to_string(&config).unwrap();
+
+            assert_eq!(serde_json::from_str::<GeneratorConfig>(&json).unwrap(), config);
+        }
+
+        it "should round-trip Level" {
+            let json = serde_json::to_string(&Level::Advanced).unwrap();
+
+            assert_eq!(json, r#""Advanced""#);
+            assert_eq!(serde_json::from_str::<Level>(&json).unwrap(), Level::Advanced);
+        }
+
+        it "should round-trip CalendarDate" {
+            let date = CalendarDate::try_new(2024, 2, 29).unwrap();
+
+            let json = serde_json::to_string(&date).unwrap();
+
+            assert_eq!(json, r#"{"year":2024,"month":2,"day":29}"#);
+            assert_eq!(serde_json::from_str::<CalendarDate>(&json).unwrap(), date);
+        }
+
+        it "should reject an inconsistent CalendarDate" {
+            let result = serde_json::from_str::<CalendarDate>(r#"{"year":2023,"month":2,"day":29}"#);
+
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "Invalid params: Invalid calendar date: 2023-02-29"
+            );
+        }
+    }
+
+    describe "Corpus" {
+        it "should write lines parsable back" {
+            let generator = ChineseFormatGenerator::new(FastRandGenerator::new());
+
+            let params = CorpusParams::default()
+                .with_mix(
+                    ["integer", "fraction", "renminbi", "date", "linear_time"]
+                        .into_iter()
+                        .map(|kind| CorpusEntry {
+                            item: PlanItem::from_kind(kind).unwrap(),
+                            weight: 1,
+                        })
+                        .collect(),
+                )
+                .with_size(200)
+                .with_seed(90);
+
+            let mut output = Vec::new();
+            generator.write_corpus(&params, &mut output).unwrap();
+            let output = String::from_utf8(output).unwrap();
+
+            let lines: Vec<&str> = output.lines().collect();
+            assert_eq!(lines.len(), 200);
+
+            for line in lines {
+                let corpus_line: CorpusLine = serde_json::from_str(line).unwrap();
+
+                assert!(params.mix.iter().any(|entry| entry.item.kind() == corpus_line.kind));
+                assert!(!corpus_line.value.is_empty());
+                assert!(!corpus_line.simplified.is_empty());
+                assert!(!corpus_line.traditional.is_empty());
+
+                assert_eq!(serde_json::to_string(&corpus_line).unwrap(), line);
+            }
+        }
+    }
+}